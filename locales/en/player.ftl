@@ -0,0 +1,299 @@
+### Texts for participants. Telegram HTML markup, substitutions are escaped in code.
+
+## Common
+
+enter-message-text = 📝 Please send me a text message
+use-keyboard = Please use the buttons under the question to answer. It's easier that way!
+permission-denied =
+    <b>❌ Access denied</b>
+    You don't have permission to use this command.
+internal-error =
+    ⚠️ <b>Unknown error</b>
+    Something unexpected happened. Please try again later or let the organizers know (see the bot description).
+error-not-in-team =
+    ❌ <b>You are not in a team</b>
+    You need to be in a team to play tracks and book the final.
+error-already-in-team = ❌ <b>You are already in a team</b>
+error-team-is-full = ❌ <b>The team has no free places left</b>
+error-track-can-not-be-started = ❌ <b>This track can't be started right now</b>
+error-not-found =
+    ❌ <b>Not found</b>
+    Looks like it was removed or changed. Please start again from the menu.
+error-invalid-value =
+    ❌ <b>Couldn't read the message</b>
+    Check that everything is written correctly and try again.
+error-slot-unavailable =
+    ❌ <b>This time is no longer available</b>
+    Please choose another time.
+error-slot-already-reserved = ❌ <b>Your team has already booked the final</b>
+error-slot-not-reserved = ❌ <b>Your team hasn't booked the final</b>
+page-footer = Page { $page } of { $pages }, { $total } in total
+no-username = (no username)
+day-month = { $month ->
+    [1] January
+    [2] February
+    [3] March
+    [4] April
+    [5] May
+    [6] June
+    [7] July
+    [8] August
+    [9] September
+    [10] October
+    [11] November
+   *[12] December
+} { $day }
+
+## Registration
+
+registration-closed =
+    🥺 <b>Unfortunately, registration is already closed!</b>
+
+    👉🏻 <i>Registration was open until { $date }, but don't be upset. Next year you can become an organizer of the “Legends of Bauman University” yourself — join the <a href="https://forms.yandex.ru/u/6897839490fa7b44d1601991">Student Council team</a> and together we'll make the new “Legends” even louder!</i>
+registration-not-opened =
+    ⏳ <b>Registration hasn't started yet!</b>
+
+    <i>It opens on { $date }, come back to us then!</i>
+
+## Menu and team
+
+menu-text =
+    📲 <b>Main menu</b>
+    Choose an option from the menu below.
+unknown-menu-option =
+    ❌ <b>Unknown command</b>
+    Sorry, I don't understand this request. Please choose one of the options in the menu.
+section-closed =
+    🔒 <b>This section is closed now</b>
+    The organizers have moved on to the next phase of the event. Back to the menu!
+operation-cancelled = ↩️ Cancelled
+nothing-to-cancel = ℹ️ There is nothing to cancel right now
+state-reset =
+    🔄 <b>The bot has been updated</b>
+    The screen you were on has changed or no longer exists. Back to the menu!
+state-reset-by-staff =
+    🔄 <b>The organizers have sent you back to the menu</b>
+    If something went wrong, just start over.
+button-unavailable = This button no longer works
+send-approval-exit-team = ❓ Do you really want to leave your current team?
+successful-exit-team =
+    👋 <b>You left the team</b>
+    You have left the team. Now you can join a new one using an invite code.
+my-team =
+    📊 <b>Team information:</b>
+    • Name: { $name }
+    • Captain: @{ $captain }
+    • Members ({ $size }/{ $max_size }):
+    { $members }
+
+## Feedback
+
+prompt-feedback = ✍️ Now you can write a comment to the organizers!
+feedback-sent =
+    📩 <b>Great, your comment has been sent!</b>
+
+    <i>We will read it soon!</i>
+feedback-reply-to-author =
+    💬 <b>The organizers replied to your comment:</b>
+
+    { $text }
+
+## Characters
+
+prompt-character-name =
+    ☺️ <b>Great choice!</b>
+
+    Meet the great scientists, engineers and designers who started their journey here, within the walls of Bauman Moscow State Technical University.
+
+    <b><i>Who would you like to know better?</i></b> Choose a person from the list or type a name.
+characters-not-found = 🤔 We couldn't find anyone with that name. Try spelling it differently or choose a person from the list.
+favorite-characters-empty = ⭐ Your favorites are empty for now. You can add a person to favorites with the button on their card.
+characters-found = 🔎 <b>Here is who we found:</b>
+character-links = 👤 <b>Learn more about the people behind this task:</b>
+character-page-title = 📚 <b>People</b>
+favorite-character-page-title = ⭐ <b>Favorite people</b>
+character-collection = 🧩 Collected { $collected } of { $total }
+characters-unlocked = 🔓 <b>New person in your collection:</b> { $names }
+character =
+    <b>{ $index }. { $name }</b>
+
+    <blockquote>“{ $quote }”</blockquote>
+
+    { $facts }<b><i>{ $legacy }</i></b>
+locked-character =
+    <b>{ $index }. ???</b>
+
+    🔒 This person is still hidden. The card will open once your team { $condition }.
+locked-character-task = solves task { $task_id }
+locked-character-track = completes the “{ $track }” track
+
+## Tracks and tasks
+
+prompt-track = ✨ <b>Choose a track</b>
+prompt-track-start =
+    As soon as you start this track, a timer will begin showing how fast you can level up your skills!
+
+    <b>Your goals:</b>
+    💡 Answer questions
+    💡 Choose the right answers
+    💡 Send photos
+    💡 Act together and fast
+
+    📸 <b>Important:</b>
+    Remember that checking photos takes time — don't put them off!
+
+    <i>Are you sure you're ready to start?</i>
+track-not-started =
+    ☝🏻<b>Wait! Your team hasn't started this track yet.</b>
+
+    ⭐️ It will open as soon as your team agrees and the captain starts it!
+track-timer-started = Time since the track started: { $elapsed }
+track-timer-finished = Track time: { $start } - { $finish } ({ $duration })
+track-menu =
+    <b>{ $title }</b>
+
+    { $description }
+
+    <b>Your progress</b>
+    Track: <i>{ $name }</i>
+    { $progress }
+
+    <b>✨ ─── TIMER ─── ✨</b>
+    🕒 { $timer }
+prompt-available-task =
+    📲 <b>Choose a task from the list</b>
+    🆕 — new, 🔁 — answered wrong before, ⏳ — photo under review
+prompt-completed-task = 📲 <b>Choose a task from the list</b>
+all-track-tasks-completed =
+    🎉 <b>Track completed!</b>
+    Congratulations! You have completed all tasks of this track. Now you can move on to other tracks and continue your journey through the “Legends of Bauman University”!
+no-completed-tasks =
+    📭 <b>Empty</b>
+    There are no completed tasks here yet.
+task-correct-answer-1 = ✅ <b>Right again!</b> You're not just a participant anymore — you're a master. Every step brings you closer to legend.
+task-correct-answer-2 = ✅ <b>You did it!</b> And you did it with dignity. Moments like this make us stronger!
+task-correct-answer-3 = ✅ <b>Correct!</b> Bauman University reveals its secrets only to the chosen few!
+task-correct-answer-4 = ✅ <b>Right!</b> You feel the spirit of Bauman University like no one else!
+task-correct-answer-5 = ✅ <b>Exactly!</b> You read the history of Bauman University like an open book!
+task-invalid-answer-1 = ❌ Missed, but every miss is a step closer to the goal. Go on, you can do it!
+task-invalid-answer-2 = ❌ Almost! But if one path turned out to be a dead end, another one surely leads to success. Shall we try again?
+# Duplicated on purpose to make it come up more often
+task-invalid-answer-3 = { task-invalid-answer-2 }
+task-invalid-answer-4 = ❌ Close, but not quite. Now your brain is working at full speed — give it one more try!
+task-invalid-answer-5 = ❌ That's not it, but you can almost feel the right answer, right? Don't stop!
+task-answer-is-close = 🤏 Almost! Check the spelling — looks like there's a typo.
+task-hint = 💡 <b>Hint:</b> { $hint }
+partial-credit = 🌓 The answer is partially correct — <b>{ $points }</b> pts so far. Try again to get the maximum!
+score-base = <b>Task points:</b> { $points }
+score-penalty = ➖ Attempts penalty: { $points }
+score-time-bonus = ➕ Speed bonus: { $points }
+score-total = <b>Total:</b> { $points }
+please-send-location = 📍 Tap the button below to send your location.
+select-at-least-one-option = ☝🏻 Select at least one option first, then tap “Done”.
+multi-choice-hint = Select all correct options and tap “Done”.
+ordering-hint = Tap the options in the correct order.
+number-hint = The answer is a number.
+location-hint = Get to the right place and send your location.
+selected-options =
+    <b>Selected:</b>
+    { $options }
+ordered-options =
+    <b>Your order:</b>
+    { $options }
+task-explanation =
+    { $question }
+
+    <b>Correct answer: </b>{ $answers }
+
+    { $explanation }
+photo-task-accepted = Great! The photo is being checked, and you can move on 📸
+photo-answer-approved =
+    📸 <b>The photo for task { $number } has been accepted!</b>
+    Points awarded: { $points }
+photo-answer-rejected =
+    📸 <b>The photo for task { $number } was not accepted</b>
+    Try sending another one!
+please-send-photo = ☝🏻 Only a photo is accepted here, and it looks like you sent something else!
+
+## Final reservation
+
+prompt-accept-final = ⭐️ <b><i>Ready for the final?</i></b>
+prompt-slot-time =
+    🕐 <b>Choose a time for the final</b>
+
+    Please choose a convenient time when your team can come to the in-person final.
+
+    ⏱ <i>The event takes no more than an hour.</i>
+
+    <i>Available slots:</i>
+prompt-slot-places =
+    👥 <b>Enter the number of participants for the final</b>
+    Please enter how many people from your team plan to come to the in-person final.
+
+    💡 <i>Your team currently has { $team_size } { $team_size ->
+        [one] member
+       *[other] members
+    }.</i>
+invalid-team-places =
+    ❌ <b>Invalid format</b>
+
+    Please enter a plain number. For example: 5
+places-greater-than-team =
+    ❌ <b>The number is too large</b>
+
+    The number of participants at the final cannot exceed the size of your team ({ $team_size } { $team_size ->
+        [one] person
+       *[other] people
+    }). Please enter a valid number.
+no-slots =
+    ⏰ <b>No places at this time</b>
+    Unfortunately, there are no slots left at the selected time for { $team_size } { $team_size ->
+        [one] person
+       *[other] people
+    }.
+
+    <i>Please choose another time for the final.</i>
+slot-successfully-reserved =
+    Great! Your reservation is confirmed.
+
+    ⏰ The final takes place on { $date } at { $time } in room { $site } { $venue }
+
+    🪧 <b><i>We look forward to seeing your team to finish this journey together. See you!</i></b>
+prompt-cancel-reservation-reason =
+    ❓ <b>Please tell us why you are cancelling</b>
+
+    • 🗓 I want to change the time
+    • 👋 I can't come to the final
+
+    💡 <i>You can freely rebook another time until { $deadline } today.</i>
+final-absence-accepted =
+    👋 <b>We're very sorry you can't join us at the final</b>
+
+    We will miss you! But we fully understand that things happen.
+
+    🗝 <b>Most importantly</b>, you are already part of the “Legends of Bauman University” and have completed the main stage! That's a huge achievement ✨
+
+    <i>We hope to see you at our other events! Stay in touch!</i>
+
+## Buttons
+
+btn-back = Back
+btn-start = Start
+btn-tracks = Tracks
+btn-my-team = My team
+btn-characters = People
+btn-give-feedback = Comment
+btn-reserve-slot = Book the final
+btn-cancel-reservation = Cancel booking
+btn-available-tasks = Available tasks
+btn-completed-tasks = Solved tasks
+btn-task = Task { $number }
+btn-done = Done
+btn-reset = Reset
+btn-send-location = 📍 Send location
+btn-can-not-accept-final = I can't come to the final
+btn-change-reservation-time = Change the time
+btn-all-characters = 📚 All people
+btn-favorite-characters = ⭐ Favorites
+btn-character-in-favorites = ⭐ In favorites
+btn-add-character-to-favorites = ☆ Add to favorites
@@ -0,0 +1,220 @@
+### Texts for organizers: administration commands and staff chat notifications.
+
+## Media
+
+invalid-upload-command-usage =
+    <b>❌ Invalid command format</b>
+    Usage:
+    <code>/upload key</code>
+invalid-media-command-usage =
+    <b>❌ Invalid command format</b>
+    Usage:
+    <code>/media key</code>
+invalid-media-usage-command-usage =
+    <b>❌ Invalid command format</b>
+    Usage:
+    <code>/mediausage key</code>
+invalid-media-rename-command-usage =
+    <b>❌ Invalid command format</b>
+    Usage:
+    <code>/mediarename key new_key</code>
+invalid-media-delete-command-usage =
+    <b>❌ Invalid command format</b>
+    Usage:
+    <code>/mediadelete key</code>
+prompt-media =
+    📤 <b>File upload</b>
+    Send a file (image, video, video note, audio, voice message or document) to the chat to get its FileID. An album is saved under the keys <code>key</code>, <code>key_2</code> and so on.
+unknown-media-format =
+    ❌ <b>Unsupported format</b>
+    Send an image, video, video note, audio, voice message or document.
+media-uploaded =
+    ✅ <b>FileID received!</b>
+    <code>{ $file_id }</code>
+media-group-uploaded =
+    ✅ <b>Album <code>{ $media }</code> uploaded</b>
+    Files: { $count }
+media-not-found = <b>❌ Media <code>{ $media }</code> not found</b>
+media-library-is-empty = 🗂 No media found
+media-references-are-valid = ✅ All media references are fine
+media-type-image = image
+media-type-video-note = video note
+media-type-audio = audio
+media-type-voice = voice message
+media-type-video = video
+media-type-document = document
+media-page-title = 🗂 <b>Media</b>
+media-page-search-title = 🔎 <b>Media matching</b> <code>{ $query }</code>
+media-usage-track = track <code>{ $track }</code>
+media-usage-task = task { $task_id }
+media-usage-character = character <code>{ $character }</code>
+media-not-used = <code>{ $media }</code> is not used anywhere
+media-usages =
+    <code>{ $media }</code> is used in:
+    { $usages }
+media-renamed = ✅ <code>{ $media }</code> renamed to <code>{ $new_media }</code>
+media-already-exists = <b>❌ Media <code>{ $media }</code> already exists</b>
+media-deleted = 🗑 <code>{ $media }</code> deleted
+media-in-use =
+    <b>❌ Media in use cannot be deleted</b>
+    { $usages }
+dangling-media-references =
+    <b>⚠️ References to missing media ({ $count }):</b>
+    { $references }
+
+## Task graph
+
+invalid-graph-command-usage =
+    <b>❌ Invalid command format</b>
+    Usage:
+    <code>/graph dot</code> or <code>/graph mermaid</code>
+task-graph-is-valid = ✅ The task dependency graph is fine
+task-graph-issues =
+    <b>⚠️ Problems in the task dependency graph ({ $count }):</b>
+    { $issues }
+task-graph-missing-dependency = <code>{ $track }</code>: task { $task_id } depends on missing task { $dependency }
+task-graph-cross-track-dependency = <code>{ $track }</code>: task { $task_id } depends on task { $dependency } from track <code>{ $dependency_track }</code>
+task-graph-cycle = <code>{ $track }</code>: cycle { $cycle }
+task-graph-unreachable = <code>{ $track }</code>: task { $task_id } can never be opened
+
+## Feedback
+
+invalid-inbox-command-usage =
+    <b>❌ Invalid command format</b>
+    Usage:
+    <code>/inbox [new|answered|archived|all]</code>
+feedback-inbox-is-empty = 📭 No messages
+prompt-feedback-reply = ✍️ Write a reply, the author will receive it from the bot
+feedback-is-archived = <b>❌ The message is archived and cannot be replied to</b>
+feedback-status-new = 🆕 new
+feedback-status-answered = ✅ answered
+feedback-status-archived = 🗄 archived
+feedback-inbox-title = 📬 <b>Feedback</b>
+feedback-inbox-status-title = 📬 <b>Feedback</b> ({ $status })
+feedback-thread-reply = ↪️ <i>Reply { $date }:</i>
+feedback-reply-sent = 📨 Reply to <b>#{ $id }</b> sent
+feedback-reply-not-delivered =
+    <b>❌ Unable to deliver the reply to #{ $id }</b>
+    The author may have blocked the bot
+feedback-archived = 🗄 <b>#{ $id }</b> moved to the archive
+btn-reply-feedback = ↪️ Reply
+btn-archive-feedback = 🗄 Archive
+
+## Roles
+
+role-superadmin = superadmin
+role-editor = content editor
+role-moderator = photo moderator
+role-staff = final site staff
+role-analyst = analyst
+invalid-role-command-usage =
+    <b>❌ Invalid command format</b>
+    Usage:
+    <code>/{ $command } user_id role</code>
+
+    Roles:
+    { $roles }
+can-not-revoke-own-role =
+    <b>❌ You can't revoke your own superadmin role</b>
+    Ask another superadmin to do it.
+role-granted = ✅ User <code>{ $user }</code> was granted the “{ $role }” role
+role-already-granted = ℹ️ User <code>{ $user }</code> already has the “{ $role }” role
+role-revoked = 🗑 The “{ $role }” role was revoked from user <code>{ $user }</code>
+role-not-granted = ℹ️ User <code>{ $user }</code> doesn't have the “{ $role }” role
+role-user-not-found = <b>❌ User <code>{ $user }</code> is not registered in the bot</b>
+staff-roles-empty = 👥 Nobody has any roles yet
+staff-roles =
+    👥 <b>Organizers</b>
+
+    { $staff }
+
+## Audit log
+
+audit-log-is-empty = 📜 No log entries found
+audit-action-media_upload = media upload
+audit-action-media_rename = media rename
+audit-action-media_delete = media deletion
+audit-action-feedback_reply = feedback reply
+audit-action-feedback_archive = feedback archiving
+audit-action-photo_review = photo answer review
+audit-action-role_grant = role grant
+audit-action-role_revoke = role revocation
+audit-action-phase_switch = phase switch
+audit-action-dialogue_reset = dialogue reset
+invalid-audit-command-usage =
+    <b>❌ Invalid command format</b>
+    Usage:
+    <code>/audit [actor=user_id] [action=code] [target=object]</code>
+
+    Actions:
+    { $actions }
+audit-log-title = 📜 <b>Audit log</b>
+audit-change-before = was: <code>{ $before }</code>
+audit-change-after = now: <code>{ $after }</code>
+
+## Event phases
+
+event-phase-registration = registration
+event-phase-quest = quest
+event-phase-final_booking = final booking
+event-phase-post_event = after the event
+menu-item-tracks = tracks
+menu-item-team = team
+menu-item-characters = people
+menu-item-final_reservation = final booking
+menu-item-feedback = feedback
+menu-items-empty = empty menu
+event-phases =
+    🗓 <b>Event phases</b>
+
+    { $phases }
+
+    Switch: <code>/phase code</code>
+invalid-phase-command-usage =
+    <b>❌ Invalid command format</b>
+    Usage:
+    <code>/phase [code]</code>
+
+    Phases:
+    { $phases }
+event-phase-switched = ✅ Switched to the “{ $phase }” phase. Players will see the new menu the next time they message the bot.
+event-phase-already-active = ℹ️ The “{ $phase }” phase is already active
+
+## Dialogue reset
+
+invalid-reset-command-usage =
+    <b>❌ Invalid command format</b>
+    Usage:
+    <code>/reset user_id</code>
+dialogue-reset = ✅ Dialogue of user <code>{ $user }</code> has been reset
+
+## Staff chat
+
+staff-user-team = team “{ $team }”
+staff-user-no-team = no team
+staff-feedback =
+    📩 <b>New comment</b>
+    { $user }
+
+    { $text }
+
+    <i>Reply via /inbox</i>
+staff-photo-answer =
+    📸 <b>Photo answer awaits review</b>
+    { $user }
+    Track <code>{ $track }</code>, task { $number }
+staff-photo-answer-approved = ✅ Accepted ({ $points } pts), reviewed by { $reviewer }
+staff-photo-answer-rejected = ❌ Rejected, reviewed by { $reviewer }
+photo-answer-already-reviewed = This answer has already been reviewed
+btn-approve-photo = ✅ Accept
+btn-reject-photo = ❌ Reject
+staff-reservation-cancelled =
+    🚫 <b>Final reservation cancelled</b>
+    { $user }
+    Reason: { $rescheduled ->
+        [yes] wants to choose another time
+       *[no] can't come to the final
+    }
+staff-internal-error =
+    ⚠️ <b>Bot error</b>
+    <code>{ $error }</code>
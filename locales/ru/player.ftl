@@ -0,0 +1,291 @@
+### Тексты для участников. HTML-разметка Telegram, подстановки экранируются в коде.
+
+## Общее
+
+enter-message-text = 📝 Напиши мне текстовое сообщение
+use-keyboard = Пожалуйста, используй кнопки под вопросом для ответа. Так будет удобнее!
+permission-denied =
+    <b>❌ Доступ запрещен</b>
+    У вас недостаточно прав для использования этой команды.
+internal-error =
+    ⚠️ <b>Неизвестная ошибка</b>
+    Произошла непредвиденная ошибка. Пожалуйста, попробуй повторить действие позже или сообщи об этом организаторам (см. описание бота).
+error-not-in-team =
+    ❌ <b>Ты не в команде</b>
+    Чтобы проходить треки и записываться на финал, нужно состоять в команде.
+error-already-in-team = ❌ <b>Ты уже состоишь в команде</b>
+error-team-is-full = ❌ <b>В команде больше нет мест</b>
+error-track-can-not-be-started = ❌ <b>Этот трек сейчас нельзя начать</b>
+error-not-found =
+    ❌ <b>Не найдено</b>
+    Похоже, это уже удалили или изменили. Попробуй начать заново из меню.
+error-invalid-value =
+    ❌ <b>Не получилось разобрать сообщение</b>
+    Проверь, что всё написано верно, и попробуй ещё раз.
+error-slot-unavailable =
+    ❌ <b>Это время уже недоступно</b>
+    Попробуй выбрать другое время.
+error-slot-already-reserved = ❌ <b>Команда уже записана на финал</b>
+error-slot-not-reserved = ❌ <b>Команда не записана на финал</b>
+page-footer = Страница { $page } из { $pages }, всего { $total }
+no-username = (без никнейма)
+# Дата в родительном падеже: «8 октября».
+day-month = { $day } { $month ->
+    [1] января
+    [2] февраля
+    [3] марта
+    [4] апреля
+    [5] мая
+    [6] июня
+    [7] июля
+    [8] августа
+    [9] сентября
+    [10] октября
+    [11] ноября
+   *[12] декабря
+}
+
+## Регистрация
+
+registration-closed =
+    🥺 <b>К сожалению, зарегистрироваться уже нельзя!</b>
+
+    👉🏻 <i>Регистрация была доступна до { $date }, но не спеши расстраиваться. В следующем год ты сам сможешь стать организатором «Легенд Бауманки» — присоединяйся к <a href="https://forms.yandex.ru/u/6897839490fa7b44d1601991">команде Студенческого совета</a> и вместе мы сделаем так, чтобы о новых «Легендах» говорили ещё громче!</i>
+registration-not-opened =
+    ⏳ <b>Регистрация ещё не началась!</b>
+
+    <i>Она откроется { $date }, возвращайся к нам!</i>
+
+## Меню и команда
+
+menu-text =
+    📲 <b>Главное меню</b>
+    Выбери нужный пункт из меню ниже.
+unknown-menu-option =
+    ❌ <b>Непонятная команда</b>
+    К сожалению, я не понимаю этот запрос. Пожалуйста, выбери одну из доступных опций в меню.
+section-closed =
+    🔒 <b>Раздел сейчас закрыт</b>
+    Организаторы перешли к следующему этапу мероприятия. Возвращаемся в меню!
+operation-cancelled = ↩️ Отменено
+nothing-to-cancel = ℹ️ Сейчас нечего отменять
+state-reset =
+    🔄 <b>Бот обновился</b>
+    Экран, на котором ты был, изменился или больше не существует. Возвращаемся в меню!
+state-reset-by-staff =
+    🔄 <b>Организаторы вернули тебя в меню</b>
+    Если что-то пошло не так, просто начни заново.
+button-unavailable = Эта кнопка больше не работает
+send-approval-exit-team = ❓ Ты действительно хочешь выйти из своей текущей команды?
+successful-exit-team =
+    👋 <b>Ты вышел из команды</b>
+    Ты успешно покинул(а) команду. Теперь ты можешь вступить в новую, используя код-приглашение.
+my-team =
+    📊 <b>Информация о команде:</b>
+    • Название: { $name }
+    • Капитан: @{ $captain }
+    • Участники ({ $size }/{ $max_size }):
+    { $members }
+
+## Обратная связь
+
+prompt-feedback = ✍️ Теперь ты можешь написать комментарий организаторам!
+feedback-sent =
+    📩 <b>Отлично, твой комментарий отправлен!</b>
+
+    <i>Мы обязательно прочитаем его в ближайшее время!</i>
+feedback-reply-to-author =
+    💬 <b>Ответ организаторов на твой комментарий:</b>
+
+    { $text }
+
+## Личности
+
+prompt-character-name =
+    ☺️ <b>Отличный выбор!</b>
+
+    Познакомься с великими учёными, инженерами и конструкторами, которые начинали свой путь здесь, в стенах МГТУ им. Н.Э. Баумана.
+
+    <b><i>Кого из них ты хочешь узнать лучше?</i></b> Выбери личность из списка или напиши имя.
+characters-not-found = 🤔 Не нашли никого с таким именем. Попробуй написать иначе или выбери личность из списка.
+favorite-characters-empty = ⭐ В избранном пока никого нет. Добавить личность в избранное можно кнопкой на её карточке.
+characters-found = 🔎 <b>Вот кого удалось найти:</b>
+character-links = 👤 <b>Узнай больше о героях этого задания:</b>
+character-page-title = 📚 <b>Личности</b>
+favorite-character-page-title = ⭐ <b>Избранные личности</b>
+character-collection = 🧩 Собрано { $collected } из { $total }
+characters-unlocked = 🔓 <b>Новая личность в коллекции:</b> { $names }
+character =
+    <b>{ $index }. { $name }</b>
+
+    <blockquote>«{ $quote }»</blockquote>
+
+    { $facts }<b><i>{ $legacy }</i></b>
+locked-character =
+    <b>{ $index }. ???</b>
+
+    🔒 Эта личность пока скрыта. Карточка откроется, когда твоя команда { $condition }.
+locked-character-task = решит задание { $task_id }
+locked-character-track = пройдёт трек «{ $track }»
+
+## Треки и задания
+
+prompt-track = ✨ <b>Выбери трек</b>
+prompt-track-start =
+    Как только вы начнёте этот трек запустится таймер, который покажет, насколько быстро вы способны прокачивать свои навыки!
+
+    <b>Ваши задачи:</b>
+    💡 Отвечать на вопросы
+    💡 Выбирать верные ответы
+    💡 Присылать фотографии
+    💡 Действовать слаженно и быстро
+
+    📸 <b>Важно:</b>
+    Помните, что проверка фотографий требует времени — не откладывайте их выполнение!
+
+    <i>Вы уверены, что готовы начать?</i>
+track-not-started =
+    ☝🏻<b>Стой! Это трек еще не был запущен вашей командой.</b>
+
+    ⭐️ Он откроется, как только вы договоритесь командой и капитан начнет его!
+track-timer-started = Прошло с момента старта трека: { $elapsed }
+track-timer-finished = Время прохождения трека: { $start } - { $finish } ({ $duration })
+track-menu =
+    <b>{ $title }</b>
+
+    { $description }
+
+    <b>Твой прогресс</b>
+    Трек: <i>{ $name }</i>
+    { $progress }
+
+    <b>✨ ─── ТАЙМЕР ─── ✨</b>
+    🕒 { $timer }
+prompt-available-task =
+    📲 <b>Выбери задание из списка</b>
+    🆕 — новое, 🔁 — был неверный ответ, ⏳ — фото на проверке
+prompt-completed-task = 📲 <b>Выбери задание из списка</b>
+all-track-tasks-completed =
+    🎉 <b>Трек завершён!</b>
+    Поздравляю! Ты успешно выполнил(а) все задания этого трека. Теперь ты можешь перейти к другим трекам и продолжить своё путешествие по «Легендам Бауманки»!
+no-completed-tasks =
+    📭 <b>Пусто</b>
+    Здесь пока нет ни одного завершённого задания.
+task-correct-answer-1 = ✅ <b>Снова прав!</b> Ты уже не участник — ты мастер. Каждый твой шаг ближе к легенде.
+task-correct-answer-2 = ✅ <b>Ты справился!</b> И справился с достоинством. Такие моменты и делают нас сильнее!
+task-correct-answer-3 = ✅ <b>Верно!</b> Бауманка открывает свои секреты только избранным!
+task-correct-answer-4 = ✅ <b>Правильно!</b> Ты чувствуешь дух Бауманки как никто другой!
+task-correct-answer-5 = ✅ <b>Точно!</b> Ты читаешь историю Бауманки как открытую книгу!
+task-invalid-answer-1 = ❌ Мимо, но каждый промах — это шаг ближе к цели. Вперёд, у тебя получится!
+task-invalid-answer-2 = ❌ Почти! Но если один путь оказался тупиком — значит, другой точно ведёт к успеху. Давай попробуем снова?
+# Пусть дублируется, будем считать, что повышенный шанс на выпадение
+task-invalid-answer-3 = { task-invalid-answer-2 }
+task-invalid-answer-4 = ❌ Близко, но не совсем. Зато теперь голова работает на полную — давай ещё один шанс!
+task-invalid-answer-5 = ❌ Это не он, но ты уже почти чувствуешь правильный ответ, верно? Не останавливайся!
+task-answer-is-close = 🤏 Почти! Проверь написание — кажется, закралась опечатка.
+task-hint = 💡 <b>Подсказка:</b> { $hint }
+partial-credit = 🌓 Ответ верен частично — пока засчитано <b>{ $points }</b> б. Попробуй ещё раз, чтобы получить максимум!
+score-base = <b>Баллы за задание:</b> { $points }
+score-penalty = ➖ Штраф за попытки: { $points }
+score-time-bonus = ➕ Бонус за скорость: { $points }
+score-total = <b>Итого:</b> { $points }
+please-send-location = 📍 Нажми на кнопку внизу, чтобы отправить свою геопозицию.
+select-at-least-one-option = ☝🏻 Сначала отметь хотя бы один вариант, а потом нажми «Готово».
+multi-choice-hint = Отметь все верные варианты и нажми «Готово».
+ordering-hint = Нажимай на варианты в правильном порядке.
+number-hint = Ответ — число.
+location-hint = Доберись до нужного места и отправь геопозицию.
+selected-options =
+    <b>Выбрано:</b>
+    { $options }
+ordered-options =
+    <b>Твой порядок:</b>
+    { $options }
+task-explanation =
+    { $question }
+
+    <b>Правильный ответ: </b>{ $answers }
+
+    { $explanation }
+photo-task-accepted = Отлично! Пока что фотография проверяется, а ты можешь двигаться дальше 📸
+photo-answer-approved =
+    📸 <b>Фотография к заданию { $number } засчитана!</b>
+    Начислено баллов: { $points }
+photo-answer-rejected =
+    📸 <b>Фотографию к заданию { $number } не приняли</b>
+    Попробуй отправить другую!
+please-send-photo = ☝🏻 Принимается именно фотография, а ты, кажется, скинул что-то не то!
+
+## Запись на финал
+
+prompt-accept-final = ⭐️ <b><i>Готов попасть на финал?</i></b>
+prompt-slot-time =
+    🕐 <b>Выберите время для финала</b>
+
+    Пожалуйста, выберите удобное время, когда ваша команда сможет подойти на очный финал.
+
+    ⏱ <i>Мероприятие займёт не более часа.</i>
+
+    <i>Доступные слоты:</i>
+prompt-slot-places =
+    👥 <b>Укажите число участников на финале</b>
+    Пожалуйста, введите, сколько человек из вашей команды планирует прийти на очный финал.
+
+    💡 <i>В команде сейчас { $team_size } участников.</i>
+invalid-team-places =
+    ❌ <b>Неверный формат</b>
+
+    Пожалуйста, введите обычное число. Например: 5
+places-greater-than-team =
+    ❌ <b>Слишком большое число</b>
+
+    Количество участников на финале не может превышать размер вашей команды ({ $team_size } человек). Пожалуйста, введите корректное число.
+no-slots =
+    ⏰ <b>В это время нет мест</b>
+    К сожалению, на выбранное время не осталось слотов для { $team_size } человек.
+
+    <i>Пожалуйста, выберите другое время для участия в финале.</i>
+slot-successfully-reserved =
+    Отлично! Запись подтверждена.
+
+    ⏰ Финал пройдет { $date } в { $time } в аудитории { $site } { $venue }
+
+    🪧 <b><i>Ждём вашу команду, чтобы вместе поставить красивую точку. До встречи!</i></b>
+prompt-cancel-reservation-reason =
+    ❓ <b>Уточните причину отмены записи</b>
+
+    • 🗓 Хочу изменить время
+    • 👋 Не смогу прийти на финал
+
+    💡 <i>Вы можете свободно перезаписываться на другое время до { $deadline } сегодня.</i>
+final-absence-accepted =
+    👋 <b>Очень жаль, что вы не сможете быть с нами на финале</b>
+
+    Мы будем скучать! Но полностью понимаем, что обстоятельства бывают разными.
+
+    🗝 <b>Главное</b> — вы уже стали частью «Легенд Бауманки» и успешно прошли основной этап! Это огромное достижение ✨
+
+    <i>Надеемся увидеть вас на других наших мероприятиях! Оставайтесь на связи!</i>
+
+## Кнопки
+
+btn-back = Назад
+btn-start = Начать
+btn-tracks = Треки
+btn-my-team = Моя команда
+btn-characters = Личности
+btn-give-feedback = Комментарий
+btn-reserve-slot = Записаться на финал
+btn-cancel-reservation = Отменить запись
+btn-available-tasks = Доступные задания
+btn-completed-tasks = Решённые задания
+btn-task = Задание { $number }
+btn-done = Готово
+btn-reset = Сбросить
+btn-send-location = 📍 Отправить геопозицию
+btn-can-not-accept-final = Не смогу прийти на финал
+btn-change-reservation-time = Хочу изменить время
+btn-all-characters = 📚 Все личности
+btn-favorite-characters = ⭐ Избранные
+btn-character-in-favorites = ⭐ В избранном
+btn-add-character-to-favorites = ☆ В избранное
@@ -0,0 +1,220 @@
+### Тексты для организаторов: команды администрирования и уведомления в чат организаторов.
+
+## Медиафайлы
+
+invalid-upload-command-usage =
+    <b>❌ Неверный формат команды</b>
+    Использование:
+    <code>/upload key</code>
+invalid-media-command-usage =
+    <b>❌ Неверный формат команды</b>
+    Использование:
+    <code>/media key</code>
+invalid-media-usage-command-usage =
+    <b>❌ Неверный формат команды</b>
+    Использование:
+    <code>/mediausage key</code>
+invalid-media-rename-command-usage =
+    <b>❌ Неверный формат команды</b>
+    Использование:
+    <code>/mediarename key new_key</code>
+invalid-media-delete-command-usage =
+    <b>❌ Неверный формат команды</b>
+    Использование:
+    <code>/mediadelete key</code>
+prompt-media =
+    📤 <b>Загрузка файла</b>
+    Отправьте файл (изображение, видео, видеосообщение, аудио, голосовое или документ) в чат, чтобы получить его FileID. Альбом сохранится под ключами <code>key</code>, <code>key_2</code> и т. д.
+unknown-media-format =
+    ❌ <b>Формат не поддерживается</b>
+    Отправьте изображение, видео, видеосообщение, аудио, голосовое или документ.
+media-uploaded =
+    ✅ <b>FileID получен!</b>
+    <code>{ $file_id }</code>
+media-group-uploaded =
+    ✅ <b>Альбом <code>{ $media }</code> загружен</b>
+    Файлов: { $count }
+media-not-found = <b>❌ Медиафайл <code>{ $media }</code> не найден</b>
+media-library-is-empty = 🗂 Медиафайлы не найдены
+media-references-are-valid = ✅ Все ссылки на медиафайлы в порядке
+media-type-image = изображение
+media-type-video-note = видеосообщение
+media-type-audio = аудио
+media-type-voice = голосовое
+media-type-video = видео
+media-type-document = документ
+media-page-title = 🗂 <b>Медиафайлы</b>
+media-page-search-title = 🔎 <b>Медиафайлы по запросу</b> <code>{ $query }</code>
+media-usage-track = трек <code>{ $track }</code>
+media-usage-task = задание { $task_id }
+media-usage-character = персонаж <code>{ $character }</code>
+media-not-used = <code>{ $media }</code> нигде не используется
+media-usages =
+    <code>{ $media }</code> используется:
+    { $usages }
+media-renamed = ✅ <code>{ $media }</code> переименован в <code>{ $new_media }</code>
+media-already-exists = <b>❌ Медиафайл <code>{ $media }</code> уже существует</b>
+media-deleted = 🗑 <code>{ $media }</code> удалён
+media-in-use =
+    <b>❌ Нельзя удалить используемый медиафайл</b>
+    { $usages }
+dangling-media-references =
+    <b>⚠️ Ссылки на несуществующие медиафайлы ({ $count }):</b>
+    { $references }
+
+## Граф заданий
+
+invalid-graph-command-usage =
+    <b>❌ Неверный формат команды</b>
+    Использование:
+    <code>/graph dot</code> или <code>/graph mermaid</code>
+task-graph-is-valid = ✅ Граф зависимостей заданий в порядке
+task-graph-issues =
+    <b>⚠️ Проблемы в графе зависимостей заданий ({ $count }):</b>
+    { $issues }
+task-graph-missing-dependency = <code>{ $track }</code>: задание { $task_id } зависит от несуществующего задания { $dependency }
+task-graph-cross-track-dependency = <code>{ $track }</code>: задание { $task_id } зависит от задания { $dependency } из трека <code>{ $dependency_track }</code>
+task-graph-cycle = <code>{ $track }</code>: цикл { $cycle }
+task-graph-unreachable = <code>{ $track }</code>: задание { $task_id } невозможно открыть
+
+## Обратная связь
+
+invalid-inbox-command-usage =
+    <b>❌ Неверный формат команды</b>
+    Использование:
+    <code>/inbox [new|answered|archived|all]</code>
+feedback-inbox-is-empty = 📭 Сообщений нет
+prompt-feedback-reply = ✍️ Напиши ответ, он придёт автору от имени бота
+feedback-is-archived = <b>❌ Сообщение в архиве, ответить на него нельзя</b>
+feedback-status-new = 🆕 новое
+feedback-status-answered = ✅ отвечено
+feedback-status-archived = 🗄 в архиве
+feedback-inbox-title = 📬 <b>Обратная связь</b>
+feedback-inbox-status-title = 📬 <b>Обратная связь</b> ({ $status })
+feedback-thread-reply = ↪️ <i>Ответ { $date }:</i>
+feedback-reply-sent = 📨 Ответ на <b>#{ $id }</b> отправлен
+feedback-reply-not-delivered =
+    <b>❌ Не удалось доставить ответ на #{ $id }</b>
+    Возможно, автор заблокировал бота
+feedback-archived = 🗄 <b>#{ $id }</b> перемещено в архив
+btn-reply-feedback = ↪️ Ответить
+btn-archive-feedback = 🗄 В архив
+
+## Роли
+
+role-superadmin = суперадмин
+role-editor = редактор контента
+role-moderator = модератор фото
+role-staff = организатор финальной площадки
+role-analyst = аналитик
+invalid-role-command-usage =
+    <b>❌ Неверный формат команды</b>
+    Использование:
+    <code>/{ $command } user_id role</code>
+
+    Роли:
+    { $roles }
+can-not-revoke-own-role =
+    <b>❌ Нельзя отозвать у себя роль суперадмина</b>
+    Попроси сделать это другого суперадмина.
+role-granted = ✅ Пользователю <code>{ $user }</code> выдана роль «{ $role }»
+role-already-granted = ℹ️ У пользователя <code>{ $user }</code> уже есть роль «{ $role }»
+role-revoked = 🗑 У пользователя <code>{ $user }</code> отозвана роль «{ $role }»
+role-not-granted = ℹ️ У пользователя <code>{ $user }</code> нет роли «{ $role }»
+role-user-not-found = <b>❌ Пользователь <code>{ $user }</code> не зарегистрирован в боте</b>
+staff-roles-empty = 👥 Ролей пока ни у кого нет
+staff-roles =
+    👥 <b>Организаторы</b>
+
+    { $staff }
+
+## Журнал действий
+
+audit-log-is-empty = 📜 Записей в журнале не найдено
+audit-action-media_upload = загрузка медиафайла
+audit-action-media_rename = переименование медиафайла
+audit-action-media_delete = удаление медиафайла
+audit-action-feedback_reply = ответ на обратную связь
+audit-action-feedback_archive = архивирование обратной связи
+audit-action-photo_review = проверка фото-ответа
+audit-action-role_grant = выдача роли
+audit-action-role_revoke = отзыв роли
+audit-action-phase_switch = переключение этапа
+audit-action-dialogue_reset = сброс диалога
+invalid-audit-command-usage =
+    <b>❌ Неверный формат команды</b>
+    Использование:
+    <code>/audit [actor=user_id] [action=код] [target=объект]</code>
+
+    Действия:
+    { $actions }
+audit-log-title = 📜 <b>Журнал действий</b>
+audit-change-before = было: <code>{ $before }</code>
+audit-change-after = стало: <code>{ $after }</code>
+
+## Этапы мероприятия
+
+event-phase-registration = регистрация
+event-phase-quest = квест
+event-phase-final_booking = запись на финал
+event-phase-post_event = после мероприятия
+menu-item-tracks = треки
+menu-item-team = команда
+menu-item-characters = личности
+menu-item-final_reservation = запись на финал
+menu-item-feedback = обратная связь
+menu-items-empty = меню пустое
+event-phases =
+    🗓 <b>Этапы мероприятия</b>
+
+    { $phases }
+
+    Переключить: <code>/phase код</code>
+invalid-phase-command-usage =
+    <b>❌ Неверный формат команды</b>
+    Использование:
+    <code>/phase [код]</code>
+
+    Этапы:
+    { $phases }
+event-phase-switched = ✅ Этап переключён на «{ $phase }». Меню игроков обновится при следующем обращении к боту.
+event-phase-already-active = ℹ️ Этап «{ $phase }» уже идёт
+
+## Сброс диалога
+
+invalid-reset-command-usage =
+    <b>❌ Неверный формат команды</b>
+    Использование:
+    <code>/reset user_id</code>
+dialogue-reset = ✅ Диалог пользователя <code>{ $user }</code> сброшен
+
+## Чат организаторов
+
+staff-user-team = команда «{ $team }»
+staff-user-no-team = без команды
+staff-feedback =
+    📩 <b>Новый комментарий</b>
+    { $user }
+
+    { $text }
+
+    <i>Ответить можно через /inbox</i>
+staff-photo-answer =
+    📸 <b>Фото-ответ ждёт проверки</b>
+    { $user }
+    Трек <code>{ $track }</code>, задание { $number }
+staff-photo-answer-approved = ✅ Засчитано ({ $points } баллов), проверил { $reviewer }
+staff-photo-answer-rejected = ❌ Отклонено, проверил { $reviewer }
+photo-answer-already-reviewed = Этот ответ уже проверен
+btn-approve-photo = ✅ Засчитать
+btn-reject-photo = ❌ Отклонить
+staff-reservation-cancelled =
+    🚫 <b>Отмена записи на финал</b>
+    { $user }
+    Причина: { $rescheduled ->
+        [yes] хочет выбрать другое время
+       *[no] не сможет прийти на финал
+    }
+staff-internal-error =
+    ⚠️ <b>Ошибка в боте</b>
+    <code>{ $error }</code>
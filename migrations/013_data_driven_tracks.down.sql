@@ -0,0 +1,30 @@
+ALTER TABLE tasks               DROP CONSTRAINT IF EXISTS tasks_track_tag_fkey;
+ALTER TABLE team_started_tracks DROP CONSTRAINT IF EXISTS team_started_tracks_track_tag_fkey;
+
+ALTER TABLE tracks
+    DROP CONSTRAINT IF EXISTS tracks_tag_slug,
+    DROP COLUMN IF EXISTS name,
+    DROP COLUMN IF EXISTS solo,
+    DROP COLUMN IF EXISTS index;
+
+DO $$ BEGIN
+    CREATE TYPE TRACK_TAG AS ENUM (
+        'muzhestvo',
+        'volya',
+        'trud',
+        'uporstvo',
+        'universitet'
+    );
+EXCEPTION WHEN duplicate_object THEN NULL; END $$;
+
+ALTER TABLE tracks              ALTER COLUMN tag       TYPE TRACK_TAG USING tag::TRACK_TAG;
+ALTER TABLE tasks               ALTER COLUMN track_tag TYPE TRACK_TAG USING track_tag::TRACK_TAG;
+ALTER TABLE team_started_tracks ALTER COLUMN track_tag TYPE TRACK_TAG USING track_tag::TRACK_TAG;
+
+ALTER TABLE tasks
+    ADD CONSTRAINT tasks_track_tag_fkey
+        FOREIGN KEY (track_tag) REFERENCES tracks (tag) ON DELETE CASCADE;
+
+ALTER TABLE team_started_tracks
+    ADD CONSTRAINT team_started_tracks_track_tag_fkey
+        FOREIGN KEY (track_tag) REFERENCES tracks (tag) ON DELETE CASCADE;
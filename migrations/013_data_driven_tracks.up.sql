@@ -0,0 +1,31 @@
+ALTER TABLE tasks               DROP CONSTRAINT IF EXISTS tasks_track_tag_fkey;
+ALTER TABLE team_started_tracks DROP CONSTRAINT IF EXISTS team_started_tracks_track_tag_fkey;
+
+ALTER TABLE tracks              ALTER COLUMN tag       TYPE VARCHAR(32) USING tag::TEXT;
+ALTER TABLE tasks               ALTER COLUMN track_tag TYPE VARCHAR(32) USING track_tag::TEXT;
+ALTER TABLE team_started_tracks ALTER COLUMN track_tag TYPE VARCHAR(32) USING track_tag::TEXT;
+
+DROP TYPE IF EXISTS TRACK_TAG;
+
+ALTER TABLE tracks
+    ADD COLUMN IF NOT EXISTS name  VARCHAR,
+    ADD COLUMN IF NOT EXISTS solo  BOOLEAN NOT NULL DEFAULT FALSE,
+    ADD COLUMN IF NOT EXISTS index INTEGER NOT NULL DEFAULT 0,
+    ADD CONSTRAINT tracks_tag_slug CHECK ( tag ~ '^[a-z][a-z0-9_]*$' );
+
+UPDATE tracks SET name = 'Мужество',    index = 1 WHERE tag = 'muzhestvo';
+UPDATE tracks SET name = 'Воля',        index = 2 WHERE tag = 'volya';
+UPDATE tracks SET name = 'Труд',        index = 3 WHERE tag = 'trud';
+UPDATE tracks SET name = 'Упорство',    index = 4 WHERE tag = 'uporstvo';
+UPDATE tracks SET name = 'Университет', index = 5, solo = TRUE WHERE tag = 'universitet';
+UPDATE tracks SET name = tag WHERE name IS NULL;
+
+ALTER TABLE tracks ALTER COLUMN name SET NOT NULL;
+
+ALTER TABLE tasks
+    ADD CONSTRAINT tasks_track_tag_fkey
+        FOREIGN KEY (track_tag) REFERENCES tracks (tag) ON DELETE CASCADE ON UPDATE CASCADE;
+
+ALTER TABLE team_started_tracks
+    ADD CONSTRAINT team_started_tracks_track_tag_fkey
+        FOREIGN KEY (track_tag) REFERENCES tracks (tag) ON DELETE CASCADE ON UPDATE CASCADE;
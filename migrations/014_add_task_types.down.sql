@@ -0,0 +1,20 @@
+ALTER TABLE task_correct_answers
+    DROP COLUMN IF EXISTS index;
+
+ALTER TABLE task_options
+    DROP COLUMN IF EXISTS index;
+
+ALTER TABLE tasks
+    DROP COLUMN IF EXISTS radius,
+    DROP COLUMN IF EXISTS longitude,
+    DROP COLUMN IF EXISTS latitude,
+    DROP COLUMN IF EXISTS numeric_tolerance,
+    DROP COLUMN IF EXISTS numeric_answer;
+
+DELETE FROM tasks WHERE task_type::TEXT IN ('multi_choice', 'number', 'ordering', 'location');
+
+ALTER TYPE TASK_TYPE RENAME TO TASK_TYPE_OLD;
+CREATE TYPE TASK_TYPE AS ENUM ('text', 'choice', 'photo');
+ALTER TABLE tasks
+    ALTER COLUMN task_type TYPE TASK_TYPE USING task_type::TEXT::TASK_TYPE;
+DROP TYPE TASK_TYPE_OLD;
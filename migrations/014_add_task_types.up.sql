@@ -0,0 +1,18 @@
+ALTER TYPE TASK_TYPE ADD VALUE IF NOT EXISTS 'multi_choice';
+ALTER TYPE TASK_TYPE ADD VALUE IF NOT EXISTS 'number';
+ALTER TYPE TASK_TYPE ADD VALUE IF NOT EXISTS 'ordering';
+ALTER TYPE TASK_TYPE ADD VALUE IF NOT EXISTS 'location';
+
+ALTER TABLE tasks
+    ADD COLUMN IF NOT EXISTS numeric_answer    DOUBLE PRECISION DEFAULT NULL,
+    ADD COLUMN IF NOT EXISTS numeric_tolerance DOUBLE PRECISION NOT NULL DEFAULT 0 CHECK ( numeric_tolerance >= 0 ),
+    ADD COLUMN IF NOT EXISTS latitude          DOUBLE PRECISION DEFAULT NULL CHECK ( latitude BETWEEN -90 AND 90 ),
+    ADD COLUMN IF NOT EXISTS longitude         DOUBLE PRECISION DEFAULT NULL CHECK ( longitude BETWEEN -180 AND 180 ),
+    ADD COLUMN IF NOT EXISTS radius            DOUBLE PRECISION DEFAULT NULL CHECK ( radius > 0 );
+
+-- Порядок вариантов важен для заданий на упорядочивание
+ALTER TABLE task_options
+    ADD COLUMN IF NOT EXISTS index INTEGER NOT NULL DEFAULT 0;
+
+ALTER TABLE task_correct_answers
+    ADD COLUMN IF NOT EXISTS index INTEGER NOT NULL DEFAULT 0;
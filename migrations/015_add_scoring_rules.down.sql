@@ -0,0 +1,13 @@
+ALTER TABLE answers
+    DROP COLUMN IF EXISTS attempts,
+    DROP COLUMN IF EXISTS completed,
+    DROP COLUMN IF EXISTS time_bonus_points,
+    DROP COLUMN IF EXISTS penalty_points,
+    DROP COLUMN IF EXISTS base_points;
+
+ALTER TABLE tasks
+    DROP COLUMN IF EXISTS partial_credit,
+    DROP COLUMN IF EXISTS time_bonus_minutes,
+    DROP COLUMN IF EXISTS time_bonus,
+    DROP COLUMN IF EXISTS min_points_percent,
+    DROP COLUMN IF EXISTS attempt_penalty_percent;
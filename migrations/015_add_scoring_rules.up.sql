@@ -0,0 +1,19 @@
+ALTER TABLE tasks
+    ADD COLUMN IF NOT EXISTS attempt_penalty_percent INTEGER NOT NULL DEFAULT 0     CHECK ( attempt_penalty_percent BETWEEN 0 AND 100 ),
+    ADD COLUMN IF NOT EXISTS min_points_percent      INTEGER NOT NULL DEFAULT 0     CHECK ( min_points_percent BETWEEN 0 AND 100 ),
+    ADD COLUMN IF NOT EXISTS time_bonus              INTEGER NOT NULL DEFAULT 0     CHECK ( time_bonus >= 0 ),
+    ADD COLUMN IF NOT EXISTS time_bonus_minutes      INTEGER NOT NULL DEFAULT 0     CHECK ( time_bonus_minutes >= 0 ),
+    ADD COLUMN IF NOT EXISTS partial_credit          BOOLEAN NOT NULL DEFAULT FALSE;
+
+-- points остаётся итоговой суммой, чтобы лидерборд можно было считать одним запросом
+ALTER TABLE answers
+    ADD COLUMN IF NOT EXISTS base_points       INTEGER NOT NULL DEFAULT 0 CHECK ( base_points >= 0 ),
+    ADD COLUMN IF NOT EXISTS penalty_points    INTEGER NOT NULL DEFAULT 0 CHECK ( penalty_points >= 0 ),
+    ADD COLUMN IF NOT EXISTS time_bonus_points INTEGER NOT NULL DEFAULT 0 CHECK ( time_bonus_points >= 0 ),
+    ADD COLUMN IF NOT EXISTS completed         BOOLEAN NOT NULL DEFAULT FALSE,
+    ADD COLUMN IF NOT EXISTS attempts          INTEGER NOT NULL DEFAULT 1 CHECK ( attempts > 0 );
+
+UPDATE answers
+SET
+    base_points = points,
+    completed = points > 0;
@@ -0,0 +1,6 @@
+CREATE TABLE IF NOT EXISTS task_hints (
+    task_id INTEGER NOT NULL REFERENCES tasks (id) ON DELETE CASCADE,
+    keyword VARCHAR NOT NULL,
+    hint    VARCHAR NOT NULL,
+    PRIMARY KEY (task_id, keyword)
+);
@@ -0,0 +1,21 @@
+ALTER TABLE tasks
+    ADD COLUMN IF NOT EXISTS media_id VARCHAR(64) DEFAULT NULL REFERENCES media (id) ON DELETE CASCADE;
+
+UPDATE tasks t
+SET    media_id = (
+    SELECT tm.media_id
+    FROM   task_media tm
+    WHERE  tm.task_id = t.id
+    ORDER BY tm.index ASC
+    LIMIT 1
+);
+
+DROP TABLE IF EXISTS task_media;
+
+DELETE FROM media WHERE media_type::TEXT IN ('audio', 'voice', 'video', 'document');
+
+ALTER TYPE MEDIA_TYPE RENAME TO MEDIA_TYPE_OLD;
+CREATE TYPE MEDIA_TYPE AS ENUM ('image', 'video_note');
+ALTER TABLE media
+    ALTER COLUMN media_type TYPE MEDIA_TYPE USING media_type::TEXT::MEDIA_TYPE;
+DROP TYPE MEDIA_TYPE_OLD;
@@ -0,0 +1,20 @@
+ALTER TYPE MEDIA_TYPE ADD VALUE IF NOT EXISTS 'audio';
+ALTER TYPE MEDIA_TYPE ADD VALUE IF NOT EXISTS 'voice';
+ALTER TYPE MEDIA_TYPE ADD VALUE IF NOT EXISTS 'video';
+ALTER TYPE MEDIA_TYPE ADD VALUE IF NOT EXISTS 'document';
+
+CREATE TABLE IF NOT EXISTS task_media (
+    task_id  INTEGER     NOT NULL REFERENCES tasks (id) ON DELETE CASCADE,
+    media_id VARCHAR(64) NOT NULL REFERENCES media (id) ON DELETE CASCADE,
+    index    INTEGER     NOT NULL DEFAULT 0,
+    PRIMARY KEY (task_id, media_id)
+);
+
+INSERT INTO task_media (task_id, media_id, index)
+SELECT id, media_id, 0
+FROM   tasks
+WHERE  media_id IS NOT NULL
+ON CONFLICT DO NOTHING;
+
+ALTER TABLE tasks
+    DROP COLUMN IF EXISTS media_id;
@@ -0,0 +1,5 @@
+CREATE TABLE IF NOT EXISTS media_content (
+    media_id  VARCHAR(64) PRIMARY KEY REFERENCES media (id) ON DELETE CASCADE,
+    file_name VARCHAR     NOT NULL,
+    data      BYTEA       NOT NULL
+);
@@ -0,0 +1,9 @@
+DROP TABLE IF EXISTS feedback_replies;
+
+DROP INDEX IF EXISTS idx_feedbacks_status_created_at;
+
+ALTER TABLE feedbacks
+    DROP COLUMN IF EXISTS created_at,
+    DROP COLUMN IF EXISTS status;
+
+DROP TYPE IF EXISTS FEEDBACK_STATUS;
@@ -0,0 +1,22 @@
+DO $$ BEGIN
+    CREATE TYPE FEEDBACK_STATUS AS ENUM (
+        'new',
+        'answered',
+        'archived'
+    );
+EXCEPTION WHEN duplicate_object THEN NULL; END $$;
+
+ALTER TABLE feedbacks
+    ADD COLUMN IF NOT EXISTS status     FEEDBACK_STATUS NOT NULL DEFAULT 'new',
+    ADD COLUMN IF NOT EXISTS created_at TIMESTAMPTZ     NOT NULL DEFAULT NOW();
+
+CREATE INDEX IF NOT EXISTS idx_feedbacks_status_created_at
+    ON feedbacks (status, created_at DESC);
+
+CREATE TABLE IF NOT EXISTS feedback_replies (
+    id          INTEGER PRIMARY KEY GENERATED ALWAYS AS IDENTITY,
+    feedback_id INTEGER     NOT NULL REFERENCES feedbacks (id) ON DELETE CASCADE,
+    author_id   BIGINT      NOT NULL REFERENCES users (id),
+    text        VARCHAR     NOT NULL,
+    created_at  TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
@@ -0,0 +1,19 @@
+CREATE TABLE IF NOT EXISTS admins (
+    user_id BIGINT PRIMARY KEY
+);
+
+ALTER TABLE admins
+    ADD CONSTRAINT fk_admin_users
+        FOREIGN KEY (user_id) REFERENCES users (id);
+
+INSERT INTO admins (user_id)
+SELECT DISTINCT user_id
+FROM user_roles
+WHERE role = 'super_admin'
+ON CONFLICT DO NOTHING;
+
+DROP TABLE IF EXISTS role_audit;
+DROP TABLE IF EXISTS user_roles;
+
+DROP TYPE IF EXISTS ROLE_ACTION;
+DROP TYPE IF EXISTS USER_ROLE;
@@ -0,0 +1,40 @@
+DO $$ BEGIN
+    CREATE TYPE USER_ROLE AS ENUM (
+        'super_admin',
+        'content_editor',
+        'photo_moderator',
+        'final_site_staff',
+        'analyst'
+    );
+EXCEPTION WHEN duplicate_object THEN NULL; END $$;
+
+DO $$ BEGIN
+    CREATE TYPE ROLE_ACTION AS ENUM (
+        'grant',
+        'revoke'
+    );
+EXCEPTION WHEN duplicate_object THEN NULL; END $$;
+
+CREATE TABLE IF NOT EXISTS user_roles (
+    user_id    BIGINT      NOT NULL REFERENCES users (id) ON DELETE CASCADE,
+    role       USER_ROLE   NOT NULL,
+    granted_by BIGINT               REFERENCES users (id) ON DELETE SET NULL,
+    granted_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (user_id, role)
+);
+
+CREATE TABLE IF NOT EXISTS role_audit (
+    id         INTEGER PRIMARY KEY GENERATED ALWAYS AS IDENTITY,
+    actor_id   BIGINT               REFERENCES users (id) ON DELETE SET NULL,
+    user_id    BIGINT      NOT NULL,
+    role       USER_ROLE   NOT NULL,
+    action     ROLE_ACTION NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+INSERT INTO user_roles (user_id, role)
+SELECT user_id, 'super_admin'
+FROM admins
+ON CONFLICT DO NOTHING;
+
+DROP TABLE IF EXISTS admins;
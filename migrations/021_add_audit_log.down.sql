@@ -0,0 +1,20 @@
+DO $$ BEGIN
+    CREATE TYPE ROLE_ACTION AS ENUM (
+        'grant',
+        'revoke'
+    );
+EXCEPTION WHEN duplicate_object THEN NULL; END $$;
+
+CREATE TABLE IF NOT EXISTS role_audit (
+    id         INTEGER PRIMARY KEY GENERATED ALWAYS AS IDENTITY,
+    actor_id   BIGINT               REFERENCES users (id) ON DELETE SET NULL,
+    user_id    BIGINT      NOT NULL,
+    role       USER_ROLE   NOT NULL,
+    action     ROLE_ACTION NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+DROP TRIGGER IF EXISTS trg_audit_log_append_only ON audit_log;
+DROP FUNCTION IF EXISTS forbid_audit_log_change();
+DROP TABLE IF EXISTS audit_log;
+DROP TYPE IF EXISTS AUDIT_ACTION;
@@ -0,0 +1,64 @@
+DO $$ BEGIN
+    CREATE TYPE AUDIT_ACTION AS ENUM (
+        'upload_media',
+        'rename_media',
+        'delete_media',
+        'reply_to_feedback',
+        'archive_feedback',
+        'review_photo_answer',
+        'grant_role',
+        'revoke_role'
+    );
+EXCEPTION WHEN duplicate_object THEN NULL; END $$;
+
+CREATE TABLE IF NOT EXISTS audit_log (
+    id         INTEGER PRIMARY KEY GENERATED ALWAYS AS IDENTITY,
+    actor_id   BIGINT       NOT NULL,
+    action     AUDIT_ACTION NOT NULL,
+    target     VARCHAR      NOT NULL,
+    before     VARCHAR               DEFAULT NULL,
+    after      VARCHAR               DEFAULT NULL,
+    created_at TIMESTAMPTZ  NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_audit_log_created_at
+    ON audit_log (created_at DESC);
+
+-- Журнал ролей становится частью общего журнала, роли записываются кодами команд
+INSERT INTO audit_log (actor_id, action, target, before, after, created_at)
+SELECT
+    actor_id,
+    CASE action WHEN 'grant' THEN 'grant_role'::AUDIT_ACTION ELSE 'revoke_role'::AUDIT_ACTION END,
+    'user:' || user_id,
+    CASE action WHEN 'revoke' THEN code END,
+    CASE action WHEN 'grant' THEN code END,
+    created_at
+FROM (
+    SELECT
+        *,
+        CASE role
+            WHEN 'super_admin' THEN 'superadmin'
+            WHEN 'content_editor' THEN 'editor'
+            WHEN 'photo_moderator' THEN 'moderator'
+            WHEN 'final_site_staff' THEN 'staff'
+            ELSE 'analyst'
+        END AS code
+    FROM role_audit
+) AS roles
+WHERE actor_id IS NOT NULL
+ORDER BY id;
+
+DROP TABLE IF EXISTS role_audit;
+DROP TYPE IF EXISTS ROLE_ACTION;
+
+-- Журнал только дополняется
+CREATE OR REPLACE FUNCTION forbid_audit_log_change() RETURNS TRIGGER AS $$
+BEGIN
+    RAISE EXCEPTION 'audit_log is append-only';
+END;
+$$ LANGUAGE plpgsql;
+
+DROP TRIGGER IF EXISTS trg_audit_log_append_only ON audit_log;
+CREATE TRIGGER trg_audit_log_append_only
+    BEFORE UPDATE OR DELETE ON audit_log
+    FOR EACH ROW EXECUTE FUNCTION forbid_audit_log_change();
@@ -0,0 +1,6 @@
+CREATE TABLE IF NOT EXISTS character_favorites (
+    user_id      BIGINT      NOT NULL REFERENCES users (id) ON DELETE CASCADE,
+    character_id VARCHAR(4)  NOT NULL REFERENCES characters (id) ON DELETE CASCADE,
+    created_at   TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (user_id, character_id)
+);
@@ -0,0 +1,5 @@
+ALTER TABLE characters
+    DROP CONSTRAINT IF EXISTS characters_single_unlock,
+    DROP COLUMN IF EXISTS silhouette_media_id,
+    DROP COLUMN IF EXISTS unlock_track_tag,
+    DROP COLUMN IF EXISTS unlock_task_id;
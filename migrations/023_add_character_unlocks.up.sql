@@ -0,0 +1,9 @@
+ALTER TABLE characters
+    ADD COLUMN IF NOT EXISTS unlock_task_id      INTEGER     DEFAULT NULL
+        REFERENCES tasks (id) ON DELETE SET NULL,
+    ADD COLUMN IF NOT EXISTS unlock_track_tag    VARCHAR(32) DEFAULT NULL
+        REFERENCES tracks (tag) ON DELETE SET NULL ON UPDATE CASCADE,
+    ADD COLUMN IF NOT EXISTS silhouette_media_id VARCHAR(64) DEFAULT NULL
+        REFERENCES media (id) ON DELETE SET NULL,
+    ADD CONSTRAINT characters_single_unlock
+        CHECK ( unlock_task_id IS NULL OR unlock_track_tag IS NULL );
@@ -0,0 +1,4 @@
+-- Значение switch_event_phase остаётся в AUDIT_ACTION: из перечисления его не удалить,
+-- а записи журнала менять нельзя
+DROP TABLE IF EXISTS event_phase;
+DROP TYPE IF EXISTS EVENT_PHASE;
@@ -0,0 +1,18 @@
+DO $$ BEGIN
+    CREATE TYPE EVENT_PHASE AS ENUM (
+        'registration',
+        'quest',
+        'final_booking',
+        'post_event'
+    );
+EXCEPTION WHEN duplicate_object THEN NULL; END $$;
+
+-- Текущий этап мероприятия, единственная строка. Пока её нет, действует этап из настроек
+CREATE TABLE IF NOT EXISTS event_phase (
+    id         BOOLEAN PRIMARY KEY DEFAULT TRUE CHECK (id),
+    phase      EVENT_PHASE NOT NULL,
+    changed_by BIGINT               REFERENCES users (id) ON DELETE SET NULL,
+    changed_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+ALTER TYPE AUDIT_ACTION ADD VALUE IF NOT EXISTS 'switch_event_phase';
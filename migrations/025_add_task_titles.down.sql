@@ -0,0 +1,5 @@
+ALTER TABLE answers
+    DROP COLUMN IF EXISTS pending_review;
+
+ALTER TABLE tasks
+    DROP COLUMN IF EXISTS title;
@@ -0,0 +1,6 @@
+-- Название показывается на кнопке в списке заданий, без него кнопка подписывается номером
+ALTER TABLE tasks
+    ADD COLUMN IF NOT EXISTS title VARCHAR(32) DEFAULT NULL CHECK ( title <> '' );
+
+ALTER TABLE answers
+    ADD COLUMN IF NOT EXISTS pending_review BOOLEAN NOT NULL DEFAULT FALSE;
@@ -0,0 +1,3 @@
+DROP INDEX IF EXISTS idx_tasks_track_tag;
+
+DROP INDEX IF EXISTS idx_answers_pending_review_created_at;
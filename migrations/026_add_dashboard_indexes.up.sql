@@ -0,0 +1,7 @@
+-- Панель мониторинга часто спрашивает фото на проверке и прогресс по трекам
+CREATE INDEX IF NOT EXISTS idx_answers_pending_review_created_at
+    ON answers (created_at)
+    WHERE pending_review;
+
+CREATE INDEX IF NOT EXISTS idx_tasks_track_tag
+    ON tasks (track_tag);
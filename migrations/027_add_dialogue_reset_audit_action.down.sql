@@ -0,0 +1,2 @@
+-- Значение reset_dialogue остаётся в AUDIT_ACTION: из перечисления его не удалить,
+-- а записи журнала менять нельзя
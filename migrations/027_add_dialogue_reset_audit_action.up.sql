@@ -0,0 +1 @@
+ALTER TYPE AUDIT_ACTION ADD VALUE IF NOT EXISTS 'reset_dialogue';
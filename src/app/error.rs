@@ -1,7 +1,9 @@
 use chrono::NaiveTime;
 
 use crate::domain::error::DomainError;
-use crate::domain::models::{CharacterName, MediaID, TaskID, TrackTag, UserID};
+use crate::domain::models::{
+    CharacterID, FeedbackID, MediaID, MediaUsage, Permission, Role, TaskID, TrackTag, UserID,
+};
 use crate::domain::models::{Places, SlotID};
 
 pub type StdError = Box<dyn std::error::Error + Send + Sync>;
@@ -20,15 +22,36 @@ pub enum AppError {
     #[error("media {0:?} not found")]
     MediaNotFound(MediaID),
 
+    #[error("media {0:?} already exists")]
+    MediaAlreadyExists(MediaID),
+
+    #[error("media {0:?} is in use")]
+    MediaInUse(MediaID, Vec<MediaUsage>),
+
+    #[error("feedback {0:?} not found")]
+    FeedbackNotFound(FeedbackID),
+
     #[error("task {0:?} not found")]
     TaskNotFound(TaskID),
 
+    #[error("answer to task {0:?} not found")]
+    AnswerNotFound(TaskID),
+
+    #[error("answer to task {0:?} is not pending review")]
+    AnswerNotPending(TaskID),
+
     #[error("character {0:?} not found")]
-    CharacterNotFound(CharacterName),
+    CharacterNotFound(CharacterID),
 
     #[error("track {0:?} not found")]
     TrackNotFound(TrackTag),
 
+    #[error("permission {0:?} denied")]
+    PermissionDenied(Permission),
+
+    #[error("user {0:?} can not revoke own role {1:?}")]
+    CanNotRevokeOwnRole(UserID, Role),
+
     #[error("user {0:?} is not in team")]
     UserNotInTeam(UserID),
 
@@ -1,7 +1,9 @@
 use crate::app::error::AppError;
 use crate::domain::models::{
-    Character, CharacterName, Feedback, Media, MediaID, Slot, SlotID, Task, TaskID, Team, TeamID,
-    Track, TrackTag, User, UserID,
+    AuditEntry, AuditFilter, Character, CharacterID, DanglingMediaReference, EventPhase, Feedback,
+    FeedbackID, FeedbackReply, FeedbackStatus, FileID, Media, MediaContent, MediaID, MediaUsage,
+    PendingPhotoReview, Role, SiteOccupancy, Slot, SlotID, Task, TaskID, Team, TeamID, Track,
+    TrackSummary, TrackTag, TrackTeamProgress, User, UserID, UserRoles,
 };
 
 use chrono::NaiveTime;
@@ -21,6 +23,12 @@ pub trait TeamProvider: Send + Sync {
     async fn team(&self, id: &TeamID) -> Result<Team, AppError>;
 }
 
+#[async_trait::async_trait]
+pub trait TeamsProvider: Send + Sync {
+    /// Все команды в порядке названия.
+    async fn teams(&self) -> Result<Vec<Team>, AppError>;
+}
+
 #[async_trait::async_trait]
 pub trait TeamByMemberProvider: Send + Sync {
     async fn team_by_member(&self, member_id: UserID) -> Result<Option<Team>, AppError>;
@@ -38,8 +46,46 @@ pub trait TeamRepository: TeamProvider + Send + Sync {
 }
 
 #[async_trait::async_trait]
-pub trait IsAdminProvider: Send + Sync {
-    async fn is_admin(&self, user_id: UserID) -> Result<bool, AppError>;
+pub trait RolesProvider: Send + Sync {
+    async fn roles(&self, user_id: UserID) -> Result<UserRoles, AppError>;
+    /// Все пользователи, у которых есть хотя бы одна роль.
+    async fn staff(&self) -> Result<Vec<(UserID, UserRoles)>, AppError>;
+}
+
+#[async_trait::async_trait]
+pub trait RolesRepository: RolesProvider + Send + Sync {
+    /// Выдаёт роль. `false`, если роль уже была.
+    async fn grant_role(
+        &self,
+        user_id: UserID,
+        role: Role,
+        actor_id: UserID,
+    ) -> Result<bool, AppError>;
+    /// Отзывает роль. `false`, если роли не было.
+    async fn revoke_role(&self, user_id: UserID, role: Role) -> Result<bool, AppError>;
+}
+
+#[async_trait::async_trait]
+pub trait AuditLogRepository: Send + Sync {
+    async fn append_audit_entry(&self, entry: AuditEntry) -> Result<(), AppError>;
+    /// Записи журнала под фильтр, от новых к старым, и их общее количество.
+    async fn audit_entries(
+        &self,
+        filter: &AuditFilter,
+        offset: usize,
+        limit: usize,
+    ) -> Result<(Vec<AuditEntry>, usize), AppError>;
+}
+
+#[async_trait::async_trait]
+pub trait EventPhaseProvider: Send + Sync {
+    /// Этап, выбранный организаторами. `None`, если его ещё не переключали.
+    async fn event_phase(&self) -> Result<Option<EventPhase>, AppError>;
+}
+
+#[async_trait::async_trait]
+pub trait EventPhaseRepository: EventPhaseProvider + Send + Sync {
+    async fn set_event_phase(&self, phase: EventPhase, actor_id: UserID) -> Result<(), AppError>;
 }
 
 #[async_trait::async_trait]
@@ -50,6 +96,24 @@ pub trait MediaProvider: Send + Sync {
 #[async_trait::async_trait]
 pub trait MediaRepository: MediaProvider + Send + Sync {
     async fn save_media(&self, media: Media) -> Result<(), AppError>;
+    async fn update_media_file_id(&self, id: &MediaID, file_id: &FileID) -> Result<(), AppError>;
+    async fn save_media_content(
+        &self,
+        id: &MediaID,
+        content: &MediaContent,
+    ) -> Result<(), AppError>;
+    async fn media_content(&self, id: &MediaID) -> Result<Option<MediaContent>, AppError>;
+    /// Медиафайлы, ID которых содержит `query`, и их общее количество.
+    async fn find_media(
+        &self,
+        query: &str,
+        offset: usize,
+        limit: usize,
+    ) -> Result<(Vec<Media>, usize), AppError>;
+    async fn rename_media(&self, id: &MediaID, new_id: &MediaID) -> Result<(), AppError>;
+    async fn delete_media(&self, id: &MediaID) -> Result<(), AppError>;
+    async fn media_usages(&self, id: &MediaID) -> Result<Vec<MediaUsage>, AppError>;
+    async fn dangling_media_references(&self) -> Result<Vec<DanglingMediaReference>, AppError>;
 }
 
 #[async_trait::async_trait]
@@ -59,18 +123,60 @@ pub trait TaskProvider: Send + Sync {
 
 #[async_trait::async_trait]
 pub trait CharactersProvider: Send + Sync {
+    /// Все персонажи в порядке `index`.
     async fn characters(&self) -> Result<Vec<Character>, AppError>;
-    async fn character_by_name(&self, name: &CharacterName) -> Result<Option<Character>, AppError>;
+}
+
+#[async_trait::async_trait]
+pub trait FavoriteCharactersRepository: Send + Sync {
+    async fn favorite_characters(&self, user_id: UserID) -> Result<Vec<CharacterID>, AppError>;
+    async fn set_favorite_character(
+        &self,
+        user_id: UserID,
+        character_id: &CharacterID,
+        favorite: bool,
+    ) -> Result<(), AppError>;
 }
 
 #[async_trait::async_trait]
 pub trait FeedbackRepository: Send + Sync {
     async fn save_feedback(&self, feedback: Feedback) -> Result<(), AppError>;
+    async fn feedback(&self, id: FeedbackID) -> Result<Feedback, AppError>;
+    /// Обратная связь с указанным статусом (или любым), от новой к старой, и общее количество.
+    async fn feedbacks(
+        &self,
+        status: Option<FeedbackStatus>,
+        offset: usize,
+        limit: usize,
+    ) -> Result<(Vec<Feedback>, usize), AppError>;
+    async fn save_feedback_reply(
+        &self,
+        id: FeedbackID,
+        reply: &FeedbackReply,
+    ) -> Result<(), AppError>;
+    async fn update_feedback_status(
+        &self,
+        id: FeedbackID,
+        status: FeedbackStatus,
+    ) -> Result<(), AppError>;
 }
 
 #[async_trait::async_trait]
 pub trait TrackProvider: Send + Sync {
-    async fn track(&self, tag: TrackTag) -> Result<Track, AppError>;
+    async fn track(&self, tag: &TrackTag) -> Result<Track, AppError>;
+    async fn tracks(&self) -> Result<Vec<Track>, AppError>;
+}
+
+/// Проверки ссылок на треки и задания без загрузки самих треков.
+#[async_trait::async_trait]
+pub trait TaskReferenceProvider: Send + Sync {
+    async fn track_exists(&self, tag: &TrackTag) -> Result<bool, AppError>;
+    /// Число вариантов ответа задания или `None`, если в треке нет такого задания.
+    async fn task_options_count(
+        &self,
+        tag: &TrackTag,
+        task_id: TaskID,
+    ) -> Result<Option<usize>, AppError>;
 }
 
 #[async_trait::async_trait]
@@ -88,3 +194,16 @@ pub trait SlotProvider: Send + Sync {
 pub trait SlotRepository: SlotProvider + Send + Sync {
     async fn save_slot(&self, slot: Slot) -> Result<(), AppError>;
 }
+
+/// Сводные запросы для мониторинга мероприятия.
+#[async_trait::async_trait]
+pub trait DashboardProvider: Send + Sync {
+    /// Названия треков и максимум баллов по ним в порядке отображения.
+    async fn track_summaries(&self) -> Result<Vec<TrackSummary>, AppError>;
+    /// Прогресс всех команд по начатым ими трекам.
+    async fn track_team_progress(&self) -> Result<Vec<TrackTeamProgress>, AppError>;
+    /// Фото-ответы на проверке, от старых к новым.
+    async fn pending_photo_reviews(&self) -> Result<Vec<PendingPhotoReview>, AppError>;
+    /// Занятость слотов финала по площадкам.
+    async fn site_occupancy(&self) -> Result<Vec<SiteOccupancy>, AppError>;
+}
@@ -1,9 +1,11 @@
 use std::sync::Arc;
 
 use crate::app::error::AppError;
-use crate::app::ports::{TaskProvider, TeamByMemberProvider, TeamRepository, TrackProvider};
-use crate::app::usecases::dto::AnswerDTO;
-use crate::domain::models::TaskID;
+use crate::app::ports::{
+    CharactersProvider, TaskProvider, TeamByMemberProvider, TeamRepository, TrackProvider,
+};
+use crate::app::usecases::dto::{AnswerDTO, CharacterLinkDTO};
+use crate::domain::models::{AnswerInput, Normalizer, TaskID};
 use crate::domain::models::{TrackTag, UserID};
 
 #[derive(Clone)]
@@ -12,6 +14,8 @@ pub struct AnswerTask {
     task_provider: Arc<dyn TaskProvider>,
     team_repository: Arc<dyn TeamRepository>,
     track_provider: Arc<dyn TrackProvider>,
+    characters_provider: Arc<dyn CharactersProvider>,
+    normalizer: Normalizer,
 }
 
 impl AnswerTask {
@@ -20,37 +24,59 @@ impl AnswerTask {
         task_provider: Arc<dyn TaskProvider>,
         team_repository: Arc<dyn TeamRepository>,
         track_provider: Arc<dyn TrackProvider>,
+        characters_provider: Arc<dyn CharactersProvider>,
+        normalizer: Normalizer,
     ) -> Self {
         Self {
             team_provider,
             task_provider,
             team_repository,
             track_provider,
+            characters_provider,
+            normalizer,
         }
     }
 
     pub async fn execute(
         &self,
         user_id: UserID,
-        track_tag: TrackTag,
+        track_tag: &TrackTag,
         task_id: TaskID,
-        text: String,
+        input: AnswerInput,
     ) -> Result<AnswerDTO, AppError> {
         match self.team_provider.team_by_member(user_id).await? {
             Some(mut team) => {
+                let characters = self.characters_provider.characters().await?;
+                let locked: Vec<_> = characters
+                    .iter()
+                    .filter(|c| !c.is_unlocked(Some(&team)))
+                    .collect();
                 let task = self.task_provider.task(task_id).await?;
-                let answer = task.answer(&text);
-                let dto = AnswerDTO {
+                let started_at = team.track_status(track_tag)?.started_at();
+                let graded = task.answer(&self.normalizer, input, team.answer(task_id), started_at);
+                let answer = graded.answer();
+                let track = self.track_provider.track(track_tag).await?;
+                let mut dto = AnswerDTO {
+                    number: track.task_number(&task_id).unwrap_or_default(),
                     points: answer.points(),
+                    score: *answer.score(),
                     completed: answer.is_ok(),
+                    grade: graded.grade(),
+                    hint: graded.hint().cloned(),
+                    unlocked_characters: vec![],
                 };
+                let answer = graded.into_answer();
                 team.save_answer(answer);
 
-                let track = self.track_provider.track(track_tag).await?;
                 let progress = track.progress(&team.answers());
                 if progress.full_completed() {
                     team.finish_track(track_tag)?;
                 }
+                dto.unlocked_characters = locked
+                    .into_iter()
+                    .filter(|c| c.is_unlocked(Some(&team)))
+                    .map(CharacterLinkDTO::from)
+                    .collect();
 
                 self.team_repository.save_team(team).await?;
                 Ok(dto)
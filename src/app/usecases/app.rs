@@ -1,35 +1,70 @@
 use crate::app::usecases::{
-    AnswerTask, CancelReservation, CheckAdmin, CheckCaptain, CheckRegistered, CheckStartedTrack,
-    GetAvailableSlotStarts, GetAvailableTasks, GetAvailableTracks, GetCharacter, GetCharacterNames,
-    GetCompletedTasks, GetMedia, GetPlayer, GetProfile, GetTask, GetTeamReservedSlot,
-    GetTeamWithMembers, GetTrackInProgress, GetUser, GetUserTeam, GiveFeedback, ReserveSlot,
-    StartTrack, UploadMedia,
+    AnswerTask, ArchiveFeedback, CancelReservation, CheckCaptain, CheckMediaReferences,
+    CheckPermission, CheckRegistered, CheckStartedTrack, CheckTaskReference, DeleteMedia,
+    FindCharacters, FindMedia, GetAuditLog, GetAvailableSlotStarts, GetAvailableTasks,
+    GetAvailableTracks, GetCharacter, GetCharacterPage, GetCompletedTasks, GetDashboard,
+    GetEventPhase, GetFeedback, GetFeedbackInbox, GetMedia, GetMediaContent, GetMediaUsages,
+    GetPlayer, GetProfile, GetSlots, GetStaff, GetTask, GetTeamProgress, GetTeamReservedSlot,
+    GetTeamWithMembers, GetTeams, GetTrackInProgress, GetUser, GetUserTeam, GiveFeedback,
+    GrantRole, RefreshMediaFileID, RenameMedia, RenderTaskGraph, ReplyToFeedback, ReserveSlot,
+    ResetDialogue, ReviewPhotoAnswer, RevokeRole, SetEventPhase, StartTrack,
+    ToggleFavoriteCharacter, UploadMedia, UploadPhotoAnswer, ValidateTaskGraph,
 };
 
+#[derive(Clone)]
 pub struct App {
     pub answer_task: AnswerTask,
+    pub archive_feedback: ArchiveFeedback,
     pub cancel_reservation: CancelReservation,
-    pub check_admin: CheckAdmin,
     pub check_captain: CheckCaptain,
+    pub check_media_references: CheckMediaReferences,
+    pub check_permission: CheckPermission,
     pub check_registered: CheckRegistered,
     pub check_started_track: CheckStartedTrack,
+    pub check_task_reference: CheckTaskReference,
+    pub delete_media: DeleteMedia,
+    pub find_characters: FindCharacters,
+    pub find_media: FindMedia,
+    pub get_audit_log: GetAuditLog,
     pub get_available_slot_starts: GetAvailableSlotStarts,
     pub get_available_tasks: GetAvailableTasks,
     pub get_available_tracks: GetAvailableTracks,
     pub get_character: GetCharacter,
-    pub get_character_names: GetCharacterNames,
+    pub get_character_page: GetCharacterPage,
     pub get_completed_tasks: GetCompletedTasks,
+    pub get_dashboard: GetDashboard,
+    pub get_event_phase: GetEventPhase,
+    pub get_feedback: GetFeedback,
+    pub get_feedback_inbox: GetFeedbackInbox,
     pub get_media: GetMedia,
+    pub get_media_content: GetMediaContent,
+    pub get_media_usages: GetMediaUsages,
     pub get_player: GetPlayer,
     pub get_profile: GetProfile,
+    pub get_slots: GetSlots,
+    pub get_staff: GetStaff,
     pub get_task: GetTask,
+    pub get_team_progress: GetTeamProgress,
     pub get_team_reserved_slot: GetTeamReservedSlot,
     pub get_team_with_members: GetTeamWithMembers,
+    pub get_teams: GetTeams,
     pub get_track_in_progress: GetTrackInProgress,
     pub get_user: GetUser,
     pub get_user_team: GetUserTeam,
     pub give_feedback: GiveFeedback,
+    pub grant_role: GrantRole,
+    pub refresh_media_file_id: RefreshMediaFileID,
+    pub rename_media: RenameMedia,
+    pub render_task_graph: RenderTaskGraph,
+    pub reply_to_feedback: ReplyToFeedback,
     pub reserve_slot: ReserveSlot,
+    pub reset_dialogue: ResetDialogue,
+    pub review_photo_answer: ReviewPhotoAnswer,
+    pub revoke_role: RevokeRole,
+    pub set_event_phase: SetEventPhase,
     pub start_track: StartTrack,
+    pub toggle_favorite_character: ToggleFavoriteCharacter,
     pub upload_media: UploadMedia,
+    pub upload_photo_answer: UploadPhotoAnswer,
+    pub validate_task_graph: ValidateTaskGraph,
 }
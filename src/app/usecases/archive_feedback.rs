@@ -0,0 +1,48 @@
+use std::sync::Arc;
+
+use crate::app::error::AppError;
+use crate::app::ports::{AuditLogRepository, FeedbackRepository};
+use crate::app::usecases::CheckPermission;
+use crate::domain::models::{AuditAction, AuditEntry, FeedbackID, Permission, UserID};
+
+#[derive(Clone)]
+pub struct ArchiveFeedback {
+    feedback_repository: Arc<dyn FeedbackRepository>,
+    audit_log: Arc<dyn AuditLogRepository>,
+    check_permission: CheckPermission,
+}
+
+impl ArchiveFeedback {
+    pub fn new(
+        feedback_repository: Arc<dyn FeedbackRepository>,
+        audit_log: Arc<dyn AuditLogRepository>,
+        check_permission: CheckPermission,
+    ) -> Self {
+        Self {
+            feedback_repository,
+            audit_log,
+            check_permission,
+        }
+    }
+
+    pub async fn execute(&self, actor_id: UserID, id: FeedbackID) -> Result<(), AppError> {
+        self.check_permission
+            .require(actor_id, Permission::ManageFeedback)
+            .await?;
+        let mut feedback = self.feedback_repository.feedback(id).await?;
+        let before = feedback.status();
+        feedback.archive();
+        self.feedback_repository
+            .update_feedback_status(id, feedback.status())
+            .await?;
+        self.audit_log
+            .append_audit_entry(AuditEntry::new(
+                actor_id,
+                AuditAction::ArchiveFeedback,
+                format!("feedback:{id}"),
+                Some(format!("{before:?}")),
+                Some(format!("{:?}", feedback.status())),
+            ))
+            .await
+    }
+}
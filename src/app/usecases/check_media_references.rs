@@ -0,0 +1,28 @@
+use std::sync::Arc;
+
+use crate::app::error::AppError;
+use crate::app::ports::MediaRepository;
+use crate::app::usecases::CheckPermission;
+use crate::domain::models::{DanglingMediaReference, Permission, UserID};
+
+#[derive(Clone)]
+pub struct CheckMediaReferences {
+    repos: Arc<dyn MediaRepository>,
+    check_permission: CheckPermission,
+}
+
+impl CheckMediaReferences {
+    pub fn new(repos: Arc<dyn MediaRepository>, check_permission: CheckPermission) -> Self {
+        Self {
+            repos,
+            check_permission,
+        }
+    }
+
+    pub async fn execute(&self, actor_id: UserID) -> Result<Vec<DanglingMediaReference>, AppError> {
+        self.check_permission
+            .require(actor_id, Permission::ViewContent)
+            .await?;
+        self.repos.dangling_media_references().await
+    }
+}
@@ -0,0 +1,30 @@
+use std::sync::Arc;
+
+use crate::app::error::AppError;
+use crate::app::ports::RolesProvider;
+use crate::domain::models::{Permission, UserID};
+
+#[derive(Clone)]
+pub struct CheckPermission {
+    provider: Arc<dyn RolesProvider>,
+}
+
+impl CheckPermission {
+    pub fn new(provider: Arc<dyn RolesProvider>) -> Self {
+        Self { provider }
+    }
+
+    pub async fn execute(&self, user_id: UserID, permission: Permission) -> Result<bool, AppError> {
+        let roles = self.provider.roles(user_id).await?;
+        Ok(roles.has_permission(permission))
+    }
+
+    /// Для админских сценариев: ошибка, если у пользователя нет права.
+    pub async fn require(&self, user_id: UserID, permission: Permission) -> Result<(), AppError> {
+        if self.execute(user_id, permission).await? {
+            Ok(())
+        } else {
+            Err(AppError::PermissionDenied(permission))
+        }
+    }
+}
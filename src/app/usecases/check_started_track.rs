@@ -15,7 +15,7 @@ impl CheckStartedTrack {
         Self { team_provider }
     }
 
-    pub async fn execute(&self, user_id: UserID, track_tag: TrackTag) -> Result<bool, AppError> {
+    pub async fn execute(&self, user_id: UserID, track_tag: &TrackTag) -> Result<bool, AppError> {
         match self.team_provider.team_by_member(user_id).await? {
             Some(team) => Ok(team.track_is_started(track_tag)),
             None => Err(AppError::UserNotInTeam(user_id)),
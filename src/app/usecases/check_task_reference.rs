@@ -0,0 +1,36 @@
+use std::sync::Arc;
+
+use crate::app::error::AppError;
+use crate::app::ports::TaskReferenceProvider;
+use crate::domain::models::{TaskID, TrackTag};
+
+#[derive(Clone)]
+pub struct CheckTaskReference {
+    task_reference_provider: Arc<dyn TaskReferenceProvider>,
+}
+
+impl CheckTaskReference {
+    pub fn new(task_reference_provider: Arc<dyn TaskReferenceProvider>) -> Self {
+        Self {
+            task_reference_provider,
+        }
+    }
+
+    /// Есть ли ещё трек, задание в нём и варианты ответа с такими индексами.
+    /// Задания и варианты могли удалить или изменить, пока игрок отвечал.
+    pub async fn execute(
+        &self,
+        track_tag: &TrackTag,
+        task_id: Option<TaskID>,
+        option_indices: &[usize],
+    ) -> Result<bool, AppError> {
+        let Some(task_id) = task_id else {
+            return self.task_reference_provider.track_exists(track_tag).await;
+        };
+        let options = self
+            .task_reference_provider
+            .task_options_count(track_tag, task_id)
+            .await?;
+        Ok(options.is_some_and(|options| option_indices.iter().all(|&i| i < options)))
+    }
+}
@@ -0,0 +1,49 @@
+use std::sync::Arc;
+
+use crate::app::error::AppError;
+use crate::app::ports::{AuditLogRepository, MediaRepository};
+use crate::app::usecases::CheckPermission;
+use crate::domain::models::{AuditAction, AuditEntry, MediaID, Permission, UserID};
+
+#[derive(Clone)]
+pub struct DeleteMedia {
+    repos: Arc<dyn MediaRepository>,
+    audit_log: Arc<dyn AuditLogRepository>,
+    check_permission: CheckPermission,
+}
+
+impl DeleteMedia {
+    pub fn new(
+        repos: Arc<dyn MediaRepository>,
+        audit_log: Arc<dyn AuditLogRepository>,
+        check_permission: CheckPermission,
+    ) -> Self {
+        Self {
+            repos,
+            audit_log,
+            check_permission,
+        }
+    }
+
+    /// Удаляет медиафайл, если на него никто не ссылается: в базе удаление каскадное.
+    pub async fn execute(&self, actor_id: UserID, id: MediaID) -> Result<(), AppError> {
+        self.check_permission
+            .require(actor_id, Permission::EditContent)
+            .await?;
+        let media = self.repos.media(&id).await?;
+        let usages = self.repos.media_usages(&id).await?;
+        if !usages.is_empty() {
+            return Err(AppError::MediaInUse(id, usages));
+        }
+        self.repos.delete_media(&id).await?;
+        self.audit_log
+            .append_audit_entry(AuditEntry::new(
+                actor_id,
+                AuditAction::DeleteMedia,
+                format!("media:{}", id.as_str()),
+                Some(media.file_id().as_str().to_string()),
+                None,
+            ))
+            .await
+    }
+}
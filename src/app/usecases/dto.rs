@@ -1,11 +1,14 @@
+use crate::domain::models::{Answer, Places, SiteOccupancy};
 use crate::domain::models::{
-    CharacterFact, CharacterID, CharacterLegacy, CharacterName, CharacterQuote, CorrectAnswer,
-    FileID, FullName, GroupName, MAX_TEAM_SIZE, Media, MediaID, MediaType, Points, Task, TaskID,
-    TaskOption, TaskText, TaskType, Team, TeamID, TeamName, Track, TrackDescription, TrackStatus,
-    TrackTag, User, Username,
+    AnswerGrade, AuditAction, AuditEntry, AuditEntryID, Character, CharacterFact, CharacterID,
+    CharacterLegacy, CharacterName, CharacterQuote, CorrectAnswer, EventPhase, Feedback,
+    FeedbackID, FeedbackReply, FeedbackStatus, FeedbackText, FileID, FullName, GroupName, Media,
+    MediaID, MediaType, MenuItem, NumericAnswer, Points, Role, ScoreBreakdown, SerialNumber, Task,
+    TaskID, TaskOption, TaskStatus, TaskText, TaskTitle, TaskType, Team, TeamID, TeamName, Track,
+    TrackDescription, TrackName, TrackStatus, TrackTag, User, UserID, Username,
 };
 use crate::domain::models::{Site, Slot, SlotID};
-use chrono::NaiveTime;
+use chrono::{DateTime, Duration, NaiveDate, NaiveTime, Utc};
 
 pub struct UserDTO {
     pub username: Option<Username>,
@@ -30,6 +33,25 @@ pub struct PlayerDTO {
     pub solo_team: bool,
     pub reserved_slot: bool,
     pub is_captain: bool,
+    /// Разделы меню, открытые на текущем этапе мероприятия.
+    pub menu_items: Vec<MenuItem>,
+}
+
+impl PlayerDTO {
+    pub fn is_enabled(&self, item: MenuItem) -> bool {
+        self.menu_items.contains(&item)
+    }
+}
+
+pub struct EventPhaseDTO {
+    pub phase: EventPhase,
+    pub menu_items: Vec<MenuItem>,
+}
+
+impl EventPhaseDTO {
+    pub fn is_enabled(&self, item: MenuItem) -> bool {
+        self.menu_items.contains(&item)
+    }
 }
 
 pub struct TeamDTO {
@@ -39,20 +61,147 @@ pub struct TeamDTO {
     pub max_size: usize,
 }
 
-impl From<Team> for TeamDTO {
-    fn from(t: Team) -> Self {
+impl TeamDTO {
+    pub fn new(team: &Team, max_size: usize) -> Self {
+        Self {
+            id: team.id().clone(),
+            name: team.name().clone(),
+            size: team.member_ids().len(),
+            max_size,
+        }
+    }
+}
+
+/// Команда в общем списке для организаторов.
+pub struct TeamOverviewDTO {
+    pub id: TeamID,
+    pub name: TeamName,
+    pub solo: bool,
+    pub size: usize,
+    pub captain_id: UserID,
+    pub points: Points,
+    pub started_tracks: usize,
+    pub finished_tracks: usize,
+    pub reserved_slot: Option<SlotID>,
+}
+
+impl From<&Team> for TeamOverviewDTO {
+    fn from(team: &Team) -> Self {
         Self {
-            id: t.id().clone(),
-            name: t.name().clone(),
-            size: t.member_ids().len(),
-            max_size: MAX_TEAM_SIZE,
+            id: team.id().clone(),
+            name: team.name().clone(),
+            solo: team.is_solo(),
+            size: team.size(),
+            captain_id: team.captain_id(),
+            points: team
+                .answers()
+                .iter()
+                .fold(Points::zero(), |acc, a| acc + a.points()),
+            started_tracks: team.started_tracks().len(),
+            finished_tracks: team
+                .started_tracks()
+                .values()
+                .filter(|s| matches!(s, TrackStatus::Finished(..)))
+                .count(),
+            reserved_slot: team.reserved_slot().cloned(),
         }
     }
 }
 
+/// Прогресс команды по трекам и все её ответы.
+pub struct TeamProgressDTO {
+    pub tracks: Vec<TeamTrackProgressDTO>,
+    pub answers: Vec<TeamAnswerDTO>,
+}
+
+pub struct TeamTrackProgressDTO {
+    pub tag: TrackTag,
+    pub name: TrackName,
+    /// `None`, если команда ещё не начинала трек.
+    pub status: Option<TrackStatus>,
+    pub points: Points,
+    pub max_points: Points,
+    pub percent: f32,
+    pub tasks: Vec<TaskItemDTO>,
+}
+
+pub struct TeamAnswerDTO {
+    pub task_id: TaskID,
+    /// Трек и номер задания в нём, если задание ещё есть в треках.
+    pub track: Option<TrackTag>,
+    pub number: Option<usize>,
+    pub text: String,
+    pub score: ScoreBreakdown,
+    pub completed: bool,
+    pub pending_review: bool,
+    pub attempts: i32,
+    pub created_at: DateTime<Utc>,
+}
+
+impl TeamAnswerDTO {
+    pub fn new(answer: &Answer, track: Option<&Track>) -> Self {
+        Self {
+            task_id: answer.task_id(),
+            track: track.map(|t| t.tag().clone()),
+            number: track.and_then(|t| t.task_number(&answer.task_id())),
+            text: answer.text().as_str().to_string(),
+            score: *answer.score(),
+            completed: answer.is_ok(),
+            pending_review: answer.is_pending_review(),
+            attempts: answer.attempts(),
+            created_at: *answer.created_at(),
+        }
+    }
+}
+
+/// Сводка для панели мониторинга мероприятия.
+pub struct DashboardDTO {
+    pub generated_at: DateTime<Utc>,
+    pub tracks: Vec<DashboardTrackDTO>,
+    pub pending_reviews: Vec<PendingReviewDTO>,
+    pub sites: Vec<SiteOccupancy>,
+    pub latest_feedback: Vec<FeedbackDTO>,
+}
+
+pub struct DashboardTrackDTO {
+    pub tag: TrackTag,
+    pub name: TrackName,
+    /// Команды, начавшие трек, от лидеров к отстающим.
+    pub teams: Vec<DashboardTeamDTO>,
+}
+
+pub struct DashboardTeamDTO {
+    pub team_id: TeamID,
+    pub team_name: TeamName,
+    pub points: Points,
+    pub percent: f32,
+    pub finished: bool,
+    pub started_at: DateTime<Utc>,
+    pub elapsed: Duration,
+}
+
+pub struct PendingReviewDTO {
+    pub team_id: TeamID,
+    pub team_name: TeamName,
+    pub task_id: TaskID,
+    pub track: Option<TrackTag>,
+    pub number: Option<usize>,
+    pub created_at: DateTime<Utc>,
+    pub waiting: Duration,
+}
+
+/// Итог проверки фото-ответа.
+pub struct PhotoReviewDTO {
+    pub track: TrackTag,
+    /// Номер задания внутри трека.
+    pub number: usize,
+    pub points: Points,
+}
+
 pub struct Profile {
     pub user: UserDTO,
     pub team_name: Option<TeamName>,
+    pub characters: CharacterCollectionDTO,
 }
 
 pub struct TeamWithMembersDTO {
@@ -95,17 +244,200 @@ impl From<Media> for MediaDTO {
     }
 }
 
+pub struct MediaPageDTO {
+    pub items: Vec<MediaDTO>,
+    pub page: usize,
+    pub pages: usize,
+    pub total: usize,
+}
+
+pub struct FeedbackReplyDTO {
+    pub author_id: UserID,
+    pub text: FeedbackText,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<&FeedbackReply> for FeedbackReplyDTO {
+    fn from(r: &FeedbackReply) -> Self {
+        Self {
+            author_id: r.author_id(),
+            text: r.text().clone(),
+            created_at: *r.created_at(),
+        }
+    }
+}
+
+pub struct FeedbackDTO {
+    pub id: FeedbackID,
+    pub author_id: UserID,
+    pub author: UserDTO,
+    pub text: FeedbackText,
+    pub status: FeedbackStatus,
+    pub created_at: DateTime<Utc>,
+    pub replies: Vec<FeedbackReplyDTO>,
+}
+
+impl FeedbackDTO {
+    pub fn new(feedback: &Feedback, author: UserDTO) -> Self {
+        Self {
+            id: feedback.id().unwrap_or_default(),
+            author_id: feedback.author_id(),
+            author,
+            text: feedback.text().clone(),
+            status: feedback.status(),
+            created_at: *feedback.created_at(),
+            replies: feedback
+                .replies()
+                .iter()
+                .map(FeedbackReplyDTO::from)
+                .collect(),
+        }
+    }
+}
+
+pub struct FeedbackPageDTO {
+    pub items: Vec<FeedbackDTO>,
+    pub page: usize,
+    pub pages: usize,
+    pub total: usize,
+}
+
+pub struct AuditEntryDTO {
+    pub id: AuditEntryID,
+    pub actor_id: UserID,
+    pub action: AuditAction,
+    pub target: String,
+    pub before: Option<String>,
+    pub after: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<&AuditEntry> for AuditEntryDTO {
+    fn from(e: &AuditEntry) -> Self {
+        Self {
+            id: e.id().unwrap_or_default(),
+            actor_id: e.actor_id(),
+            action: e.action(),
+            target: e.target().to_string(),
+            before: e.before().map(str::to_string),
+            after: e.after().map(str::to_string),
+            created_at: *e.created_at(),
+        }
+    }
+}
+
+pub struct AuditPageDTO {
+    pub items: Vec<AuditEntryDTO>,
+    pub page: usize,
+    pub pages: usize,
+    pub total: usize,
+}
+
+pub struct StaffMemberDTO {
+    pub id: UserID,
+    pub user: UserDTO,
+    pub roles: Vec<Role>,
+}
+
 pub struct CharacterDTO {
     pub id: CharacterID,
+    pub index: SerialNumber,
     pub name: CharacterName,
     pub quote: CharacterQuote,
     pub facts: Vec<CharacterFact>,
     pub legacy: CharacterLegacy,
-    pub image_id: FileID,
+    /// У закрытой карточки — силуэт, если он загружен.
+    pub image_id: Option<FileID>,
+    pub favorite: bool,
+    /// Условие открытия, если карточка ещё закрыта.
+    pub locked: Option<CharacterUnlockDTO>,
+    /// Соседние по `index` персонажи для листания карточек.
+    pub previous: Option<CharacterID>,
+    pub next: Option<CharacterID>,
+}
+
+/// Что нужно сделать, чтобы открыть карточку.
+pub enum CharacterUnlockDTO {
+    Task(TaskID),
+    Track(TrackName),
+}
+
+pub struct CharacterItemDTO {
+    pub id: CharacterID,
+    pub index: SerialNumber,
+    pub name: CharacterName,
+    pub favorite: bool,
+    pub locked: bool,
+}
+
+impl CharacterItemDTO {
+    pub fn new(character: &Character, favorite: bool, locked: bool) -> Self {
+        Self {
+            id: character.id().clone(),
+            index: character.index(),
+            name: character.name().clone(),
+            favorite,
+            locked,
+        }
+    }
+}
+
+pub struct CharacterPageDTO {
+    pub items: Vec<CharacterItemDTO>,
+    pub favorites_only: bool,
+    pub page: usize,
+    pub pages: usize,
+    pub total: usize,
+    pub collection: CharacterCollectionDTO,
+}
+
+/// Сколько карточек открыла команда игрока.
+pub struct CharacterCollectionDTO {
+    pub collected: usize,
+    pub total: usize,
+}
+
+impl CharacterCollectionDTO {
+    pub fn new(characters: &[Character], team: Option<&Team>) -> Self {
+        Self {
+            collected: characters.iter().filter(|c| c.is_unlocked(team)).count(),
+            total: characters.len(),
+        }
+    }
+}
+
+/// Ссылка на карточку персонажа, например из пояснения к заданию.
+pub struct CharacterLinkDTO {
+    pub id: CharacterID,
+    pub name: CharacterName,
+}
+
+impl From<&Character> for CharacterLinkDTO {
+    fn from(character: &Character) -> Self {
+        Self {
+            id: character.id().clone(),
+            name: character.name().clone(),
+        }
+    }
+}
+
+pub struct TrackDTO {
+    pub tag: TrackTag,
+    pub name: TrackName,
+}
+
+impl From<&Track> for TrackDTO {
+    fn from(track: &Track) -> Self {
+        Self {
+            tag: track.tag().clone(),
+            name: track.name().clone(),
+        }
+    }
 }
 
 pub struct TrackDescriptionDTO {
     pub tag: TrackTag,
+    pub name: TrackName,
     pub description: TrackDescription,
     pub media: MediaDTO,
 }
@@ -113,7 +445,8 @@ pub struct TrackDescriptionDTO {
 impl TrackDescriptionDTO {
     pub fn new(track: &Track, media: MediaDTO) -> Self {
         Self {
-            tag: track.tag(),
+            tag: track.tag().clone(),
+            name: track.name().clone(),
             description: track.description().clone(),
             media,
         }
@@ -122,6 +455,7 @@ impl TrackDescriptionDTO {
 
 pub struct TrackInProgressDTO {
     pub tag: TrackTag,
+    pub name: TrackName,
     pub description: TrackDescription,
     pub media: MediaDTO,
     pub status: TrackStatus,
@@ -131,7 +465,8 @@ pub struct TrackInProgressDTO {
 impl TrackInProgressDTO {
     pub fn new(track: &Track, media: MediaDTO, status: TrackStatus, percent: f32) -> Self {
         Self {
-            tag: track.tag(),
+            tag: track.tag().clone(),
+            name: track.name().clone(),
             description: track.description().clone(),
             media,
             status,
@@ -140,18 +475,41 @@ impl TrackInProgressDTO {
     }
 }
 
+/// Задание в списке заданий трека.
+pub struct TaskItemDTO {
+    pub id: TaskID,
+    /// Номер задания внутри трека, показывается вместо ID из базы.
+    pub number: usize,
+    pub title: Option<TaskTitle>,
+    pub status: TaskStatus,
+}
+
+impl TaskItemDTO {
+    pub fn new(track: &Track, task: &Task, status: TaskStatus) -> Self {
+        Self {
+            id: task.id(),
+            number: track.task_number(&task.id()).unwrap_or_default(),
+            title: task.title().cloned(),
+            status,
+        }
+    }
+}
+
 pub struct TaskDTO {
     pub id: TaskID,
     pub task_type: TaskType,
     pub question: TaskText,
     pub explanation: TaskText,
-    pub media: Option<MediaDTO>,
+    pub media: Vec<MediaDTO>,
     pub options: Vec<TaskOption>,
     pub correct_answers: Vec<CorrectAnswer>,
+    pub numeric_answer: Option<NumericAnswer>,
+    /// Персонажи, упомянутые в пояснении.
+    pub characters: Vec<CharacterLinkDTO>,
 }
 
 impl TaskDTO {
-    pub fn new(task: &Task, media: Option<MediaDTO>) -> Self {
+    pub fn new(task: &Task, media: Vec<MediaDTO>, characters: Vec<CharacterLinkDTO>) -> Self {
         Self {
             id: task.id(),
             task_type: task.task_type(),
@@ -160,13 +518,22 @@ impl TaskDTO {
             media,
             options: task.options().clone(),
             correct_answers: task.correct_answers().clone(),
+            numeric_answer: task.numeric_answer().copied(),
+            characters,
         }
     }
 }
 
 pub struct AnswerDTO {
+    /// Номер задания внутри трека.
+    pub number: usize,
     pub points: Points,
+    pub score: ScoreBreakdown,
     pub completed: bool,
+    pub grade: AnswerGrade,
+    pub hint: Option<TaskText>,
+    /// Персонажи, открытые этим ответом.
+    pub unlocked_characters: Vec<CharacterLinkDTO>,
 }
 
 pub struct SlotDTO {
@@ -175,6 +542,29 @@ pub struct SlotDTO {
     pub site: Site,
 }
 
+/// Слот финала со всеми записями, для организаторов.
+pub struct SlotOverviewDTO {
+    pub id: SlotID,
+    pub start: NaiveTime,
+    pub site: Site,
+    pub capacity: usize,
+    pub reserved: usize,
+    pub reservations: Vec<SlotReservationDTO>,
+}
+
+pub struct SlotReservationDTO {
+    pub team_id: TeamID,
+    pub team_name: TeamName,
+    pub places: Places,
+}
+
+/// Запись на финал: слот и место проведения.
+pub struct ReservationDTO {
+    pub slot: SlotDTO,
+    pub date: NaiveDate,
+    pub venue: String,
+}
+
 impl From<Slot> for SlotDTO {
     fn from(s: Slot) -> Self {
         Self {
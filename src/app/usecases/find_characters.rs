@@ -0,0 +1,46 @@
+use std::sync::Arc;
+
+use crate::app::error::AppError;
+use crate::app::ports::{CharactersProvider, FavoriteCharactersRepository, TeamByMemberProvider};
+use crate::app::usecases::dto::CharacterItemDTO;
+use crate::domain::models::{UserID, search_characters};
+
+#[derive(Clone)]
+pub struct FindCharacters {
+    characters_provider: Arc<dyn CharactersProvider>,
+    favorites_repository: Arc<dyn FavoriteCharactersRepository>,
+    team_provider: Arc<dyn TeamByMemberProvider>,
+}
+
+impl FindCharacters {
+    pub fn new(
+        characters_provider: Arc<dyn CharactersProvider>,
+        favorites_repository: Arc<dyn FavoriteCharactersRepository>,
+        team_provider: Arc<dyn TeamByMemberProvider>,
+    ) -> Self {
+        Self {
+            characters_provider,
+            favorites_repository,
+            team_provider,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        user_id: UserID,
+        query: &str,
+    ) -> Result<Vec<CharacterItemDTO>, AppError> {
+        let characters = self.characters_provider.characters().await?;
+        let favorites = self
+            .favorites_repository
+            .favorite_characters(user_id)
+            .await?;
+        let team = self.team_provider.team_by_member(user_id).await?;
+        // Закрытые карточки не ищем, иначе поиск раскроет имена.
+        Ok(search_characters(&characters, query)
+            .into_iter()
+            .filter(|c| c.is_unlocked(team.as_ref()))
+            .map(|c| CharacterItemDTO::new(c, favorites.contains(c.id()), false))
+            .collect())
+    }
+}
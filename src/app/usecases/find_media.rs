@@ -0,0 +1,45 @@
+use std::sync::Arc;
+
+use crate::app::error::AppError;
+use crate::app::ports::MediaRepository;
+use crate::app::usecases::CheckPermission;
+use crate::app::usecases::dto::{MediaDTO, MediaPageDTO};
+use crate::domain::models::{Permission, UserID};
+
+pub const MEDIA_PAGE_SIZE: usize = 8;
+
+#[derive(Clone)]
+pub struct FindMedia {
+    repos: Arc<dyn MediaRepository>,
+    check_permission: CheckPermission,
+}
+
+impl FindMedia {
+    pub fn new(repos: Arc<dyn MediaRepository>, check_permission: CheckPermission) -> Self {
+        Self {
+            repos,
+            check_permission,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        actor_id: UserID,
+        query: &str,
+        page: usize,
+    ) -> Result<MediaPageDTO, AppError> {
+        self.check_permission
+            .require(actor_id, Permission::ViewContent)
+            .await?;
+        let (media, total) = self
+            .repos
+            .find_media(query, page * MEDIA_PAGE_SIZE, MEDIA_PAGE_SIZE)
+            .await?;
+        Ok(MediaPageDTO {
+            items: media.into_iter().map(MediaDTO::from).collect(),
+            page,
+            pages: total.div_ceil(MEDIA_PAGE_SIZE).max(1),
+            total,
+        })
+    }
+}
@@ -0,0 +1,45 @@
+use std::sync::Arc;
+
+use crate::app::error::AppError;
+use crate::app::ports::AuditLogRepository;
+use crate::app::usecases::CheckPermission;
+use crate::app::usecases::dto::{AuditEntryDTO, AuditPageDTO};
+use crate::domain::models::{AuditFilter, Permission, UserID};
+
+pub const AUDIT_PAGE_SIZE: usize = 10;
+
+#[derive(Clone)]
+pub struct GetAuditLog {
+    audit_log: Arc<dyn AuditLogRepository>,
+    check_permission: CheckPermission,
+}
+
+impl GetAuditLog {
+    pub fn new(audit_log: Arc<dyn AuditLogRepository>, check_permission: CheckPermission) -> Self {
+        Self {
+            audit_log,
+            check_permission,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        actor_id: UserID,
+        filter: &AuditFilter,
+        page: usize,
+    ) -> Result<AuditPageDTO, AppError> {
+        self.check_permission
+            .require(actor_id, Permission::ViewAuditLog)
+            .await?;
+        let (entries, total) = self
+            .audit_log
+            .audit_entries(filter, page * AUDIT_PAGE_SIZE, AUDIT_PAGE_SIZE)
+            .await?;
+        Ok(AuditPageDTO {
+            items: entries.iter().map(AuditEntryDTO::from).collect(),
+            page,
+            pages: total.div_ceil(AUDIT_PAGE_SIZE).max(1),
+            total,
+        })
+    }
+}
@@ -2,8 +2,9 @@ use std::sync::Arc;
 
 use crate::app::error::AppError;
 use crate::app::ports::{TeamByMemberProvider, TrackProvider};
+use crate::app::usecases::dto::TaskItemDTO;
+use crate::domain::models::TrackTag;
 use crate::domain::models::UserID;
-use crate::domain::models::{TaskID, TrackTag};
 
 #[derive(Clone)]
 pub struct GetAvailableTasks {
@@ -25,18 +26,22 @@ impl GetAvailableTasks {
     pub async fn execute(
         &self,
         user_id: UserID,
-        track_tag: TrackTag,
-    ) -> Result<Vec<TaskID>, AppError> {
+        track_tag: &TrackTag,
+    ) -> Result<Vec<TaskItemDTO>, AppError> {
         let track = self.track_provider.track(track_tag).await?;
         match self.team_provider.team_by_member(user_id).await? {
             Some(team) => {
                 let progress = track.progress(&team.answers());
-                let ids = progress
+                let mut tasks: Vec<TaskItemDTO> = progress
                     .available_and_in_progress_tasks()
                     .iter()
-                    .map(|&t| t.id())
+                    .filter_map(|&t| {
+                        let status = progress.task_status(&t.id())?;
+                        Some(TaskItemDTO::new(&track, t, status))
+                    })
                     .collect();
-                Ok(ids)
+                tasks.sort_by_key(|t| t.number);
+                Ok(tasks)
             }
             None => Err(AppError::UserNotInTeam(user_id))?,
         }
@@ -1,22 +1,37 @@
 use std::sync::Arc;
 
 use crate::app::error::AppError;
-use crate::app::ports::TeamByMemberProvider;
-use crate::domain::models::{TrackTag, UserID};
+use crate::app::ports::{TeamByMemberProvider, TrackProvider};
+use crate::app::usecases::dto::TrackDTO;
+use crate::domain::models::UserID;
 
 #[derive(Clone)]
 pub struct GetAvailableTracks {
     team_provider: Arc<dyn TeamByMemberProvider>,
+    track_provider: Arc<dyn TrackProvider>,
 }
 
 impl GetAvailableTracks {
-    pub fn new(team_provider: Arc<dyn TeamByMemberProvider>) -> Self {
-        Self { team_provider }
+    pub fn new(
+        team_provider: Arc<dyn TeamByMemberProvider>,
+        track_provider: Arc<dyn TrackProvider>,
+    ) -> Self {
+        Self {
+            team_provider,
+            track_provider,
+        }
     }
 
-    pub async fn execute(&self, user_id: UserID) -> Result<Vec<TrackTag>, AppError> {
+    pub async fn execute(&self, user_id: UserID) -> Result<Vec<TrackDTO>, AppError> {
         match self.team_provider.team_by_member(user_id).await? {
-            Some(team) => Ok(team.available_tracks().into()),
+            Some(team) => {
+                let tracks = self.track_provider.tracks().await?;
+                Ok(tracks
+                    .iter()
+                    .filter(|t| t.is_available_for(&team))
+                    .map(TrackDTO::from)
+                    .collect())
+            }
             None => Err(AppError::UserNotInTeam(user_id)),
         }
     }
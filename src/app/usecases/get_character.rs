@@ -1,41 +1,88 @@
 use std::sync::Arc;
 
 use crate::app::error::AppError;
-use crate::app::ports::{CharactersProvider, MediaProvider};
-use crate::app::usecases::dto::CharacterDTO;
-use crate::domain::models::CharacterName;
+use crate::app::ports::{
+    CharactersProvider, FavoriteCharactersRepository, MediaProvider, TeamByMemberProvider,
+    TrackProvider,
+};
+use crate::app::usecases::dto::{CharacterDTO, CharacterUnlockDTO};
+use crate::domain::models::{CharacterID, CharacterUnlock, UserID};
 
 #[derive(Clone)]
 pub struct GetCharacter {
     characters_provider: Arc<dyn CharactersProvider>,
     media_provider: Arc<dyn MediaProvider>,
+    favorites_repository: Arc<dyn FavoriteCharactersRepository>,
+    team_provider: Arc<dyn TeamByMemberProvider>,
+    track_provider: Arc<dyn TrackProvider>,
 }
 
 impl GetCharacter {
     pub fn new(
         provider: Arc<dyn CharactersProvider>,
         media_provider: Arc<dyn MediaProvider>,
+        favorites_repository: Arc<dyn FavoriteCharactersRepository>,
+        team_provider: Arc<dyn TeamByMemberProvider>,
+        track_provider: Arc<dyn TrackProvider>,
     ) -> Self {
         GetCharacter {
             characters_provider: provider,
             media_provider,
+            favorites_repository,
+            team_provider,
+            track_provider,
         }
     }
 
-    pub async fn execute(&self, name: &CharacterName) -> Result<CharacterDTO, AppError> {
-        match self.characters_provider.character_by_name(name).await? {
-            None => Err(AppError::CharacterNotFound(name.clone())),
-            Some(character) => {
-                let image = self.media_provider.media(character.media_id()).await?;
-                Ok(CharacterDTO {
-                    id: character.id().clone(),
-                    name: character.name().clone(),
-                    quote: character.quote().clone(),
-                    facts: character.facts().clone(),
-                    legacy: character.legacy().clone(),
-                    image_id: image.file_id().clone(),
-                })
+    pub async fn execute(
+        &self,
+        user_id: UserID,
+        id: &CharacterID,
+    ) -> Result<CharacterDTO, AppError> {
+        let characters = self.characters_provider.characters().await?;
+        let position = characters
+            .iter()
+            .position(|c| c.id() == id)
+            .ok_or_else(|| AppError::CharacterNotFound(id.clone()))?;
+        let character = &characters[position];
+        let team = self.team_provider.team_by_member(user_id).await?;
+        let unlocked = character.is_unlocked(team.as_ref());
+        // Закрытую карточку показываем силуэтом, настоящую картинку не раскрываем.
+        let media_id = if unlocked {
+            Some(character.media_id())
+        } else {
+            character.silhouette_media_id()
+        };
+        let image_id = match media_id {
+            Some(media_id) => Some(self.media_provider.media(media_id).await?.file_id().clone()),
+            None => None,
+        };
+        let locked = match character.unlock() {
+            Some(_) if unlocked => None,
+            Some(CharacterUnlock::Task(task_id)) => Some(CharacterUnlockDTO::Task(*task_id)),
+            Some(CharacterUnlock::Track(tag)) => {
+                let track = self.track_provider.track(tag).await?;
+                Some(CharacterUnlockDTO::Track(track.name().clone()))
             }
-        }
+            None => None,
+        };
+        let favorite = self
+            .favorites_repository
+            .favorite_characters(user_id)
+            .await?
+            .contains(id);
+        Ok(CharacterDTO {
+            id: character.id().clone(),
+            index: character.index(),
+            name: character.name().clone(),
+            quote: character.quote().clone(),
+            facts: character.facts().clone(),
+            legacy: character.legacy().clone(),
+            image_id,
+            favorite,
+            locked,
+            previous: position.checked_sub(1).map(|i| characters[i].id().clone()),
+            next: characters.get(position + 1).map(|c| c.id().clone()),
+        })
     }
 }
@@ -0,0 +1,65 @@
+use std::sync::Arc;
+
+use crate::app::error::AppError;
+use crate::app::ports::{CharactersProvider, FavoriteCharactersRepository, TeamByMemberProvider};
+use crate::app::usecases::dto::{CharacterCollectionDTO, CharacterItemDTO, CharacterPageDTO};
+use crate::domain::models::UserID;
+
+pub const CHARACTER_PAGE_SIZE: usize = 8;
+
+#[derive(Clone)]
+pub struct GetCharacterPage {
+    characters_provider: Arc<dyn CharactersProvider>,
+    favorites_repository: Arc<dyn FavoriteCharactersRepository>,
+    team_provider: Arc<dyn TeamByMemberProvider>,
+}
+
+impl GetCharacterPage {
+    pub fn new(
+        characters_provider: Arc<dyn CharactersProvider>,
+        favorites_repository: Arc<dyn FavoriteCharactersRepository>,
+        team_provider: Arc<dyn TeamByMemberProvider>,
+    ) -> Self {
+        Self {
+            characters_provider,
+            favorites_repository,
+            team_provider,
+        }
+    }
+
+    /// Страница галереи в порядке `index`, при `favorites_only` — только избранные.
+    pub async fn execute(
+        &self,
+        user_id: UserID,
+        favorites_only: bool,
+        page: usize,
+    ) -> Result<CharacterPageDTO, AppError> {
+        let characters = self.characters_provider.characters().await?;
+        let favorites = self
+            .favorites_repository
+            .favorite_characters(user_id)
+            .await?;
+        let team = self.team_provider.team_by_member(user_id).await?;
+        let items: Vec<_> = characters
+            .iter()
+            .map(|c| {
+                let locked = !c.is_unlocked(team.as_ref());
+                CharacterItemDTO::new(c, favorites.contains(c.id()), locked)
+            })
+            .filter(|item| !favorites_only || item.favorite)
+            .collect();
+        let total = items.len();
+        Ok(CharacterPageDTO {
+            items: items
+                .into_iter()
+                .skip(page * CHARACTER_PAGE_SIZE)
+                .take(CHARACTER_PAGE_SIZE)
+                .collect(),
+            favorites_only,
+            page,
+            pages: total.div_ceil(CHARACTER_PAGE_SIZE).max(1),
+            total,
+            collection: CharacterCollectionDTO::new(&characters, team.as_ref()),
+        })
+    }
+}
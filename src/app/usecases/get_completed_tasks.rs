@@ -2,8 +2,9 @@ use std::sync::Arc;
 
 use crate::app::error::AppError;
 use crate::app::ports::{TeamByMemberProvider, TrackProvider};
+use crate::app::usecases::dto::TaskItemDTO;
 use crate::domain::models::UserID;
-use crate::domain::models::{TaskID, TrackTag};
+use crate::domain::models::{TaskStatus, TrackTag};
 
 #[derive(Clone)]
 pub struct GetCompletedTasks {
@@ -25,14 +26,19 @@ impl GetCompletedTasks {
     pub async fn execute(
         &self,
         user_id: UserID,
-        track_tag: TrackTag,
-    ) -> Result<Vec<TaskID>, AppError> {
+        track_tag: &TrackTag,
+    ) -> Result<Vec<TaskItemDTO>, AppError> {
         let track = self.track_provider.track(track_tag).await?;
         match self.team_provider.team_by_member(user_id).await? {
             Some(team) => {
                 let progress = track.progress(&team.answers());
-                let ids = progress.completed_tasks().iter().map(|&t| t.id()).collect();
-                Ok(ids)
+                let mut tasks: Vec<TaskItemDTO> = progress
+                    .completed_tasks()
+                    .iter()
+                    .map(|&t| TaskItemDTO::new(&track, t, TaskStatus::Completed))
+                    .collect();
+                tasks.sort_by_key(|t| t.number);
+                Ok(tasks)
             }
             None => Err(AppError::UserNotInTeam(user_id))?,
         }
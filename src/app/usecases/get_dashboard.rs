@@ -0,0 +1,112 @@
+use std::sync::Arc;
+
+use chrono::Utc;
+
+use crate::app::error::AppError;
+use crate::app::ports::{DashboardProvider, FeedbackRepository, UserProvider};
+use crate::app::usecases::CheckPermission;
+use crate::app::usecases::dto::{
+    DashboardDTO, DashboardTeamDTO, DashboardTrackDTO, FeedbackDTO, PendingReviewDTO,
+};
+use crate::domain::models::{Permission, TrackStatus, UserID};
+
+/// Сколько последних отзывов показывает панель.
+pub const DASHBOARD_FEEDBACK_COUNT: usize = 5;
+
+#[derive(Clone)]
+pub struct GetDashboard {
+    dashboard_provider: Arc<dyn DashboardProvider>,
+    feedback_repository: Arc<dyn FeedbackRepository>,
+    user_provider: Arc<dyn UserProvider>,
+    check_permission: CheckPermission,
+}
+
+impl GetDashboard {
+    pub fn new(
+        dashboard_provider: Arc<dyn DashboardProvider>,
+        feedback_repository: Arc<dyn FeedbackRepository>,
+        user_provider: Arc<dyn UserProvider>,
+        check_permission: CheckPermission,
+    ) -> Self {
+        Self {
+            dashboard_provider,
+            feedback_repository,
+            user_provider,
+            check_permission,
+        }
+    }
+
+    pub async fn execute(&self, actor_id: UserID) -> Result<DashboardDTO, AppError> {
+        self.check_permission
+            .require(actor_id, Permission::ViewProgress)
+            .await?;
+        let now = Utc::now();
+        let tracks = self.dashboard_provider.track_summaries().await?;
+        let progress = self.dashboard_provider.track_team_progress().await?;
+
+        let tracks_dto = tracks
+            .iter()
+            .map(|track| {
+                let mut teams: Vec<DashboardTeamDTO> = progress
+                    .iter()
+                    .filter(|p| p.track_tag == track.tag)
+                    .map(|p| DashboardTeamDTO {
+                        team_id: p.team_id.clone(),
+                        team_name: p.team_name.clone(),
+                        points: p.points,
+                        percent: track.percent(p.points),
+                        finished: matches!(p.status, TrackStatus::Finished(..)),
+                        started_at: p.status.started_at(),
+                        elapsed: p.status.elapsed(now),
+                    })
+                    .collect();
+                teams.sort_by(|a, b| {
+                    b.percent
+                        .total_cmp(&a.percent)
+                        .then(a.elapsed.cmp(&b.elapsed))
+                });
+                DashboardTrackDTO {
+                    tag: track.tag.clone(),
+                    name: track.name.clone(),
+                    teams,
+                }
+            })
+            .collect();
+
+        let pending_reviews = self
+            .dashboard_provider
+            .pending_photo_reviews()
+            .await?
+            .into_iter()
+            .map(|review| PendingReviewDTO {
+                track: review.track_tag,
+                number: review.number,
+                waiting: now - review.created_at,
+                team_id: review.team_id,
+                team_name: review.team_name,
+                task_id: review.task_id,
+                created_at: review.created_at,
+            })
+            .collect();
+
+        let sites = self.dashboard_provider.site_occupancy().await?;
+
+        let (feedbacks, _) = self
+            .feedback_repository
+            .feedbacks(None, 0, DASHBOARD_FEEDBACK_COUNT)
+            .await?;
+        let mut latest_feedback = Vec::with_capacity(feedbacks.len());
+        for feedback in feedbacks {
+            let author = self.user_provider.user(feedback.author_id()).await?;
+            latest_feedback.push(FeedbackDTO::new(&feedback, author.into()));
+        }
+
+        Ok(DashboardDTO {
+            generated_at: now,
+            tracks: tracks_dto,
+            pending_reviews,
+            sites,
+            latest_feedback,
+        })
+    }
+}
@@ -0,0 +1,32 @@
+use std::sync::Arc;
+
+use crate::app::error::AppError;
+use crate::app::ports::EventPhaseProvider;
+use crate::app::usecases::dto::EventPhaseDTO;
+use crate::config::PhasesConfig;
+
+#[derive(Clone)]
+pub struct GetEventPhase {
+    provider: Arc<dyn EventPhaseProvider>,
+    phases: PhasesConfig,
+}
+
+impl GetEventPhase {
+    pub fn new(provider: Arc<dyn EventPhaseProvider>, phases: PhasesConfig) -> Self {
+        Self { provider, phases }
+    }
+
+    /// Текущий этап и доступные на нём разделы меню. Читается при каждом обращении,
+    /// поэтому переключение этапа действует сразу, без перезапуска бота.
+    pub async fn execute(&self) -> Result<EventPhaseDTO, AppError> {
+        let phase = self
+            .provider
+            .event_phase()
+            .await?
+            .unwrap_or(self.phases.initial);
+        Ok(EventPhaseDTO {
+            phase,
+            menu_items: self.phases.menu_items(phase).to_vec(),
+        })
+    }
+}
@@ -0,0 +1,37 @@
+use std::sync::Arc;
+
+use crate::app::error::AppError;
+use crate::app::ports::{FeedbackRepository, UserProvider};
+use crate::app::usecases::CheckPermission;
+use crate::app::usecases::dto::FeedbackDTO;
+use crate::domain::models::{FeedbackID, Permission, UserID};
+
+#[derive(Clone)]
+pub struct GetFeedback {
+    feedback_repository: Arc<dyn FeedbackRepository>,
+    user_provider: Arc<dyn UserProvider>,
+    check_permission: CheckPermission,
+}
+
+impl GetFeedback {
+    pub fn new(
+        feedback_repository: Arc<dyn FeedbackRepository>,
+        user_provider: Arc<dyn UserProvider>,
+        check_permission: CheckPermission,
+    ) -> Self {
+        Self {
+            feedback_repository,
+            user_provider,
+            check_permission,
+        }
+    }
+
+    pub async fn execute(&self, actor_id: UserID, id: FeedbackID) -> Result<FeedbackDTO, AppError> {
+        self.check_permission
+            .require(actor_id, Permission::ManageFeedback)
+            .await?;
+        let feedback = self.feedback_repository.feedback(id).await?;
+        let author = self.user_provider.user(feedback.author_id()).await?;
+        Ok(FeedbackDTO::new(&feedback, author.into()))
+    }
+}
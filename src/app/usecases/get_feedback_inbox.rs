@@ -0,0 +1,57 @@
+use std::sync::Arc;
+
+use crate::app::error::AppError;
+use crate::app::ports::{FeedbackRepository, UserProvider};
+use crate::app::usecases::CheckPermission;
+use crate::app::usecases::dto::{FeedbackDTO, FeedbackPageDTO};
+use crate::domain::models::{FeedbackStatus, Permission, UserID};
+
+pub const FEEDBACK_PAGE_SIZE: usize = 5;
+
+#[derive(Clone)]
+pub struct GetFeedbackInbox {
+    feedback_repository: Arc<dyn FeedbackRepository>,
+    user_provider: Arc<dyn UserProvider>,
+    check_permission: CheckPermission,
+}
+
+impl GetFeedbackInbox {
+    pub fn new(
+        feedback_repository: Arc<dyn FeedbackRepository>,
+        user_provider: Arc<dyn UserProvider>,
+        check_permission: CheckPermission,
+    ) -> Self {
+        Self {
+            feedback_repository,
+            user_provider,
+            check_permission,
+        }
+    }
+
+    /// Страница обратной связи с указанным статусом, `None` — любой статус.
+    pub async fn execute(
+        &self,
+        actor_id: UserID,
+        status: Option<FeedbackStatus>,
+        page: usize,
+    ) -> Result<FeedbackPageDTO, AppError> {
+        self.check_permission
+            .require(actor_id, Permission::ManageFeedback)
+            .await?;
+        let (feedbacks, total) = self
+            .feedback_repository
+            .feedbacks(status, page * FEEDBACK_PAGE_SIZE, FEEDBACK_PAGE_SIZE)
+            .await?;
+        let mut items = Vec::with_capacity(feedbacks.len());
+        for feedback in feedbacks {
+            let author = self.user_provider.user(feedback.author_id()).await?;
+            items.push(FeedbackDTO::new(&feedback, author.into()));
+        }
+        Ok(FeedbackPageDTO {
+            items,
+            page,
+            pages: total.div_ceil(FEEDBACK_PAGE_SIZE).max(1),
+            total,
+        })
+    }
+}
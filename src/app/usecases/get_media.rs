@@ -2,19 +2,30 @@ use std::sync::Arc;
 
 use crate::app::error::AppError;
 use crate::app::ports::MediaProvider;
-use crate::domain::models::{Media, MediaID};
+use crate::app::usecases::CheckPermission;
+use crate::domain::models::{Media, MediaID, Permission, UserID};
 
 #[derive(Clone)]
 pub struct GetMedia {
     provider: Arc<dyn MediaProvider + Send + Sync>,
+    check_permission: CheckPermission,
 }
 
 impl GetMedia {
-    pub fn new(provider: Arc<dyn MediaProvider + Send + Sync>) -> Self {
-        Self { provider }
+    pub fn new(
+        provider: Arc<dyn MediaProvider + Send + Sync>,
+        check_permission: CheckPermission,
+    ) -> Self {
+        Self {
+            provider,
+            check_permission,
+        }
     }
 
-    pub async fn execute(&self, id: MediaID) -> Result<Media, AppError> {
+    pub async fn execute(&self, actor_id: UserID, id: MediaID) -> Result<Media, AppError> {
+        self.check_permission
+            .require(actor_id, Permission::ViewContent)
+            .await?;
         self.provider.media(&id).await.map(Into::into)
     }
 }
@@ -0,0 +1,20 @@
+use std::sync::Arc;
+
+use crate::app::error::AppError;
+use crate::app::ports::MediaRepository;
+use crate::domain::models::{MediaContent, MediaID};
+
+#[derive(Clone)]
+pub struct GetMediaContent {
+    repos: Arc<dyn MediaRepository>,
+}
+
+impl GetMediaContent {
+    pub fn new(repos: Arc<dyn MediaRepository>) -> Self {
+        Self { repos }
+    }
+
+    pub async fn execute(&self, id: MediaID) -> Result<Option<MediaContent>, AppError> {
+        self.repos.media_content(&id).await
+    }
+}
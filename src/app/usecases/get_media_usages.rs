@@ -0,0 +1,33 @@
+use std::sync::Arc;
+
+use crate::app::error::AppError;
+use crate::app::ports::MediaRepository;
+use crate::app::usecases::CheckPermission;
+use crate::domain::models::{MediaID, MediaUsage, Permission, UserID};
+
+#[derive(Clone)]
+pub struct GetMediaUsages {
+    repos: Arc<dyn MediaRepository>,
+    check_permission: CheckPermission,
+}
+
+impl GetMediaUsages {
+    pub fn new(repos: Arc<dyn MediaRepository>, check_permission: CheckPermission) -> Self {
+        Self {
+            repos,
+            check_permission,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        actor_id: UserID,
+        id: MediaID,
+    ) -> Result<Vec<MediaUsage>, AppError> {
+        self.check_permission
+            .require(actor_id, Permission::ViewContent)
+            .await?;
+        self.repos.media(&id).await?;
+        self.repos.media_usages(&id).await
+    }
+}
@@ -3,6 +3,7 @@ use std::sync::Arc;
 use crate::app::error::AppError;
 use crate::app::ports::TeamByMemberProvider;
 use crate::app::ports::UserProvider;
+use crate::app::usecases::GetEventPhase;
 use crate::app::usecases::dto::PlayerDTO;
 use crate::domain::models::UserID;
 
@@ -10,16 +11,19 @@ use crate::domain::models::UserID;
 pub struct GetPlayer {
     user_provider: Arc<dyn UserProvider>,
     team_provider: Arc<dyn TeamByMemberProvider>,
+    get_event_phase: GetEventPhase,
 }
 
 impl GetPlayer {
     pub fn new(
         user_provider: Arc<dyn UserProvider>,
         team_provider: Arc<dyn TeamByMemberProvider>,
+        get_event_phase: GetEventPhase,
     ) -> Self {
         Self {
             user_provider,
             team_provider,
+            get_event_phase,
         }
     }
 
@@ -30,11 +34,13 @@ impl GetPlayer {
             .team_by_member(user_id)
             .await?
             .ok_or(AppError::UserNotInTeam(user_id))?;
+        let phase = self.get_event_phase.execute().await?;
         Ok(PlayerDTO {
             username: user.username().cloned(),
             solo_team: team.is_solo(),
             reserved_slot: team.reserved_slot().is_some(),
             is_captain: team.is_captain(user_id),
+            menu_items: phase.menu_items,
         })
     }
 }
@@ -1,8 +1,8 @@
 use std::sync::Arc;
 
 use crate::app::error::AppError;
-use crate::app::ports::{TeamByMemberProvider, UserProvider};
-use crate::app::usecases::dto::Profile;
+use crate::app::ports::{CharactersProvider, TeamByMemberProvider, UserProvider};
+use crate::app::usecases::dto::{CharacterCollectionDTO, Profile};
 use crate::domain::models::UserID;
 
 use super::dto::UserDTO;
@@ -11,31 +11,31 @@ use super::dto::UserDTO;
 pub struct GetProfile {
     user_provider: Arc<dyn UserProvider>,
     team_provider: Arc<dyn TeamByMemberProvider>,
+    characters_provider: Arc<dyn CharactersProvider>,
 }
 
 impl GetProfile {
     pub fn new(
         user_provider: Arc<dyn UserProvider>,
         team_provider: Arc<dyn TeamByMemberProvider>,
+        characters_provider: Arc<dyn CharactersProvider>,
     ) -> GetProfile {
         Self {
             user_provider,
             team_provider,
+            characters_provider,
         }
     }
 
     pub async fn execute(&self, user_id: UserID) -> Result<Profile, AppError> {
         let user = self.user_provider.user(user_id).await?;
         let dto = UserDTO::from(user);
-        match self.team_provider.team_by_member(user_id).await? {
-            Some(team) => Ok(Profile {
-                user: dto,
-                team_name: Some(team.name().clone()),
-            }),
-            None => Ok(Profile {
-                user: dto,
-                team_name: None,
-            }),
-        }
+        let team = self.team_provider.team_by_member(user_id).await?;
+        let characters = self.characters_provider.characters().await?;
+        Ok(Profile {
+            user: dto,
+            team_name: team.as_ref().map(|t| t.name().clone()),
+            characters: CharacterCollectionDTO::new(&characters, team.as_ref()),
+        })
     }
 }
@@ -0,0 +1,63 @@
+use std::sync::Arc;
+
+use crate::app::error::AppError;
+use crate::app::ports::{SlotsProvider, TeamProvider};
+use crate::app::usecases::CheckPermission;
+use crate::app::usecases::dto::{SlotOverviewDTO, SlotReservationDTO};
+use crate::domain::models::{Permission, UserID};
+
+#[derive(Clone)]
+pub struct GetSlots {
+    slots_provider: Arc<dyn SlotsProvider>,
+    team_provider: Arc<dyn TeamProvider>,
+    check_permission: CheckPermission,
+}
+
+impl GetSlots {
+    pub fn new(
+        slots_provider: Arc<dyn SlotsProvider>,
+        team_provider: Arc<dyn TeamProvider>,
+        check_permission: CheckPermission,
+    ) -> Self {
+        Self {
+            slots_provider,
+            team_provider,
+            check_permission,
+        }
+    }
+
+    /// Все слоты финала по времени начала вместе с записавшимися командами.
+    pub async fn execute(&self, actor_id: UserID) -> Result<Vec<SlotOverviewDTO>, AppError> {
+        self.check_permission
+            .require(actor_id, Permission::ViewProgress)
+            .await?;
+        let mut slots = self.slots_provider.slots().await?;
+        slots.sort_by(|a, b| {
+            a.start()
+                .cmp(&b.start())
+                .then_with(|| a.site().as_str().cmp(b.site().as_str()))
+        });
+
+        let mut items = Vec::with_capacity(slots.len());
+        for slot in slots {
+            let mut reservations = Vec::with_capacity(slot.reservations().len());
+            for reservation in slot.reservations() {
+                let team = self.team_provider.team(reservation.team_id()).await?;
+                reservations.push(SlotReservationDTO {
+                    team_id: team.id().clone(),
+                    team_name: team.name().clone(),
+                    places: reservation.places(),
+                });
+            }
+            items.push(SlotOverviewDTO {
+                id: slot.id().clone(),
+                start: slot.start(),
+                site: slot.site().clone(),
+                capacity: slot.capacity(),
+                reserved: slot.reserved(),
+                reservations,
+            });
+        }
+        Ok(items)
+    }
+}
@@ -0,0 +1,51 @@
+use std::sync::Arc;
+
+use crate::app::error::AppError;
+use crate::app::ports::{RolesProvider, UserProvider};
+use crate::app::usecases::CheckPermission;
+use crate::app::usecases::dto::StaffMemberDTO;
+use crate::domain::models::{Permission, UserID};
+
+#[derive(Clone)]
+pub struct GetStaff {
+    roles_provider: Arc<dyn RolesProvider>,
+    user_provider: Arc<dyn UserProvider>,
+    check_permission: CheckPermission,
+}
+
+impl GetStaff {
+    pub fn new(
+        roles_provider: Arc<dyn RolesProvider>,
+        user_provider: Arc<dyn UserProvider>,
+        check_permission: CheckPermission,
+    ) -> Self {
+        Self {
+            roles_provider,
+            user_provider,
+            check_permission,
+        }
+    }
+
+    pub async fn execute(&self, actor_id: UserID) -> Result<Vec<StaffMemberDTO>, AppError> {
+        self.check_permission
+            .require(actor_id, Permission::ManageRoles)
+            .await?;
+        let staff = self.roles_provider.staff().await?;
+        let mut members = Vec::with_capacity(staff.len());
+        for (id, roles) in staff {
+            let user = self.user_provider.user(id).await?;
+            members.push(StaffMemberDTO {
+                id,
+                user: user.into(),
+                roles: roles.roles().to_vec(),
+            });
+        }
+        Ok(members)
+    }
+
+    /// Без проверки прав: список нужен боту при запуске.
+    pub async fn members(&self) -> Result<Vec<UserID>, AppError> {
+        let staff = self.roles_provider.staff().await?;
+        Ok(staff.into_iter().map(|(id, _)| id).collect())
+    }
+}
@@ -1,36 +1,43 @@
 use std::sync::Arc;
 
 use crate::app::error::AppError;
-use crate::app::ports::{MediaProvider, TaskProvider};
+use crate::app::ports::{CharactersProvider, MediaProvider, TaskProvider};
 use crate::app::usecases::dto::MediaDTO;
-use crate::app::usecases::dto::TaskDTO;
-use crate::domain::models::TaskID;
+use crate::app::usecases::dto::{CharacterLinkDTO, TaskDTO};
+use crate::domain::models::{TaskID, mentioned_characters};
 
 #[derive(Clone)]
 pub struct GetTask {
     task_provider: Arc<dyn TaskProvider>,
     media_provider: Arc<dyn MediaProvider>,
+    characters_provider: Arc<dyn CharactersProvider>,
 }
 
 impl GetTask {
     pub fn new(
         task_provider: Arc<dyn TaskProvider>,
         media_provider: Arc<dyn MediaProvider>,
+        characters_provider: Arc<dyn CharactersProvider>,
     ) -> Self {
         Self {
             task_provider,
             media_provider,
+            characters_provider,
         }
     }
 
     pub async fn execute(&self, id: TaskID) -> Result<TaskDTO, AppError> {
         let task = self.task_provider.task(id).await?;
-        let media = if let Some(media_id) = task.media_id() {
-            let media = self.media_provider.media(media_id).await?;
-            Some(MediaDTO::from(media))
-        } else {
-            None
-        };
-        Ok(TaskDTO::new(&task, media))
+        let mut media = Vec::new();
+        for media_id in task.media_ids() {
+            let m = self.media_provider.media(media_id).await?;
+            media.push(MediaDTO::from(m));
+        }
+        let characters = self.characters_provider.characters().await?;
+        let characters = mentioned_characters(&characters, task.explanation().as_str())
+            .into_iter()
+            .map(CharacterLinkDTO::from)
+            .collect();
+        Ok(TaskDTO::new(&task, media, characters))
     }
 }
@@ -0,0 +1,83 @@
+use std::cmp::Reverse;
+use std::sync::Arc;
+
+use crate::app::error::AppError;
+use crate::app::ports::{TeamProvider, TrackProvider};
+use crate::app::usecases::CheckPermission;
+use crate::app::usecases::dto::{
+    TaskItemDTO, TeamAnswerDTO, TeamProgressDTO, TeamTrackProgressDTO,
+};
+use crate::domain::models::{Permission, TeamID, UserID};
+
+#[derive(Clone)]
+pub struct GetTeamProgress {
+    team_provider: Arc<dyn TeamProvider>,
+    track_provider: Arc<dyn TrackProvider>,
+    check_permission: CheckPermission,
+}
+
+impl GetTeamProgress {
+    pub fn new(
+        team_provider: Arc<dyn TeamProvider>,
+        track_provider: Arc<dyn TrackProvider>,
+        check_permission: CheckPermission,
+    ) -> Self {
+        Self {
+            team_provider,
+            track_provider,
+            check_permission,
+        }
+    }
+
+    /// Прогресс по всем трекам, включая неначатые, и ответы от новых к старым.
+    pub async fn execute(
+        &self,
+        actor_id: UserID,
+        team_id: TeamID,
+    ) -> Result<TeamProgressDTO, AppError> {
+        self.check_permission
+            .require(actor_id, Permission::ViewProgress)
+            .await?;
+        let team = self.team_provider.team(&team_id).await?;
+        let tracks = self.track_provider.tracks().await?;
+        let answers = team.answers();
+
+        let tracks_progress = tracks
+            .iter()
+            .map(|track| {
+                let progress = track.progress(&answers);
+                let tasks = track
+                    .tasks()
+                    .into_iter()
+                    .filter_map(|t| {
+                        let status = progress.task_status(&t.id())?;
+                        Some(TaskItemDTO::new(track, t, status))
+                    })
+                    .collect();
+                TeamTrackProgressDTO {
+                    tag: track.tag().clone(),
+                    name: track.name().clone(),
+                    status: team.track_status(track.tag()).ok().cloned(),
+                    points: progress.points(),
+                    max_points: progress.max_points(),
+                    percent: progress.percent(),
+                    tasks,
+                }
+            })
+            .collect();
+
+        let mut team_answers: Vec<TeamAnswerDTO> = answers
+            .iter()
+            .map(|&a| {
+                let track = tracks.iter().find(|t| t.task(&a.task_id()).is_some());
+                TeamAnswerDTO::new(a, track)
+            })
+            .collect();
+        team_answers.sort_by_key(|a| Reverse(a.created_at));
+
+        Ok(TeamProgressDTO {
+            tracks: tracks_progress,
+            answers: team_answers,
+        })
+    }
+}
@@ -5,6 +5,7 @@ use crate::app::ports::{SlotProvider, TeamByMemberProvider};
 use crate::app::usecases::dto::SlotDTO;
 use crate::domain::models::UserID;
 
+#[derive(Clone)]
 pub struct GetTeamReservedSlot {
     team_provider: Arc<dyn TeamByMemberProvider>,
     slot_provider: Arc<dyn SlotProvider>,
@@ -2,25 +2,45 @@ use std::sync::Arc;
 
 use crate::app::error::AppError;
 use crate::app::ports::{TeamProvider, UserProvider};
+use crate::app::usecases::CheckPermission;
 use crate::app::usecases::dto::{TeamWithMembersDTO, UserDTO};
-use crate::domain::models::{MAX_TEAM_SIZE, TeamID};
+use crate::domain::models::{Permission, TeamID, UserID};
 
 #[derive(Clone)]
 pub struct GetTeamWithMembers {
     team_provider: Arc<dyn TeamProvider>,
     user_provider: Arc<dyn UserProvider>,
+    max_team_size: usize,
+    check_permission: CheckPermission,
 }
 
 impl GetTeamWithMembers {
-    pub fn new(team_provider: Arc<dyn TeamProvider>, user_provider: Arc<dyn UserProvider>) -> Self {
+    pub fn new(
+        team_provider: Arc<dyn TeamProvider>,
+        user_provider: Arc<dyn UserProvider>,
+        max_team_size: usize,
+        check_permission: CheckPermission,
+    ) -> Self {
         Self {
             team_provider,
             user_provider,
+            max_team_size,
+            check_permission,
         }
     }
 
-    pub async fn execute(&self, team_id: TeamID) -> Result<TeamWithMembersDTO, AppError> {
+    /// Состав команды видят её участники и организаторы с правом просмотра прогресса.
+    pub async fn execute(
+        &self,
+        actor_id: UserID,
+        team_id: TeamID,
+    ) -> Result<TeamWithMembersDTO, AppError> {
         let team = self.team_provider.team(&team_id).await?;
+        if !team.member_ids().contains(&actor_id) {
+            self.check_permission
+                .require(actor_id, Permission::ViewProgress)
+                .await?;
+        }
         let mut members: Vec<UserDTO> = Vec::new();
         for &member_id in team.member_ids() {
             let member = self.user_provider.user(member_id).await?;
@@ -29,7 +49,7 @@ impl GetTeamWithMembers {
         let captain = self.user_provider.user(team.captain_id()).await?.into();
         Ok(TeamWithMembersDTO::new(
             &team,
-            MAX_TEAM_SIZE,
+            self.max_team_size,
             captain,
             members,
         ))
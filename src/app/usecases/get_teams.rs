@@ -0,0 +1,30 @@
+use std::sync::Arc;
+
+use crate::app::error::AppError;
+use crate::app::ports::TeamsProvider;
+use crate::app::usecases::CheckPermission;
+use crate::app::usecases::dto::TeamOverviewDTO;
+use crate::domain::models::{Permission, UserID};
+
+#[derive(Clone)]
+pub struct GetTeams {
+    teams_provider: Arc<dyn TeamsProvider>,
+    check_permission: CheckPermission,
+}
+
+impl GetTeams {
+    pub fn new(teams_provider: Arc<dyn TeamsProvider>, check_permission: CheckPermission) -> Self {
+        Self {
+            teams_provider,
+            check_permission,
+        }
+    }
+
+    pub async fn execute(&self, actor_id: UserID) -> Result<Vec<TeamOverviewDTO>, AppError> {
+        self.check_permission
+            .require(actor_id, Permission::ViewProgress)
+            .await?;
+        let teams = self.teams_provider.teams().await?;
+        Ok(teams.iter().map(TeamOverviewDTO::from).collect())
+    }
+}
@@ -28,7 +28,7 @@ impl GetTrackInProgress {
     pub async fn execute(
         &self,
         user_id: UserID,
-        track_tag: TrackTag,
+        track_tag: &TrackTag,
     ) -> Result<TrackInProgressDTO, AppError> {
         match self.team_provider.team_by_member(user_id).await? {
             Some(team) => {
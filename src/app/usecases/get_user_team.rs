@@ -8,16 +8,20 @@ use crate::domain::models::UserID;
 #[derive(Clone)]
 pub struct GetUserTeam {
     provider: Arc<dyn TeamByMemberProvider>,
+    max_team_size: usize,
 }
 
 impl GetUserTeam {
-    pub fn new(provider: Arc<dyn TeamByMemberProvider>) -> GetUserTeam {
-        Self { provider }
+    pub fn new(provider: Arc<dyn TeamByMemberProvider>, max_team_size: usize) -> GetUserTeam {
+        Self {
+            provider,
+            max_team_size,
+        }
     }
 
     pub async fn execute(&self, user_id: UserID) -> Result<Option<TeamDTO>, AppError> {
         match self.provider.team_by_member(user_id).await? {
-            Some(team) => Ok(Some(team.into())),
+            Some(team) => Ok(Some(TeamDTO::new(&team, self.max_team_size))),
             None => Ok(None),
         }
     }
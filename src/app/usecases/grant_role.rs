@@ -0,0 +1,59 @@
+use std::sync::Arc;
+
+use crate::app::error::AppError;
+use crate::app::ports::{AuditLogRepository, RolesRepository, UserProvider};
+use crate::app::usecases::CheckPermission;
+use crate::domain::models::{AuditAction, AuditEntry, Permission, Role, UserID};
+
+#[derive(Clone)]
+pub struct GrantRole {
+    roles_repository: Arc<dyn RolesRepository>,
+    user_provider: Arc<dyn UserProvider>,
+    audit_log: Arc<dyn AuditLogRepository>,
+    check_permission: CheckPermission,
+}
+
+impl GrantRole {
+    pub fn new(
+        roles_repository: Arc<dyn RolesRepository>,
+        user_provider: Arc<dyn UserProvider>,
+        audit_log: Arc<dyn AuditLogRepository>,
+        check_permission: CheckPermission,
+    ) -> Self {
+        Self {
+            roles_repository,
+            user_provider,
+            audit_log,
+            check_permission,
+        }
+    }
+
+    /// Выдаёт роль зарегистрированному пользователю. `false`, если роль уже была.
+    pub async fn execute(
+        &self,
+        actor_id: UserID,
+        user_id: UserID,
+        role: Role,
+    ) -> Result<bool, AppError> {
+        self.check_permission
+            .require(actor_id, Permission::ManageRoles)
+            .await?;
+        self.user_provider.user(user_id).await?;
+        let granted = self
+            .roles_repository
+            .grant_role(user_id, role, actor_id)
+            .await?;
+        if granted {
+            self.audit_log
+                .append_audit_entry(AuditEntry::new(
+                    actor_id,
+                    AuditAction::GrantRole,
+                    format!("user:{}", user_id.as_i64()),
+                    None,
+                    Some(role.code().to_string()),
+                ))
+                .await?;
+        }
+        Ok(granted)
+    }
+}
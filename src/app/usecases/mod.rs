@@ -2,53 +2,111 @@ pub mod app;
 pub mod dto;
 
 mod answer_task;
+mod archive_feedback;
 mod cancel_reservation;
-mod check_admin;
 mod check_captain;
+mod check_media_references;
+mod check_permission;
 mod check_registered;
 mod check_started_track;
+mod check_task_reference;
+mod delete_media;
+mod find_characters;
+mod find_media;
+mod get_audit_log;
 mod get_available_slot_starts;
 mod get_available_tasks;
 mod get_available_tracks;
 mod get_character;
-mod get_character_names;
+mod get_character_page;
 mod get_completed_tasks;
+mod get_dashboard;
+mod get_event_phase;
+mod get_feedback;
+mod get_feedback_inbox;
 mod get_media;
+mod get_media_content;
+mod get_media_usages;
 mod get_player;
 mod get_profile;
+mod get_slots;
+mod get_staff;
 mod get_task;
+mod get_team_progress;
 mod get_team_reserved_slot;
 mod get_team_with_members;
+mod get_teams;
 mod get_track_in_progress;
 mod get_user;
 mod get_user_team;
 mod give_feedback;
+mod grant_role;
+mod refresh_media_file_id;
+mod rename_media;
+mod render_task_graph;
+mod reply_to_feedback;
 mod reserve_slot;
+mod reset_dialogue;
+mod review_photo_answer;
+mod revoke_role;
+mod set_event_phase;
 mod start_track;
+mod toggle_favorite_character;
 mod upload_media;
+mod upload_photo_answer;
+mod validate_task_graph;
 
 pub use answer_task::*;
+pub use archive_feedback::*;
 pub use cancel_reservation::*;
-pub use check_admin::*;
 pub use check_captain::*;
+pub use check_media_references::*;
+pub use check_permission::*;
 pub use check_registered::*;
 pub use check_started_track::*;
+pub use check_task_reference::*;
+pub use delete_media::*;
+pub use find_characters::*;
+pub use find_media::*;
+pub use get_audit_log::*;
 pub use get_available_slot_starts::*;
 pub use get_available_tasks::*;
 pub use get_available_tracks::*;
 pub use get_character::*;
-pub use get_character_names::*;
+pub use get_character_page::*;
 pub use get_completed_tasks::*;
+pub use get_dashboard::*;
+pub use get_event_phase::*;
+pub use get_feedback::*;
+pub use get_feedback_inbox::*;
 pub use get_media::*;
+pub use get_media_content::*;
+pub use get_media_usages::*;
 pub use get_player::*;
 pub use get_profile::*;
+pub use get_slots::*;
+pub use get_staff::*;
 pub use get_task::*;
+pub use get_team_progress::*;
 pub use get_team_reserved_slot::*;
 pub use get_team_with_members::*;
+pub use get_teams::*;
 pub use get_track_in_progress::*;
 pub use get_user::*;
 pub use get_user_team::*;
 pub use give_feedback::*;
+pub use grant_role::*;
+pub use refresh_media_file_id::*;
+pub use rename_media::*;
+pub use render_task_graph::*;
+pub use reply_to_feedback::*;
 pub use reserve_slot::*;
+pub use reset_dialogue::*;
+pub use review_photo_answer::*;
+pub use revoke_role::*;
+pub use set_event_phase::*;
 pub use start_track::*;
+pub use toggle_favorite_character::*;
 pub use upload_media::*;
+pub use upload_photo_answer::*;
+pub use validate_task_graph::*;
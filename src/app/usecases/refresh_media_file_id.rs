@@ -0,0 +1,21 @@
+use std::sync::Arc;
+
+use crate::app::error::AppError;
+use crate::app::ports::MediaRepository;
+use crate::domain::models::{FileID, MediaID};
+
+#[derive(Clone)]
+pub struct RefreshMediaFileID {
+    repos: Arc<dyn MediaRepository>,
+}
+
+impl RefreshMediaFileID {
+    pub fn new(repos: Arc<dyn MediaRepository>) -> Self {
+        Self { repos }
+    }
+
+    /// Запоминает `file_id`, выданный текущим ботом после повторной загрузки файла.
+    pub async fn execute(&self, id: MediaID, file_id: FileID) -> Result<(), AppError> {
+        self.repos.update_media_file_id(&id, &file_id).await
+    }
+}
@@ -0,0 +1,55 @@
+use std::sync::Arc;
+
+use crate::app::error::AppError;
+use crate::app::ports::{AuditLogRepository, MediaRepository};
+use crate::app::usecases::CheckPermission;
+use crate::domain::models::{AuditAction, AuditEntry, MediaID, Permission, UserID};
+
+#[derive(Clone)]
+pub struct RenameMedia {
+    repos: Arc<dyn MediaRepository>,
+    audit_log: Arc<dyn AuditLogRepository>,
+    check_permission: CheckPermission,
+}
+
+impl RenameMedia {
+    pub fn new(
+        repos: Arc<dyn MediaRepository>,
+        audit_log: Arc<dyn AuditLogRepository>,
+        check_permission: CheckPermission,
+    ) -> Self {
+        Self {
+            repos,
+            audit_log,
+            check_permission,
+        }
+    }
+
+    /// Переименовывает медиафайл вместе со всеми ссылками на него.
+    pub async fn execute(
+        &self,
+        actor_id: UserID,
+        id: MediaID,
+        new_id: MediaID,
+    ) -> Result<(), AppError> {
+        self.check_permission
+            .require(actor_id, Permission::EditContent)
+            .await?;
+        self.repos.media(&id).await?;
+        match self.repos.media(&new_id).await {
+            Ok(_) => return Err(AppError::MediaAlreadyExists(new_id)),
+            Err(AppError::MediaNotFound(_)) => {}
+            Err(err) => return Err(err),
+        }
+        self.repos.rename_media(&id, &new_id).await?;
+        self.audit_log
+            .append_audit_entry(AuditEntry::new(
+                actor_id,
+                AuditAction::RenameMedia,
+                format!("media:{}", id.as_str()),
+                Some(id.to_string()),
+                Some(new_id.to_string()),
+            ))
+            .await
+    }
+}
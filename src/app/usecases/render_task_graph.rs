@@ -0,0 +1,33 @@
+use std::sync::Arc;
+
+use crate::app::error::AppError;
+use crate::app::ports::TrackProvider;
+use crate::app::usecases::CheckPermission;
+use crate::domain::models::{Permission, TaskGraphFormat, UserID, render_task_graph};
+
+#[derive(Clone)]
+pub struct RenderTaskGraph {
+    track_provider: Arc<dyn TrackProvider>,
+    check_permission: CheckPermission,
+}
+
+impl RenderTaskGraph {
+    pub fn new(track_provider: Arc<dyn TrackProvider>, check_permission: CheckPermission) -> Self {
+        Self {
+            track_provider,
+            check_permission,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        actor_id: UserID,
+        format: TaskGraphFormat,
+    ) -> Result<String, AppError> {
+        self.check_permission
+            .require(actor_id, Permission::ViewContent)
+            .await?;
+        let tracks = self.track_provider.tracks().await?;
+        Ok(render_task_graph(&tracks, format))
+    }
+}
@@ -0,0 +1,55 @@
+use std::sync::Arc;
+
+use crate::app::error::AppError;
+use crate::app::ports::{AuditLogRepository, FeedbackRepository};
+use crate::app::usecases::CheckPermission;
+use crate::domain::models::{
+    AuditAction, AuditEntry, FeedbackID, FeedbackText, Permission, UserID,
+};
+
+#[derive(Clone)]
+pub struct ReplyToFeedback {
+    feedback_repository: Arc<dyn FeedbackRepository>,
+    audit_log: Arc<dyn AuditLogRepository>,
+    check_permission: CheckPermission,
+}
+
+impl ReplyToFeedback {
+    pub fn new(
+        feedback_repository: Arc<dyn FeedbackRepository>,
+        audit_log: Arc<dyn AuditLogRepository>,
+        check_permission: CheckPermission,
+    ) -> Self {
+        Self {
+            feedback_repository,
+            audit_log,
+            check_permission,
+        }
+    }
+
+    /// Сохраняет ответ в переписке. Доставкой ответа автору занимается бот.
+    pub async fn execute(
+        &self,
+        actor_id: UserID,
+        id: FeedbackID,
+        text: FeedbackText,
+    ) -> Result<(), AppError> {
+        self.check_permission
+            .require(actor_id, Permission::ManageFeedback)
+            .await?;
+        let mut feedback = self.feedback_repository.feedback(id).await?;
+        let reply = feedback.reply(actor_id, text)?;
+        self.feedback_repository
+            .save_feedback_reply(id, reply)
+            .await?;
+        self.audit_log
+            .append_audit_entry(AuditEntry::new(
+                actor_id,
+                AuditAction::ReplyToFeedback,
+                format!("feedback:{id}"),
+                None,
+                Some(reply.text().as_str().to_string()),
+            ))
+            .await
+    }
+}
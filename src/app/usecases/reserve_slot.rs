@@ -3,7 +3,8 @@ use std::sync::Arc;
 
 use crate::app::error::AppError;
 use crate::app::ports::{SlotRepository, SlotsProvider, TeamByMemberProvider, TeamRepository};
-use crate::app::usecases::dto::SlotDTO;
+use crate::app::usecases::dto::{ReservationDTO, SlotDTO};
+use crate::config::EventConfig;
 use crate::domain::models::{Places, UserID};
 
 #[derive(Clone)]
@@ -12,6 +13,7 @@ pub struct ReserveSlot {
     slot_repository: Arc<dyn SlotRepository>,
     team_provider: Arc<dyn TeamByMemberProvider>,
     team_repository: Arc<dyn TeamRepository>,
+    event: EventConfig,
 }
 
 impl ReserveSlot {
@@ -20,12 +22,14 @@ impl ReserveSlot {
         slot_repository: Arc<dyn SlotRepository>,
         team_provider: Arc<dyn TeamByMemberProvider>,
         team_repository: Arc<dyn TeamRepository>,
+        event: EventConfig,
     ) -> Self {
         Self {
             slots_provider,
             slot_repository,
             team_provider,
             team_repository,
+            event,
         }
     }
 
@@ -34,7 +38,7 @@ impl ReserveSlot {
         user_id: UserID,
         start: NaiveTime,
         places: Places,
-    ) -> Result<SlotDTO, AppError> {
+    ) -> Result<ReservationDTO, AppError> {
         let mut team = self
             .team_provider
             .team_by_member(user_id)
@@ -55,7 +59,11 @@ impl ReserveSlot {
             .min_by_key(|s| s.available_places());
         if let Some(mut best_slot) = best_slot {
             best_slot.reserve(team.id().clone(), places)?;
-            let dto = SlotDTO::from(best_slot.clone());
+            let dto = ReservationDTO {
+                slot: SlotDTO::from(best_slot.clone()),
+                date: self.event.final_date,
+                venue: self.event.final_venue.clone(),
+            };
             team.reserve(best_slot.id().clone())?;
             self.slot_repository.save_slot(best_slot).await?;
             self.team_repository.save_team(team).await?;
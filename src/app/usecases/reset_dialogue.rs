@@ -0,0 +1,46 @@
+use std::sync::Arc;
+
+use crate::app::error::AppError;
+use crate::app::ports::{AuditLogRepository, IsRegisteredUserProvider};
+use crate::app::usecases::CheckPermission;
+use crate::domain::models::{AuditAction, AuditEntry, Permission, UserID};
+
+#[derive(Clone)]
+pub struct ResetDialogue {
+    provider: Arc<dyn IsRegisteredUserProvider>,
+    audit_log: Arc<dyn AuditLogRepository>,
+    check_permission: CheckPermission,
+}
+
+impl ResetDialogue {
+    pub fn new(
+        provider: Arc<dyn IsRegisteredUserProvider>,
+        audit_log: Arc<dyn AuditLogRepository>,
+        check_permission: CheckPermission,
+    ) -> Self {
+        Self {
+            provider,
+            audit_log,
+            check_permission,
+        }
+    }
+
+    /// Записывает сброс диалога пользователя в журнал. Само состояние хранит бот:
+    /// `true` — пользователь зарегистрирован и его нужно вернуть в меню.
+    pub async fn execute(&self, actor_id: UserID, user_id: UserID) -> Result<bool, AppError> {
+        self.check_permission
+            .require(actor_id, Permission::ManageEvent)
+            .await?;
+        let registered = self.provider.is_registered(user_id).await?;
+        self.audit_log
+            .append_audit_entry(AuditEntry::new(
+                actor_id,
+                AuditAction::ResetDialogue,
+                format!("user:{}", user_id.as_i64()),
+                None,
+                None,
+            ))
+            .await?;
+        Ok(registered)
+    }
+}
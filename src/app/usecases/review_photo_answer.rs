@@ -0,0 +1,98 @@
+use std::sync::Arc;
+
+use crate::app::error::AppError;
+use crate::app::ports::{
+    AuditLogRepository, TaskProvider, TeamByMemberProvider, TeamRepository, TrackProvider,
+};
+use crate::app::usecases::CheckPermission;
+use crate::app::usecases::dto::PhotoReviewDTO;
+use crate::domain::models::{AuditAction, AuditEntry, Permission, TaskID, TrackStatus, UserID};
+
+#[derive(Clone)]
+pub struct ReviewPhotoAnswer {
+    team_provider: Arc<dyn TeamByMemberProvider>,
+    task_provider: Arc<dyn TaskProvider>,
+    team_repository: Arc<dyn TeamRepository>,
+    track_provider: Arc<dyn TrackProvider>,
+    audit_log: Arc<dyn AuditLogRepository>,
+    check_permission: CheckPermission,
+}
+
+impl ReviewPhotoAnswer {
+    pub fn new(
+        team_provider: Arc<dyn TeamByMemberProvider>,
+        task_provider: Arc<dyn TaskProvider>,
+        team_repository: Arc<dyn TeamRepository>,
+        track_provider: Arc<dyn TrackProvider>,
+        audit_log: Arc<dyn AuditLogRepository>,
+        check_permission: CheckPermission,
+    ) -> Self {
+        Self {
+            team_provider,
+            task_provider,
+            team_repository,
+            track_provider,
+            audit_log,
+            check_permission,
+        }
+    }
+
+    /// Засчитывает или отклоняет фото-ответ команды игрока и возвращает трек задания и баллы.
+    pub async fn execute(
+        &self,
+        actor_id: UserID,
+        user_id: UserID,
+        task_id: TaskID,
+        approved: bool,
+    ) -> Result<PhotoReviewDTO, AppError> {
+        self.check_permission
+            .require(actor_id, Permission::ReviewPhotos)
+            .await?;
+        let mut team = self
+            .team_provider
+            .team_by_member(user_id)
+            .await?
+            .ok_or(AppError::UserNotInTeam(user_id))?;
+        let task = self.task_provider.task(task_id).await?;
+        let track = self
+            .track_provider
+            .tracks()
+            .await?
+            .into_iter()
+            .find(|track| track.task(&task_id).is_some())
+            .ok_or(AppError::TaskNotFound(task_id))?;
+        let started_at = team.track_status(track.tag())?.started_at();
+        let answer = team
+            .answer(task_id)
+            .ok_or(AppError::AnswerNotFound(task_id))?;
+        // Повторное нажатие или второй организатор не должны пересчитывать баллы.
+        if !answer.is_pending_review() {
+            return Err(AppError::AnswerNotPending(task_id));
+        }
+        let before = answer.points();
+
+        let answer = task.review(answer, approved, started_at);
+        let points = answer.points();
+        team.save_answer(answer);
+        let finished = matches!(team.track_status(track.tag())?, TrackStatus::Finished(..));
+        if !finished && track.progress(&team.answers()).full_completed() {
+            team.finish_track(track.tag())?;
+        }
+
+        self.team_repository.save_team(team).await?;
+        self.audit_log
+            .append_audit_entry(AuditEntry::new(
+                actor_id,
+                AuditAction::ReviewPhotoAnswer,
+                format!("answer:{}:{task_id}", user_id.as_i64()),
+                Some(before.as_i32().to_string()),
+                Some(points.as_i32().to_string()),
+            ))
+            .await?;
+        Ok(PhotoReviewDTO {
+            track: track.tag().clone(),
+            number: track.task_number(&task_id).unwrap_or_default(),
+            points,
+        })
+    }
+}
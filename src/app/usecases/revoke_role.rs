@@ -0,0 +1,55 @@
+use std::sync::Arc;
+
+use crate::app::error::AppError;
+use crate::app::ports::{AuditLogRepository, RolesRepository};
+use crate::app::usecases::CheckPermission;
+use crate::domain::models::{AuditAction, AuditEntry, Permission, Role, UserID};
+
+#[derive(Clone)]
+pub struct RevokeRole {
+    roles_repository: Arc<dyn RolesRepository>,
+    audit_log: Arc<dyn AuditLogRepository>,
+    check_permission: CheckPermission,
+}
+
+impl RevokeRole {
+    pub fn new(
+        roles_repository: Arc<dyn RolesRepository>,
+        audit_log: Arc<dyn AuditLogRepository>,
+        check_permission: CheckPermission,
+    ) -> Self {
+        Self {
+            roles_repository,
+            audit_log,
+            check_permission,
+        }
+    }
+
+    /// Отзывает роль. Отозвать у себя роль суперадмина нельзя, чтобы не остаться без управления ролями.
+    pub async fn execute(
+        &self,
+        actor_id: UserID,
+        user_id: UserID,
+        role: Role,
+    ) -> Result<bool, AppError> {
+        self.check_permission
+            .require(actor_id, Permission::ManageRoles)
+            .await?;
+        if actor_id == user_id && role == Role::SuperAdmin {
+            return Err(AppError::CanNotRevokeOwnRole(user_id, role));
+        }
+        let revoked = self.roles_repository.revoke_role(user_id, role).await?;
+        if revoked {
+            self.audit_log
+                .append_audit_entry(AuditEntry::new(
+                    actor_id,
+                    AuditAction::RevokeRole,
+                    format!("user:{}", user_id.as_i64()),
+                    Some(role.code().to_string()),
+                    None,
+                ))
+                .await?;
+        }
+        Ok(revoked)
+    }
+}
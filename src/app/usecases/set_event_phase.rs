@@ -0,0 +1,52 @@
+use std::sync::Arc;
+
+use crate::app::error::AppError;
+use crate::app::ports::{AuditLogRepository, EventPhaseRepository};
+use crate::app::usecases::{CheckPermission, GetEventPhase};
+use crate::domain::models::{AuditAction, AuditEntry, EventPhase, Permission, UserID};
+
+#[derive(Clone)]
+pub struct SetEventPhase {
+    repository: Arc<dyn EventPhaseRepository>,
+    audit_log: Arc<dyn AuditLogRepository>,
+    get_event_phase: GetEventPhase,
+    check_permission: CheckPermission,
+}
+
+impl SetEventPhase {
+    pub fn new(
+        repository: Arc<dyn EventPhaseRepository>,
+        audit_log: Arc<dyn AuditLogRepository>,
+        get_event_phase: GetEventPhase,
+        check_permission: CheckPermission,
+    ) -> Self {
+        Self {
+            repository,
+            audit_log,
+            get_event_phase,
+            check_permission,
+        }
+    }
+
+    /// Переключает этап мероприятия. `false`, если он уже был текущим.
+    pub async fn execute(&self, actor_id: UserID, phase: EventPhase) -> Result<bool, AppError> {
+        self.check_permission
+            .require(actor_id, Permission::ManageEvent)
+            .await?;
+        let current = self.get_event_phase.execute().await?.phase;
+        if current == phase {
+            return Ok(false);
+        }
+        self.repository.set_event_phase(phase, actor_id).await?;
+        self.audit_log
+            .append_audit_entry(AuditEntry::new(
+                actor_id,
+                AuditAction::SwitchEventPhase,
+                "event".to_string(),
+                Some(current.code().to_string()),
+                Some(phase.code().to_string()),
+            ))
+            .await?;
+        Ok(true)
+    }
+}
@@ -31,22 +31,21 @@ impl StartTrack {
     pub async fn execute(
         &self,
         user_id: UserID,
-        track_tag: TrackTag,
+        track_tag: &TrackTag,
     ) -> Result<TrackInProgressDTO, AppError> {
         match self.team_provider.team_by_member(user_id).await? {
             Some(mut team) => {
-                team.start_track(track_tag)?;
+                team.start_track(track_tag.clone())?;
                 let track = self.track_provider.track(track_tag).await?;
                 let answers = team.answers();
                 let progress = track.progress(&answers);
                 let media = MediaDTO::from(self.media_provider.media(track.media_id()).await?);
-                let dto = TrackInProgressDTO {
-                    tag: track_tag,
-                    description: track.description().clone(),
+                let dto = TrackInProgressDTO::new(
+                    &track,
                     media,
-                    status: team.track_status(track_tag)?.clone(),
-                    percent: progress.percent(),
-                };
+                    team.track_status(track_tag)?.clone(),
+                    progress.percent(),
+                );
                 self.team_repository.save_team(team).await?;
                 Ok(dto)
             }
@@ -0,0 +1,40 @@
+use std::sync::Arc;
+
+use crate::app::error::AppError;
+use crate::app::ports::{CharactersProvider, FavoriteCharactersRepository};
+use crate::domain::models::{CharacterID, UserID};
+
+#[derive(Clone)]
+pub struct ToggleFavoriteCharacter {
+    characters_provider: Arc<dyn CharactersProvider>,
+    favorites_repository: Arc<dyn FavoriteCharactersRepository>,
+}
+
+impl ToggleFavoriteCharacter {
+    pub fn new(
+        characters_provider: Arc<dyn CharactersProvider>,
+        favorites_repository: Arc<dyn FavoriteCharactersRepository>,
+    ) -> Self {
+        Self {
+            characters_provider,
+            favorites_repository,
+        }
+    }
+
+    /// Добавляет персонажа в избранное или убирает из него. Возвращает новое состояние.
+    pub async fn execute(&self, user_id: UserID, id: &CharacterID) -> Result<bool, AppError> {
+        let characters = self.characters_provider.characters().await?;
+        if !characters.iter().any(|c| c.id() == id) {
+            return Err(AppError::CharacterNotFound(id.clone()));
+        }
+        let favorite = !self
+            .favorites_repository
+            .favorite_characters(user_id)
+            .await?
+            .contains(id);
+        self.favorites_repository
+            .set_favorite_character(user_id, id, favorite)
+            .await?;
+        Ok(favorite)
+    }
+}
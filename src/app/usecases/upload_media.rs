@@ -1,20 +1,59 @@
 use std::sync::Arc;
 
 use crate::app::error::AppError;
-use crate::app::ports::MediaRepository;
-use crate::domain::models::Media;
+use crate::app::ports::{AuditLogRepository, MediaRepository};
+use crate::app::usecases::CheckPermission;
+use crate::domain::models::{AuditAction, AuditEntry, Media, MediaContent, Permission, UserID};
 
 #[derive(Clone)]
 pub struct UploadMedia {
     repos: Arc<dyn MediaRepository>,
+    audit_log: Arc<dyn AuditLogRepository>,
+    check_permission: CheckPermission,
 }
 
 impl UploadMedia {
-    pub fn new(repos: Arc<dyn MediaRepository>) -> Self {
-        Self { repos }
+    pub fn new(
+        repos: Arc<dyn MediaRepository>,
+        audit_log: Arc<dyn AuditLogRepository>,
+        check_permission: CheckPermission,
+    ) -> Self {
+        Self {
+            repos,
+            audit_log,
+            check_permission,
+        }
     }
 
-    pub async fn execute(&self, media: Media) -> Result<(), AppError> {
-        self.repos.save_media(media).await
+    /// Сохраняет медиафайл и, если удалось скачать, его исходные байты.
+    pub async fn execute(
+        &self,
+        actor_id: UserID,
+        media: Media,
+        content: Option<MediaContent>,
+    ) -> Result<(), AppError> {
+        self.check_permission
+            .require(actor_id, Permission::EditContent)
+            .await?;
+        let media_id = media.id().clone();
+        let before = match self.repos.media(&media_id).await {
+            Ok(previous) => Some(previous.file_id().as_str().to_string()),
+            Err(AppError::MediaNotFound(_)) => None,
+            Err(err) => return Err(err),
+        };
+        let after = media.file_id().as_str().to_string();
+        self.repos.save_media(media).await?;
+        if let Some(content) = content {
+            self.repos.save_media_content(&media_id, &content).await?;
+        }
+        self.audit_log
+            .append_audit_entry(AuditEntry::new(
+                actor_id,
+                AuditAction::UploadMedia,
+                format!("media:{}", media_id.as_str()),
+                before,
+                Some(after),
+            ))
+            .await
     }
 }
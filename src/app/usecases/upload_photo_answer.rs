@@ -0,0 +1,21 @@
+use std::sync::Arc;
+
+use crate::app::error::AppError;
+use crate::app::ports::MediaRepository;
+use crate::domain::models::Media;
+
+#[derive(Clone)]
+pub struct UploadPhotoAnswer {
+    repos: Arc<dyn MediaRepository>,
+}
+
+impl UploadPhotoAnswer {
+    pub fn new(repos: Arc<dyn MediaRepository>) -> Self {
+        Self { repos }
+    }
+
+    /// Сохраняет фото, присланное игроком в ответ на задание.
+    pub async fn execute(&self, media: Media) -> Result<(), AppError> {
+        self.repos.save_media(media).await
+    }
+}
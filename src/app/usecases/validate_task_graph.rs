@@ -0,0 +1,34 @@
+use std::sync::Arc;
+
+use crate::app::error::AppError;
+use crate::app::ports::TrackProvider;
+use crate::app::usecases::CheckPermission;
+use crate::domain::models::{Permission, TaskGraphIssue, UserID, validate_task_graph};
+
+#[derive(Clone)]
+pub struct ValidateTaskGraph {
+    track_provider: Arc<dyn TrackProvider>,
+    check_permission: CheckPermission,
+}
+
+impl ValidateTaskGraph {
+    pub fn new(track_provider: Arc<dyn TrackProvider>, check_permission: CheckPermission) -> Self {
+        Self {
+            track_provider,
+            check_permission,
+        }
+    }
+
+    pub async fn execute(&self, actor_id: UserID) -> Result<Vec<TaskGraphIssue>, AppError> {
+        self.check_permission
+            .require(actor_id, Permission::ViewContent)
+            .await?;
+        self.validate().await
+    }
+
+    /// Без проверки прав: граф проверяется и при запуске бота.
+    pub async fn validate(&self) -> Result<Vec<TaskGraphIssue>, AppError> {
+        let tracks = self.track_provider.tracks().await?;
+        Ok(validate_task_graph(&tracks))
+    }
+}
@@ -0,0 +1,199 @@
+use std::fmt::{Display, Formatter};
+
+use chrono::NaiveTime;
+use teloxide::types::CallbackQuery;
+
+use crate::domain::models::{CharacterID, FeedbackID, MediaID, MenuItem, TaskID, TrackTag, UserID};
+
+/// Telegram ограничивает callback data 64 байтами.
+pub const CALLBACK_DATA_MAX_LENGTH: usize = 64;
+
+/// Данные inline-кнопки. В Telegram хранятся строкой `префикс:аргументы`,
+/// префиксы кнопок организаторов и личностей совпадают с прежними, чтобы
+/// работали кнопки в уже отправленных сообщениях.
+#[derive(Debug, Clone)]
+pub enum CallbackData {
+    // Меню
+    Menu,
+    MenuItem(MenuItem),
+
+    // Треки
+    Track(TrackTag),
+    StartTrack(TrackTag),
+    AvailableTasks(TrackTag),
+    CompletedTasks(TrackTag),
+    AvailableTask(TrackTag, TaskID),
+    CompletedTask(TrackTag, TaskID),
+    /// Вариант ответа по индексу в списке вариантов задания.
+    TaskOption(TaskID, usize),
+    /// Отправить выбранные варианты.
+    TaskDone(TaskID),
+    /// Начать упорядочивание заново.
+    TaskReset(TaskID),
+
+    // Запись на финал
+    SlotStarts,
+    SlotStart(NaiveTime),
+    /// `true` — команда хочет выбрать другое время.
+    CancelReservation(bool),
+
+    // Личности
+    /// Только избранные и номер страницы.
+    CharacterPage(bool, usize),
+    Character(CharacterID),
+    CharacterSwitch(CharacterID),
+    CharacterFavorite(CharacterID),
+
+    // Организаторам
+    /// Номер страницы и поисковый запрос.
+    MediaPage(usize, String),
+    Media(MediaID),
+    /// Номер страницы и фильтр из команды `/audit`.
+    AuditPage(usize, String),
+    /// Код статуса из команды `/inbox` и номер страницы.
+    FeedbackPage(String, usize),
+    Feedback(FeedbackID),
+    FeedbackReply(FeedbackID),
+    FeedbackArchive(FeedbackID),
+    /// Автор фото-ответа, задание и решение проверяющего.
+    PhotoReview(UserID, TaskID, bool),
+}
+
+impl CallbackData {
+    pub fn from_query(q: CallbackQuery) -> Option<Self> {
+        Self::parse(q.data.as_deref()?)
+    }
+
+    pub fn parse(data: &str) -> Option<Self> {
+        let (prefix, args) = data.split_once(':').unwrap_or((data, ""));
+        let callback = match prefix {
+            "m" => Self::Menu,
+            "mi" => Self::MenuItem(args.parse().ok()?),
+            "t" => Self::Track(TrackTag::new(args).ok()?),
+            "ts" => Self::StartTrack(TrackTag::new(args).ok()?),
+            "ta" => Self::AvailableTasks(TrackTag::new(args).ok()?),
+            "tc" => Self::CompletedTasks(TrackTag::new(args).ok()?),
+            "tk" => {
+                let (tag, task_id) = args.split_once(':')?;
+                Self::AvailableTask(TrackTag::new(tag).ok()?, task_id.parse().ok()?)
+            }
+            "td" => {
+                let (tag, task_id) = args.split_once(':')?;
+                Self::CompletedTask(TrackTag::new(tag).ok()?, task_id.parse().ok()?)
+            }
+            "to" => {
+                let (task_id, index) = args.split_once(':')?;
+                Self::TaskOption(task_id.parse().ok()?, index.parse().ok()?)
+            }
+            "tf" => Self::TaskDone(args.parse().ok()?),
+            "tr" => Self::TaskReset(args.parse().ok()?),
+            "ss" => Self::SlotStarts,
+            "st" => Self::SlotStart(NaiveTime::parse_from_str(args, "%H:%M").ok()?),
+            "sc" => Self::CancelReservation(parse_flag(args)?),
+            "cp" => {
+                let (mode, page) = args.split_once(':')?;
+                Self::CharacterPage(mode == "f", page.parse().ok()?)
+            }
+            "cs" => Self::Character(CharacterID::try_from(args.to_string()).ok()?),
+            "cn" => Self::CharacterSwitch(CharacterID::try_from(args.to_string()).ok()?),
+            "cf" => Self::CharacterFavorite(CharacterID::try_from(args.to_string()).ok()?),
+            "mp" => {
+                let (page, query) = args.split_once(':')?;
+                Self::MediaPage(page.parse().ok()?, query.to_string())
+            }
+            "ms" => Self::Media(MediaID::new(args.to_string()).ok()?),
+            "al" => {
+                let (page, filter) = args.split_once(':')?;
+                Self::AuditPage(page.parse().ok()?, filter.to_string())
+            }
+            "fp" => {
+                let (status, page) = args.split_once(':')?;
+                Self::FeedbackPage(status.to_string(), page.parse().ok()?)
+            }
+            "fs" => Self::Feedback(args.parse().ok()?),
+            "fr" => Self::FeedbackReply(args.parse().ok()?),
+            "fa" => Self::FeedbackArchive(args.parse().ok()?),
+            "pa" | "pr" => {
+                let (user_id, task_id) = args.split_once(':')?;
+                Self::PhotoReview(
+                    UserID::new(user_id.parse().ok()?),
+                    task_id.parse().ok()?,
+                    prefix == "pa",
+                )
+            }
+            _ => return None,
+        };
+        Some(callback)
+    }
+
+    /// Раздел меню игрока, к которому относится кнопка.
+    pub fn menu_item(&self) -> Option<MenuItem> {
+        match self {
+            Self::MenuItem(item) => Some(*item),
+            Self::Track(..)
+            | Self::StartTrack(..)
+            | Self::AvailableTasks(..)
+            | Self::CompletedTasks(..)
+            | Self::AvailableTask(..)
+            | Self::CompletedTask(..)
+            | Self::TaskOption(..)
+            | Self::TaskDone(..)
+            | Self::TaskReset(..) => Some(MenuItem::Tracks),
+            Self::SlotStarts | Self::SlotStart(..) | Self::CancelReservation(..) => {
+                Some(MenuItem::FinalReservation)
+            }
+            Self::CharacterPage(..)
+            | Self::Character(..)
+            | Self::CharacterSwitch(..)
+            | Self::CharacterFavorite(..) => Some(MenuItem::Characters),
+            _ => None,
+        }
+    }
+}
+
+fn parse_flag(s: &str) -> Option<bool> {
+    match s {
+        "1" => Some(true),
+        "0" => Some(false),
+        _ => None,
+    }
+}
+
+impl Display for CallbackData {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Menu => write!(f, "m"),
+            Self::MenuItem(item) => write!(f, "mi:{}", item.code()),
+            Self::Track(tag) => write!(f, "t:{}", tag.as_str()),
+            Self::StartTrack(tag) => write!(f, "ts:{}", tag.as_str()),
+            Self::AvailableTasks(tag) => write!(f, "ta:{}", tag.as_str()),
+            Self::CompletedTasks(tag) => write!(f, "tc:{}", tag.as_str()),
+            Self::AvailableTask(tag, task_id) => write!(f, "tk:{}:{task_id}", tag.as_str()),
+            Self::CompletedTask(tag, task_id) => write!(f, "td:{}:{task_id}", tag.as_str()),
+            Self::TaskOption(task_id, index) => write!(f, "to:{task_id}:{index}"),
+            Self::TaskDone(task_id) => write!(f, "tf:{task_id}"),
+            Self::TaskReset(task_id) => write!(f, "tr:{task_id}"),
+            Self::SlotStarts => write!(f, "ss"),
+            Self::SlotStart(start) => write!(f, "st:{}", start.format("%H:%M")),
+            Self::CancelReservation(rescheduled) => write!(f, "sc:{}", u8::from(*rescheduled)),
+            Self::CharacterPage(favorites_only, page) => {
+                let mode = if *favorites_only { "f" } else { "a" };
+                write!(f, "cp:{mode}:{page}")
+            }
+            Self::Character(id) => write!(f, "cs:{}", id.as_str()),
+            Self::CharacterSwitch(id) => write!(f, "cn:{}", id.as_str()),
+            Self::CharacterFavorite(id) => write!(f, "cf:{}", id.as_str()),
+            Self::MediaPage(page, query) => write!(f, "mp:{page}:{query}"),
+            Self::Media(id) => write!(f, "ms:{}", id.as_str()),
+            Self::AuditPage(page, filter) => write!(f, "al:{page}:{filter}"),
+            Self::FeedbackPage(status, page) => write!(f, "fp:{status}:{page}"),
+            Self::Feedback(id) => write!(f, "fs:{id}"),
+            Self::FeedbackReply(id) => write!(f, "fr:{id}"),
+            Self::FeedbackArchive(id) => write!(f, "fa:{id}"),
+            Self::PhotoReview(user_id, task_id, approved) => {
+                let prefix = if *approved { "pa" } else { "pr" };
+                write!(f, "{prefix}:{}:{task_id}", user_id.as_i64())
+            }
+        }
+    }
+}
@@ -0,0 +1,59 @@
+use teloxide::prelude::*;
+use teloxide::types::{BotCommand, BotCommandScope, Recipient};
+use teloxide::utils::command::BotCommands;
+
+use crate::app::usecases::GetStaff;
+use crate::bot::BotHandlerResult;
+use crate::bot::handlers::audit_log::AuditLogCommand;
+use crate::bot::handlers::commands::{ContentCommand, PlayerCommand};
+use crate::bot::handlers::dialogue_state::DialogueStateCommand;
+use crate::bot::handlers::event_phase::EventPhaseCommand;
+use crate::bot::handlers::feedback_inbox::FeedbackInboxCommand;
+use crate::bot::handlers::media_library::MediaLibraryCommand;
+use crate::bot::handlers::roles::RolesCommand;
+use crate::domain::models::UserID;
+
+fn staff_commands() -> Vec<BotCommand> {
+    [
+        PlayerCommand::bot_commands(),
+        ContentCommand::bot_commands(),
+        MediaLibraryCommand::bot_commands(),
+        FeedbackInboxCommand::bot_commands(),
+        AuditLogCommand::bot_commands(),
+        RolesCommand::bot_commands(),
+        EventPhaseCommand::bot_commands(),
+        DialogueStateCommand::bot_commands(),
+    ]
+    .concat()
+}
+
+fn staff_scope(user_id: UserID) -> BotCommandScope {
+    BotCommandScope::Chat {
+        chat_id: Recipient::Id(ChatId(user_id.as_i64())),
+    }
+}
+
+/// Список команд в меню Telegram: игрокам только свои, организаторам — все.
+pub async fn register_commands(bot: &Bot, get_staff: &GetStaff) -> BotHandlerResult {
+    bot.set_my_commands(PlayerCommand::bot_commands()).await?;
+    // Один недоступный организатор не должен оставлять остальных без команд.
+    for user_id in get_staff.members().await? {
+        if let Err(err) = set_staff_commands(bot, user_id).await {
+            log::warn!("Unable to set staff commands for {user_id:?}: {err}");
+        }
+    }
+    Ok(())
+}
+
+pub async fn set_staff_commands(bot: &Bot, user_id: UserID) -> BotHandlerResult {
+    bot.set_my_commands(staff_commands())
+        .scope(staff_scope(user_id))
+        .await?;
+    Ok(())
+}
+
+/// Бывшему организатору Telegram снова показывает команды игрока.
+pub async fn remove_staff_commands(bot: &Bot, user_id: UserID) -> BotHandlerResult {
+    bot.delete_my_commands().scope(staff_scope(user_id)).await?;
+    Ok(())
+}
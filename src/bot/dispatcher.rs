@@ -1,16 +1,32 @@
-use crate::bot::handlers::slots::slots_scheme;
+use crate::bot::handlers::slots::{SlotBooking, slots_callbacks, slots_scheme};
 use std::sync::Arc;
-use teloxide::dispatching::dialogue::serializer::Json;
-use teloxide::dispatching::dialogue::{PostgresStorage, enter};
+use teloxide::dispatching::dialogue::enter;
 use teloxide::dispatching::{DefaultKey, UpdateHandler};
 use teloxide::prelude::*;
 
 use crate::app::error::AppError;
 use crate::app::usecases::app::App;
-use crate::bot::fsm::BotState;
-use crate::bot::handlers::commands::commands_scheme;
-use crate::bot::handlers::menu::menu_scheme;
-use crate::bot::handlers::tracks::tracks_scheme;
+use crate::bot::callbacks::CallbackData;
+use crate::bot::errors::catch_errors;
+use crate::bot::fsm::{BotState, DialogueStorage};
+use crate::bot::handlers::audit_log::{audit_log_callbacks, audit_log_scheme};
+use crate::bot::handlers::characters::{characters_callbacks, characters_scheme};
+use crate::bot::handlers::commands::{CancelScreens, commands_scheme};
+use crate::bot::handlers::dialogue_state::{dialogue_state_scheme, stale_state_callbacks};
+use crate::bot::handlers::event_phase::{
+    closed_section_callbacks, event_phase_scheme, unavailable_button_scheme,
+};
+use crate::bot::handlers::feedback_inbox::{feedback_inbox_callbacks, feedback_inbox_scheme};
+use crate::bot::handlers::media_library::{
+    MediaCard, media_library_callbacks, media_library_scheme,
+};
+use crate::bot::handlers::menu::{MenuSections, menu_callbacks, menu_scheme};
+use crate::bot::handlers::photo_review::photo_review_callbacks;
+use crate::bot::handlers::roles::roles_scheme;
+use crate::bot::handlers::shared::answer_callback;
+use crate::bot::handlers::tracks::{tracks_callbacks, tracks_scheme};
+use crate::bot::staff_chat::{StaffChat, StaffErrorHandler};
+use crate::config::Config;
 
 pub struct BotDispatcher;
 
@@ -18,24 +34,61 @@ impl BotDispatcher {
     pub async fn create(
         bot: Bot,
         app: App,
-        postgres_storage: Arc<PostgresStorage<Json>>,
+        postgres_storage: Arc<DialogueStorage>,
+        staff_chat: StaffChat,
+        config: Arc<Config>,
     ) -> Dispatcher<Bot, AppError, DefaultKey> {
-        Dispatcher::builder(bot, Self::scheme())
+        let media_card = MediaCard {
+            get_media: app.get_media.clone(),
+            get_media_usages: app.get_media_usages.clone(),
+            get_media_content: app.get_media_content.clone(),
+            refresh_media_file_id: app.refresh_media_file_id.clone(),
+        };
+        let cancel_screens = CancelScreens {
+            get_available_tracks: app.get_available_tracks.clone(),
+            get_available_tasks: app.get_available_tasks.clone(),
+            get_character_page: app.get_character_page.clone(),
+        };
+        let menu_sections = MenuSections {
+            get_user_team: app.get_user_team.clone(),
+            get_team_with_members: app.get_team_with_members.clone(),
+            get_character_page: app.get_character_page.clone(),
+            get_available_tracks: app.get_available_tracks.clone(),
+        };
+        let slot_booking = SlotBooking {
+            get_available_slot_starts: app.get_available_slot_starts.clone(),
+            reserve_slot: app.reserve_slot.clone(),
+            cancel_reservation: app.cancel_reservation.clone(),
+        };
+        Dispatcher::builder(bot.clone(), Self::scheme())
             .dependencies(dptree::deps![
                 app.answer_task,
+                app.archive_feedback,
                 app.cancel_reservation,
-                app.check_admin,
                 app.check_captain,
+                app.check_media_references,
+                app.check_permission,
                 app.check_registered,
+                app.delete_media,
+                app.find_characters,
+                app.find_media,
+                app.get_audit_log,
                 app.get_available_slot_starts,
                 app.get_available_tasks,
                 app.check_started_track,
+                app.check_task_reference,
                 app.get_character,
-                app.get_character_names,
+                app.get_character_page,
                 app.get_completed_tasks,
+                app.get_event_phase,
+                app.get_feedback,
+                app.get_feedback_inbox,
                 app.get_media,
+                app.get_media_content,
+                app.get_media_usages,
                 app.get_player,
                 app.get_profile,
+                app.get_staff,
                 app.get_task,
                 app.get_team_reserved_slot,
                 app.get_available_tracks,
@@ -44,11 +97,30 @@ impl BotDispatcher {
                 app.get_user,
                 app.get_user_team,
                 app.give_feedback,
+                app.grant_role,
+                app.refresh_media_file_id,
+                app.rename_media,
+                app.render_task_graph,
+                app.reply_to_feedback,
                 app.reserve_slot,
+                app.reset_dialogue,
+                app.review_photo_answer,
+                app.revoke_role,
+                app.set_event_phase,
                 app.start_track,
+                app.toggle_favorite_character,
                 app.upload_media,
-                postgres_storage
+                app.upload_photo_answer,
+                app.validate_task_graph,
+                cancel_screens,
+                media_card,
+                menu_sections,
+                slot_booking,
+                postgres_storage,
+                staff_chat.clone(),
+                config
             ])
+            .error_handler(StaffErrorHandler::new(bot, staff_chat))
             .default_handler(|upd| async move {
                 log::warn!("Unhandled update: {:?}", upd);
             })
@@ -57,10 +129,41 @@ impl BotDispatcher {
     }
 
     fn scheme() -> UpdateHandler<AppError> {
-        enter::<Update, PostgresStorage<Json>, BotState, _>()
-            .branch(commands_scheme())
-            .branch(menu_scheme())
-            .branch(tracks_scheme())
-            .branch(slots_scheme())
+        enter::<Update, DialogueStorage, BotState, _>().chain(catch_errors(
+            dptree::entry()
+                .branch(commands_scheme())
+                .branch(dialogue_state_scheme())
+                .branch(media_library_scheme())
+                .branch(feedback_inbox_scheme())
+                .branch(roles_scheme())
+                .branch(audit_log_scheme())
+                .branch(event_phase_scheme())
+                .branch(menu_scheme())
+                .branch(characters_scheme())
+                .branch(tracks_scheme())
+                .branch(slots_scheme())
+                .branch(Self::callbacks_scheme())
+                .branch(unavailable_button_scheme()),
+        ))
+    }
+
+    /// Нажатия inline-кнопок. Кнопки организаторов сами отвечают на нажатие,
+    /// кнопкам игроков отвечает `answer_callback` и передаёт `Reply` в их сообщение.
+    fn callbacks_scheme() -> UpdateHandler<AppError> {
+        Update::filter_callback_query()
+            .filter_map(CallbackData::from_query)
+            .branch(media_library_callbacks())
+            .branch(feedback_inbox_callbacks())
+            .branch(photo_review_callbacks())
+            .branch(audit_log_callbacks())
+            .branch(
+                answer_callback()
+                    .branch(stale_state_callbacks())
+                    .branch(closed_section_callbacks())
+                    .branch(menu_callbacks())
+                    .branch(characters_callbacks())
+                    .branch(tracks_callbacks())
+                    .branch(slots_callbacks()),
+            )
     }
 }
@@ -0,0 +1,85 @@
+use std::ops::ControlFlow;
+
+use dptree::di::DependencyMap;
+use teloxide::dispatching::UpdateHandler;
+use teloxide::prelude::*;
+use teloxide::types::UpdateKind;
+
+use crate::app::error::AppError;
+use crate::bot::fsm::{BotDialogue, BotState};
+use crate::bot::handlers::shared::{send_error_message, send_internal_error};
+use crate::bot::i18n::Locale;
+use crate::bot::texts;
+
+/// Перехватывает ошибки обработчиков: отвечает пользователю, возвращает диалог
+/// в безопасное состояние и пишет в лог, с каким пользователем и состоянием это случилось.
+///
+/// Ожидаемые ошибки на этом заканчиваются, неожиданные уходят дальше,
+/// в `StaffErrorHandler`, чтобы о них узнали организаторы.
+pub fn catch_errors(handler: UpdateHandler<AppError>) -> UpdateHandler<AppError> {
+    let sig = handler.sig().clone();
+    let description = handler.description().clone();
+    dptree::from_fn_with_description(
+        description,
+        move |deps: DependencyMap, cont| {
+            let handler = handler.clone();
+            async move {
+                match handler.execute(deps.clone(), cont).await {
+                    ControlFlow::Break(Err(error)) => {
+                        ControlFlow::Break(handle_error(&deps, error).await)
+                    }
+                    flow => flow,
+                }
+            }
+        },
+        sig,
+    )
+}
+
+async fn handle_error(deps: &DependencyMap, error: AppError) -> Result<(), AppError> {
+    let Some(update) = deps.try_get::<Update>() else {
+        return Err(error);
+    };
+    let state = deps.try_get::<BotState>();
+    let user = update.from();
+    let locale = user.map_or(Locale::DEFAULT, Locale::of_user);
+    let message = texts::error_message(locale, &error);
+    let context = format!(
+        "update {}, user {:?}, state {:?}",
+        update.id.0,
+        user.map(|u| u.id.0),
+        state.as_deref(),
+    );
+    if message.is_some() {
+        log::warn!("Handler failed ({context}): {error}");
+    } else {
+        log::error!("Handler failed ({context}): {error}");
+    }
+
+    if let Some(bot) = deps.try_get::<Bot>() {
+        if let UpdateKind::CallbackQuery(q) = &update.kind {
+            // Кнопке могли уже ответить, повторный ответ Telegram отклонит.
+            let _ = bot.answer_callback_query(q.id.clone()).await;
+        }
+        if let Some(chat) = update.chat() {
+            let sent = match &message {
+                Some(text) => send_error_message(&bot, chat.id, text.clone()).await,
+                None => send_internal_error(&bot, chat.id, locale).await,
+            };
+            if let Err(err) = sent {
+                log::warn!("Failed to send error message ({context}): {err}");
+            }
+        }
+    }
+
+    if let (Some(dialogue), Some(state)) = (deps.try_get::<BotDialogue>(), state)
+        && let Err(err) = dialogue.update(state.reset()).await
+    {
+        log::warn!("Failed to reset dialogue ({context}): {err}");
+    }
+
+    match message {
+        Some(_) => Ok(()),
+        None => Err(error),
+    }
+}
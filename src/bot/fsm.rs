@@ -1,15 +1,18 @@
 use chrono::NaiveTime;
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
 use teloxide::dispatching::dialogue::PostgresStorage;
-use teloxide::dispatching::dialogue::serializer::Json;
+use teloxide::dispatching::dialogue::serializer::Serializer;
 use teloxide::prelude::Dialogue;
 
-use crate::domain::models::{MediaID, TaskID, TrackTag};
+use crate::domain::models::{FeedbackID, MediaID, MenuItem, TaskID, TrackTag};
 
-#[derive(Default, Clone, Serialize, Deserialize)]
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
 pub enum BotState {
     #[default]
     Idle,
+    /// Сохранённое состояние не удалось прочитать после обновления бота.
+    Stale,
 
     // Menu
     MenuOption,
@@ -24,6 +27,9 @@ pub enum BotState {
     CompletedTask(TrackTag),
     TaskAnswer(TrackTag, TaskID),
     TaskPhoto(TrackTag, TaskID),
+    TaskMultiChoice(TrackTag, TaskID, Vec<usize>),
+    TaskOrdering(TrackTag, TaskID, Vec<usize>),
+    TaskLocation(TrackTag, TaskID),
 
     // Slots
     AcceptFinal,
@@ -33,6 +39,146 @@ pub enum BotState {
 
     // Admin
     Media(MediaID),
+    MediaGroup(MediaID, String, usize),
+    FeedbackReply(FeedbackID),
 }
 
-pub type BotDialogue = Dialogue<BotState, PostgresStorage<Json>>;
+impl BotState {
+    /// Раздел меню, к которому относится состояние. Сам переход по меню и админские
+    /// сценарии не зависят от этапа мероприятия.
+    pub fn menu_item(&self) -> Option<MenuItem> {
+        match self {
+            BotState::Idle
+            | BotState::Stale
+            | BotState::MenuOption
+            | BotState::Media(..)
+            | BotState::MediaGroup(..)
+            | BotState::FeedbackReply(..) => None,
+            BotState::CharacterName => Some(MenuItem::Characters),
+            BotState::Feedback => Some(MenuItem::Feedback),
+            BotState::Track
+            | BotState::StartTrack(..)
+            | BotState::TrackTaskGroup(..)
+            | BotState::AvailableTask(..)
+            | BotState::CompletedTask(..)
+            | BotState::TaskAnswer(..)
+            | BotState::TaskPhoto(..)
+            | BotState::TaskMultiChoice(..)
+            | BotState::TaskOrdering(..)
+            | BotState::TaskLocation(..) => Some(MenuItem::Tracks),
+            BotState::AcceptFinal
+            | BotState::SlotStart
+            | BotState::SlotPlaces(..)
+            | BotState::CancelReason => Some(MenuItem::FinalReservation),
+        }
+    }
+
+    /// Состояние после ошибки: игрок возвращается в меню, остальные — в начало.
+    pub fn reset(&self) -> Self {
+        match self {
+            BotState::Idle
+            | BotState::Media(..)
+            | BotState::MediaGroup(..)
+            | BotState::FeedbackReply(..) => BotState::Idle,
+            _ => BotState::MenuOption,
+        }
+    }
+
+    /// Трек, задание и выбранные варианты ответа, на которые ссылается состояние.
+    pub fn task_reference(&self) -> Option<(&TrackTag, Option<TaskID>, &[usize])> {
+        match self {
+            BotState::StartTrack(tag)
+            | BotState::TrackTaskGroup(tag)
+            | BotState::AvailableTask(tag)
+            | BotState::CompletedTask(tag) => Some((tag, None, &[])),
+            BotState::TaskAnswer(tag, task_id)
+            | BotState::TaskPhoto(tag, task_id)
+            | BotState::TaskLocation(tag, task_id) => Some((tag, Some(*task_id), &[])),
+            BotState::TaskMultiChoice(tag, task_id, options)
+            | BotState::TaskOrdering(tag, task_id, options) => Some((tag, Some(*task_id), options)),
+            _ => None,
+        }
+    }
+
+    /// Экран, на котором игрок выбирает inline-кнопкой, а не вводит ответ.
+    pub fn is_navigation(&self) -> bool {
+        matches!(
+            self,
+            BotState::MenuOption
+                | BotState::Track
+                | BotState::StartTrack(..)
+                | BotState::TrackTaskGroup(..)
+                | BotState::AvailableTask(..)
+                | BotState::CompletedTask(..)
+                | BotState::AcceptFinal
+                | BotState::SlotStart
+                | BotState::CancelReason
+        )
+    }
+}
+
+/// Версия формата состояний в хранилище диалогов. Увеличивается, когда состояния
+/// меняются несовместимо, а `migrate_state` переводит старые записи в новый формат.
+pub const BOT_STATE_VERSION: u32 = 1;
+
+#[derive(Serialize)]
+struct VersionedStateRef<'a> {
+    version: u32,
+    state: &'a BotState,
+}
+
+#[derive(Deserialize)]
+struct VersionedState {
+    version: u32,
+    state: Value,
+}
+
+fn migrate_state(mut version: u32, mut state: Value) -> Option<Value> {
+    while version < BOT_STATE_VERSION {
+        state = match version {
+            // До версий состояние хранилось без обёртки в том же формате.
+            0 => state,
+            _ => return None,
+        };
+        version += 1;
+    }
+    (version == BOT_STATE_VERSION).then_some(state)
+}
+
+/// JSON с номером версии. Запись, которую не получилось прочитать,
+/// превращается в `BotState::Stale`, а не в ошибку хранилища.
+pub struct StateSerializer;
+
+impl Serializer<BotState> for StateSerializer {
+    type Error = serde_json::Error;
+
+    fn serialize(&self, val: &BotState) -> Result<Vec<u8>, Self::Error> {
+        serde_json::to_vec(&VersionedStateRef {
+            version: BOT_STATE_VERSION,
+            state: val,
+        })
+    }
+
+    fn deserialize(&self, data: &[u8]) -> Result<BotState, Self::Error> {
+        let value: Value = serde_json::from_slice(data)?;
+        let (version, state) = match serde_json::from_value::<VersionedState>(value.clone()) {
+            Ok(versioned) => (versioned.version, versioned.state),
+            Err(_) => (0, value),
+        };
+        let Some(state) = migrate_state(version, state) else {
+            log::warn!("Unable to migrate dialogue state from version {version}");
+            return Ok(BotState::Stale);
+        };
+        match serde_json::from_value(state) {
+            Ok(state) => Ok(state),
+            Err(err) => {
+                log::warn!("Unable to read dialogue state: {err}");
+                Ok(BotState::Stale)
+            }
+        }
+    }
+}
+
+pub type DialogueStorage = PostgresStorage<StateSerializer>;
+
+pub type BotDialogue = Dialogue<BotState, DialogueStorage>;
@@ -0,0 +1,118 @@
+use teloxide::dispatching::UpdateHandler;
+use teloxide::macros::BotCommands;
+use teloxide::prelude::*;
+use teloxide::types::ParseMode;
+
+use crate::app::error::AppError;
+use crate::app::usecases::{CheckPermission, GetAuditLog};
+use crate::bot::callbacks::CallbackData;
+use crate::bot::handlers::shared::send_permission_denied;
+use crate::bot::i18n::Locale;
+use crate::bot::keyboards::make_audit_page_keyboard;
+use crate::bot::{BotHandlerResult, texts};
+use crate::domain::models::{AuditFilter, Permission, UserID};
+
+#[derive(BotCommands, Clone)]
+#[command(description = "Журнал действий организаторов")]
+pub enum AuditLogCommand {
+    #[command(rename = "audit", description = "журнал действий организаторов")]
+    Audit(String),
+}
+
+/// Фильтр из аргументов `actor=<user_id> action=<код> target=<подстрока>`, все необязательны.
+fn parse_audit_filter(args: &str) -> Option<AuditFilter> {
+    let mut filter = AuditFilter::default();
+    for arg in args.split_whitespace() {
+        match arg.split_once('=')? {
+            ("actor", id) => filter.actor_id = Some(UserID::new(id.parse().ok()?)),
+            ("action", code) => filter.action = Some(code.parse().ok()?),
+            ("target", target) if !target.is_empty() => filter.target = Some(target.to_string()),
+            _ => return None,
+        }
+    }
+    Some(filter)
+}
+
+async fn handle_audit_command(
+    bot: Bot,
+    msg: Message,
+    command: AuditLogCommand,
+    check_permission: CheckPermission,
+    get_audit_log: GetAuditLog,
+) -> BotHandlerResult {
+    let locale = Locale::of_message(&msg);
+    let user_id = UserID::new(msg.chat.id.0);
+    if !check_permission
+        .execute(user_id, Permission::ViewAuditLog)
+        .await?
+    {
+        return send_permission_denied(&bot, &msg).await;
+    }
+    let AuditLogCommand::Audit(args) = command;
+    let args = args.trim();
+    let Some(filter) = parse_audit_filter(args) else {
+        bot.send_message(msg.chat.id, texts::invalid_audit_command_usage(locale))
+            .parse_mode(ParseMode::Html)
+            .await?;
+        return Ok(());
+    };
+
+    let page = get_audit_log.execute(user_id, &filter, 0).await?;
+    if page.items.is_empty() {
+        bot.send_message(msg.chat.id, texts::audit_log_is_empty(locale))
+            .parse_mode(ParseMode::Html)
+            .await?;
+        return Ok(());
+    }
+    bot.send_message(msg.chat.id, texts::audit_page(locale, &page))
+        .parse_mode(ParseMode::Html)
+        .reply_markup(make_audit_page_keyboard(&page, args))
+        .await?;
+    Ok(())
+}
+
+/// Листает журнал, редактируя исходное сообщение.
+async fn receive_audit_page(
+    bot: Bot,
+    q: CallbackQuery,
+    (page, args): (usize, String),
+    check_permission: CheckPermission,
+    get_audit_log: GetAuditLog,
+) -> BotHandlerResult {
+    let locale = Locale::of_user(&q.from);
+    bot.answer_callback_query(q.id.clone()).await?;
+    let Some(msg) = q.regular_message() else {
+        return Ok(());
+    };
+    let user_id = UserID::new(q.from.id.0 as i64);
+    if !check_permission
+        .execute(user_id, Permission::ViewAuditLog)
+        .await?
+    {
+        return Ok(());
+    }
+    let Some(filter) = parse_audit_filter(&args) else {
+        return Ok(());
+    };
+    let page = get_audit_log.execute(user_id, &filter, page).await?;
+    bot.edit_message_text(msg.chat.id, msg.id, texts::audit_page(locale, &page))
+        .parse_mode(ParseMode::Html)
+        .reply_markup(make_audit_page_keyboard(&page, &args))
+        .await?;
+    Ok(())
+}
+
+pub fn audit_log_scheme() -> UpdateHandler<AppError> {
+    use dptree::case;
+
+    let command_handler = teloxide::filter_command::<AuditLogCommand, _>()
+        .branch(case![AuditLogCommand::Audit(args)].endpoint(handle_audit_command));
+
+    Update::filter_message().branch(command_handler)
+}
+
+pub fn audit_log_callbacks() -> UpdateHandler<AppError> {
+    use dptree::case;
+
+    case![CallbackData::AuditPage(page, args)].endpoint(receive_audit_page)
+}
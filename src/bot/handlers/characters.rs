@@ -0,0 +1,223 @@
+use teloxide::dispatching::UpdateHandler;
+use teloxide::prelude::*;
+use teloxide::types::{InputFile, InputMedia, InputMediaPhoto, Message, ParseMode};
+
+use crate::app::error::AppError;
+use crate::app::usecases::dto::{CharacterDTO, CharacterPageDTO};
+use crate::app::usecases::{
+    FindCharacters, GetCharacter, GetCharacterPage, ToggleFavoriteCharacter,
+};
+use crate::bot::callbacks::CallbackData;
+use crate::bot::fsm::{BotDialogue, BotState};
+use crate::bot::handlers::shared::{Reply, send_enter_message};
+use crate::bot::i18n::Locale;
+use crate::bot::keyboards::{
+    make_back_keyboard, make_character_keyboard, make_character_page_keyboard,
+    make_characters_found_keyboard,
+};
+use crate::bot::{BotHandlerResult, texts};
+use crate::domain::models::{CharacterID, UserID};
+
+pub async fn prompt_characters(
+    bot: Bot,
+    reply: Reply,
+    dialogue: BotDialogue,
+    page: &CharacterPageDTO,
+) -> BotHandlerResult {
+    reply
+        .show(
+            &bot,
+            texts::prompt_character_name(reply.locale),
+            make_back_keyboard(reply.locale, CallbackData::Menu),
+        )
+        .await?;
+    reply
+        .detached()
+        .show(
+            &bot,
+            texts::character_page(reply.locale, page),
+            make_character_page_keyboard(reply.locale, page),
+        )
+        .await?;
+    dialogue.update(BotState::CharacterName).await?;
+    Ok(())
+}
+
+/// Поиск персонажа по введённому имени, допускает опечатки.
+async fn receive_character_name(
+    bot: Bot,
+    msg: Message,
+    get_character: GetCharacter,
+    find_characters: FindCharacters,
+) -> BotHandlerResult {
+    let locale = Locale::of_message(&msg);
+    let user_id = UserID::new(msg.chat.id.0);
+    let Some(text) = msg.text() else {
+        return send_enter_message(&bot, &msg).await;
+    };
+    let found = find_characters.execute(user_id, text).await?;
+    match found.as_slice() {
+        [] => {
+            bot.send_message(msg.chat.id, texts::characters_not_found(locale))
+                .parse_mode(ParseMode::Html)
+                .await?;
+        }
+        [item] => {
+            let character = get_character.execute(user_id, &item.id).await?;
+            send_character(&bot, msg.chat.id, locale, &character).await?;
+        }
+        items => {
+            bot.send_message(msg.chat.id, texts::characters_found(locale))
+                .reply_markup(make_characters_found_keyboard(items))
+                .parse_mode(ParseMode::Html)
+                .await?;
+        }
+    }
+    Ok(())
+}
+
+pub async fn send_character(
+    bot: &Bot,
+    chat_id: ChatId,
+    locale: Locale,
+    character: &CharacterDTO,
+) -> BotHandlerResult {
+    // У закрытой карточки без силуэта картинки нет, отправляем только текст.
+    match &character.image_id {
+        Some(image_id) => {
+            bot.send_photo(chat_id, InputFile::file_id(image_id.clone().into()))
+                .caption(texts::character(locale, character))
+                .reply_markup(make_character_keyboard(locale, character))
+                .parse_mode(ParseMode::Html)
+                .await?;
+        }
+        None => {
+            bot.send_message(chat_id, texts::character(locale, character))
+                .reply_markup(make_character_keyboard(locale, character))
+                .parse_mode(ParseMode::Html)
+                .await?;
+        }
+    }
+    Ok(())
+}
+
+/// Листает список персонажей, редактируя исходное сообщение.
+async fn receive_character_page(
+    bot: Bot,
+    reply: Reply,
+    (favorites_only, page): (bool, usize),
+    get_character_page: GetCharacterPage,
+) -> BotHandlerResult {
+    let page = get_character_page
+        .execute(reply.user_id(), favorites_only, page)
+        .await?;
+    if favorites_only && page.total == 0 {
+        return reply
+            .send(&bot, texts::favorite_characters_empty(reply.locale))
+            .await;
+    }
+    // Карточка персонажа — фото, его текст не отредактировать, и список придёт новым сообщением.
+    reply
+        .show(
+            &bot,
+            texts::character_page(reply.locale, &page),
+            make_character_page_keyboard(reply.locale, &page),
+        )
+        .await
+}
+
+async fn receive_character_show(
+    bot: Bot,
+    reply: Reply,
+    id: CharacterID,
+    get_character: GetCharacter,
+) -> BotHandlerResult {
+    match get_character.execute(reply.user_id(), &id).await {
+        Err(AppError::CharacterNotFound(_)) => Ok(()),
+        Err(err) => Err(err),
+        Ok(character) => send_character(&bot, reply.chat_id, reply.locale, &character).await,
+    }
+}
+
+/// Переключает карточку на соседнего персонажа.
+async fn receive_character_switch(
+    bot: Bot,
+    q: CallbackQuery,
+    id: CharacterID,
+    get_character: GetCharacter,
+) -> BotHandlerResult {
+    let locale = Locale::of_user(&q.from);
+    let Some(msg) = q.regular_message() else {
+        return Ok(());
+    };
+    let user_id = UserID::new(q.from.id.0 as i64);
+    let character = match get_character.execute(user_id, &id).await {
+        Err(AppError::CharacterNotFound(_)) => return Ok(()),
+        result => result?,
+    };
+    match (&character.image_id, msg.photo().is_some()) {
+        (Some(image_id), true) => {
+            let media = InputMedia::Photo(
+                InputMediaPhoto::new(InputFile::file_id(image_id.clone().into()))
+                    .caption(texts::character(locale, &character))
+                    .parse_mode(ParseMode::Html),
+            );
+            bot.edit_message_media(msg.chat.id, msg.id, media)
+                .reply_markup(make_character_keyboard(locale, &character))
+                .await?;
+        }
+        (None, false) => {
+            bot.edit_message_text(msg.chat.id, msg.id, texts::character(locale, &character))
+                .reply_markup(make_character_keyboard(locale, &character))
+                .parse_mode(ParseMode::Html)
+                .await?;
+        }
+        // Фото в текст и обратно не превратить, поэтому карточку отправляем заново.
+        _ => send_character(&bot, msg.chat.id, locale, &character).await?,
+    }
+    Ok(())
+}
+
+async fn receive_character_favorite(
+    bot: Bot,
+    q: CallbackQuery,
+    id: CharacterID,
+    get_character: GetCharacter,
+    toggle_favorite_character: ToggleFavoriteCharacter,
+) -> BotHandlerResult {
+    let Some(msg) = q.regular_message() else {
+        return Ok(());
+    };
+    let user_id = UserID::new(q.from.id.0 as i64);
+    match toggle_favorite_character.execute(user_id, &id).await {
+        Err(AppError::CharacterNotFound(_)) => return Ok(()),
+        result => result?,
+    };
+    let character = get_character.execute(user_id, &id).await?;
+    bot.edit_message_reply_markup(msg.chat.id, msg.id)
+        .reply_markup(make_character_keyboard(
+            Locale::of_user(&q.from),
+            &character,
+        ))
+        .await?;
+    Ok(())
+}
+
+pub fn characters_scheme() -> UpdateHandler<AppError> {
+    use dptree::case;
+
+    Update::filter_message().branch(case![BotState::CharacterName].endpoint(receive_character_name))
+}
+
+pub fn characters_callbacks() -> UpdateHandler<AppError> {
+    use dptree::case;
+
+    dptree::entry()
+        .branch(
+            case![CallbackData::CharacterPage(favorites_only, page)]
+                .endpoint(receive_character_page),
+        )
+        .branch(case![CallbackData::Character(id)].endpoint(receive_character_show))
+        .branch(case![CallbackData::CharacterSwitch(id)].endpoint(receive_character_switch))
+        .branch(case![CallbackData::CharacterFavorite(id)].endpoint(receive_character_favorite))
+}
@@ -1,32 +1,68 @@
+use std::sync::Arc;
+
+use chrono::Local;
 use teloxide::dispatching::UpdateHandler;
 use teloxide::macros::BotCommands;
 use teloxide::prelude::*;
-use teloxide::types::ParseMode;
+use teloxide::types::{InputFile, KeyboardRemove, ParseMode};
 
 use crate::app::error::AppError;
 use crate::app::usecases::{
-    CheckAdmin, CheckRegistered, GetMedia, GetPlayer, GetUser, UploadMedia,
+    CheckPermission, CheckRegistered, GetAvailableTasks, GetAvailableTracks, GetCharacterPage,
+    GetMedia, GetMediaContent, GetPlayer, GetUser, RefreshMediaFileID, RenderTaskGraph,
+    UploadMedia, ValidateTaskGraph,
 };
 use crate::bot::fsm::{BotDialogue, BotState};
+use crate::bot::handlers::characters::prompt_characters;
 use crate::bot::handlers::menu::prompt_menu;
-use crate::bot::handlers::shared::{send_media_with_caption, send_permission_denied};
+use crate::bot::handlers::shared::{
+    Reply, download_media_content, media_from_message, send_media_with_caption,
+    send_permission_denied,
+};
+use crate::bot::handlers::tracks::{prompt_available_task, prompt_track};
+use crate::bot::i18n::Locale;
 use crate::bot::{BotHandlerResult, texts};
-use crate::domain::models::{FileID, Media, MediaID, UserID};
+use crate::config::{Config, RegistrationConfig};
+use crate::domain::models::{FileID, Media, MediaID, Permission, TaskGraphFormat, UserID};
+
+/// Use case-ы экранов, на которые возвращает /cancel.
+#[derive(Clone)]
+pub struct CancelScreens {
+    pub get_available_tracks: GetAvailableTracks,
+    pub get_available_tasks: GetAvailableTasks,
+    pub get_character_page: GetCharacterPage,
+}
 
 #[derive(BotCommands, Clone)]
-#[command(description = "Команды регистрации")]
-enum BotCommand {
+#[command(description = "Команды игрока")]
+pub enum PlayerCommand {
     #[command(rename = "start", description = "начать регистрацию")]
     Start(String),
 
+    #[command(rename = "menu", description = "открыть меню")]
+    Menu,
+
+    #[command(rename = "cancel", description = "отменить текущую операцию")]
+    Cancel,
+}
+
+#[derive(BotCommands, Clone)]
+#[command(description = "Медиафайлы и задания")]
+pub enum ContentCommand {
     #[command(rename = "upload", description = "загрузить медиа")]
     Upload(String),
 
     #[command(rename = "media", description = "получить медиафайл")]
     Media(String),
 
-    #[command(rename = "cancel", description = "отменить текущую операцию")]
-    Cancel,
+    #[command(
+        rename = "validate",
+        description = "проверить граф зависимостей заданий"
+    )]
+    Validate,
+
+    #[command(rename = "graph", description = "выгрузить граф зависимостей заданий")]
+    Graph(String),
 }
 
 async fn handle_start_command(
@@ -35,19 +71,106 @@ async fn handle_start_command(
     dialogue: BotDialogue,
     check_registered: CheckRegistered,
     get_player: GetPlayer,
+    config: Arc<Config>,
 ) -> BotHandlerResult {
     let user_id = UserID::new(msg.chat.id.0);
     let registered = check_registered.execute(user_id).await?;
     if !registered {
-        send_registration_closed(&bot, &msg).await
+        send_registration_closed(&bot, &msg, &config.registration).await
     } else {
         let player = get_player.execute(user_id).await?;
-        prompt_menu(bot, msg, dialogue, &player).await
+        prompt_menu(bot, Reply::to_message(&msg), dialogue, &player).await
+    }
+}
+
+/// Возвращает на экран, с которого начата текущая операция. Незавершённый ответ
+/// на задание или выбор времени сбрасывается вместе с состоянием диалога.
+async fn handle_cancel_command(
+    bot: Bot,
+    msg: Message,
+    dialogue: BotDialogue,
+    state: BotState,
+    check_registered: CheckRegistered,
+    get_player: GetPlayer,
+    screens: CancelScreens,
+) -> BotHandlerResult {
+    let reply = Reply::to_message(&msg);
+    let user_id = reply.user_id();
+    match state {
+        BotState::Idle | BotState::Stale => {
+            if !check_registered.execute(user_id).await? {
+                return send_cancel_text(&bot, &msg, texts::nothing_to_cancel(reply.locale)).await;
+            }
+        }
+        BotState::Media(_) | BotState::FeedbackReply(_) => {
+            send_cancel_text(&bot, &msg, texts::operation_cancelled(reply.locale)).await?;
+            dialogue.exit().await?;
+            return Ok(());
+        }
+        // Уже присланные файлы альбома остаются в библиотеке.
+        BotState::MediaGroup(media_id, _, count) => {
+            let text = texts::media_group_uploaded(reply.locale, &media_id, count);
+            send_cancel_text(&bot, &msg, text).await?;
+            dialogue.exit().await?;
+            return Ok(());
+        }
+        _ => send_cancel_text(&bot, &msg, texts::operation_cancelled(reply.locale)).await?,
     }
+    match state {
+        BotState::TaskAnswer(tag, _)
+        | BotState::TaskPhoto(tag, _)
+        | BotState::TaskMultiChoice(tag, ..)
+        | BotState::TaskOrdering(tag, ..)
+        | BotState::TaskLocation(tag, _) => {
+            let tasks = screens.get_available_tasks.execute(user_id, &tag).await?;
+            if !tasks.is_empty() {
+                return prompt_available_task(bot, reply, dialogue, tag, &tasks).await;
+            }
+            let tracks = screens.get_available_tracks.execute(user_id).await?;
+            prompt_track(bot, reply, dialogue, &tracks).await
+        }
+        BotState::StartTrack(_)
+        | BotState::TrackTaskGroup(_)
+        | BotState::AvailableTask(_)
+        | BotState::CompletedTask(_) => {
+            let tracks = screens.get_available_tracks.execute(user_id).await?;
+            prompt_track(bot, reply, dialogue, &tracks).await
+        }
+        BotState::CharacterName => {
+            let page = screens
+                .get_character_page
+                .execute(user_id, false, 0)
+                .await?;
+            prompt_characters(bot, reply, dialogue, &page).await
+        }
+        _ => {
+            let player = get_player.execute(user_id).await?;
+            prompt_menu(bot, reply, dialogue, &player).await
+        }
+    }
+}
+
+/// Клавиатура для геопозиции больше не нужна.
+async fn send_cancel_text(bot: &Bot, msg: &Message, text: String) -> BotHandlerResult {
+    bot.send_message(msg.chat.id, text)
+        .reply_markup(KeyboardRemove::new())
+        .parse_mode(ParseMode::Html)
+        .await?;
+    Ok(())
 }
 
-async fn send_registration_closed(bot: &Bot, msg: &Message) -> BotHandlerResult {
-    bot.send_message(msg.chat.id, texts::REGISTRATION_CLOSED)
+async fn send_registration_closed(
+    bot: &Bot,
+    msg: &Message,
+    registration: &RegistrationConfig,
+) -> BotHandlerResult {
+    let locale = Locale::of_message(msg);
+    let text = if Local::now().date_naive() < registration.opens_on {
+        texts::registration_not_opened(locale, registration)
+    } else {
+        texts::registration_closed(locale, registration)
+    };
+    bot.send_message(msg.chat.id, text)
         .parse_mode(ParseMode::Html)
         .await?;
     Ok(())
@@ -57,13 +180,16 @@ async fn handle_upload_command(
     bot: Bot,
     msg: Message,
     dialogue: BotDialogue,
-    command: BotCommand,
-    check_admin: CheckAdmin,
+    command: ContentCommand,
+    check_permission: CheckPermission,
 ) -> BotHandlerResult {
     let user_id = UserID::new(msg.chat.id.0);
-    if !check_admin.execute(user_id).await? {
+    if !check_permission
+        .execute(user_id, Permission::EditContent)
+        .await?
+    {
         send_permission_denied(&bot, &msg).await?;
-    } else if let BotCommand::Upload(key) = command {
+    } else if let ContentCommand::Upload(key) = command {
         match MediaID::new(key) {
             Err(_) => send_invalid_usage_upload_command(&bot, &msg).await?,
             Ok(id) => prompt_media(bot, msg, dialogue, id).await?,
@@ -73,7 +199,8 @@ async fn handle_upload_command(
 }
 
 async fn send_invalid_usage_upload_command(bot: &Bot, msg: &Message) -> BotHandlerResult {
-    bot.send_message(msg.chat.id, texts::INVALID_UPLOAD_COMMAND_USAGE)
+    let locale = Locale::of_message(msg);
+    bot.send_message(msg.chat.id, texts::invalid_upload_command_usage(locale))
         .parse_mode(ParseMode::Html)
         .await?;
     Ok(())
@@ -85,7 +212,8 @@ async fn prompt_media(
     dialogue: BotDialogue,
     media_id: MediaID,
 ) -> BotHandlerResult {
-    bot.send_message(msg.chat.id, texts::PROMPT_MEDIA)
+    let locale = Locale::of_message(&msg);
+    bot.send_message(msg.chat.id, texts::prompt_media(locale))
         .parse_mode(ParseMode::Html)
         .await?;
     dialogue.update(BotState::Media(media_id)).await?;
@@ -97,39 +225,118 @@ async fn receive_media(
     msg: Message,
     dialogue: BotDialogue,
     upload_media: UploadMedia,
-    get_media: GetMedia,
+    get_media_content: GetMediaContent,
+    refresh_media_file_id: RefreshMediaFileID,
     media_id: MediaID,
 ) -> BotHandlerResult {
-    if let Some(photos) = msg.photo() {
-        let photo = photos.first().unwrap();
-        let file_id = FileID::new(photo.file.id.0.clone())?;
-        let media = Media::image(media_id.clone(), file_id.clone());
-        upload_media.execute(media).await?;
-        let media = get_media.execute(media_id.clone()).await?;
-        send_media_with_caption(
-            &bot,
-            &msg,
-            media,
-            &format!("<code>{}</code>", media_id.as_str()),
-        )
-        .await?;
-        send_successful_media_uploaded(bot, msg, dialogue, &file_id).await?;
-    } else if let Some(video_note) = msg.video_note() {
-        let file_id = FileID::new(video_note.file.id.0.clone())?;
-        let media = Media::video_note(media_id.clone(), file_id.clone());
-        upload_media.execute(media).await?;
-        let media = get_media.execute(media_id.clone()).await?;
-        send_media_with_caption(
-            &bot,
-            &msg,
-            media,
-            &format!("<code>{}</code>", media_id.as_str()),
+    let Some(media) = media_from_message(&msg, media_id.clone())? else {
+        return send_unknown_media_format(bot, msg, dialogue).await;
+    };
+    let file_id = media.file_id().clone();
+    upload_and_show_media(
+        &bot,
+        &msg,
+        media,
+        &upload_media,
+        &get_media_content,
+        &refresh_media_file_id,
+    )
+    .await?;
+    match msg.media_group_id() {
+        // Остальные файлы альбома придут отдельными сообщениями
+        Some(group_id) => {
+            send_media_uploaded(&bot, &msg, &file_id).await?;
+            dialogue
+                .update(BotState::MediaGroup(media_id, group_id.0.clone(), 1))
+                .await?;
+            Ok(())
+        }
+        None => send_successful_media_uploaded(bot, msg, dialogue, &file_id).await,
+    }
+}
+
+async fn receive_media_group_item(
+    bot: Bot,
+    msg: Message,
+    dialogue: BotDialogue,
+    upload_media: UploadMedia,
+    get_media_content: GetMediaContent,
+    refresh_media_file_id: RefreshMediaFileID,
+    (media_id, group_id, count): (MediaID, String, usize),
+) -> BotHandlerResult {
+    let locale = Locale::of_message(&msg);
+    if msg.media_group_id().is_none_or(|id| id.0 != group_id) {
+        bot.send_message(
+            msg.chat.id,
+            texts::media_group_uploaded(locale, &media_id, count),
         )
+        .parse_mode(ParseMode::Html)
         .await?;
-        send_successful_media_uploaded(bot, msg, dialogue, &file_id).await?;
-    } else {
-        send_unknown_media_format(bot, msg, dialogue).await?;
+        dialogue.exit().await?;
+        return Ok(());
     }
+
+    let item_id = MediaID::new(format!("{}_{}", media_id.as_str(), count + 1))?;
+    let Some(media) = media_from_message(&msg, item_id)? else {
+        return send_unknown_media_format(bot, msg, dialogue).await;
+    };
+    let file_id = media.file_id().clone();
+    upload_and_show_media(
+        &bot,
+        &msg,
+        media,
+        &upload_media,
+        &get_media_content,
+        &refresh_media_file_id,
+    )
+    .await?;
+    send_media_uploaded(&bot, &msg, &file_id).await?;
+    dialogue
+        .update(BotState::MediaGroup(media_id, group_id, count + 1))
+        .await?;
+    Ok(())
+}
+
+async fn upload_and_show_media(
+    bot: &Bot,
+    msg: &Message,
+    media: Media,
+    upload_media: &UploadMedia,
+    get_media_content: &GetMediaContent,
+    refresh_media_file_id: &RefreshMediaFileID,
+) -> BotHandlerResult {
+    let media_id = media.id().clone();
+    let content = match download_media_content(bot, &media).await {
+        Ok(content) => Some(content),
+        Err(err) => {
+            log::warn!("Unable to download media {}: {err}", media_id.as_str());
+            None
+        }
+    };
+    let uploaded = Media::new(
+        media_id.clone(),
+        media.file_id().clone(),
+        media.media_type(),
+    );
+    upload_media
+        .execute(UserID::new(msg.chat.id.0), media, content)
+        .await?;
+    send_media_with_caption(
+        bot,
+        msg,
+        uploaded,
+        &format!("<code>{}</code>", media_id.as_str()),
+        get_media_content,
+        refresh_media_file_id,
+    )
+    .await
+}
+
+async fn send_media_uploaded(bot: &Bot, msg: &Message, file_id: &FileID) -> BotHandlerResult {
+    let locale = Locale::of_message(msg);
+    bot.send_message(msg.chat.id, texts::media_uploaded(locale, file_id))
+        .parse_mode(ParseMode::Html)
+        .await?;
     Ok(())
 }
 
@@ -138,7 +345,8 @@ async fn send_unknown_media_format(
     msg: Message,
     dialogue: BotDialogue,
 ) -> BotHandlerResult {
-    bot.send_message(msg.chat.id, texts::UNKNOWN_MEDIA_FORMAT)
+    let locale = Locale::of_message(&msg);
+    bot.send_message(msg.chat.id, texts::unknown_media_format(locale))
         .parse_mode(ParseMode::Html)
         .await?;
     dialogue.exit().await?;
@@ -151,7 +359,8 @@ async fn send_successful_media_uploaded(
     dialogue: BotDialogue,
     file_id: &FileID,
 ) -> BotHandlerResult {
-    bot.send_message(msg.chat.id, texts::media_uploaded(file_id))
+    let locale = Locale::of_message(&msg);
+    bot.send_message(msg.chat.id, texts::media_uploaded(locale, file_id))
         .parse_mode(ParseMode::Html)
         .await?;
     dialogue.exit().await?;
@@ -161,23 +370,30 @@ async fn send_successful_media_uploaded(
 async fn handle_media_command(
     bot: Bot,
     msg: Message,
-    command: BotCommand,
+    command: ContentCommand,
     get_media: GetMedia,
-    check_admin: CheckAdmin,
+    check_permission: CheckPermission,
+    get_media_content: GetMediaContent,
+    refresh_media_file_id: RefreshMediaFileID,
 ) -> BotHandlerResult {
     let user_id = UserID::new(msg.chat.id.0);
-    if !check_admin.execute(user_id).await? {
+    if !check_permission
+        .execute(user_id, Permission::ViewContent)
+        .await?
+    {
         send_permission_denied(&bot, &msg).await
-    } else if let BotCommand::Media(key) = command {
+    } else if let ContentCommand::Media(key) = command {
         match MediaID::new(key) {
             Err(_) => send_invalid_usage_media_command(&bot, &msg).await,
-            Ok(id) => match get_media.execute(id.clone()).await {
+            Ok(id) => match get_media.execute(user_id, id.clone()).await {
                 Ok(media) => {
                     send_media_with_caption(
                         &bot,
                         &msg,
                         media,
                         &format!("<code>{}</code>", id.as_str()),
+                        &get_media_content,
+                        &refresh_media_file_id,
                     )
                     .await
                 }
@@ -191,28 +407,105 @@ async fn handle_media_command(
 }
 
 async fn send_invalid_usage_media_command(bot: &Bot, msg: &Message) -> BotHandlerResult {
-    bot.send_message(msg.chat.id, texts::INVALID_MEDIA_COMMAND_USAGE)
+    let locale = Locale::of_message(msg);
+    bot.send_message(msg.chat.id, texts::invalid_media_command_usage(locale))
         .parse_mode(ParseMode::Html)
         .await?;
     Ok(())
 }
 
 async fn send_media_not_found(bot: &Bot, msg: &Message, id: MediaID) -> BotHandlerResult {
-    bot.send_message(msg.chat.id, texts::media_not_found(&id))
+    let locale = Locale::of_message(msg);
+    bot.send_message(msg.chat.id, texts::media_not_found(locale, &id))
         .parse_mode(ParseMode::Html)
         .await?;
     Ok(())
 }
 
+async fn handle_validate_command(
+    bot: Bot,
+    msg: Message,
+    check_permission: CheckPermission,
+    validate_task_graph: ValidateTaskGraph,
+) -> BotHandlerResult {
+    let locale = Locale::of_message(&msg);
+    let user_id = UserID::new(msg.chat.id.0);
+    if !check_permission
+        .execute(user_id, Permission::ViewContent)
+        .await?
+    {
+        return send_permission_denied(&bot, &msg).await;
+    }
+    let issues = validate_task_graph.execute(user_id).await?;
+    let text = if issues.is_empty() {
+        texts::task_graph_is_valid(locale)
+    } else {
+        texts::task_graph_issues(locale, &issues)
+    };
+    bot.send_message(msg.chat.id, text)
+        .parse_mode(ParseMode::Html)
+        .await?;
+    Ok(())
+}
+
+async fn handle_graph_command(
+    bot: Bot,
+    msg: Message,
+    command: ContentCommand,
+    check_permission: CheckPermission,
+    render_task_graph: RenderTaskGraph,
+) -> BotHandlerResult {
+    let locale = Locale::of_message(&msg);
+    let user_id = UserID::new(msg.chat.id.0);
+    if !check_permission
+        .execute(user_id, Permission::ViewContent)
+        .await?
+    {
+        return send_permission_denied(&bot, &msg).await;
+    }
+    let format = match command {
+        ContentCommand::Graph(format) => match format.trim() {
+            "dot" => Some(TaskGraphFormat::Dot),
+            "mermaid" => Some(TaskGraphFormat::Mermaid),
+            _ => None,
+        },
+        _ => None,
+    };
+    match format {
+        None => {
+            bot.send_message(msg.chat.id, texts::invalid_graph_command_usage(locale))
+                .parse_mode(ParseMode::Html)
+                .await?;
+        }
+        Some(format) => {
+            let graph = render_task_graph.execute(user_id, format).await?;
+            let file = InputFile::memory(graph.into_bytes())
+                .file_name(format!("quest.{}", format.file_extension()));
+            bot.send_document(msg.chat.id, file).await?;
+        }
+    }
+    Ok(())
+}
+
 pub fn commands_scheme() -> UpdateHandler<AppError> {
     use dptree::case;
 
-    let command_handler = teloxide::filter_command::<BotCommand, _>()
-        .branch(case![BotCommand::Start(payload)].endpoint(handle_start_command))
-        .branch(case![BotCommand::Upload(key)].endpoint(handle_upload_command))
-        .branch(case![BotCommand::Media(key)].endpoint(handle_media_command));
+    let player_command_handler = teloxide::filter_command::<PlayerCommand, _>()
+        .branch(case![PlayerCommand::Start(payload)].endpoint(handle_start_command))
+        .branch(case![PlayerCommand::Menu].endpoint(handle_start_command))
+        .branch(case![PlayerCommand::Cancel].endpoint(handle_cancel_command));
+
+    let content_command_handler = teloxide::filter_command::<ContentCommand, _>()
+        .branch(case![ContentCommand::Upload(key)].endpoint(handle_upload_command))
+        .branch(case![ContentCommand::Media(key)].endpoint(handle_media_command))
+        .branch(case![ContentCommand::Validate].endpoint(handle_validate_command))
+        .branch(case![ContentCommand::Graph(format)].endpoint(handle_graph_command));
 
     Update::filter_message()
-        .branch(command_handler)
+        .branch(player_command_handler)
+        .branch(content_command_handler)
         .branch(case![BotState::Media(key)].endpoint(receive_media))
+        .branch(
+            case![BotState::MediaGroup(key, group_id, count)].endpoint(receive_media_group_item),
+        )
 }
@@ -0,0 +1,122 @@
+use std::sync::Arc;
+
+use teloxide::dispatching::UpdateHandler;
+use teloxide::macros::BotCommands;
+use teloxide::prelude::*;
+use teloxide::types::ParseMode;
+
+use crate::app::error::AppError;
+use crate::app::usecases::{CheckPermission, CheckTaskReference, GetPlayer, ResetDialogue};
+use crate::bot::fsm::{BotDialogue, BotState, DialogueStorage};
+use crate::bot::handlers::menu::prompt_menu;
+use crate::bot::handlers::shared::{Reply, send_permission_denied};
+use crate::bot::i18n::Locale;
+use crate::bot::{BotHandlerResult, texts};
+use crate::domain::models::{Permission, UserID};
+
+#[derive(BotCommands, Clone)]
+#[command(description = "Диалоги игроков")]
+pub enum DialogueStateCommand {
+    #[command(rename = "reset", description = "вернуть пользователя в меню")]
+    Reset(String),
+}
+
+async fn handle_reset_command(
+    bot: Bot,
+    msg: Message,
+    command: DialogueStateCommand,
+    check_permission: CheckPermission,
+    reset_dialogue: ResetDialogue,
+    get_player: GetPlayer,
+    storage: Arc<DialogueStorage>,
+) -> BotHandlerResult {
+    let locale = Locale::of_message(&msg);
+    let actor_id = UserID::new(msg.chat.id.0);
+    if !check_permission
+        .execute(actor_id, Permission::ManageEvent)
+        .await?
+    {
+        return send_permission_denied(&bot, &msg).await;
+    }
+    let DialogueStateCommand::Reset(args) = command;
+    let Ok(user_id) = args.trim().parse().map(UserID::new) else {
+        return send_text(&bot, &msg, &texts::invalid_reset_command_usage(locale)).await;
+    };
+    let dialogue = BotDialogue::new(storage, ChatId(user_id.as_i64()));
+    if reset_dialogue.execute(actor_id, user_id).await? {
+        // Язык пользователя известен только из его сообщений.
+        let reply = Reply::to_chat(dialogue.chat_id(), Locale::DEFAULT);
+        reply
+            .send(&bot, texts::state_reset_by_staff(reply.locale))
+            .await?;
+        let player = get_player.execute(user_id).await?;
+        prompt_menu(bot.clone(), reply, dialogue, &player).await?;
+    } else {
+        dialogue.exit().await?;
+    }
+    send_text(&bot, &msg, &texts::dialogue_reset(locale, user_id)).await
+}
+
+async fn send_text(bot: &Bot, msg: &Message, text: &str) -> BotHandlerResult {
+    bot.send_message(msg.chat.id, text)
+        .parse_mode(ParseMode::Html)
+        .await?;
+    Ok(())
+}
+
+/// Состояние не прочиталось после обновления бота или ссылается на трек,
+/// задание или варианты ответа, которых больше нет.
+async fn is_stale_state(state: BotState, check_task_reference: CheckTaskReference) -> bool {
+    if matches!(state, BotState::Stale) {
+        return true;
+    }
+    let Some((tag, task_id, options)) = state.task_reference() else {
+        return false;
+    };
+    match check_task_reference.execute(tag, task_id, options).await {
+        Ok(valid) => !valid,
+        Err(err) => {
+            log::error!("Unable to check dialogue state: {err}");
+            false
+        }
+    }
+}
+
+async fn leave_stale_state(
+    bot: Bot,
+    msg: Message,
+    dialogue: BotDialogue,
+    get_player: GetPlayer,
+) -> BotHandlerResult {
+    let reply = Reply::to_message(&msg);
+    reply.send(&bot, texts::state_reset(reply.locale)).await?;
+    let player = get_player.execute(reply.user_id()).await?;
+    prompt_menu(bot, reply, dialogue, &player).await
+}
+
+async fn answer_stale_state_button(
+    bot: Bot,
+    reply: Reply,
+    dialogue: BotDialogue,
+    get_player: GetPlayer,
+) -> BotHandlerResult {
+    reply.send(&bot, texts::state_reset(reply.locale)).await?;
+    let player = get_player.execute(reply.user_id()).await?;
+    prompt_menu(bot, reply.detached(), dialogue, &player).await
+}
+
+pub fn dialogue_state_scheme() -> UpdateHandler<AppError> {
+    use dptree::case;
+
+    let command_handler = teloxide::filter_command::<DialogueStateCommand, _>()
+        .branch(case![DialogueStateCommand::Reset(args)].endpoint(handle_reset_command));
+
+    Update::filter_message()
+        .branch(command_handler)
+        .branch(dptree::filter_async(is_stale_state).endpoint(leave_stale_state))
+}
+
+/// Нажатие кнопки игроком, у которого устаревшее состояние диалога.
+pub fn stale_state_callbacks() -> UpdateHandler<AppError> {
+    dptree::filter_async(is_stale_state).endpoint(answer_stale_state_button)
+}
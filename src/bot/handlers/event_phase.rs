@@ -0,0 +1,167 @@
+use std::sync::Arc;
+
+use teloxide::dispatching::UpdateHandler;
+use teloxide::macros::BotCommands;
+use teloxide::prelude::*;
+use teloxide::types::ParseMode;
+
+use crate::app::error::AppError;
+use crate::app::usecases::{CheckPermission, GetEventPhase, GetPlayer, SetEventPhase};
+use crate::bot::callbacks::CallbackData;
+use crate::bot::fsm::{BotDialogue, BotState};
+use crate::bot::handlers::menu::prompt_menu;
+use crate::bot::handlers::shared::{Reply, send_permission_denied};
+use crate::bot::i18n::Locale;
+use crate::bot::{BotHandlerResult, texts};
+use crate::config::Config;
+use crate::domain::models::{EventPhase, MenuItem, Permission, UserID};
+
+#[derive(BotCommands, Clone)]
+#[command(description = "Этапы мероприятия")]
+pub enum EventPhaseCommand {
+    #[command(
+        rename = "phase",
+        description = "текущий этап мероприятия или переключение на другой"
+    )]
+    Phase(String),
+}
+
+async fn handle_phase_command(
+    bot: Bot,
+    msg: Message,
+    command: EventPhaseCommand,
+    check_permission: CheckPermission,
+    get_event_phase: GetEventPhase,
+    set_event_phase: SetEventPhase,
+    config: Arc<Config>,
+) -> BotHandlerResult {
+    let locale = Locale::of_message(&msg);
+    let actor_id = UserID::new(msg.chat.id.0);
+    if !check_permission
+        .execute(actor_id, Permission::ManageEvent)
+        .await?
+    {
+        return send_permission_denied(&bot, &msg).await;
+    }
+    let EventPhaseCommand::Phase(args) = command;
+    let args = args.trim();
+    if args.is_empty() {
+        let current = get_event_phase.execute().await?.phase;
+        return send_text(
+            &bot,
+            &msg,
+            &texts::event_phases(locale, &config.phases, current),
+        )
+        .await;
+    }
+    let Ok(phase) = args.parse::<EventPhase>() else {
+        return send_text(
+            &bot,
+            &msg,
+            &texts::invalid_phase_command_usage(locale, &config.phases),
+        )
+        .await;
+    };
+    if set_event_phase.execute(actor_id, phase).await? {
+        send_text(&bot, &msg, &texts::event_phase_switched(locale, phase)).await
+    } else {
+        send_text(
+            &bot,
+            &msg,
+            &texts::event_phase_already_active(locale, phase),
+        )
+        .await
+    }
+}
+
+async fn send_text(bot: &Bot, msg: &Message, text: &str) -> BotHandlerResult {
+    bot.send_message(msg.chat.id, text)
+        .parse_mode(ParseMode::Html)
+        .await?;
+    Ok(())
+}
+
+async fn is_enabled(get_event_phase: &GetEventPhase, item: MenuItem) -> bool {
+    match get_event_phase.execute().await {
+        Ok(phase) => phase.is_enabled(item),
+        Err(err) => {
+            log::error!("Unable to get event phase: {err}");
+            false
+        }
+    }
+}
+
+/// Игрок остался в диалоге раздела, который закрыли переключением этапа.
+async fn leave_closed_section(
+    bot: Bot,
+    msg: Message,
+    dialogue: BotDialogue,
+    get_player: GetPlayer,
+) -> BotHandlerResult {
+    let locale = Locale::of_message(&msg);
+    send_text(&bot, &msg, &texts::section_closed(locale)).await?;
+    let player = get_player.execute(UserID::new(msg.chat.id.0)).await?;
+    prompt_menu(bot, Reply::to_message(&msg), dialogue, &player).await
+}
+
+/// Кнопка раздела, который закрыли после отправки сообщения.
+async fn answer_closed_section_button(
+    bot: Bot,
+    reply: Reply,
+    dialogue: BotDialogue,
+    get_player: GetPlayer,
+) -> BotHandlerResult {
+    reply
+        .send(&bot, texts::section_closed(reply.locale))
+        .await?;
+    let player = get_player.execute(reply.user_id()).await?;
+    prompt_menu(bot, reply.detached(), dialogue, &player).await
+}
+
+/// Кнопка со старого сообщения, которую уже никто не обрабатывает.
+async fn answer_unavailable_button(bot: Bot, q: CallbackQuery) -> BotHandlerResult {
+    let locale = Locale::of_user(&q.from);
+    bot.answer_callback_query(q.id)
+        .text(texts::button_unavailable(locale))
+        .await?;
+    Ok(())
+}
+
+pub fn event_phase_scheme() -> UpdateHandler<AppError> {
+    use dptree::case;
+
+    let command_handler = teloxide::filter_command::<EventPhaseCommand, _>()
+        .branch(case![EventPhaseCommand::Phase(args)].endpoint(handle_phase_command));
+
+    let closed_section_handler = dptree::filter_async(
+        |state: BotState, get_event_phase: GetEventPhase| async move {
+            match state.menu_item() {
+                Some(item) => !is_enabled(&get_event_phase, item).await,
+                None => false,
+            }
+        },
+    )
+    .endpoint(leave_closed_section);
+
+    Update::filter_message()
+        .branch(command_handler)
+        .branch(closed_section_handler)
+}
+
+/// Пропускает нажатия кнопок закрытых на текущем этапе разделов.
+pub fn closed_section_callbacks() -> UpdateHandler<AppError> {
+    dptree::filter_async(
+        |data: CallbackData, get_event_phase: GetEventPhase| async move {
+            match data.menu_item() {
+                Some(item) => !is_enabled(&get_event_phase, item).await,
+                None => false,
+            }
+        },
+    )
+    .endpoint(answer_closed_section_button)
+}
+
+/// Подключается последней: отвечает на нажатия, которые не обработала ни одна схема.
+pub fn unavailable_button_scheme() -> UpdateHandler<AppError> {
+    Update::filter_callback_query().endpoint(answer_unavailable_button)
+}
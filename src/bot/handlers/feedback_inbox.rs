@@ -0,0 +1,263 @@
+use teloxide::dispatching::UpdateHandler;
+use teloxide::macros::BotCommands;
+use teloxide::prelude::*;
+use teloxide::types::ParseMode;
+
+use crate::app::error::AppError;
+use crate::app::usecases::{
+    ArchiveFeedback, CheckPermission, GetFeedback, GetFeedbackInbox, ReplyToFeedback,
+};
+use crate::bot::callbacks::CallbackData;
+use crate::bot::fsm::{BotDialogue, BotState};
+use crate::bot::handlers::shared::{send_enter_message, send_permission_denied};
+use crate::bot::i18n::Locale;
+use crate::bot::keyboards::{make_feedback_keyboard, make_feedback_page_keyboard};
+use crate::bot::{BotHandlerResult, texts};
+use crate::domain::models::{FeedbackID, FeedbackStatus, FeedbackText, Permission, UserID};
+
+#[derive(BotCommands, Clone)]
+#[command(description = "Обратная связь")]
+pub enum FeedbackInboxCommand {
+    #[command(rename = "inbox", description = "входящая обратная связь")]
+    Inbox(String),
+}
+
+/// Код фильтра → статус, `None` внутри — все статусы.
+fn parse_status_filter(code: &str) -> Option<Option<FeedbackStatus>> {
+    match code {
+        "" | "new" => Some(Some(FeedbackStatus::New)),
+        "answered" => Some(Some(FeedbackStatus::Answered)),
+        "archived" => Some(Some(FeedbackStatus::Archived)),
+        "all" => Some(None),
+        _ => None,
+    }
+}
+
+async fn handle_inbox_command(
+    bot: Bot,
+    msg: Message,
+    command: FeedbackInboxCommand,
+    check_permission: CheckPermission,
+    get_feedback_inbox: GetFeedbackInbox,
+) -> BotHandlerResult {
+    let locale = Locale::of_message(&msg);
+    let user_id = UserID::new(msg.chat.id.0);
+    if !check_permission
+        .execute(user_id, Permission::ManageFeedback)
+        .await?
+    {
+        return send_permission_denied(&bot, &msg).await;
+    }
+    let FeedbackInboxCommand::Inbox(code) = command;
+    let code = code.trim();
+    let Some(status) = parse_status_filter(code) else {
+        bot.send_message(msg.chat.id, texts::invalid_inbox_command_usage(locale))
+            .parse_mode(ParseMode::Html)
+            .await?;
+        return Ok(());
+    };
+    let code = if code.is_empty() { "new" } else { code };
+
+    let page = get_feedback_inbox.execute(user_id, status, 0).await?;
+    if page.items.is_empty() {
+        bot.send_message(msg.chat.id, texts::feedback_inbox_is_empty(locale))
+            .parse_mode(ParseMode::Html)
+            .await?;
+        return Ok(());
+    }
+    bot.send_message(msg.chat.id, texts::feedback_page(locale, &page, status))
+        .parse_mode(ParseMode::Html)
+        .reply_markup(make_feedback_page_keyboard(&page, code))
+        .await?;
+    Ok(())
+}
+
+async fn receive_feedback_page(
+    bot: Bot,
+    q: CallbackQuery,
+    (code, page): (String, usize),
+    check_permission: CheckPermission,
+    get_feedback_inbox: GetFeedbackInbox,
+) -> BotHandlerResult {
+    let locale = Locale::of_user(&q.from);
+    bot.answer_callback_query(q.id.clone()).await?;
+    let Some(msg) = q.regular_message() else {
+        return Ok(());
+    };
+    let user_id = UserID::new(q.from.id.0 as i64);
+    if !check_permission
+        .execute(user_id, Permission::ManageFeedback)
+        .await?
+    {
+        return Ok(());
+    }
+    let Some(status) = parse_status_filter(&code) else {
+        return Ok(());
+    };
+    let page = get_feedback_inbox.execute(user_id, status, page).await?;
+    bot.edit_message_text(
+        msg.chat.id,
+        msg.id,
+        texts::feedback_page(locale, &page, status),
+    )
+    .parse_mode(ParseMode::Html)
+    .reply_markup(make_feedback_page_keyboard(&page, &code))
+    .await?;
+    Ok(())
+}
+
+async fn receive_feedback_show(
+    bot: Bot,
+    q: CallbackQuery,
+    id: FeedbackID,
+    check_permission: CheckPermission,
+    get_feedback: GetFeedback,
+) -> BotHandlerResult {
+    let locale = Locale::of_user(&q.from);
+    bot.answer_callback_query(q.id.clone()).await?;
+    let Some(msg) = q.regular_message() else {
+        return Ok(());
+    };
+    let user_id = UserID::new(q.from.id.0 as i64);
+    if !check_permission
+        .execute(user_id, Permission::ManageFeedback)
+        .await?
+    {
+        return Ok(());
+    }
+    let feedback = get_feedback.execute(user_id, id).await?;
+    bot.send_message(msg.chat.id, texts::feedback_thread(locale, &feedback))
+        .parse_mode(ParseMode::Html)
+        .reply_markup(make_feedback_keyboard(locale, &feedback))
+        .await?;
+    Ok(())
+}
+
+async fn receive_feedback_reply_request(
+    bot: Bot,
+    q: CallbackQuery,
+    id: FeedbackID,
+    dialogue: BotDialogue,
+    check_permission: CheckPermission,
+    get_feedback: GetFeedback,
+) -> BotHandlerResult {
+    let locale = Locale::of_user(&q.from);
+    bot.answer_callback_query(q.id.clone()).await?;
+    let Some(msg) = q.regular_message() else {
+        return Ok(());
+    };
+    let user_id = UserID::new(q.from.id.0 as i64);
+    if !check_permission
+        .execute(user_id, Permission::ManageFeedback)
+        .await?
+    {
+        return Ok(());
+    }
+    let feedback = get_feedback.execute(user_id, id).await?;
+    if feedback.status == FeedbackStatus::Archived {
+        bot.send_message(msg.chat.id, texts::feedback_is_archived(locale))
+            .parse_mode(ParseMode::Html)
+            .await?;
+        return Ok(());
+    }
+    bot.send_message(msg.chat.id, texts::prompt_feedback_reply(locale))
+        .parse_mode(ParseMode::Html)
+        .await?;
+    dialogue.update(BotState::FeedbackReply(id)).await?;
+    Ok(())
+}
+
+async fn receive_feedback_archive(
+    bot: Bot,
+    q: CallbackQuery,
+    id: FeedbackID,
+    check_permission: CheckPermission,
+    archive_feedback: ArchiveFeedback,
+) -> BotHandlerResult {
+    let locale = Locale::of_user(&q.from);
+    bot.answer_callback_query(q.id.clone()).await?;
+    let Some(msg) = q.regular_message() else {
+        return Ok(());
+    };
+    let user_id = UserID::new(q.from.id.0 as i64);
+    if !check_permission
+        .execute(user_id, Permission::ManageFeedback)
+        .await?
+    {
+        return Ok(());
+    }
+    archive_feedback.execute(user_id, id).await?;
+    bot.send_message(msg.chat.id, texts::feedback_archived(locale, id))
+        .parse_mode(ParseMode::Html)
+        .await?;
+    Ok(())
+}
+
+/// Сначала доставляет ответ автору и только потом сохраняет его в переписке.
+async fn receive_feedback_reply(
+    bot: Bot,
+    msg: Message,
+    dialogue: BotDialogue,
+    id: FeedbackID,
+    get_feedback: GetFeedback,
+    reply_to_feedback: ReplyToFeedback,
+) -> BotHandlerResult {
+    let locale = Locale::of_message(&msg);
+    let Some(text) = msg.text() else {
+        return send_enter_message(&bot, &msg).await;
+    };
+    let user_id = UserID::new(msg.chat.id.0);
+    let text = FeedbackText::new(text.to_string())?;
+    let feedback = get_feedback.execute(user_id, id).await?;
+    if feedback.status == FeedbackStatus::Archived {
+        bot.send_message(msg.chat.id, texts::feedback_is_archived(locale))
+            .parse_mode(ParseMode::Html)
+            .await?;
+        dialogue.exit().await?;
+        return Ok(());
+    }
+
+    let delivered = bot
+        .send_message(
+            ChatId(feedback.author_id.as_i64()),
+            texts::feedback_reply_to_author(Locale::DEFAULT, &text),
+        )
+        .parse_mode(ParseMode::Html)
+        .await;
+    let answer = match delivered {
+        Ok(_) => {
+            reply_to_feedback.execute(user_id, id, text).await?;
+            texts::feedback_reply_sent(locale, id)
+        }
+        Err(err) => {
+            log::warn!("Unable to deliver reply to feedback {id}: {err}");
+            texts::feedback_reply_not_delivered(locale, id)
+        }
+    };
+    bot.send_message(msg.chat.id, answer)
+        .parse_mode(ParseMode::Html)
+        .await?;
+    dialogue.exit().await?;
+    Ok(())
+}
+
+pub fn feedback_inbox_scheme() -> UpdateHandler<AppError> {
+    use dptree::case;
+
+    let command_handler = teloxide::filter_command::<FeedbackInboxCommand, _>()
+        .branch(case![FeedbackInboxCommand::Inbox(status)].endpoint(handle_inbox_command));
+
+    Update::filter_message()
+        .branch(command_handler)
+        .branch(case![BotState::FeedbackReply(id)].endpoint(receive_feedback_reply))
+}
+
+pub fn feedback_inbox_callbacks() -> UpdateHandler<AppError> {
+    use dptree::case;
+
+    dptree::entry()
+        .branch(case![CallbackData::FeedbackPage(status, page)].endpoint(receive_feedback_page))
+        .branch(case![CallbackData::Feedback(id)].endpoint(receive_feedback_show))
+        .branch(case![CallbackData::FeedbackReply(id)].endpoint(receive_feedback_reply_request))
+        .branch(case![CallbackData::FeedbackArchive(id)].endpoint(receive_feedback_archive))
+}
@@ -0,0 +1,335 @@
+use teloxide::dispatching::UpdateHandler;
+use teloxide::macros::BotCommands;
+use teloxide::prelude::*;
+use teloxide::types::ParseMode;
+
+use crate::app::error::AppError;
+use crate::app::usecases::{
+    CheckMediaReferences, CheckPermission, DeleteMedia, FindMedia, GetMedia, GetMediaContent,
+    GetMediaUsages, RefreshMediaFileID, RenameMedia,
+};
+use crate::bot::callbacks::CallbackData;
+use crate::bot::handlers::shared::{send_media_with_caption, send_permission_denied};
+use crate::bot::i18n::Locale;
+use crate::bot::keyboards::make_media_page_keyboard;
+use crate::bot::{BotHandlerResult, texts};
+use crate::domain::models::{MediaID, Permission, UserID};
+
+/// Use case-ы карточки медиафайла.
+#[derive(Clone)]
+pub struct MediaCard {
+    pub get_media: GetMedia,
+    pub get_media_usages: GetMediaUsages,
+    pub get_media_content: GetMediaContent,
+    pub refresh_media_file_id: RefreshMediaFileID,
+}
+
+#[derive(BotCommands, Clone)]
+#[command(description = "Библиотека медиафайлов")]
+pub enum MediaLibraryCommand {
+    #[command(rename = "medialist", description = "список медиафайлов")]
+    List(String),
+
+    #[command(rename = "mediausage", description = "где используется медиафайл")]
+    Usage(String),
+
+    #[command(rename = "mediarename", description = "переименовать медиафайл")]
+    Rename(String),
+
+    #[command(rename = "mediadelete", description = "удалить медиафайл")]
+    Delete(String),
+
+    #[command(
+        rename = "mediacheck",
+        description = "найти ссылки на несуществующие медиафайлы"
+    )]
+    Check,
+}
+
+async fn handle_list_command(
+    bot: Bot,
+    msg: Message,
+    command: MediaLibraryCommand,
+    check_permission: CheckPermission,
+    find_media: FindMedia,
+) -> BotHandlerResult {
+    let locale = Locale::of_message(&msg);
+    let user_id = UserID::new(msg.chat.id.0);
+    if !check_permission
+        .execute(user_id, Permission::ViewContent)
+        .await?
+    {
+        return send_permission_denied(&bot, &msg).await;
+    }
+    let MediaLibraryCommand::List(query) = command else {
+        return Ok(());
+    };
+    let query = query.trim();
+    let page = find_media.execute(user_id, query, 0).await?;
+    if page.items.is_empty() {
+        bot.send_message(msg.chat.id, texts::media_library_is_empty(locale))
+            .parse_mode(ParseMode::Html)
+            .await?;
+        return Ok(());
+    }
+    bot.send_message(msg.chat.id, texts::media_page(locale, &page, query))
+        .parse_mode(ParseMode::Html)
+        .reply_markup(make_media_page_keyboard(&page, query))
+        .await?;
+    Ok(())
+}
+
+async fn handle_usage_command(
+    bot: Bot,
+    msg: Message,
+    command: MediaLibraryCommand,
+    check_permission: CheckPermission,
+    get_media_usages: GetMediaUsages,
+) -> BotHandlerResult {
+    let locale = Locale::of_message(&msg);
+    let user_id = UserID::new(msg.chat.id.0);
+    if !check_permission
+        .execute(user_id, Permission::ViewContent)
+        .await?
+    {
+        return send_permission_denied(&bot, &msg).await;
+    }
+    let MediaLibraryCommand::Usage(key) = command else {
+        return Ok(());
+    };
+    let Ok(id) = MediaID::new(key.trim().to_string()) else {
+        return send_text(
+            &bot,
+            &msg,
+            &texts::invalid_media_usage_command_usage(locale),
+        )
+        .await;
+    };
+    match get_media_usages.execute(user_id, id.clone()).await {
+        Ok(usages) => send_text(&bot, &msg, &texts::media_usages(locale, &id, &usages)).await,
+        Err(AppError::MediaNotFound(_)) => {
+            send_text(&bot, &msg, &texts::media_not_found(locale, &id)).await
+        }
+        Err(err) => Err(err),
+    }
+}
+
+async fn handle_rename_command(
+    bot: Bot,
+    msg: Message,
+    command: MediaLibraryCommand,
+    check_permission: CheckPermission,
+    rename_media: RenameMedia,
+) -> BotHandlerResult {
+    let locale = Locale::of_message(&msg);
+    let user_id = UserID::new(msg.chat.id.0);
+    if !check_permission
+        .execute(user_id, Permission::EditContent)
+        .await?
+    {
+        return send_permission_denied(&bot, &msg).await;
+    }
+    let MediaLibraryCommand::Rename(args) = command else {
+        return Ok(());
+    };
+    let ids: Vec<&str> = args.split_whitespace().collect();
+    let [id, new_id] = ids[..] else {
+        return send_text(
+            &bot,
+            &msg,
+            &texts::invalid_media_rename_command_usage(locale),
+        )
+        .await;
+    };
+    let (Ok(id), Ok(new_id)) = (
+        MediaID::new(id.to_string()),
+        MediaID::new(new_id.to_string()),
+    ) else {
+        return send_text(
+            &bot,
+            &msg,
+            &texts::invalid_media_rename_command_usage(locale),
+        )
+        .await;
+    };
+    match rename_media
+        .execute(user_id, id.clone(), new_id.clone())
+        .await
+    {
+        Ok(()) => send_text(&bot, &msg, &texts::media_renamed(locale, &id, &new_id)).await,
+        Err(AppError::MediaNotFound(_)) => {
+            send_text(&bot, &msg, &texts::media_not_found(locale, &id)).await
+        }
+        Err(AppError::MediaAlreadyExists(_)) => {
+            send_text(&bot, &msg, &texts::media_already_exists(locale, &new_id)).await
+        }
+        Err(err) => Err(err),
+    }
+}
+
+async fn handle_delete_command(
+    bot: Bot,
+    msg: Message,
+    command: MediaLibraryCommand,
+    check_permission: CheckPermission,
+    delete_media: DeleteMedia,
+) -> BotHandlerResult {
+    let locale = Locale::of_message(&msg);
+    let user_id = UserID::new(msg.chat.id.0);
+    if !check_permission
+        .execute(user_id, Permission::EditContent)
+        .await?
+    {
+        return send_permission_denied(&bot, &msg).await;
+    }
+    let MediaLibraryCommand::Delete(key) = command else {
+        return Ok(());
+    };
+    let Ok(id) = MediaID::new(key.trim().to_string()) else {
+        return send_text(
+            &bot,
+            &msg,
+            &texts::invalid_media_delete_command_usage(locale),
+        )
+        .await;
+    };
+    match delete_media.execute(user_id, id.clone()).await {
+        Ok(()) => send_text(&bot, &msg, &texts::media_deleted(locale, &id)).await,
+        Err(AppError::MediaNotFound(_)) => {
+            send_text(&bot, &msg, &texts::media_not_found(locale, &id)).await
+        }
+        Err(AppError::MediaInUse(_, usages)) => {
+            send_text(&bot, &msg, &texts::media_in_use(locale, &id, &usages)).await
+        }
+        Err(err) => Err(err),
+    }
+}
+
+async fn handle_check_command(
+    bot: Bot,
+    msg: Message,
+    check_permission: CheckPermission,
+    check_media_references: CheckMediaReferences,
+) -> BotHandlerResult {
+    let locale = Locale::of_message(&msg);
+    let user_id = UserID::new(msg.chat.id.0);
+    if !check_permission
+        .execute(user_id, Permission::ViewContent)
+        .await?
+    {
+        return send_permission_denied(&bot, &msg).await;
+    }
+    let references = check_media_references.execute(user_id).await?;
+    if references.is_empty() {
+        send_text(&bot, &msg, &texts::media_references_are_valid(locale)).await
+    } else {
+        send_text(
+            &bot,
+            &msg,
+            &texts::dangling_media_references(locale, &references),
+        )
+        .await
+    }
+}
+
+async fn send_text(bot: &Bot, msg: &Message, text: &str) -> BotHandlerResult {
+    bot.send_message(msg.chat.id, text)
+        .parse_mode(ParseMode::Html)
+        .await?;
+    Ok(())
+}
+
+/// Листает список медиафайлов, редактируя исходное сообщение.
+async fn receive_media_page(
+    bot: Bot,
+    q: CallbackQuery,
+    (page, query): (usize, String),
+    check_permission: CheckPermission,
+    find_media: FindMedia,
+) -> BotHandlerResult {
+    let locale = Locale::of_user(&q.from);
+    bot.answer_callback_query(q.id.clone()).await?;
+    let Some(msg) = q.regular_message() else {
+        return Ok(());
+    };
+    let user_id = UserID::new(q.from.id.0 as i64);
+    if !check_permission
+        .execute(user_id, Permission::ViewContent)
+        .await?
+    {
+        return Ok(());
+    }
+    let page = find_media.execute(user_id, &query, page).await?;
+    bot.edit_message_text(
+        msg.chat.id,
+        msg.id,
+        texts::media_page(locale, &page, &query),
+    )
+    .parse_mode(ParseMode::Html)
+    .reply_markup(make_media_page_keyboard(&page, &query))
+    .await?;
+    Ok(())
+}
+
+async fn receive_media_show(
+    bot: Bot,
+    q: CallbackQuery,
+    id: MediaID,
+    check_permission: CheckPermission,
+    media_card: MediaCard,
+) -> BotHandlerResult {
+    let locale = Locale::of_user(&q.from);
+    bot.answer_callback_query(q.id.clone()).await?;
+    let Some(msg) = q.regular_message() else {
+        return Ok(());
+    };
+    let user_id = UserID::new(q.from.id.0 as i64);
+    if !check_permission
+        .execute(user_id, Permission::ViewContent)
+        .await?
+    {
+        return Ok(());
+    }
+    match media_card.get_media.execute(user_id, id.clone()).await {
+        Ok(media) => {
+            let usages = media_card
+                .get_media_usages
+                .execute(user_id, id.clone())
+                .await?;
+            send_media_with_caption(
+                &bot,
+                msg,
+                media,
+                &texts::media_usages(locale, &id, &usages),
+                &media_card.get_media_content,
+                &media_card.refresh_media_file_id,
+            )
+            .await
+        }
+        Err(AppError::MediaNotFound(_)) => {
+            send_text(&bot, msg, &texts::media_not_found(locale, &id)).await
+        }
+        Err(err) => Err(err),
+    }
+}
+
+pub fn media_library_scheme() -> UpdateHandler<AppError> {
+    use dptree::case;
+
+    let command_handler = teloxide::filter_command::<MediaLibraryCommand, _>()
+        .branch(case![MediaLibraryCommand::List(query)].endpoint(handle_list_command))
+        .branch(case![MediaLibraryCommand::Usage(key)].endpoint(handle_usage_command))
+        .branch(case![MediaLibraryCommand::Rename(args)].endpoint(handle_rename_command))
+        .branch(case![MediaLibraryCommand::Delete(key)].endpoint(handle_delete_command))
+        .branch(case![MediaLibraryCommand::Check].endpoint(handle_check_command));
+
+    Update::filter_message().branch(command_handler)
+}
+
+pub fn media_library_callbacks() -> UpdateHandler<AppError> {
+    use dptree::case;
+
+    dptree::entry()
+        .branch(case![CallbackData::MediaPage(page, query)].endpoint(receive_media_page))
+        .branch(case![CallbackData::Media(id)].endpoint(receive_media_show))
+}
@@ -1,173 +1,145 @@
+use std::sync::Arc;
+
 use crate::GetPlayer;
 use teloxide::dispatching::UpdateHandler;
 use teloxide::prelude::*;
-use teloxide::types::{InputFile, Message, ParseMode};
+use teloxide::types::{KeyboardRemove, Message, ParseMode};
 
 use crate::app::error::AppError;
-use crate::app::usecases::dto::{CharacterDTO, PlayerDTO, TeamWithMembersDTO};
+use crate::app::usecases::dto::{PlayerDTO, TeamWithMembersDTO};
 use crate::app::usecases::{
-    GetAvailableTracks, GetCharacter, GetCharacterNames, GetTeamWithMembers, GetUserTeam,
-    GiveFeedback,
+    GetAvailableTracks, GetCharacterPage, GetTeamWithMembers, GetUserTeam, GiveFeedback,
 };
+use crate::bot::callbacks::CallbackData;
 use crate::bot::fsm::{BotDialogue, BotState};
-use crate::bot::handlers::shared::{send_enter_message, send_use_keyboard};
+use crate::bot::handlers::characters::prompt_characters;
+use crate::bot::handlers::shared::{Reply, send_enter_message};
 use crate::bot::handlers::slots::{prompt_accept_final, prompt_cancel_reservation_reason};
 use crate::bot::handlers::tracks::prompt_track;
-use crate::bot::keyboards::{
-    make_back_keyboard, make_characters_keyboard_with_back, make_menu_keyboard,
-};
-use crate::bot::{BotHandlerResult, keyboards, texts};
-use crate::domain::models::{CharacterName, FeedbackText, UserID};
+use crate::bot::i18n::Locale;
+use crate::bot::keyboards::{make_back_keyboard, make_menu_keyboard};
+use crate::bot::staff_chat::StaffChat;
+use crate::bot::{BotHandlerResult, texts};
+use crate::config::Config;
+use crate::domain::models::{FeedbackText, MenuItem, UserID};
+
+/// Use case-ы разделов, на которые ведут пункты меню.
+#[derive(Clone)]
+pub struct MenuSections {
+    pub get_user_team: GetUserTeam,
+    pub get_team_with_members: GetTeamWithMembers,
+    pub get_character_page: GetCharacterPage,
+    pub get_available_tracks: GetAvailableTracks,
+}
 
 pub async fn prompt_menu(
     bot: Bot,
-    msg: Message,
+    reply: Reply,
     dialogue: BotDialogue,
     player: &PlayerDTO,
 ) -> BotHandlerResult {
-    let markup = make_menu_keyboard(player);
-    bot.send_message(msg.chat.id, texts::MENU_TEXT)
-        .reply_markup(markup)
-        .parse_mode(ParseMode::Html)
+    reply
+        .show(
+            &bot,
+            texts::menu_text(reply.locale),
+            make_menu_keyboard(reply.locale, player),
+        )
         .await?;
     dialogue.update(BotState::MenuOption).await?;
     Ok(())
 }
 
-async fn receive_menu_option(
+async fn receive_menu(
     bot: Bot,
-    msg: Message,
+    reply: Reply,
     dialogue: BotDialogue,
     get_player: GetPlayer,
-    get_user_team: GetUserTeam,
-    get_team_with_members: GetTeamWithMembers,
-    get_character_names: GetCharacterNames,
-    get_available_tracks: GetAvailableTracks,
 ) -> BotHandlerResult {
-    let user_id = UserID::new(msg.chat.id.0);
-    match msg.text() {
-        None => send_enter_message(&bot, &msg).await?,
-        Some(text) => match text {
-            /*keyboards::BTN_MY_TEAM => {
-                if let Some(team) = get_user_team.execute(user_id).await? {
-                    let team = get_team_with_members.execute(team.id).await?;
-                    if !team.solo {
-                        send_my_team(&bot, &msg, team).await?;
-                    }
-                    let player = get_player.execute(user_id).await?;
-                    prompt_menu(bot, msg, dialogue, &player).await?
-                }
-            }
-            keyboards::BTN_TRACKS => {
-                let tracks = get_available_tracks.execute(user_id).await?;
-                prompt_track(bot, msg, dialogue, &tracks).await?
-            }
-            keyboards::BTN_CHARACTERS => {
-                let names = get_character_names.execute().await?;
-                prompt_character_name(bot, msg, dialogue, &names).await?
-            }
-            keyboards::BTN_RESERVE_SLOT => {
-                let player = get_player.execute(user_id).await?;
-                if player.is_captain {
-                    prompt_accept_final(bot, msg, dialogue).await?
-                } else {
-                    send_unknown_menu_option(&bot, &msg).await?;
-                    let player = get_player.execute(user_id).await?;
-                    prompt_menu(bot, msg, dialogue, &player).await?
-                }
-            }
-            keyboards::BTN_CANCEL_RESERVATION => {
-                prompt_cancel_reservation_reason(bot, msg, dialogue).await?
-            }*/
-            keyboards::BTN_GIVE_FEEDBACK => prompt_feedback(bot, msg, dialogue).await?,
-            _ => {
-                send_unknown_menu_option(&bot, &msg).await?;
-                let player = get_player.execute(user_id).await?;
-                prompt_menu(bot, msg, dialogue, &player).await?
-            }
-        },
-    }
-    Ok(())
-}
-
-async fn send_unknown_menu_option(bot: &Bot, msg: &Message) -> BotHandlerResult {
-    bot.send_message(msg.chat.id, texts::UNKNOWN_MENU_OPTION)
-        .parse_mode(ParseMode::Html)
-        .await?;
-    Ok(())
+    let player = get_player.execute(reply.user_id()).await?;
+    prompt_menu(bot, reply, dialogue, &player).await
 }
 
-async fn send_my_team(bot: &Bot, msg: &Message, team: TeamWithMembersDTO) -> BotHandlerResult {
-    bot.send_message(msg.chat.id, texts::my_team(team))
-        .parse_mode(ParseMode::Html)
-        .await?;
-    Ok(())
-}
-
-async fn prompt_character_name(
+async fn receive_menu_item(
     bot: Bot,
-    msg: Message,
+    reply: Reply,
     dialogue: BotDialogue,
-    names: &[CharacterName],
+    item: MenuItem,
+    get_player: GetPlayer,
+    sections: MenuSections,
+    config: Arc<Config>,
 ) -> BotHandlerResult {
-    bot.send_message(msg.chat.id, texts::PROMPT_CHARACTER_NAME)
-        .reply_markup(make_characters_keyboard_with_back(names))
-        .parse_mode(ParseMode::Html)
-        .await?;
-    dialogue.update(BotState::CharacterName).await?;
-    Ok(())
+    let user_id = reply.user_id();
+    let player = get_player.execute(user_id).await?;
+    match item {
+        MenuItem::Team => {
+            if let Some(team) = sections.get_user_team.execute(user_id).await? {
+                let team = sections
+                    .get_team_with_members
+                    .execute(user_id, team.id)
+                    .await?;
+                if !team.solo {
+                    return show_my_team(&bot, reply, team).await;
+                }
+            }
+            prompt_menu(bot, reply, dialogue, &player).await
+        }
+        MenuItem::Tracks => {
+            let tracks = sections.get_available_tracks.execute(user_id).await?;
+            prompt_track(bot, reply, dialogue, &tracks).await
+        }
+        MenuItem::Characters => {
+            let page = sections
+                .get_character_page
+                .execute(user_id, false, 0)
+                .await?;
+            prompt_characters(bot, reply, dialogue, &page).await
+        }
+        // Кнопка могла остаться в меню, отправленном до смены капитана.
+        MenuItem::FinalReservation if !player.is_captain => {
+            prompt_menu(bot, reply, dialogue, &player).await
+        }
+        MenuItem::FinalReservation if player.reserved_slot => {
+            prompt_cancel_reservation_reason(bot, reply, dialogue, &config.event).await
+        }
+        MenuItem::FinalReservation => prompt_accept_final(bot, reply, dialogue).await,
+        MenuItem::Feedback => prompt_feedback(bot, reply, dialogue).await,
+    }
 }
 
-async fn receive_character_name(
+/// Текст в состоянии, где ждут нажатия кнопки, например со старой reply-клавиатуры.
+/// Её убираем и показываем меню заново.
+async fn receive_unexpected_text(
     bot: Bot,
     msg: Message,
     dialogue: BotDialogue,
     get_player: GetPlayer,
-    get_character: GetCharacter,
-    get_character_names: GetCharacterNames,
 ) -> BotHandlerResult {
-    let user_id = UserID::new(msg.chat.id.0);
-    match msg.text() {
-        None => send_enter_message(&bot, &msg).await,
-        Some(keyboards::BTN_BACK) => {
-            let player = get_player.execute(user_id).await?;
-            prompt_menu(bot, msg, dialogue, &player).await
-        }
-        Some(text) => {
-            let name = CharacterName::new(text.to_string())?;
-            match get_character.execute(&name).await {
-                Err(AppError::CharacterNotFound(_)) => send_use_keyboard(&bot, &msg).await,
-                Err(err) => Err(err),
-                Ok(character) => {
-                    let names = get_character_names.execute().await?;
-                    send_character(&bot, &msg, character, &names).await
-                }
-            }
-        }
-    }
+    let locale = Locale::of_message(&msg);
+    bot.send_message(msg.chat.id, texts::unknown_menu_option(locale))
+        .reply_markup(KeyboardRemove::new())
+        .parse_mode(ParseMode::Html)
+        .await?;
+    let player = get_player.execute(UserID::new(msg.chat.id.0)).await?;
+    prompt_menu(bot, Reply::to_message(&msg), dialogue, &player).await
 }
 
-async fn send_character(
-    bot: &Bot,
-    msg: &Message,
-    character: CharacterDTO,
-    names: &[CharacterName],
-) -> BotHandlerResult {
-    bot.send_photo(
-        msg.chat.id,
-        InputFile::file_id(character.image_id.clone().into()),
-    )
-    .caption(texts::character(character))
-    .reply_markup(make_characters_keyboard_with_back(names))
-    .parse_mode(ParseMode::Html)
-    .await?;
-    Ok(())
+async fn show_my_team(bot: &Bot, reply: Reply, team: TeamWithMembersDTO) -> BotHandlerResult {
+    reply
+        .show(
+            bot,
+            texts::my_team(reply.locale, team),
+            make_back_keyboard(reply.locale, CallbackData::Menu),
+        )
+        .await
 }
 
-async fn prompt_feedback(bot: Bot, msg: Message, dialogue: BotDialogue) -> BotHandlerResult {
-    bot.send_message(msg.chat.id, texts::PROMPT_FEEDBACK)
-        .reply_markup(make_back_keyboard())
-        .parse_mode(ParseMode::Html)
+async fn prompt_feedback(bot: Bot, reply: Reply, dialogue: BotDialogue) -> BotHandlerResult {
+    reply
+        .show(
+            &bot,
+            texts::prompt_feedback(reply.locale),
+            make_back_keyboard(reply.locale, CallbackData::Menu),
+        )
         .await?;
     dialogue.update(BotState::Feedback).await?;
     Ok(())
@@ -179,26 +151,23 @@ async fn receive_feedback(
     dialogue: BotDialogue,
     give_feedback: GiveFeedback,
     get_player: GetPlayer,
+    staff_chat: StaffChat,
 ) -> BotHandlerResult {
     let user_id = UserID::new(msg.chat.id.0);
-    match msg.text() {
-        None => send_enter_message(&bot, &msg).await,
-        Some(keyboards::BTN_BACK) => {
-            let player = get_player.execute(user_id).await?;
-            prompt_menu(bot, msg, dialogue, &player).await
-        }
-        Some(text) => {
-            let text = FeedbackText::new(text.to_string())?;
-            give_feedback.execute(user_id, text).await?;
-            send_feedback_sent(&bot, &msg).await?;
-            let player = get_player.execute(user_id).await?;
-            prompt_menu(bot, msg, dialogue, &player).await
-        }
-    }
+    let Some(text) = msg.text() else {
+        return send_enter_message(&bot, &msg).await;
+    };
+    let text = FeedbackText::new(text.to_string())?;
+    give_feedback.execute(user_id, text.clone()).await?;
+    send_feedback_sent(&bot, &msg).await?;
+    staff_chat.notify_feedback(&bot, user_id, &text).await;
+    let player = get_player.execute(user_id).await?;
+    prompt_menu(bot, Reply::to_message(&msg), dialogue, &player).await
 }
 
 async fn send_feedback_sent(bot: &Bot, msg: &Message) -> BotHandlerResult {
-    bot.send_message(msg.chat.id, texts::FEEDBACK_SENT)
+    let locale = Locale::of_message(msg);
+    bot.send_message(msg.chat.id, texts::feedback_sent(locale))
         .parse_mode(ParseMode::Html)
         .await?;
     Ok(())
@@ -208,7 +177,17 @@ pub fn menu_scheme() -> UpdateHandler<AppError> {
     use dptree::case;
 
     Update::filter_message()
-        .branch(case![BotState::MenuOption].endpoint(receive_menu_option))
-        .branch(case![BotState::CharacterName].endpoint(receive_character_name))
         .branch(case![BotState::Feedback].endpoint(receive_feedback))
+        .branch(
+            dptree::filter(|state: BotState| state.is_navigation())
+                .endpoint(receive_unexpected_text),
+        )
+}
+
+pub fn menu_callbacks() -> UpdateHandler<AppError> {
+    use dptree::case;
+
+    dptree::entry()
+        .branch(case![CallbackData::Menu].endpoint(receive_menu))
+        .branch(case![CallbackData::MenuItem(item)].endpoint(receive_menu_item))
 }
@@ -1,6 +1,14 @@
+pub mod audit_log;
+pub mod characters;
 pub mod commands;
+pub mod dialogue_state;
+pub mod event_phase;
+pub mod feedback_inbox;
+pub mod media_library;
 pub mod menu;
+pub mod photo_review;
+pub mod roles;
 pub mod slots;
 pub mod tracks;
 
-mod shared;
+pub mod shared;
@@ -0,0 +1,85 @@
+use teloxide::dispatching::UpdateHandler;
+use teloxide::prelude::*;
+use teloxide::types::ParseMode;
+
+use crate::app::error::AppError;
+use crate::app::usecases::{CheckPermission, GetProfile, ReviewPhotoAnswer};
+use crate::bot::callbacks::CallbackData;
+use crate::bot::i18n::Locale;
+use crate::bot::{BotHandlerResult, texts};
+use crate::domain::models::{Permission, TaskID, UserID};
+
+/// Проверка фото-ответа организатором из чата организаторов.
+async fn receive_photo_review(
+    bot: Bot,
+    q: CallbackQuery,
+    (user_id, task_id, approved): (UserID, TaskID, bool),
+    check_permission: CheckPermission,
+    review_photo_answer: ReviewPhotoAnswer,
+    get_profile: GetProfile,
+) -> BotHandlerResult {
+    let locale = Locale::of_user(&q.from);
+    let reviewer_id = UserID::new(q.from.id.0 as i64);
+    if !check_permission
+        .execute(reviewer_id, Permission::ReviewPhotos)
+        .await?
+    {
+        bot.answer_callback_query(q.id.clone())
+            .text(texts::permission_denied(locale))
+            .await?;
+        return Ok(());
+    }
+    let review = match review_photo_answer
+        .execute(reviewer_id, user_id, task_id, approved)
+        .await
+    {
+        Ok(review) => review,
+        Err(AppError::AnswerNotPending(_)) => {
+            bot.answer_callback_query(q.id.clone())
+                .text(texts::photo_answer_already_reviewed(locale))
+                .await?;
+            if let Some(msg) = q.regular_message() {
+                bot.edit_message_reply_markup(msg.chat.id, msg.id).await?;
+            }
+            return Ok(());
+        }
+        Err(err) => return Err(err),
+    };
+    bot.answer_callback_query(q.id.clone()).await?;
+
+    // Язык игрока неизвестен, пока он сам не написал боту.
+    let text = if approved {
+        texts::photo_answer_approved(Locale::DEFAULT, review.number, review.points)
+    } else {
+        texts::photo_answer_rejected(Locale::DEFAULT, review.number)
+    };
+    bot.send_message(ChatId(user_id.as_i64()), text)
+        .parse_mode(ParseMode::Html)
+        .await?;
+
+    if let Some(msg) = q.regular_message() {
+        // Подпись собирается заново: в msg.caption() уже нет разметки и экранирования.
+        let profile = get_profile.execute(user_id).await?;
+        let caption = format!(
+            "{}\n\n{}",
+            texts::staff_photo_answer(Locale::DEFAULT, &profile, &review.track, review.number),
+            texts::staff_photo_answer_reviewed(
+                Locale::DEFAULT,
+                approved,
+                review.points,
+                &q.from.full_name()
+            )
+        );
+        bot.edit_message_caption(msg.chat.id, msg.id)
+            .caption(caption)
+            .parse_mode(ParseMode::Html)
+            .await?;
+    }
+    Ok(())
+}
+
+pub fn photo_review_callbacks() -> UpdateHandler<AppError> {
+    use dptree::case;
+
+    case![CallbackData::PhotoReview(user_id, task_id, approved)].endpoint(receive_photo_review)
+}
@@ -0,0 +1,162 @@
+use teloxide::dispatching::UpdateHandler;
+use teloxide::macros::BotCommands;
+use teloxide::prelude::*;
+use teloxide::types::ParseMode;
+
+use crate::app::error::AppError;
+use crate::app::usecases::{CheckPermission, GetStaff, GrantRole, RevokeRole};
+use crate::bot::command_list::{remove_staff_commands, set_staff_commands};
+use crate::bot::handlers::shared::send_permission_denied;
+use crate::bot::i18n::Locale;
+use crate::bot::{BotHandlerResult, texts};
+use crate::domain::models::{Permission, Role, UserID};
+
+#[derive(BotCommands, Clone)]
+#[command(description = "Роли организаторов")]
+pub enum RolesCommand {
+    #[command(rename = "grant", description = "выдать роль")]
+    Grant(String),
+
+    #[command(rename = "revoke", description = "отозвать роль")]
+    Revoke(String),
+
+    #[command(rename = "roles", description = "список организаторов и их ролей")]
+    Roles,
+}
+
+/// Аргументы `{user_id} {role}`.
+fn parse_role_args(args: &str) -> Option<(UserID, Role)> {
+    let args: Vec<&str> = args.split_whitespace().collect();
+    let [user_id, role] = args[..] else {
+        return None;
+    };
+    Some((UserID::new(user_id.parse().ok()?), role.parse().ok()?))
+}
+
+async fn handle_grant_command(
+    bot: Bot,
+    msg: Message,
+    command: RolesCommand,
+    check_permission: CheckPermission,
+    grant_role: GrantRole,
+) -> BotHandlerResult {
+    let locale = Locale::of_message(&msg);
+    let actor_id = UserID::new(msg.chat.id.0);
+    if !check_permission
+        .execute(actor_id, Permission::ManageRoles)
+        .await?
+    {
+        return send_permission_denied(&bot, &msg).await;
+    }
+    let RolesCommand::Grant(args) = command else {
+        return Ok(());
+    };
+    let Some((user_id, role)) = parse_role_args(&args) else {
+        return send_text(
+            &bot,
+            &msg,
+            &texts::invalid_role_command_usage(locale, "grant"),
+        )
+        .await;
+    };
+    match grant_role.execute(actor_id, user_id, role).await {
+        Ok(true) => {
+            if let Err(err) = set_staff_commands(&bot, user_id).await {
+                log::warn!("Unable to set staff commands: {err}");
+            }
+            send_text(&bot, &msg, &texts::role_granted(locale, user_id, role)).await
+        }
+        Ok(false) => {
+            send_text(
+                &bot,
+                &msg,
+                &texts::role_already_granted(locale, user_id, role),
+            )
+            .await
+        }
+        Err(AppError::UserNotFound(_)) => {
+            send_text(&bot, &msg, &texts::role_user_not_found(locale, user_id)).await
+        }
+        Err(err) => Err(err),
+    }
+}
+
+async fn handle_revoke_command(
+    bot: Bot,
+    msg: Message,
+    command: RolesCommand,
+    check_permission: CheckPermission,
+    revoke_role: RevokeRole,
+    get_staff: GetStaff,
+) -> BotHandlerResult {
+    let locale = Locale::of_message(&msg);
+    let actor_id = UserID::new(msg.chat.id.0);
+    if !check_permission
+        .execute(actor_id, Permission::ManageRoles)
+        .await?
+    {
+        return send_permission_denied(&bot, &msg).await;
+    }
+    let RolesCommand::Revoke(args) = command else {
+        return Ok(());
+    };
+    let Some((user_id, role)) = parse_role_args(&args) else {
+        return send_text(
+            &bot,
+            &msg,
+            &texts::invalid_role_command_usage(locale, "revoke"),
+        )
+        .await;
+    };
+    match revoke_role.execute(actor_id, user_id, role).await {
+        Ok(true) => {
+            if !get_staff.members().await?.contains(&user_id)
+                && let Err(err) = remove_staff_commands(&bot, user_id).await
+            {
+                log::warn!("Unable to remove staff commands: {err}");
+            }
+            send_text(&bot, &msg, &texts::role_revoked(locale, user_id, role)).await
+        }
+        Ok(false) => send_text(&bot, &msg, &texts::role_not_granted(locale, user_id, role)).await,
+        Err(AppError::CanNotRevokeOwnRole(..)) => {
+            send_text(&bot, &msg, &texts::can_not_revoke_own_role(locale)).await
+        }
+        Err(err) => Err(err),
+    }
+}
+
+async fn handle_roles_command(
+    bot: Bot,
+    msg: Message,
+    check_permission: CheckPermission,
+    get_staff: GetStaff,
+) -> BotHandlerResult {
+    let locale = Locale::of_message(&msg);
+    let actor_id = UserID::new(msg.chat.id.0);
+    if !check_permission
+        .execute(actor_id, Permission::ManageRoles)
+        .await?
+    {
+        return send_permission_denied(&bot, &msg).await;
+    }
+    let staff = get_staff.execute(actor_id).await?;
+    send_text(&bot, &msg, &texts::staff_roles(locale, &staff)).await
+}
+
+async fn send_text(bot: &Bot, msg: &Message, text: &str) -> BotHandlerResult {
+    bot.send_message(msg.chat.id, text)
+        .parse_mode(ParseMode::Html)
+        .await?;
+    Ok(())
+}
+
+pub fn roles_scheme() -> UpdateHandler<AppError> {
+    use dptree::case;
+
+    let command_handler = teloxide::filter_command::<RolesCommand, _>()
+        .branch(case![RolesCommand::Grant(args)].endpoint(handle_grant_command))
+        .branch(case![RolesCommand::Revoke(args)].endpoint(handle_revoke_command))
+        .branch(case![RolesCommand::Roles].endpoint(handle_roles_command));
+
+    Update::filter_message().branch(command_handler)
+}
@@ -1,33 +1,148 @@
+use teloxide::dispatching::UpdateHandler;
+use teloxide::net::Download;
 use teloxide::prelude::*;
-use teloxide::types::{FileId as TelegramFileId, InputFile, KeyboardRemove, ParseMode};
+use teloxide::types::{
+    FileId as TelegramFileId, InlineKeyboardMarkup, InputFile, InputMedia, InputMediaAudio,
+    InputMediaDocument, InputMediaPhoto, InputMediaVideo, KeyboardRemove, MessageId, ParseMode,
+};
 
+use teloxide::{ApiError, RequestError};
+
+use crate::app::error::AppError;
+use crate::app::usecases::dto::MediaDTO;
+use crate::app::usecases::{GetMediaContent, RefreshMediaFileID};
+use crate::bot::i18n::Locale;
 use crate::bot::{BotHandlerResult, texts};
-use crate::domain::models::{FileID, Media, MediaType};
+use crate::domain::models::{FileID, Media, MediaContent, MediaID, MediaType, UserID};
+
+/// Куда показать следующий экран: на место сообщения с нажатой inline-кнопкой
+/// или новым сообщением.
+#[derive(Clone, Copy)]
+pub struct Reply {
+    pub chat_id: ChatId,
+    pub locale: Locale,
+    editable: Option<MessageId>,
+}
+
+impl Reply {
+    pub fn to_message(msg: &Message) -> Self {
+        Self {
+            chat_id: msg.chat.id,
+            locale: Locale::of_message(msg),
+            editable: None,
+        }
+    }
+
+    /// Сообщение, которое бот отправляет пользователю сам, не в ответ на его действие.
+    pub fn to_chat(chat_id: ChatId, locale: Locale) -> Self {
+        Self {
+            chat_id,
+            locale,
+            editable: None,
+        }
+    }
+
+    /// Заменить можно только текст, экраны на месте фото отправляются заново.
+    pub fn to_callback(q: &CallbackQuery) -> Option<Self> {
+        let msg = q.regular_message()?;
+        Some(Self {
+            chat_id: msg.chat.id,
+            locale: Locale::of_user(&q.from),
+            editable: msg.text().is_some().then_some(msg.id),
+        })
+    }
+
+    pub fn user_id(&self) -> UserID {
+        UserID::new(self.chat_id.0)
+    }
+
+    /// Следующий экран нужен под уже отправленными сообщениями, например под
+    /// медиафайлами задания.
+    pub fn detached(self) -> Self {
+        Self {
+            editable: None,
+            ..self
+        }
+    }
+
+    pub async fn show(
+        &self,
+        bot: &Bot,
+        text: String,
+        markup: InlineKeyboardMarkup,
+    ) -> BotHandlerResult {
+        if let Some(message_id) = self.editable {
+            let edited = bot
+                .edit_message_text(self.chat_id, message_id, text.clone())
+                .reply_markup(markup.clone())
+                .parse_mode(ParseMode::Html)
+                .await;
+            match edited {
+                Ok(_) | Err(RequestError::Api(ApiError::MessageNotModified)) => return Ok(()),
+                Err(RequestError::Api(
+                    ApiError::MessageCantBeEdited | ApiError::MessageToEditNotFound,
+                )) => {}
+                Err(err) => return Err(err.into()),
+            }
+        }
+        bot.send_message(self.chat_id, text)
+            .reply_markup(markup)
+            .parse_mode(ParseMode::Html)
+            .await?;
+        Ok(())
+    }
+
+    pub async fn send(&self, bot: &Bot, text: String) -> BotHandlerResult {
+        bot.send_message(self.chat_id, text)
+            .parse_mode(ParseMode::Html)
+            .await?;
+        Ok(())
+    }
+}
+
+/// Отвечает на нажатие inline-кнопки и передаёт дальше `Reply` в её сообщение.
+pub fn answer_callback() -> UpdateHandler<AppError> {
+    dptree::filter_map_async(|bot: Bot, q: CallbackQuery| async move {
+        let reply = Reply::to_callback(&q)?;
+        if let Err(err) = bot.answer_callback_query(q.id).await {
+            log::warn!("Unable to answer callback query: {err}");
+        }
+        Some(reply)
+    })
+}
 
 pub async fn send_enter_message(bot: &Bot, msg: &Message) -> BotHandlerResult {
-    bot.send_message(msg.chat.id, texts::ENTER_MESSAGE_TEXT)
+    let locale = Locale::of_message(msg);
+    bot.send_message(msg.chat.id, texts::enter_message_text(locale))
         .parse_mode(ParseMode::Html)
         .await?;
     Ok(())
 }
 
-pub async fn send_internal_error(bot: &Bot, msg: &Message) -> BotHandlerResult {
-    bot.send_message(msg.chat.id, texts::INTERNAL_ERROR)
+/// Клавиатуру убираем: после ошибки диалог начинается заново.
+pub async fn send_error_message(bot: &Bot, chat_id: ChatId, text: String) -> BotHandlerResult {
+    bot.send_message(chat_id, text)
         .reply_markup(KeyboardRemove::new())
         .parse_mode(ParseMode::Html)
         .await?;
     Ok(())
 }
 
+pub async fn send_internal_error(bot: &Bot, chat_id: ChatId, locale: Locale) -> BotHandlerResult {
+    send_error_message(bot, chat_id, texts::internal_error(locale)).await
+}
+
 pub async fn send_use_keyboard(bot: &Bot, msg: &Message) -> BotHandlerResult {
-    bot.send_message(msg.chat.id, texts::USE_KEYBOARD)
+    let locale = Locale::of_message(msg);
+    bot.send_message(msg.chat.id, texts::use_keyboard(locale))
         .parse_mode(ParseMode::Html)
         .await?;
     Ok(())
 }
 
 pub async fn send_permission_denied(bot: &Bot, msg: &Message) -> BotHandlerResult {
-    bot.send_message(msg.chat.id, texts::PERMISSION_DENIED)
+    let locale = Locale::of_message(msg);
+    bot.send_message(msg.chat.id, texts::permission_denied(locale))
         .parse_mode(ParseMode::Html)
         .await?;
     Ok(())
@@ -38,24 +153,276 @@ pub async fn send_media_with_caption(
     msg: &Message,
     media: Media,
     caption: &str,
+    get_media_content: &GetMediaContent,
+    refresh_media_file_id: &RefreshMediaFileID,
+) -> BotHandlerResult {
+    if let MediaType::VideoNote = media.media_type() {
+        // У видеосообщений нет подписи
+        send_media(
+            bot,
+            msg.chat.id,
+            media,
+            None,
+            get_media_content,
+            refresh_media_file_id,
+        )
+        .await?;
+        bot.send_message(msg.chat.id, caption)
+            .parse_mode(ParseMode::Html)
+            .await?;
+        Ok(())
+    } else {
+        send_media(
+            bot,
+            msg.chat.id,
+            media,
+            Some(caption),
+            get_media_content,
+            refresh_media_file_id,
+        )
+        .await
+    }
+}
+
+/// Telegram не принимает в один альбом больше десяти файлов.
+const MEDIA_GROUP_MAX_SIZE: usize = 10;
+
+/// Отправляет медиафайлы по порядку, объединяя соседние совместимые файлы в альбомы.
+pub async fn send_media_list(
+    bot: &Bot,
+    chat_id: ChatId,
+    media: &[MediaDTO],
+    get_media_content: &GetMediaContent,
+    refresh_media_file_id: &RefreshMediaFileID,
 ) -> BotHandlerResult {
-    match media.media_type() {
+    let mut groups: Vec<Vec<&MediaDTO>> = Vec::new();
+    for m in media {
+        match groups.last_mut() {
+            Some(group)
+                if group.len() < MEDIA_GROUP_MAX_SIZE
+                    && group[0].media_type.groups_with(&m.media_type) =>
+            {
+                group.push(m)
+            }
+            _ => groups.push(vec![m]),
+        }
+    }
+
+    for group in groups {
+        if let [single] = group.as_slice() {
+            let media = Media::new(single.id.clone(), single.file_id.clone(), single.media_type);
+            send_media(
+                bot,
+                chat_id,
+                media,
+                None,
+                get_media_content,
+                refresh_media_file_id,
+            )
+            .await?;
+        } else {
+            send_album(
+                bot,
+                chat_id,
+                &group,
+                get_media_content,
+                refresh_media_file_id,
+            )
+            .await?;
+        }
+    }
+    Ok(())
+}
+
+/// Отправляет медиафайл по `file_id`. Если `file_id` выдан другим ботом,
+/// загружает файл заново из сохранённых байтов и запоминает новый `file_id`.
+async fn send_media(
+    bot: &Bot,
+    chat_id: ChatId,
+    media: Media,
+    caption: Option<&str>,
+    get_media_content: &GetMediaContent,
+    refresh_media_file_id: &RefreshMediaFileID,
+) -> BotHandlerResult {
+    let media_id = media.id().clone();
+    let media_type = media.media_type();
+    match send_media_file(bot, chat_id, media_type, media.into(), caption).await {
+        Err(err) if is_invalid_file_error(&err) => {
+            let Some(content) = get_media_content.execute(media_id.clone()).await? else {
+                return Err(err.into());
+            };
+            log::info!("Re-uploading media {}: {err}", media_id.as_str());
+            let sent = send_media_file(bot, chat_id, media_type, content.into(), caption).await?;
+            refresh_file_id(&sent, media_id, refresh_media_file_id).await
+        }
+        result => {
+            result?;
+            Ok(())
+        }
+    }
+}
+
+async fn send_media_file(
+    bot: &Bot,
+    chat_id: ChatId,
+    media_type: MediaType,
+    file: InputFile,
+    caption: Option<&str>,
+) -> Result<Message, RequestError> {
+    let caption = caption.unwrap_or_default();
+    match media_type {
         MediaType::Image => {
-            bot.send_photo(msg.chat.id, media.into())
+            bot.send_photo(chat_id, file)
+                .caption(caption)
+                .parse_mode(ParseMode::Html)
+                .await
+        }
+        MediaType::VideoNote => bot.send_video_note(chat_id, file).await,
+        MediaType::Audio => {
+            bot.send_audio(chat_id, file)
+                .caption(caption)
+                .parse_mode(ParseMode::Html)
+                .await
+        }
+        MediaType::Voice => {
+            bot.send_voice(chat_id, file)
+                .caption(caption)
+                .parse_mode(ParseMode::Html)
+                .await
+        }
+        MediaType::Video => {
+            bot.send_video(chat_id, file)
                 .caption(caption)
                 .parse_mode(ParseMode::Html)
-                .await?;
+                .await
         }
-        MediaType::VideoNote => {
-            bot.send_video_note(msg.chat.id, media.into()).await?;
-            bot.send_message(msg.chat.id, caption)
+        MediaType::Document => {
+            bot.send_document(chat_id, file)
+                .caption(caption)
                 .parse_mode(ParseMode::Html)
-                .await?;
+                .await
+        }
+    }
+}
+
+async fn send_album(
+    bot: &Bot,
+    chat_id: ChatId,
+    group: &[&MediaDTO],
+    get_media_content: &GetMediaContent,
+    refresh_media_file_id: &RefreshMediaFileID,
+) -> BotHandlerResult {
+    let files = group
+        .iter()
+        .map(|m| (m.media_type, InputFile::file_id(m.file_id.clone().into())))
+        .collect();
+    let err = match bot.send_media_group(chat_id, make_album(files)).await {
+        Err(err) if is_invalid_file_error(&err) => err,
+        result => {
+            result?;
+            return Ok(());
+        }
+    };
+
+    // Неизвестно, какой из файлов устарел, поэтому заново загружаются все сохранённые
+    let mut files = Vec::with_capacity(group.len());
+    let mut reuploaded = Vec::with_capacity(group.len());
+    for m in group {
+        match get_media_content.execute(m.id.clone()).await? {
+            Some(content) => {
+                files.push((m.media_type, content.into()));
+                reuploaded.push(true);
+            }
+            None => {
+                files.push((m.media_type, InputFile::file_id(m.file_id.clone().into())));
+                reuploaded.push(false);
+            }
+        }
+    }
+    if !reuploaded.contains(&true) {
+        return Err(err.into());
+    }
+    log::info!("Re-uploading album of {} media: {err}", group.len());
+    let sent = bot.send_media_group(chat_id, make_album(files)).await?;
+    for ((m, sent), reuploaded) in group.iter().zip(&sent).zip(reuploaded) {
+        if reuploaded {
+            refresh_file_id(sent, m.id.clone(), refresh_media_file_id).await?;
         }
     }
     Ok(())
 }
 
+fn make_album(files: Vec<(MediaType, InputFile)>) -> Vec<InputMedia> {
+    files
+        .into_iter()
+        .map(|(media_type, file)| match media_type {
+            MediaType::Video => InputMedia::Video(InputMediaVideo::new(file)),
+            MediaType::Audio => InputMedia::Audio(InputMediaAudio::new(file)),
+            MediaType::Document => InputMedia::Document(InputMediaDocument::new(file)),
+            _ => InputMedia::Photo(InputMediaPhoto::new(file)),
+        })
+        .collect()
+}
+
+fn is_invalid_file_error(err: &RequestError) -> bool {
+    matches!(
+        err,
+        RequestError::Api(
+            ApiError::WrongFileId | ApiError::WrongFileIdOrUrl | ApiError::FileIdInvalid
+        )
+    )
+}
+
+async fn refresh_file_id(
+    sent: &Message,
+    media_id: MediaID,
+    refresh_media_file_id: &RefreshMediaFileID,
+) -> BotHandlerResult {
+    if let Some(media) = media_from_message(sent, media_id)? {
+        refresh_media_file_id
+            .execute(media.id().clone(), media.file_id().clone())
+            .await?;
+    }
+    Ok(())
+}
+
+/// Медиафайл из сообщения. У фото берётся самый большой размер.
+pub fn media_from_message(msg: &Message, media_id: MediaID) -> Result<Option<Media>, AppError> {
+    let media = if let Some(photos) = msg.photo() {
+        let photo = photos.last().unwrap();
+        Media::image(media_id, FileID::new(photo.file.id.0.clone())?)
+    } else if let Some(video_note) = msg.video_note() {
+        Media::video_note(media_id, FileID::new(video_note.file.id.0.clone())?)
+    } else if let Some(audio) = msg.audio() {
+        Media::audio(media_id, FileID::new(audio.file.id.0.clone())?)
+    } else if let Some(voice) = msg.voice() {
+        Media::voice(media_id, FileID::new(voice.file.id.0.clone())?)
+    } else if let Some(video) = msg.video() {
+        Media::video(media_id, FileID::new(video.file.id.0.clone())?)
+    } else if let Some(document) = msg.document() {
+        Media::document(media_id, FileID::new(document.file.id.0.clone())?)
+    } else {
+        return Ok(None);
+    };
+    Ok(Some(media))
+}
+
+/// Скачивает файл, чтобы его можно было загрузить заново от имени другого бота.
+pub async fn download_media_content(bot: &Bot, media: &Media) -> Result<MediaContent, AppError> {
+    let file = bot.get_file(media.file_id().clone().into()).await?;
+    let mut data = Vec::new();
+    bot.download_file(&file.path, &mut data)
+        .await
+        .map_err(RequestError::from)?;
+    let file_name = file
+        .path
+        .rsplit('/')
+        .next()
+        .unwrap_or(media.id().as_str())
+        .to_string();
+    Ok(MediaContent::new(file_name, data)?)
+}
+
 impl Into<TelegramFileId> for FileID {
     fn into(self) -> TelegramFileId {
         TelegramFileId::from(self.to_string())
@@ -67,3 +434,10 @@ impl Into<InputFile> for Media {
         InputFile::file_id(self.file_id().clone().into())
     }
 }
+
+impl From<MediaContent> for InputFile {
+    fn from(content: MediaContent) -> Self {
+        let file_name = content.file_name().to_string();
+        InputFile::memory(content.into_data()).file_name(file_name)
+    }
+}
@@ -4,64 +4,90 @@ use teloxide::prelude::*;
 use teloxide::types::ParseMode;
 
 use crate::app::error::AppError;
-use crate::app::usecases::dto::SlotDTO;
+use crate::app::usecases::dto::ReservationDTO;
 use crate::app::usecases::{
     CancelReservation, GetAvailableSlotStarts, GetPlayer, GetUserTeam, ReserveSlot,
 };
+use crate::bot::callbacks::CallbackData;
 use crate::bot::fsm::{BotDialogue, BotState};
 use crate::bot::handlers::menu::prompt_menu;
-use crate::bot::handlers::shared::{send_enter_message, send_use_keyboard};
+use crate::bot::handlers::shared::{Reply, send_enter_message};
+use crate::bot::i18n::Locale;
 use crate::bot::keyboards::{
-    make_back_keyboard, make_cancel_reservation_keyboard_with_back,
-    make_slot_start_keyboard_with_back, make_start_and_back_keyboard,
+    make_accept_final_keyboard, make_back_keyboard, make_cancel_reservation_keyboard,
+    make_slot_start_keyboard,
 };
-use crate::bot::{BotHandlerResult, keyboards, texts};
-use crate::domain::models::UserID;
+use crate::bot::staff_chat::StaffChat;
+use crate::bot::{BotHandlerResult, texts};
+use crate::config::EventConfig;
+
+/// Use case-ы записи на финал.
+#[derive(Clone)]
+pub struct SlotBooking {
+    pub get_available_slot_starts: GetAvailableSlotStarts,
+    pub reserve_slot: ReserveSlot,
+    pub cancel_reservation: CancelReservation,
+}
 
 pub async fn prompt_accept_final(
     bot: Bot,
-    msg: Message,
+    reply: Reply,
     dialogue: BotDialogue,
 ) -> BotHandlerResult {
-    bot.send_message(msg.chat.id, texts::PROMPT_ACCEPT_FINAL)
-        .reply_markup(make_start_and_back_keyboard())
-        .parse_mode(ParseMode::Html)
+    reply
+        .show(
+            &bot,
+            texts::prompt_accept_final(reply.locale),
+            make_accept_final_keyboard(reply.locale),
+        )
         .await?;
     dialogue.update(BotState::AcceptFinal).await?;
     Ok(())
 }
 
-async fn receive_accept_final(
+/// Записью управляет только капитан. Остальным и по кнопкам со старых сообщений,
+/// когда запись уже сделана или отменена, показываем меню.
+async fn can_reserve_or_menu(
+    bot: &Bot,
+    reply: Reply,
+    dialogue: &BotDialogue,
+    get_player: &GetPlayer,
+    reserved: bool,
+) -> Result<bool, AppError> {
+    let player = get_player.execute(reply.user_id()).await?;
+    if player.is_captain && player.reserved_slot == reserved {
+        return Ok(true);
+    }
+    prompt_menu(bot.clone(), reply, dialogue.clone(), &player).await?;
+    Ok(false)
+}
+
+async fn receive_slot_starts(
     bot: Bot,
-    msg: Message,
+    reply: Reply,
     dialogue: BotDialogue,
     get_player: GetPlayer,
     get_available_slot_starts: GetAvailableSlotStarts,
 ) -> BotHandlerResult {
-    let user_id = UserID::new(msg.chat.id.0);
-    match msg.text() {
-        None => send_enter_message(&bot, &msg).await,
-        Some(keyboards::BTN_BACK) => {
-            let player = get_player.execute(user_id).await?;
-            prompt_menu(bot, msg, dialogue, &player).await
-        }
-        Some(keyboards::BTN_START) => {
-            let starts = get_available_slot_starts.execute().await?;
-            prompt_slot_start(bot, msg, dialogue, &starts).await
-        }
-        Some(_) => send_use_keyboard(&bot, &msg).await,
+    if !can_reserve_or_menu(&bot, reply, &dialogue, &get_player, false).await? {
+        return Ok(());
     }
+    let starts = get_available_slot_starts.execute().await?;
+    prompt_slot_start(bot, reply, dialogue, &starts).await
 }
 
 async fn prompt_slot_start(
     bot: Bot,
-    msg: Message,
+    reply: Reply,
     dialogue: BotDialogue,
     starts: &[NaiveTime],
 ) -> BotHandlerResult {
-    bot.send_message(msg.chat.id, texts::PROMPT_SLOT_TIME)
-        .reply_markup(make_slot_start_keyboard_with_back(starts))
-        .parse_mode(ParseMode::Html)
+    reply
+        .show(
+            &bot,
+            texts::prompt_slot_time(reply.locale),
+            make_slot_start_keyboard(reply.locale, starts),
+        )
         .await?;
     dialogue.update(BotState::SlotStart).await?;
     Ok(())
@@ -69,66 +95,55 @@ async fn prompt_slot_start(
 
 async fn receive_slot_start(
     bot: Bot,
-    msg: Message,
+    reply: Reply,
     dialogue: BotDialogue,
+    start: NaiveTime,
     get_player: GetPlayer,
     get_user_team: GetUserTeam,
-    get_available_slot_starts: GetAvailableSlotStarts,
-    reserve_slot: ReserveSlot,
+    slot_booking: SlotBooking,
 ) -> BotHandlerResult {
-    let user_id = UserID::new(msg.chat.id.0);
-    match msg.text() {
-        None => send_enter_message(&bot, &msg).await,
-        Some(keyboards::BTN_BACK) => {
+    if !can_reserve_or_menu(&bot, reply, &dialogue, &get_player, false).await? {
+        return Ok(());
+    }
+    let user_id = reply.user_id();
+    let team = get_user_team
+        .execute(user_id)
+        .await?
+        .ok_or(AppError::UserNotInTeam(user_id))?;
+    if team.size > 1 {
+        return prompt_slot_places(bot, reply, dialogue, start, team.size).await;
+    }
+    match slot_booking.reserve_slot.execute(user_id, start, 1).await {
+        Ok(slot) => {
+            send_slot_successfully_reserved(&bot, reply, slot).await?;
             let player = get_player.execute(user_id).await?;
-            prompt_menu(bot, msg, dialogue, &player).await
+            prompt_menu(bot, reply.detached(), dialogue, &player).await
+        }
+        Err(AppError::PlacesGreaterThanTeamSize(_, team_size)) => {
+            send_places_greater_than_team_size(&bot, reply, team_size).await
         }
-        Some(text) => match NaiveTime::parse_from_str(text, "%H:%M") {
-            Ok(start) => {
-                let team = get_user_team
-                    .execute(user_id)
-                    .await?
-                    .ok_or(AppError::UserNotInTeam(user_id))?;
-                if team.size > 1 {
-                    prompt_slot_places(bot, msg, dialogue, start, team.size).await
-                } else {
-                    match reserve_slot.execute(user_id, start, 1).await {
-                        Ok(slot) => {
-                            send_slot_successfully_reserved(&bot, &msg, slot).await?;
-                            let player = get_player.execute(user_id).await?;
-                            prompt_menu(bot, msg, dialogue, &player).await
-                        }
-                        Err(AppError::PlacesGreaterThanTeamSize(_, team_size)) => {
-                            send_places_greater_than_team_size(&bot, &msg, team_size).await
-                        }
-                        Err(AppError::NoAvailableSlots(_, team_size)) => {
-                            send_no_slots(&bot, &msg, team_size).await?;
-                            let slots = get_available_slot_starts.execute().await?;
-                            prompt_slot_start(bot, msg, dialogue, &slots).await
-                        }
-                        Err(err) => Err(err),
-                    }
-                }
-            }
-            Err(_) => {
-                send_use_keyboard(&bot, &msg).await?;
-                let starts = get_available_slot_starts.execute().await?;
-                prompt_slot_start(bot, msg, dialogue, &starts).await
-            }
-        },
+        Err(AppError::NoAvailableSlots(_, team_size)) => {
+            send_no_slots(&bot, reply, team_size).await?;
+            let slots = slot_booking.get_available_slot_starts.execute().await?;
+            prompt_slot_start(bot, reply.detached(), dialogue, &slots).await
+        }
+        Err(err) => Err(err),
     }
 }
 
 async fn prompt_slot_places(
     bot: Bot,
-    msg: Message,
+    reply: Reply,
     dialogue: BotDialogue,
     start: NaiveTime,
     team_size: usize,
 ) -> BotHandlerResult {
-    bot.send_message(msg.chat.id, texts::prompt_slot_places(team_size))
-        .reply_markup(make_back_keyboard())
-        .parse_mode(ParseMode::Html)
+    reply
+        .show(
+            &bot,
+            texts::prompt_slot_places(reply.locale, team_size),
+            make_back_keyboard(reply.locale, CallbackData::SlotStarts),
+        )
         .await?;
     dialogue.update(BotState::SlotPlaces(start)).await?;
     Ok(())
@@ -143,48 +158,48 @@ async fn receive_slot_places(
     reserve_slot: ReserveSlot,
     get_player: GetPlayer,
 ) -> BotHandlerResult {
-    let user_id = UserID::new(msg.chat.id.0);
-    match msg.text() {
-        None => send_enter_message(&bot, &msg).await,
-        Some(keyboards::BTN_BACK) => {
-            let starts = get_available_slot_starts.execute().await?;
-            prompt_slot_start(bot, msg, dialogue, &starts).await
+    let reply = Reply::to_message(&msg);
+    let user_id = reply.user_id();
+    let Some(text) = msg.text() else {
+        return send_enter_message(&bot, &msg).await;
+    };
+    let Ok(places) = text.parse::<usize>() else {
+        return send_invalid_slot_places(&bot, &msg).await;
+    };
+    match reserve_slot.execute(user_id, start, places).await {
+        Ok(slot) => {
+            send_slot_successfully_reserved(&bot, reply, slot).await?;
+            let player = get_player.execute(user_id).await?;
+            prompt_menu(bot, reply, dialogue, &player).await
+        }
+        Err(AppError::PlacesGreaterThanTeamSize(_, team_size)) => {
+            send_places_greater_than_team_size(&bot, reply, team_size).await
+        }
+        Err(AppError::NoAvailableSlots(_, team_size)) => {
+            send_no_slots(&bot, reply, team_size).await?;
+            let slots = get_available_slot_starts.execute().await?;
+            prompt_slot_start(bot, reply, dialogue, &slots).await
         }
-        Some(text) => match text.parse::<usize>() {
-            Ok(places) => match reserve_slot.execute(user_id, start, places).await {
-                Ok(slot) => {
-                    send_slot_successfully_reserved(&bot, &msg, slot).await?;
-                    let player = get_player.execute(user_id).await?;
-                    prompt_menu(bot, msg, dialogue, &player).await
-                }
-                Err(AppError::PlacesGreaterThanTeamSize(_, team_size)) => {
-                    send_places_greater_than_team_size(&bot, &msg, team_size).await
-                }
-                Err(AppError::NoAvailableSlots(_, team_size)) => {
-                    send_no_slots(&bot, &msg, team_size).await?;
-                    let slots = get_available_slot_starts.execute().await?;
-                    prompt_slot_start(bot, msg, dialogue, &slots).await
-                }
-                Err(err) => Err(err),
-            },
-            Err(_) => send_invalid_slot_places(&bot, &msg).await,
-        },
+        Err(err) => Err(err),
     }
 }
 
 async fn send_slot_successfully_reserved(
     bot: &Bot,
-    msg: &Message,
-    slot: SlotDTO,
+    reply: Reply,
+    reservation: ReservationDTO,
 ) -> BotHandlerResult {
-    bot.send_message(msg.chat.id, texts::slot_successfully_reserved(slot))
-        .parse_mode(ParseMode::Html)
-        .await?;
-    Ok(())
+    reply
+        .send(
+            bot,
+            texts::slot_successfully_reserved(reply.locale, &reservation),
+        )
+        .await
 }
 
 async fn send_invalid_slot_places(bot: &Bot, msg: &Message) -> BotHandlerResult {
-    bot.send_message(msg.chat.id, texts::INVALID_TEAM_PLACES)
+    let locale = Locale::of_message(msg);
+    bot.send_message(msg.chat.id, texts::invalid_team_places(locale))
         .parse_mode(ParseMode::Html)
         .await?;
     Ok(())
@@ -192,78 +207,91 @@ async fn send_invalid_slot_places(bot: &Bot, msg: &Message) -> BotHandlerResult
 
 async fn send_places_greater_than_team_size(
     bot: &Bot,
-    msg: &Message,
+    reply: Reply,
     team_size: usize,
 ) -> BotHandlerResult {
-    bot.send_message(msg.chat.id, texts::places_greater_than_team(team_size))
-        .parse_mode(ParseMode::Html)
-        .await?;
-    Ok(())
+    reply
+        .send(
+            bot,
+            texts::places_greater_than_team(reply.locale, team_size),
+        )
+        .await
 }
 
-async fn send_no_slots(bot: &Bot, msg: &Message, team_size: usize) -> BotHandlerResult {
-    bot.send_message(msg.chat.id, texts::no_slots(team_size))
-        .parse_mode(ParseMode::Html)
-        .await?;
-    Ok(())
+async fn send_no_slots(bot: &Bot, reply: Reply, team_size: usize) -> BotHandlerResult {
+    reply
+        .send(bot, texts::no_slots(reply.locale, team_size))
+        .await
 }
 
 pub async fn prompt_cancel_reservation_reason(
     bot: Bot,
-    msg: Message,
+    reply: Reply,
     dialogue: BotDialogue,
+    event: &EventConfig,
 ) -> BotHandlerResult {
-    bot.send_message(msg.chat.id, texts::PROMPT_CANCEL_RESERVATION_REASON)
-        .reply_markup(make_cancel_reservation_keyboard_with_back())
-        .parse_mode(ParseMode::Html)
+    reply
+        .show(
+            &bot,
+            texts::prompt_cancel_reservation_reason(reply.locale, event),
+            make_cancel_reservation_keyboard(reply.locale),
+        )
         .await?;
     dialogue.update(BotState::CancelReason).await?;
     Ok(())
 }
 
-async fn receive_cancel_reservation_reason(
+/// `rescheduled` — команда не отказывается от финала, а выбирает другое время.
+async fn receive_cancel_reservation(
     bot: Bot,
-    msg: Message,
+    reply: Reply,
     dialogue: BotDialogue,
+    rescheduled: bool,
     get_player: GetPlayer,
-    cancel_reservation: CancelReservation,
-    get_available_slot_starts: GetAvailableSlotStarts,
+    slot_booking: SlotBooking,
+    staff_chat: StaffChat,
 ) -> BotHandlerResult {
-    let user_id = UserID::new(msg.chat.id.0);
-    match msg.text() {
-        None => send_enter_message(&bot, &msg).await,
-        Some(keyboards::BTN_BACK) => {
-            let player = get_player.execute(user_id).await?;
-            prompt_menu(bot, msg, dialogue, &player).await
-        }
-        Some(keyboards::BTN_CAN_NOT_ACCEPT_FINAL) => {
-            send_ochen_zhal(&bot, &msg).await?;
-            cancel_reservation.execute(user_id).await?;
-            let player = get_player.execute(user_id).await?;
-            prompt_menu(bot, msg, dialogue, &player).await
-        }
-        Some(keyboards::BTN_CHANGE_RESERVATION_TIME) => {
-            cancel_reservation.execute(user_id).await?;
-            let starts = get_available_slot_starts.execute().await?;
-            prompt_slot_start(bot, msg, dialogue, &starts).await
-        }
-        Some(_) => send_use_keyboard(&bot, &msg).await,
+    if !can_reserve_or_menu(&bot, reply, &dialogue, &get_player, true).await? {
+        return Ok(());
+    }
+    let user_id = reply.user_id();
+    if !rescheduled {
+        send_ochen_zhal(&bot, reply).await?;
+    }
+    slot_booking.cancel_reservation.execute(user_id).await?;
+    staff_chat
+        .notify_reservation_cancelled(&bot, user_id, rescheduled)
+        .await;
+    if rescheduled {
+        let starts = slot_booking.get_available_slot_starts.execute().await?;
+        prompt_slot_start(bot, reply, dialogue, &starts).await
+    } else {
+        let player = get_player.execute(user_id).await?;
+        prompt_menu(bot, reply.detached(), dialogue, &player).await
     }
 }
 
-async fn send_ochen_zhal(bot: &Bot, msg: &Message) -> BotHandlerResult {
-    bot.send_message(msg.chat.id, texts::OCHEN_ZHAL)
-        .parse_mode(ParseMode::Html)
-        .await?;
-    Ok(())
+async fn send_ochen_zhal(bot: &Bot, reply: Reply) -> BotHandlerResult {
+    reply
+        .send(bot, texts::final_absence_accepted(reply.locale))
+        .await
 }
 
 pub fn slots_scheme() -> UpdateHandler<AppError> {
     use dptree::case;
 
     Update::filter_message()
-        .branch(case![BotState::AcceptFinal].endpoint(receive_accept_final))
-        .branch(case![BotState::SlotStart].endpoint(receive_slot_start))
         .branch(case![BotState::SlotPlaces(start)].endpoint(receive_slot_places))
-        .branch(case![BotState::CancelReason].endpoint(receive_cancel_reservation_reason))
+}
+
+pub fn slots_callbacks() -> UpdateHandler<AppError> {
+    use dptree::case;
+
+    dptree::entry()
+        .branch(case![CallbackData::SlotStarts].endpoint(receive_slot_starts))
+        .branch(case![CallbackData::SlotStart(start)].endpoint(receive_slot_start))
+        .branch(
+            case![CallbackData::CancelReservation(rescheduled)]
+                .endpoint(receive_cancel_reservation),
+        )
 }
@@ -1,36 +1,44 @@
 use crate::app::usecases::{
-    AnswerTask, CheckCaptain, GetAvailableTracks, GetCompletedTasks, GetPlayer, UploadMedia,
+    AnswerTask, CheckCaptain, GetAvailableTracks, GetCompletedTasks, UploadPhotoAnswer,
 };
 use teloxide::dispatching::UpdateHandler;
 use teloxide::prelude::*;
-use teloxide::types::{InputFile, ParseMode};
+use teloxide::types::{InputFile, KeyboardRemove, ParseMode};
 
 use crate::app::error::AppError;
-use crate::app::usecases::dto::{TaskDTO, TrackInProgressDTO};
+use crate::app::usecases::dto::{AnswerDTO, TaskDTO, TaskItemDTO, TrackDTO, TrackInProgressDTO};
 use crate::app::usecases::{
-    CheckStartedTrack, GetAvailableTasks, GetTask, GetTrackInProgress, StartTrack,
+    CheckStartedTrack, GetAvailableTasks, GetMediaContent, GetTask, GetTrackInProgress,
+    RefreshMediaFileID, StartTrack,
 };
+use crate::bot::callbacks::CallbackData;
 use crate::bot::fsm::BotState;
-use crate::bot::handlers::menu::prompt_menu;
-use crate::bot::handlers::shared::{send_enter_message, send_use_keyboard};
+use crate::bot::handlers::shared::{Reply, send_enter_message, send_media_list, send_use_keyboard};
+use crate::bot::i18n::Locale;
 use crate::bot::keyboards::{
-    BTN_TASK_ID_PREFIX, make_back_keyboard, make_options_keyboard_with_back,
-    make_start_and_back_keyboard, make_tasks_group_keyboard_with_back,
-    make_tasks_keyboard_with_back, make_tracks_keyboard_with_back,
+    make_character_links_keyboard, make_location_keyboard, make_multi_choice_keyboard,
+    make_options_keyboard, make_ordering_keyboard, make_start_track_keyboard,
+    make_task_back_keyboard, make_tasks_group_keyboard, make_tasks_keyboard, make_tracks_keyboard,
+};
+use crate::bot::staff_chat::StaffChat;
+use crate::bot::{BotHandlerResult, fsm::BotDialogue, texts};
+use crate::domain::models::{
+    AnswerGrade, AnswerInput, FileID, GeoPoint, Media, MediaID, TaskID, TaskType, TrackTag, UserID,
 };
-use crate::bot::{BotHandlerResult, fsm::BotDialogue, keyboards, texts};
-use crate::domain::models::{FileID, Media, MediaID, TaskID, TaskType, TrackTag, UserID};
 use crate::utils::uuid::new_pseudo_uuid;
 
 pub async fn prompt_track(
     bot: Bot,
-    msg: Message,
+    reply: Reply,
     dialogue: BotDialogue,
-    tracks: &[TrackTag],
+    tracks: &[TrackDTO],
 ) -> BotHandlerResult {
-    bot.send_message(msg.chat.id, texts::PROMPT_TRACK)
-        .reply_markup(make_tracks_keyboard_with_back(tracks))
-        .parse_mode(ParseMode::Html)
+    reply
+        .show(
+            &bot,
+            texts::prompt_track(reply.locale),
+            make_tracks_keyboard(reply.locale, tracks),
+        )
         .await?;
     dialogue.update(BotState::Track).await?;
     Ok(())
@@ -38,58 +46,50 @@ pub async fn prompt_track(
 
 async fn receive_track(
     bot: Bot,
-    msg: Message,
+    reply: Reply,
     dialogue: BotDialogue,
-    get_player: GetPlayer,
+    tag: TrackTag,
     check_started_track: CheckStartedTrack,
     get_track_in_progress: GetTrackInProgress,
     check_captain: CheckCaptain,
     get_available_tracks: GetAvailableTracks,
 ) -> BotHandlerResult {
-    let user_id = UserID::new(msg.chat.id.0);
-    match msg.text() {
-        None => send_enter_message(&bot, &msg).await,
-        Some(keyboards::BTN_BACK) => {
-            let player = get_player.execute(user_id).await?;
-            prompt_menu(bot, msg, dialogue, &player).await
-        }
-        Some(text) => {
-            if let Some(tag) = TrackTag::try_parse(text) {
-                let started = check_started_track.execute(user_id, tag).await?;
-                let is_captain = check_captain.execute(user_id).await?;
-                if started {
-                    let track = get_track_in_progress.execute(user_id, tag).await?;
-                    prompt_track_task_groups(bot, msg, dialogue, &track, is_captain).await
-                } else if is_captain {
-                    prompt_track_start(bot, msg, dialogue, tag).await
-                } else {
-                    send_track_is_not_started(&bot, &msg).await?;
-                    let tracks = get_available_tracks.execute(user_id).await?;
-                    prompt_track(bot, msg, dialogue, &tracks).await
-                }
-            } else {
-                send_use_keyboard(&bot, &msg).await
-            }
-        }
+    let user_id = reply.user_id();
+    let tracks = get_available_tracks.execute(user_id).await?;
+    if !tracks.iter().any(|t| t.tag == tag) {
+        return prompt_track(bot, reply, dialogue, &tracks).await;
+    }
+    let started = check_started_track.execute(user_id, &tag).await?;
+    let is_captain = check_captain.execute(user_id).await?;
+    if started {
+        let track = get_track_in_progress.execute(user_id, &tag).await?;
+        prompt_track_task_groups(bot, reply, dialogue, &track, is_captain).await
+    } else if is_captain {
+        prompt_track_start(bot, reply, dialogue, tag).await
+    } else {
+        send_track_is_not_started(&bot, reply).await?;
+        prompt_track(bot, reply.detached(), dialogue, &tracks).await
     }
 }
 
-async fn send_track_is_not_started(bot: &Bot, msg: &Message) -> BotHandlerResult {
-    bot.send_message(msg.chat.id, texts::TRACK_NOT_STARTED)
-        .parse_mode(ParseMode::Html)
-        .await?;
-    Ok(())
+async fn send_track_is_not_started(bot: &Bot, reply: Reply) -> BotHandlerResult {
+    reply
+        .send(bot, texts::track_not_started(reply.locale))
+        .await
 }
 
 async fn prompt_track_start(
     bot: Bot,
-    msg: Message,
+    reply: Reply,
     dialogue: BotDialogue,
     tag: TrackTag,
 ) -> BotHandlerResult {
-    bot.send_message(msg.chat.id, texts::PROMPT_TRACK_START)
-        .reply_markup(make_start_and_back_keyboard())
-        .parse_mode(ParseMode::Html)
+    reply
+        .show(
+            &bot,
+            texts::prompt_track_start(reply.locale),
+            make_start_track_keyboard(reply.locale, &tag),
+        )
         .await?;
     dialogue.update(BotState::StartTrack(tag)).await?;
     Ok(())
@@ -97,113 +97,119 @@ async fn prompt_track_start(
 
 async fn receive_track_start(
     bot: Bot,
-    msg: Message,
+    reply: Reply,
     dialogue: BotDialogue,
     tag: TrackTag,
     start_track: StartTrack,
+    check_started_track: CheckStartedTrack,
+    get_track_in_progress: GetTrackInProgress,
     get_available_tracks: GetAvailableTracks,
     check_captain: CheckCaptain,
 ) -> BotHandlerResult {
-    let user_id = UserID::new(msg.chat.id.0);
-    match msg.text() {
-        None => send_enter_message(&bot, &msg).await,
-        Some(keyboards::BTN_BACK) => {
-            let tracks = get_available_tracks.execute(user_id).await?;
-            prompt_track(bot, msg, dialogue, &tracks).await
-        }
-        Some(keyboards::BTN_START) => {
-            let track = start_track.execute(user_id, tag).await?;
-            let is_captain = check_captain.execute(user_id).await?;
-            prompt_track_task_groups(bot, msg, dialogue, &track, is_captain).await
-        }
-        Some(_) => send_use_keyboard(&bot, &msg).await,
+    let user_id = reply.user_id();
+    if !check_captain.execute(user_id).await? {
+        let tracks = get_available_tracks.execute(user_id).await?;
+        return prompt_track(bot, reply, dialogue, &tracks).await;
     }
+    // Кнопка «Начать» остаётся на сообщении и после старта трека.
+    let track = if check_started_track.execute(user_id, &tag).await? {
+        get_track_in_progress.execute(user_id, &tag).await?
+    } else {
+        start_track.execute(user_id, &tag).await?
+    };
+    prompt_track_task_groups(bot, reply, dialogue, &track, true).await
 }
 
+/// Меню трека — фото с подписью, поэтому всегда отправляется новым сообщением.
 async fn prompt_track_task_groups(
     bot: Bot,
-    msg: Message,
+    reply: Reply,
     dialogue: BotDialogue,
     track: &TrackInProgressDTO,
     is_captain: bool,
 ) -> BotHandlerResult {
     bot.send_photo(
-        msg.chat.id,
+        reply.chat_id,
         InputFile::file_id(track.media.file_id.clone().into()),
     )
-    .caption(texts::track_menu(&track))
-    .reply_markup(make_tasks_group_keyboard_with_back(is_captain))
+    .caption(texts::track_menu(reply.locale, track))
+    .reply_markup(make_tasks_group_keyboard(
+        reply.locale,
+        &track.tag,
+        is_captain,
+    ))
     .parse_mode(ParseMode::Html)
     .await?;
-    dialogue.update(BotState::TrackTaskGroup(track.tag)).await?;
+    dialogue
+        .update(BotState::TrackTaskGroup(track.tag.clone()))
+        .await?;
     Ok(())
 }
 
-async fn receive_tasks_group(
+async fn receive_available_tasks(
     bot: Bot,
-    msg: Message,
+    reply: Reply,
     dialogue: BotDialogue,
     tag: TrackTag,
     get_available_tasks: GetAvailableTasks,
-    get_completed_tasks: GetCompletedTasks,
     get_available_tracks: GetAvailableTracks,
     check_captain: CheckCaptain,
 ) -> BotHandlerResult {
-    let user_id = UserID::new(msg.chat.id.0);
-    match msg.text() {
-        None => send_enter_message(&bot, &msg).await,
-        Some(keyboards::BTN_BACK) => {
-            let tracks = get_available_tracks.execute(user_id).await?;
-            prompt_track(bot, msg, dialogue, &tracks).await
-        }
-        Some(keyboards::BTN_AVAILABLE_TASKS) => {
-            let available_tasks = get_available_tasks.execute(user_id, tag).await?;
-            if available_tasks.is_empty() {
-                send_all_task_completed(&bot, &msg).await?;
-                let tracks = get_available_tracks.execute(user_id).await?;
-                prompt_track(bot, msg, dialogue, &tracks).await
-            } else {
-                prompt_available_task(bot, msg, dialogue, tag, &available_tasks).await
-            }
-        }
-        Some(keyboards::BTN_COMPLETED_TASKS) => {
-            let completed_tasks = get_completed_tasks.execute(user_id, tag).await?;
-            if completed_tasks.is_empty() {
-                let is_captain = check_captain.execute(user_id).await?;
-                send_no_tasks_completed(&bot, &msg, is_captain).await
-            } else {
-                prompt_completed_task(bot, msg, dialogue, tag, &completed_tasks).await
-            }
-        }
-        Some(_) => send_use_keyboard(&bot, &msg).await,
+    let user_id = reply.user_id();
+    if !check_captain.execute(user_id).await? {
+        let tracks = get_available_tracks.execute(user_id).await?;
+        return prompt_track(bot, reply, dialogue, &tracks).await;
+    }
+    let available_tasks = get_available_tasks.execute(user_id, &tag).await?;
+    if available_tasks.is_empty() {
+        send_all_task_completed(&bot, reply).await?;
+        let tracks = get_available_tracks.execute(user_id).await?;
+        prompt_track(bot, reply.detached(), dialogue, &tracks).await
+    } else {
+        prompt_available_task(bot, reply, dialogue, tag, &available_tasks).await
     }
 }
 
-async fn send_all_task_completed(bot: &Bot, msg: &Message) -> BotHandlerResult {
-    bot.send_message(msg.chat.id, texts::ALL_TRACK_TASKS_COMPLETED)
-        .parse_mode(ParseMode::Html)
-        .await?;
-    Ok(())
+async fn receive_completed_tasks(
+    bot: Bot,
+    reply: Reply,
+    dialogue: BotDialogue,
+    tag: TrackTag,
+    get_completed_tasks: GetCompletedTasks,
+) -> BotHandlerResult {
+    let completed_tasks = get_completed_tasks.execute(reply.user_id(), &tag).await?;
+    if completed_tasks.is_empty() {
+        send_no_tasks_completed(&bot, reply).await
+    } else {
+        prompt_completed_task(bot, reply, dialogue, tag, &completed_tasks).await
+    }
 }
 
-async fn send_no_tasks_completed(bot: &Bot, msg: &Message, is_captain: bool) -> BotHandlerResult {
-    bot.send_message(msg.chat.id, texts::NO_COMPLETED_TASKS)
-        .reply_markup(make_tasks_group_keyboard_with_back(is_captain))
-        .parse_mode(ParseMode::Html)
-        .await?;
-    Ok(())
+async fn send_all_task_completed(bot: &Bot, reply: Reply) -> BotHandlerResult {
+    reply
+        .send(bot, texts::all_track_tasks_completed(reply.locale))
+        .await
+}
+
+async fn send_no_tasks_completed(bot: &Bot, reply: Reply) -> BotHandlerResult {
+    reply
+        .send(bot, texts::no_completed_tasks(reply.locale))
+        .await
 }
 
-async fn prompt_available_task(
+pub async fn prompt_available_task(
     bot: Bot,
-    msg: Message,
+    reply: Reply,
     dialogue: BotDialogue,
     track_tag: TrackTag,
-    task_ids: &[TaskID],
+    tasks: &[TaskItemDTO],
 ) -> BotHandlerResult {
-    bot.send_message(msg.chat.id, texts::PROMPT_AVAILABLE_TASK)
-        .reply_markup(make_tasks_keyboard_with_back(task_ids))
-        .parse_mode(ParseMode::Html)
+    reply
+        .show(
+            &bot,
+            texts::prompt_available_task(reply.locale),
+            make_tasks_keyboard(reply.locale, &track_tag, tasks, false),
+        )
         .await?;
     dialogue.update(BotState::AvailableTask(track_tag)).await?;
     Ok(())
@@ -211,14 +217,17 @@ async fn prompt_available_task(
 
 async fn prompt_completed_task(
     bot: Bot,
-    msg: Message,
+    reply: Reply,
     dialogue: BotDialogue,
     track_tag: TrackTag,
-    task_ids: &[TaskID],
+    tasks: &[TaskItemDTO],
 ) -> BotHandlerResult {
-    bot.send_message(msg.chat.id, texts::PROMPT_COMPLETED_TASK)
-        .reply_markup(make_tasks_keyboard_with_back(task_ids))
-        .parse_mode(ParseMode::Html)
+    reply
+        .show(
+            &bot,
+            texts::prompt_completed_task(reply.locale),
+            make_tasks_keyboard(reply.locale, &track_tag, tasks, true),
+        )
         .await?;
     dialogue.update(BotState::CompletedTask(track_tag)).await?;
     Ok(())
@@ -226,87 +235,78 @@ async fn prompt_completed_task(
 
 async fn receive_available_task(
     bot: Bot,
-    msg: Message,
+    reply: Reply,
     dialogue: BotDialogue,
-    tag: TrackTag,
+    (tag, task_id): (TrackTag, TaskID),
     get_task: GetTask,
-    get_track_in_progress: GetTrackInProgress,
+    get_available_tracks: GetAvailableTracks,
     check_captain: CheckCaptain,
+    get_media_content: GetMediaContent,
+    refresh_media_file_id: RefreshMediaFileID,
 ) -> BotHandlerResult {
-    let user_id = UserID::new(msg.chat.id.0);
-    match msg.text() {
-        None => send_enter_message(&bot, &msg).await,
-        Some(keyboards::BTN_BACK) => {
-            let track = get_track_in_progress.execute(user_id, tag).await?;
-            let is_captain = check_captain.execute(user_id).await?;
-            prompt_track_task_groups(bot, msg, dialogue, &track, is_captain).await
+    let user_id = reply.user_id();
+    if !check_captain.execute(user_id).await? {
+        let tracks = get_available_tracks.execute(user_id).await?;
+        return prompt_track(bot, reply, dialogue, &tracks).await;
+    }
+    let task = get_task.execute(task_id).await?;
+    // Вопрос должен оказаться под медиафайлами задания.
+    let reply = if task.media.is_empty() {
+        reply
+    } else {
+        send_media_list(
+            &bot,
+            reply.chat_id,
+            &task.media,
+            &get_media_content,
+            &refresh_media_file_id,
+        )
+        .await?;
+        reply.detached()
+    };
+    match task.task_type {
+        TaskType::Photo => prompt_photo_answer(bot, reply, dialogue, tag, &task).await,
+        TaskType::MultiChoice => {
+            prompt_multi_choice_answer(bot, reply, dialogue, tag, &task, vec![]).await
+        }
+        TaskType::Ordering => {
+            prompt_ordering_answer(bot, reply, dialogue, tag, &task, vec![]).await
+        }
+        TaskType::Location => prompt_location_answer(bot, reply, dialogue, tag, &task).await,
+        TaskType::Text | TaskType::Choice | TaskType::Number => {
+            prompt_text_task_answer(bot, reply, dialogue, tag, &task).await
         }
-        Some(text) => match text.strip_prefix(BTN_TASK_ID_PREFIX) {
-            None => send_use_keyboard(&bot, &msg).await,
-            Some(name) => match name.parse::<i32>() {
-                Err(_) => send_use_keyboard(&bot, &msg).await,
-                Ok(id) => {
-                    let task = get_task.execute(id).await?;
-                    if matches!(task.task_type, TaskType::Photo) {
-                        prompt_photo_answer(bot, msg, dialogue, tag, &task).await
-                    } else {
-                        prompt_text_task_answer(bot, msg, dialogue, tag, &task).await
-                    }
-                }
-            },
-        },
     }
 }
 
 async fn receive_completed_task(
     bot: Bot,
-    msg: Message,
+    reply: Reply,
     dialogue: BotDialogue,
-    tag: TrackTag,
+    (tag, task_id): (TrackTag, TaskID),
     get_task: GetTask,
     get_completed_tasks: GetCompletedTasks,
-    get_track_in_progress: GetTrackInProgress,
-    check_captain: CheckCaptain,
 ) -> BotHandlerResult {
-    let user_id = UserID::new(msg.chat.id.0);
-    match msg.text() {
-        None => send_enter_message(&bot, &msg).await,
-        Some(keyboards::BTN_BACK) => {
-            let track = get_track_in_progress.execute(user_id, tag).await?;
-            let is_captain = check_captain.execute(user_id).await?;
-            prompt_track_task_groups(bot, msg, dialogue, &track, is_captain).await
-        }
-        Some(text) => match text.strip_prefix(BTN_TASK_ID_PREFIX) {
-            None => send_use_keyboard(&bot, &msg).await,
-            Some(name) => match name.parse::<i32>() {
-                Err(_) => send_use_keyboard(&bot, &msg).await,
-                Ok(id) => {
-                    let task = get_task.execute(id).await?;
-                    send_task_question_and_explanation(&bot, &msg, &task).await?;
-                    let completed_tasks = get_completed_tasks.execute(user_id, tag).await?;
-                    prompt_completed_task(bot, msg, dialogue, tag, &completed_tasks).await
-                }
-            },
-        },
-    }
+    let task = get_task.execute(task_id).await?;
+    send_task_question_and_explanation(&bot, reply, &task).await?;
+    let completed_tasks = get_completed_tasks.execute(reply.user_id(), &tag).await?;
+    prompt_completed_task(bot, reply.detached(), dialogue, tag, &completed_tasks).await
 }
 
 async fn prompt_text_task_answer(
     bot: Bot,
-    msg: Message,
+    reply: Reply,
     dialogue: BotDialogue,
     track_tag: TrackTag,
     task: &TaskDTO,
 ) -> BotHandlerResult {
     let keyboard = if matches!(task.task_type, TaskType::Choice) {
-        make_options_keyboard_with_back(&task.options)
+        make_options_keyboard(reply.locale, &track_tag, task.id, &task.options)
     } else {
-        make_back_keyboard()
+        make_task_back_keyboard(reply.locale, &track_tag)
     };
-
-    bot.send_message(msg.chat.id, task.question.as_str())
-        .reply_markup(keyboard)
-        .parse_mode(ParseMode::Html)
+    reply
+        .show(&bot, texts::task_question(reply.locale, task), keyboard)
         .await?;
     dialogue
         .update(BotState::TaskAnswer(track_tag, task.id))
@@ -314,28 +314,109 @@ async fn prompt_text_task_answer(
     Ok(())
 }
 
-async fn prompt_photo_answer(
+async fn prompt_multi_choice_answer(
     bot: Bot,
-    msg: Message,
+    reply: Reply,
     dialogue: BotDialogue,
     track_tag: TrackTag,
     task: &TaskDTO,
+    selected: Vec<usize>,
 ) -> BotHandlerResult {
-    if let Some(media) = &task.media {
-        bot.send_photo(
-            msg.chat.id,
-            InputFile::file_id(media.file_id.clone().into()),
+    let mut text = texts::task_question(reply.locale, task);
+    if !selected.is_empty() {
+        let options: Vec<_> = selected.iter().map(|&i| &task.options[i]).collect();
+        text = format!(
+            "{text}\n\n{}",
+            texts::selected_options(reply.locale, &options)
+        );
+    }
+    reply
+        .show(
+            &bot,
+            text,
+            make_multi_choice_keyboard(reply.locale, &track_tag, task.id, &task.options, &selected),
         )
-        .caption(task.question.as_str())
-        .reply_markup(make_back_keyboard())
-        .parse_mode(ParseMode::Html)
         .await?;
-    } else {
-        bot.send_message(msg.chat.id, task.question.as_str())
-            .reply_markup(make_back_keyboard())
-            .parse_mode(ParseMode::Html)
-            .await?;
+    dialogue
+        .update(BotState::TaskMultiChoice(track_tag, task.id, selected))
+        .await?;
+    Ok(())
+}
+
+async fn prompt_ordering_answer(
+    bot: Bot,
+    reply: Reply,
+    dialogue: BotDialogue,
+    track_tag: TrackTag,
+    task: &TaskDTO,
+    chosen: Vec<usize>,
+) -> BotHandlerResult {
+    let mut text = texts::task_question(reply.locale, task);
+    if !chosen.is_empty() {
+        let options: Vec<_> = chosen.iter().map(|&i| &task.options[i]).collect();
+        text = format!(
+            "{text}\n\n{}",
+            texts::ordered_options(reply.locale, &options)
+        );
     }
+    reply
+        .show(
+            &bot,
+            text,
+            make_ordering_keyboard(reply.locale, &track_tag, task.id, &task.options, &chosen),
+        )
+        .await?;
+    dialogue
+        .update(BotState::TaskOrdering(track_tag, task.id, chosen))
+        .await?;
+    Ok(())
+}
+
+/// Геопозицию отправляют кнопкой обычной клавиатуры, поэтому она приходит
+/// отдельным сообщением под вопросом.
+async fn prompt_location_answer(
+    bot: Bot,
+    reply: Reply,
+    dialogue: BotDialogue,
+    track_tag: TrackTag,
+    task: &TaskDTO,
+) -> BotHandlerResult {
+    reply
+        .show(
+            &bot,
+            texts::task_question(reply.locale, task),
+            make_task_back_keyboard(reply.locale, &track_tag),
+        )
+        .await?;
+    send_please_send_location(&bot, reply).await?;
+    dialogue
+        .update(BotState::TaskLocation(track_tag, task.id))
+        .await?;
+    Ok(())
+}
+
+async fn send_please_send_location(bot: &Bot, reply: Reply) -> BotHandlerResult {
+    bot.send_message(reply.chat_id, texts::please_send_location(reply.locale))
+        .reply_markup(make_location_keyboard(reply.locale))
+        .parse_mode(ParseMode::Html)
+        .await?;
+    Ok(())
+}
+
+async fn prompt_photo_answer(
+    bot: Bot,
+    reply: Reply,
+    dialogue: BotDialogue,
+    track_tag: TrackTag,
+    task: &TaskDTO,
+) -> BotHandlerResult {
+    reply
+        .show(
+            &bot,
+            task.question.to_string(),
+            make_task_back_keyboard(reply.locale, &track_tag),
+        )
+        .await?;
     dialogue
         .update(BotState::TaskPhoto(track_tag, task.id))
         .await?;
@@ -351,27 +432,259 @@ async fn receive_task_answer(
     get_task: GetTask,
     get_available_tasks: GetAvailableTasks,
 ) -> BotHandlerResult {
-    let user_id = UserID::new(msg.chat.id.0);
-    match msg.text() {
-        None => send_enter_message(&bot, &msg).await,
-        Some(keyboards::BTN_BACK) => {
-            let tasks = get_available_tasks.execute(user_id, tag).await?;
-            prompt_available_task(bot, msg, dialogue, tag, &tasks).await
+    let reply = Reply::to_message(&msg);
+    let Some(text) = msg.text() else {
+        return send_enter_message(&bot, &msg).await;
+    };
+    let answer = answer_task
+        .execute(
+            reply.user_id(),
+            &tag,
+            task_id,
+            AnswerInput::Text(text.to_string()),
+        )
+        .await?;
+    if answer.completed {
+        let task = get_task.execute(task_id).await?;
+        finish_task(
+            bot,
+            reply,
+            dialogue,
+            tag,
+            &task,
+            &answer,
+            get_available_tasks,
+        )
+        .await
+    } else {
+        send_answer_is_invalid(&bot, reply, &answer).await
+    }
+}
+
+/// Результат отправляется новыми сообщениями, а под ними — снова список заданий.
+async fn finish_task(
+    bot: Bot,
+    reply: Reply,
+    dialogue: BotDialogue,
+    tag: TrackTag,
+    task: &TaskDTO,
+    answer: &AnswerDTO,
+    get_available_tasks: GetAvailableTasks,
+) -> BotHandlerResult {
+    let locale = reply.locale;
+    send_answer_is_correct(&bot, reply).await?;
+    if let Some(text) = texts::score_breakdown(locale, &answer.score) {
+        reply.send(&bot, text).await?;
+    }
+    send_task_explanation(&bot, reply, task).await?;
+    if !answer.unlocked_characters.is_empty() {
+        bot.send_message(
+            reply.chat_id,
+            texts::characters_unlocked(locale, &answer.unlocked_characters),
+        )
+        .reply_markup(make_character_links_keyboard(&answer.unlocked_characters))
+        .parse_mode(ParseMode::Html)
+        .await?;
+    }
+    let tasks = get_available_tasks.execute(reply.user_id(), &tag).await?;
+    prompt_available_task(bot, reply.detached(), dialogue, tag, &tasks).await
+}
+
+/// Вариант ответа выбран кнопкой. Кнопки старых вопросов не совпадают
+/// с заданием из состояния диалога.
+async fn receive_task_option(
+    bot: Bot,
+    reply: Reply,
+    dialogue: BotDialogue,
+    state: BotState,
+    (task_id, index): (TaskID, usize),
+    answer_task: AnswerTask,
+    get_task: GetTask,
+    get_available_tasks: GetAvailableTasks,
+) -> BotHandlerResult {
+    let user_id = reply.user_id();
+    match state {
+        BotState::TaskAnswer(tag, id) if id == task_id => {
+            let task = get_task.execute(task_id).await?;
+            let Some(option) = task.options.get(index) else {
+                return send_button_unavailable(&bot, reply).await;
+            };
+            let answer = answer_task
+                .execute(
+                    user_id,
+                    &tag,
+                    task_id,
+                    AnswerInput::Text(option.to_string()),
+                )
+                .await?;
+            if answer.completed {
+                finish_task(
+                    bot,
+                    reply,
+                    dialogue,
+                    tag,
+                    &task,
+                    &answer,
+                    get_available_tasks,
+                )
+                .await
+            } else {
+                send_answer_is_invalid(&bot, reply, &answer).await
+            }
+        }
+        BotState::TaskMultiChoice(tag, id, mut selected) if id == task_id => {
+            let task = get_task.execute(task_id).await?;
+            if index >= task.options.len() {
+                return send_button_unavailable(&bot, reply).await;
+            }
+            if let Some(pos) = selected.iter().position(|&s| s == index) {
+                selected.remove(pos);
+            } else {
+                selected.push(index);
+            }
+            prompt_multi_choice_answer(bot, reply, dialogue, tag, &task, selected).await
         }
-        Some(text) => {
+        BotState::TaskOrdering(tag, id, mut chosen) if id == task_id => {
+            let task = get_task.execute(task_id).await?;
+            if index >= task.options.len() || chosen.contains(&index) {
+                return send_button_unavailable(&bot, reply).await;
+            }
+            chosen.push(index);
+            if chosen.len() < task.options.len() {
+                return prompt_ordering_answer(bot, reply, dialogue, tag, &task, chosen).await;
+            }
+            let items = chosen
+                .iter()
+                .map(|&i| task.options[i].to_string())
+                .collect();
             let answer = answer_task
-                .execute(user_id, tag, task_id, text.into())
+                .execute(user_id, &tag, task_id, AnswerInput::Items(items))
                 .await?;
             if answer.completed {
-                send_answer_is_correct(&bot, &msg).await?;
-                let task = get_task.execute(task_id).await?;
-                send_task_explanation(&bot, &msg, &task).await?;
-                let tasks = get_available_tasks.execute(user_id, tag).await?;
-                prompt_available_task(bot, msg, dialogue, tag, &tasks).await
+                finish_task(
+                    bot,
+                    reply,
+                    dialogue,
+                    tag,
+                    &task,
+                    &answer,
+                    get_available_tasks,
+                )
+                .await
             } else {
-                send_answer_is_invalid(&bot, &msg).await
+                send_answer_is_invalid(&bot, reply, &answer).await?;
+                prompt_ordering_answer(bot, reply.detached(), dialogue, tag, &task, vec![]).await
             }
         }
+        _ => send_button_unavailable(&bot, reply).await,
+    }
+}
+
+async fn receive_task_done(
+    bot: Bot,
+    reply: Reply,
+    dialogue: BotDialogue,
+    state: BotState,
+    task_id: TaskID,
+    answer_task: AnswerTask,
+    get_task: GetTask,
+    get_available_tasks: GetAvailableTasks,
+) -> BotHandlerResult {
+    let BotState::TaskMultiChoice(tag, id, selected) = state else {
+        return send_button_unavailable(&bot, reply).await;
+    };
+    if id != task_id {
+        return send_button_unavailable(&bot, reply).await;
+    }
+    if selected.is_empty() {
+        return reply
+            .send(&bot, texts::select_at_least_one_option(reply.locale))
+            .await;
+    }
+    let task = get_task.execute(task_id).await?;
+    let items = selected
+        .iter()
+        .map(|&i| task.options[i].to_string())
+        .collect();
+    let answer = answer_task
+        .execute(reply.user_id(), &tag, task_id, AnswerInput::Items(items))
+        .await?;
+    if answer.completed {
+        finish_task(
+            bot,
+            reply,
+            dialogue,
+            tag,
+            &task,
+            &answer,
+            get_available_tasks,
+        )
+        .await
+    } else {
+        send_answer_is_invalid(&bot, reply, &answer).await?;
+        prompt_multi_choice_answer(bot, reply.detached(), dialogue, tag, &task, vec![]).await
+    }
+}
+
+async fn receive_task_reset(
+    bot: Bot,
+    reply: Reply,
+    dialogue: BotDialogue,
+    state: BotState,
+    task_id: TaskID,
+    get_task: GetTask,
+) -> BotHandlerResult {
+    match state {
+        BotState::TaskOrdering(tag, id, _) if id == task_id => {
+            let task = get_task.execute(task_id).await?;
+            prompt_ordering_answer(bot, reply, dialogue, tag, &task, vec![]).await
+        }
+        _ => send_button_unavailable(&bot, reply).await,
+    }
+}
+
+async fn send_button_unavailable(bot: &Bot, reply: Reply) -> BotHandlerResult {
+    reply
+        .send(bot, texts::button_unavailable(reply.locale))
+        .await
+}
+
+/// В заданиях с вариантами ответ выбирается только кнопками.
+async fn receive_task_choice_text(bot: Bot, msg: Message) -> BotHandlerResult {
+    send_use_keyboard(&bot, &msg).await
+}
+
+async fn receive_task_location(
+    bot: Bot,
+    msg: Message,
+    dialogue: BotDialogue,
+    (tag, task_id): (TrackTag, TaskID),
+    answer_task: AnswerTask,
+    get_task: GetTask,
+    get_available_tasks: GetAvailableTasks,
+) -> BotHandlerResult {
+    let reply = Reply::to_message(&msg);
+    let Some(location) = msg.location() else {
+        return send_please_send_location(&bot, reply).await;
+    };
+    let point = GeoPoint::new(location.latitude, location.longitude)?;
+    let answer = answer_task
+        .execute(reply.user_id(), &tag, task_id, AnswerInput::Location(point))
+        .await?;
+    if answer.completed {
+        let task = get_task.execute(task_id).await?;
+        finish_task(
+            bot,
+            reply,
+            dialogue,
+            tag,
+            &task,
+            &answer,
+            get_available_tasks,
+        )
+        .await
+    } else {
+        send_answer_is_invalid(&bot, reply, &answer).await
     }
 }
 
@@ -384,60 +697,72 @@ async fn receive_task_photo(
     (tag, task_id): (TrackTag, TaskID),
     answer_task: AnswerTask,
     get_available_tasks: GetAvailableTasks,
-    upload_media: UploadMedia,
+    upload_photo_answer: UploadPhotoAnswer,
+    staff_chat: StaffChat,
 ) -> BotHandlerResult {
     let user_id = UserID::new(msg.chat.id.0);
-    match msg.text() {
-        Some(keyboards::BTN_BACK) => {
-            let tasks = get_available_tasks.execute(user_id, tag).await?;
-            prompt_available_task(bot, msg, dialogue, tag, &tasks).await?;
-            return Ok(());
-        }
-        _ => {}
-    }
-
     if let Some(photos) = msg.photo() {
-        let photo = photos.first().unwrap();
+        let photo = photos.last().unwrap();
         let file_id = FileID::new(photo.file.id.0.clone())?;
         let media_id = MediaID::new(new_pseudo_uuid(PHOTO_TASK_ANSWER_MEDIA_ID_LENGTH)).unwrap();
         let media = Media::image(media_id.clone(), file_id.clone());
-        upload_media.execute(media).await?;
+        upload_photo_answer.execute(media).await?;
         send_photo_answer_accepted(&bot, &msg).await?;
-        answer_task
-            .execute(user_id, tag, task_id, media_id.to_string())
+        let answer = answer_task
+            .execute(
+                user_id,
+                &tag,
+                task_id,
+                AnswerInput::Text(media_id.to_string()),
+            )
             .await?;
-        let tasks = get_available_tasks.execute(user_id, tag).await?;
-        prompt_available_task(bot, msg, dialogue, tag, &tasks).await
+        staff_chat
+            .notify_photo_answer(&bot, user_id, &tag, task_id, answer.number, file_id)
+            .await;
+        let tasks = get_available_tasks.execute(user_id, &tag).await?;
+        prompt_available_task(bot, Reply::to_message(&msg), dialogue, tag, &tasks).await
     } else {
         send_invalid_photo_sent(&bot, &msg).await
     }
 }
 
 async fn send_photo_answer_accepted(bot: &Bot, msg: &Message) -> BotHandlerResult {
-    bot.send_message(msg.chat.id, texts::PHOTO_TASK_ACCEPTED)
-        .reply_markup(make_back_keyboard())
+    let locale = Locale::of_message(msg);
+    bot.send_message(msg.chat.id, texts::photo_task_accepted(locale))
         .parse_mode(ParseMode::Html)
         .await?;
     Ok(())
 }
 
 async fn send_invalid_photo_sent(bot: &Bot, msg: &Message) -> BotHandlerResult {
-    bot.send_message(msg.chat.id, texts::PLEASE_SEND_PHOTO)
-        .reply_markup(make_back_keyboard())
+    let locale = Locale::of_message(msg);
+    bot.send_message(msg.chat.id, texts::please_send_photo(locale))
         .parse_mode(ParseMode::Html)
         .await?;
     Ok(())
 }
 
-async fn send_answer_is_correct(bot: &Bot, msg: &Message) -> BotHandlerResult {
-    bot.send_message(msg.chat.id, texts::correct_answer())
+/// Заодно убирает клавиатуру с кнопкой геопозиции.
+async fn send_answer_is_correct(bot: &Bot, reply: Reply) -> BotHandlerResult {
+    bot.send_message(reply.chat_id, texts::correct_answer(reply.locale))
+        .reply_markup(KeyboardRemove::new())
         .parse_mode(ParseMode::Html)
         .await?;
     Ok(())
 }
 
-async fn send_task_explanation(bot: &Bot, msg: &Message, task: &TaskDTO) -> BotHandlerResult {
-    bot.send_message(msg.chat.id, task.explanation.to_string())
+async fn send_task_explanation(bot: &Bot, reply: Reply, task: &TaskDTO) -> BotHandlerResult {
+    reply.send(bot, task.explanation.to_string()).await?;
+    send_task_character_links(bot, reply, task).await
+}
+
+/// Ссылки на карточки персонажей, упомянутых в пояснении.
+async fn send_task_character_links(bot: &Bot, reply: Reply, task: &TaskDTO) -> BotHandlerResult {
+    if task.characters.is_empty() {
+        return Ok(());
+    }
+    bot.send_message(reply.chat_id, texts::character_links(reply.locale))
+        .reply_markup(make_character_links_keyboard(&task.characters))
         .parse_mode(ParseMode::Html)
         .await?;
     Ok(())
@@ -445,19 +770,33 @@ async fn send_task_explanation(bot: &Bot, msg: &Message, task: &TaskDTO) -> BotH
 
 async fn send_task_question_and_explanation(
     bot: &Bot,
-    msg: &Message,
+    reply: Reply,
     task: &TaskDTO,
 ) -> BotHandlerResult {
-    bot.send_message(msg.chat.id, texts::task_question_and_explanation(&task))
-        .parse_mode(ParseMode::Html)
+    reply
+        .send(
+            bot,
+            texts::task_question_and_explanation(reply.locale, task),
+        )
         .await?;
-    Ok(())
+    send_task_character_links(bot, reply, task).await
 }
 
-async fn send_answer_is_invalid(bot: &Bot, msg: &Message) -> BotHandlerResult {
-    bot.send_message(msg.chat.id, texts::invalid_answer())
-        .parse_mode(ParseMode::Html)
-        .await?;
+async fn send_answer_is_invalid(bot: &Bot, reply: Reply, answer: &AnswerDTO) -> BotHandlerResult {
+    let locale = reply.locale;
+    let text = match answer.grade {
+        AnswerGrade::Close => texts::task_answer_is_close(locale),
+        AnswerGrade::Correct | AnswerGrade::Wrong => texts::invalid_answer(locale),
+    };
+    reply.send(bot, text).await?;
+    if let Some(hint) = &answer.hint {
+        reply.send(bot, texts::task_hint(locale, hint)).await?;
+    }
+    if answer.points.is_positive() {
+        reply
+            .send(bot, texts::partial_credit(locale, answer.points))
+            .await?;
+    }
     Ok(())
 }
 
@@ -465,11 +804,29 @@ pub fn tracks_scheme() -> UpdateHandler<AppError> {
     use dptree::case;
 
     Update::filter_message()
-        .branch(case![BotState::Track].endpoint(receive_track))
-        .branch(case![BotState::StartTrack(tag)].endpoint(receive_track_start))
-        .branch(case![BotState::TrackTaskGroup(tag)].endpoint(receive_tasks_group))
-        .branch(case![BotState::AvailableTask(tag)].endpoint(receive_available_task))
         .branch(case![BotState::TaskAnswer(tag, task_id)].endpoint(receive_task_answer))
-        .branch(case![BotState::CompletedTask(tag)].endpoint(receive_completed_task))
         .branch(case![BotState::TaskPhoto(tag, task_id)].endpoint(receive_task_photo))
+        .branch(
+            case![BotState::TaskMultiChoice(tag, task_id, selected)]
+                .endpoint(receive_task_choice_text),
+        )
+        .branch(
+            case![BotState::TaskOrdering(tag, task_id, chosen)].endpoint(receive_task_choice_text),
+        )
+        .branch(case![BotState::TaskLocation(tag, task_id)].endpoint(receive_task_location))
+}
+
+pub fn tracks_callbacks() -> UpdateHandler<AppError> {
+    use dptree::case;
+
+    dptree::entry()
+        .branch(case![CallbackData::Track(tag)].endpoint(receive_track))
+        .branch(case![CallbackData::StartTrack(tag)].endpoint(receive_track_start))
+        .branch(case![CallbackData::AvailableTasks(tag)].endpoint(receive_available_tasks))
+        .branch(case![CallbackData::CompletedTasks(tag)].endpoint(receive_completed_tasks))
+        .branch(case![CallbackData::AvailableTask(tag, task_id)].endpoint(receive_available_task))
+        .branch(case![CallbackData::CompletedTask(tag, task_id)].endpoint(receive_completed_task))
+        .branch(case![CallbackData::TaskOption(task_id, index)].endpoint(receive_task_option))
+        .branch(case![CallbackData::TaskDone(task_id)].endpoint(receive_task_done))
+        .branch(case![CallbackData::TaskReset(task_id)].endpoint(receive_task_reset))
 }
@@ -0,0 +1,172 @@
+use std::collections::{BTreeSet, HashMap};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+use fluent_bundle::concurrent::FluentBundle;
+use fluent_bundle::{FluentArgs, FluentError, FluentResource, FluentValue};
+use fluent_syntax::ast::Entry;
+use fluent_syntax::parser::ParserError;
+use teloxide::types::{Message, User};
+use thiserror::Error;
+use unic_langid::LanguageIdentifier;
+
+#[derive(Error, Debug)]
+pub enum I18nError {
+    #[error("unable to read locale file {0}: {1}")]
+    Read(PathBuf, std::io::Error),
+
+    #[error("unable to parse locale file {0}: {1:?}")]
+    Parse(PathBuf, Vec<ParserError>),
+
+    #[error("invalid messages in locale {0}: {1:?}")]
+    Bundle(&'static str, Vec<FluentError>),
+
+    #[error("locale {0} is missing keys: {1}")]
+    MissingKeys(&'static str, String),
+}
+
+/// Язык, на котором бот отвечает пользователю.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Locale {
+    Ru,
+    En,
+}
+
+impl Locale {
+    pub const ALL: [Locale; 2] = [Locale::Ru, Locale::En];
+
+    /// Для пользователей без `language_code` и для сообщений в общие чаты.
+    pub const DEFAULT: Locale = Locale::Ru;
+
+    pub fn code(&self) -> &'static str {
+        match self {
+            Locale::Ru => "ru",
+            Locale::En => "en",
+        }
+    }
+
+    /// Русский для русскоязычных и понимающих русский, английский для всех остальных.
+    pub fn from_language_code(code: Option<&str>) -> Self {
+        let Some(code) = code else {
+            return Self::DEFAULT;
+        };
+        let language = code.split(['-', '_']).next().unwrap_or_default();
+        match language.to_lowercase().as_str() {
+            "ru" | "uk" | "be" | "kk" => Locale::Ru,
+            _ => Locale::En,
+        }
+    }
+
+    pub fn of_user(user: &User) -> Self {
+        Self::from_language_code(user.language_code.as_deref())
+    }
+
+    pub fn of_message(msg: &Message) -> Self {
+        msg.from.as_ref().map_or(Self::DEFAULT, Self::of_user)
+    }
+}
+
+/// Шаблоны сообщений всех языков. Файлы лежат в `<dir>/<locale>/*.ftl` в формате Fluent.
+pub struct Catalog {
+    bundles: HashMap<Locale, FluentBundle<FluentResource>>,
+}
+
+impl Catalog {
+    /// Загружает все языки и проверяет, что в каждом есть все ключи.
+    pub fn load(dir: &Path) -> Result<Self, I18nError> {
+        let mut bundles = HashMap::new();
+        let mut keys = HashMap::new();
+        for locale in Locale::ALL {
+            let (bundle, locale_keys) = Self::load_locale(dir, locale)?;
+            bundles.insert(locale, bundle);
+            keys.insert(locale, locale_keys);
+        }
+
+        let all_keys: BTreeSet<&String> = keys.values().flatten().collect();
+        for locale in Locale::ALL {
+            let missing = all_keys
+                .iter()
+                .filter(|key| !keys[&locale].contains(**key))
+                .map(|key| key.as_str())
+                .collect::<Vec<_>>();
+            if !missing.is_empty() {
+                return Err(I18nError::MissingKeys(locale.code(), missing.join(", ")));
+            }
+        }
+        Ok(Self { bundles })
+    }
+
+    fn load_locale(
+        dir: &Path,
+        locale: Locale,
+    ) -> Result<(FluentBundle<FluentResource>, BTreeSet<String>), I18nError> {
+        let locale_dir = dir.join(locale.code());
+        let mut paths = fs::read_dir(&locale_dir)
+            .map_err(|err| I18nError::Read(locale_dir.clone(), err))?
+            .filter_map(|entry| entry.ok().map(|e| e.path()))
+            .filter(|path| path.extension().is_some_and(|ext| ext == "ftl"))
+            .collect::<Vec<_>>();
+        paths.sort();
+
+        let language: LanguageIdentifier = locale.code().parse().expect("valid language code");
+        let mut bundle = FluentBundle::new_concurrent(vec![language]);
+        // Иначе Fluent обрамляет подстановки невидимыми символами направления текста.
+        bundle.set_use_isolating(false);
+        let mut keys = BTreeSet::new();
+        for path in paths {
+            let source =
+                fs::read_to_string(&path).map_err(|err| I18nError::Read(path.clone(), err))?;
+            let resource = FluentResource::try_new(source)
+                .map_err(|(_, errors)| I18nError::Parse(path.clone(), errors))?;
+            for entry in resource.entries() {
+                if let Entry::Message(message) = entry {
+                    keys.insert(message.id.name.to_string());
+                }
+            }
+            bundle
+                .add_resource(resource)
+                .map_err(|errors| I18nError::Bundle(locale.code(), errors))?;
+        }
+        Ok((bundle, keys))
+    }
+
+    pub fn format(&self, locale: Locale, key: &str, args: Option<&FluentArgs>) -> String {
+        let bundle = &self.bundles[&locale];
+        let Some(pattern) = bundle.get_message(key).and_then(|m| m.value()) else {
+            log::error!("Missing text {key} in locale {}", locale.code());
+            return key.to_string();
+        };
+        let mut errors = Vec::new();
+        let text = bundle.format_pattern(pattern, args, &mut errors);
+        if !errors.is_empty() {
+            log::error!(
+                "Unable to format text {key} in locale {}: {errors:?}",
+                locale.code()
+            );
+        }
+        text.into_owned()
+    }
+}
+
+static CATALOG: OnceLock<Catalog> = OnceLock::new();
+
+/// Загружает шаблоны при запуске, до обработки первого сообщения.
+pub fn init(dir: &Path) -> Result<(), I18nError> {
+    let catalog = Catalog::load(dir)?;
+    let _ = CATALOG.set(catalog);
+    Ok(())
+}
+
+/// Текст сообщения `key` с подстановкой именованных параметров.
+pub fn tr(locale: Locale, key: &str, args: &[(&str, FluentValue)]) -> String {
+    let catalog = CATALOG.get().expect("texts are not loaded");
+    if args.is_empty() {
+        return catalog.format(locale, key, None);
+    }
+    let mut fluent_args = FluentArgs::with_capacity(args.len());
+    for (name, value) in args {
+        fluent_args.set(*name, value.clone());
+    }
+    catalog.format(locale, key, Some(&fluent_args))
+}
@@ -1,167 +1,515 @@
 use chrono::NaiveTime;
-use teloxide::types::{KeyboardButton, KeyboardMarkup};
+use teloxide::types::{
+    ButtonRequest, InlineKeyboardButton, InlineKeyboardMarkup, KeyboardButton, KeyboardMarkup,
+};
 
-use crate::app::usecases::dto::PlayerDTO;
-use crate::domain::models::{CharacterName, TaskID, TaskOption, TrackTag};
+use crate::app::usecases::dto::{
+    AuditPageDTO, CharacterDTO, CharacterItemDTO, CharacterLinkDTO, CharacterPageDTO, FeedbackDTO,
+    FeedbackPageDTO, MediaPageDTO, PlayerDTO, TaskItemDTO, TrackDTO,
+};
+use crate::bot::callbacks::{CALLBACK_DATA_MAX_LENGTH, CallbackData};
+use crate::bot::i18n::{Locale, tr};
+use crate::domain::models::{
+    FeedbackStatus, MenuItem, TaskID, TaskOption, TaskStatus, TrackTag, UserID,
+};
 
 type StaticStr = &'static str;
 
-pub const BTN_BACK: StaticStr = "Назад";
+fn button(text: impl Into<String>, data: CallbackData) -> InlineKeyboardButton {
+    InlineKeyboardButton::callback(text, data.to_string())
+}
+
+/// Подпись кнопки из шаблонов текстов.
+fn label(locale: Locale, key: &str) -> String {
+    tr(locale, key, &[])
+}
 
-pub fn make_back_keyboard() -> KeyboardMarkup {
-    KeyboardMarkup::new(vec![vec![KeyboardButton::new(BTN_BACK)]])
-        .resize_keyboard()
-        .one_time_keyboard()
+fn back_button(locale: Locale, to: CallbackData) -> InlineKeyboardButton {
+    button(label(locale, "btn-back"), to)
 }
 
-pub const BTN_START: StaticStr = "Начать";
+pub fn make_back_keyboard(locale: Locale, to: CallbackData) -> InlineKeyboardMarkup {
+    InlineKeyboardMarkup::new([[back_button(locale, to)]])
+}
 
-pub fn make_start_and_back_keyboard() -> KeyboardMarkup {
-    let buttons = vec![vec![
-        KeyboardButton::new(BTN_START),
-        KeyboardButton::new(BTN_BACK),
-    ]];
-    KeyboardMarkup::new(buttons)
-        .resize_keyboard()
-        .one_time_keyboard()
+pub fn make_start_track_keyboard(locale: Locale, tag: &TrackTag) -> InlineKeyboardMarkup {
+    InlineKeyboardMarkup::new([[
+        button(
+            label(locale, "btn-start"),
+            CallbackData::StartTrack(tag.clone()),
+        ),
+        back_button(locale, CallbackData::MenuItem(MenuItem::Tracks)),
+    ]])
 }
 
-pub fn make_tracks_keyboard_with_back(tracks: &[TrackTag]) -> KeyboardMarkup {
+pub fn make_tracks_keyboard(locale: Locale, tracks: &[TrackDTO]) -> InlineKeyboardMarkup {
     let mut keyboard = Vec::new();
     for chunk in tracks.chunks(2) {
         let row: Vec<_> = chunk
             .iter()
-            .map(|name| KeyboardButton::new(name.as_str()))
+            .map(|track| button(track.name.as_str(), CallbackData::Track(track.tag.clone())))
             .collect();
         keyboard.push(row);
     }
-    keyboard.push(vec![KeyboardButton::new(BTN_BACK)]);
-    KeyboardMarkup::new(keyboard)
-        .resize_keyboard()
-        .one_time_keyboard()
+    keyboard.push(vec![back_button(locale, CallbackData::Menu)]);
+    InlineKeyboardMarkup::new(keyboard)
 }
 
-pub fn make_characters_keyboard_with_back(names: &[CharacterName]) -> KeyboardMarkup {
-    let mut keyboard = Vec::new();
-    for chunk in names.chunks(3) {
-        let row: Vec<_> = chunk
-            .iter()
-            .map(|name| KeyboardButton::new(name.to_string()))
-            .collect();
-        keyboard.push(row);
+fn character_buttons(items: &[CharacterItemDTO]) -> Vec<Vec<InlineKeyboardButton>> {
+    items
+        .iter()
+        .map(|c| {
+            let text = if c.locked {
+                format!("🔒 {}. ???", c.index)
+            } else {
+                let star = if c.favorite { " ⭐" } else { "" };
+                format!("{}. {}{star}", c.index, c.name.as_str())
+            };
+            vec![button(text, CallbackData::Character(c.id.clone()))]
+        })
+        .collect()
+}
+
+pub fn make_character_page_keyboard(
+    locale: Locale,
+    page: &CharacterPageDTO,
+) -> InlineKeyboardMarkup {
+    let mut keyboard = character_buttons(&page.items);
+
+    let mut navigation = Vec::new();
+    if page.page > 0 {
+        navigation.push(button(
+            "⬅️",
+            CallbackData::CharacterPage(page.favorites_only, page.page - 1),
+        ));
+    }
+    if page.page + 1 < page.pages {
+        navigation.push(button(
+            "➡️",
+            CallbackData::CharacterPage(page.favorites_only, page.page + 1),
+        ));
+    }
+    if !navigation.is_empty() {
+        keyboard.push(navigation);
     }
-    keyboard.push(vec![KeyboardButton::new(BTN_BACK)]);
-    KeyboardMarkup::new(keyboard)
-        .resize_keyboard()
-        .one_time_keyboard()
+    keyboard.push(vec![if page.favorites_only {
+        button(
+            label(locale, "btn-all-characters"),
+            CallbackData::CharacterPage(false, 0),
+        )
+    } else {
+        button(
+            label(locale, "btn-favorite-characters"),
+            CallbackData::CharacterPage(true, 0),
+        )
+    }]);
+    InlineKeyboardMarkup::new(keyboard)
 }
 
-pub const BTN_AVAILABLE_TASKS: StaticStr = "Доступные задания";
-pub const BTN_COMPLETED_TASKS: StaticStr = "Решённые задания";
+pub fn make_characters_found_keyboard(items: &[CharacterItemDTO]) -> InlineKeyboardMarkup {
+    InlineKeyboardMarkup::new(character_buttons(items))
+}
 
-pub fn make_tasks_group_keyboard_with_back(is_captain: bool) -> KeyboardMarkup {
-    let mut first_row = vec![KeyboardButton::new(BTN_COMPLETED_TASKS)];
-    if is_captain {
-        first_row.push(KeyboardButton::new(BTN_AVAILABLE_TASKS));
+pub fn make_character_keyboard(locale: Locale, character: &CharacterDTO) -> InlineKeyboardMarkup {
+    let mut row = Vec::new();
+    if let Some(previous) = &character.previous {
+        row.push(button(
+            "⬅️",
+            CallbackData::CharacterSwitch(previous.clone()),
+        ));
+    }
+    if character.locked.is_none() {
+        row.push(button(
+            label(
+                locale,
+                if character.favorite {
+                    "btn-character-in-favorites"
+                } else {
+                    "btn-add-character-to-favorites"
+                },
+            ),
+            CallbackData::CharacterFavorite(character.id.clone()),
+        ));
     }
-    let buttons = vec![first_row, vec![KeyboardButton::new(BTN_BACK)]];
-    KeyboardMarkup::new(buttons)
-        .resize_keyboard()
-        .one_time_keyboard()
+    if let Some(next) = &character.next {
+        row.push(button("➡️", CallbackData::CharacterSwitch(next.clone())));
+    }
+    InlineKeyboardMarkup::new([
+        row,
+        vec![button(
+            label(locale, "btn-all-characters"),
+            CallbackData::CharacterPage(false, 0),
+        )],
+    ])
 }
 
-pub const BTN_TASK_ID_PREFIX: StaticStr = "Задание ";
+/// Ссылки на карточки персонажей, упомянутых в пояснении к заданию.
+pub fn make_character_links_keyboard(characters: &[CharacterLinkDTO]) -> InlineKeyboardMarkup {
+    InlineKeyboardMarkup::new(characters.iter().map(|c| {
+        vec![button(
+            format!("👤 {}", c.name.as_str()),
+            CallbackData::Character(c.id.clone()),
+        )]
+    }))
+}
 
-pub fn make_tasks_keyboard_with_back(task_ids: &[TaskID]) -> KeyboardMarkup {
-    let mut keyboard = Vec::new();
-    for chunk in task_ids.chunks(4) {
-        let row: Vec<_> = chunk
-            .iter()
-            .map(|&id| KeyboardButton::new(format!("{BTN_TASK_ID_PREFIX}{id}")))
-            .collect();
-        keyboard.push(row);
+pub fn make_tasks_group_keyboard(
+    locale: Locale,
+    tag: &TrackTag,
+    is_captain: bool,
+) -> InlineKeyboardMarkup {
+    let mut first_row = vec![button(
+        label(locale, "btn-completed-tasks"),
+        CallbackData::CompletedTasks(tag.clone()),
+    )];
+    if is_captain {
+        first_row.push(button(
+            label(locale, "btn-available-tasks"),
+            CallbackData::AvailableTasks(tag.clone()),
+        ));
     }
-    keyboard.push(vec![KeyboardButton::new(BTN_BACK)]);
-    KeyboardMarkup::new(keyboard)
-        .resize_keyboard()
-        .one_time_keyboard()
+    InlineKeyboardMarkup::new([
+        first_row,
+        vec![back_button(
+            locale,
+            CallbackData::MenuItem(MenuItem::Tracks),
+        )],
+    ])
 }
 
-pub fn make_options_keyboard_with_back(options: &[TaskOption]) -> KeyboardMarkup {
-    let mut keyboard = Vec::new();
-    for chunk in options.chunks(2) {
-        let row: Vec<_> = chunk
-            .iter()
-            .map(|option| KeyboardButton::new(option.to_string()))
-            .collect();
-        keyboard.push(row);
+fn task_status_marker(status: TaskStatus) -> StaticStr {
+    match status {
+        TaskStatus::NotAvailable | TaskStatus::Available => "🆕",
+        TaskStatus::InProgress => "🔁",
+        TaskStatus::UnderReview => "⏳",
+        TaskStatus::Completed => "✅",
+    }
+}
+
+fn task_button_text(locale: Locale, task: &TaskItemDTO) -> String {
+    let marker = task_status_marker(task.status);
+    match &task.title {
+        Some(title) => format!("{marker} {}. {}", task.number, title.as_str()),
+        None => format!(
+            "{marker} {}",
+            tr(locale, "btn-task", &[("number", task.number.into())])
+        ),
     }
-    keyboard.push(vec![KeyboardButton::new(BTN_BACK)]);
-    KeyboardMarkup::new(keyboard)
-        .resize_keyboard()
-        .one_time_keyboard()
 }
 
-pub fn make_slot_start_keyboard_with_back(times: &[NaiveTime]) -> KeyboardMarkup {
+/// `completed` — список решённых заданий, их можно только перечитать.
+pub fn make_tasks_keyboard(
+    locale: Locale,
+    tag: &TrackTag,
+    tasks: &[TaskItemDTO],
+    completed: bool,
+) -> InlineKeyboardMarkup {
+    let mut keyboard: Vec<_> = tasks
+        .iter()
+        .map(|task| {
+            let data = if completed {
+                CallbackData::CompletedTask(tag.clone(), task.id)
+            } else {
+                CallbackData::AvailableTask(tag.clone(), task.id)
+            };
+            vec![button(task_button_text(locale, task), data)]
+        })
+        .collect();
+    keyboard.push(vec![back_button(locale, CallbackData::Track(tag.clone()))]);
+    InlineKeyboardMarkup::new(keyboard)
+}
+
+/// Из вопроса задания можно вернуться к списку доступных заданий трека.
+pub fn make_task_back_keyboard(locale: Locale, tag: &TrackTag) -> InlineKeyboardMarkup {
+    make_back_keyboard(locale, CallbackData::AvailableTasks(tag.clone()))
+}
+
+fn option_rows(task_id: TaskID, options: Vec<(usize, String)>) -> Vec<Vec<InlineKeyboardButton>> {
+    options
+        .chunks(2)
+        .map(|chunk| {
+            chunk
+                .iter()
+                .map(|(i, text)| button(text.as_str(), CallbackData::TaskOption(task_id, *i)))
+                .collect()
+        })
+        .collect()
+}
+
+pub fn make_options_keyboard(
+    locale: Locale,
+    tag: &TrackTag,
+    task_id: TaskID,
+    options: &[TaskOption],
+) -> InlineKeyboardMarkup {
+    let options = options.iter().map(|o| o.to_string()).enumerate().collect();
+    let mut keyboard = option_rows(task_id, options);
+    keyboard.push(vec![back_button(
+        locale,
+        CallbackData::AvailableTasks(tag.clone()),
+    )]);
+    InlineKeyboardMarkup::new(keyboard)
+}
+
+const SELECTED_OPTION_PREFIX: StaticStr = "✅ ";
+
+pub fn make_multi_choice_keyboard(
+    locale: Locale,
+    tag: &TrackTag,
+    task_id: TaskID,
+    options: &[TaskOption],
+    selected: &[usize],
+) -> InlineKeyboardMarkup {
+    let options = options
+        .iter()
+        .enumerate()
+        .map(|(i, option)| {
+            if selected.contains(&i) {
+                (i, format!("{SELECTED_OPTION_PREFIX}{}", option.as_str()))
+            } else {
+                (i, option.to_string())
+            }
+        })
+        .collect();
+    let mut keyboard = option_rows(task_id, options);
+    keyboard.push(vec![
+        button(label(locale, "btn-done"), CallbackData::TaskDone(task_id)),
+        back_button(locale, CallbackData::AvailableTasks(tag.clone())),
+    ]);
+    InlineKeyboardMarkup::new(keyboard)
+}
+
+pub fn make_ordering_keyboard(
+    locale: Locale,
+    tag: &TrackTag,
+    task_id: TaskID,
+    options: &[TaskOption],
+    chosen: &[usize],
+) -> InlineKeyboardMarkup {
+    let remaining = options
+        .iter()
+        .enumerate()
+        .filter(|(i, _)| !chosen.contains(i))
+        .map(|(i, option)| (i, option.to_string()))
+        .collect();
+    let mut keyboard = option_rows(task_id, remaining);
+    keyboard.push(vec![
+        button(label(locale, "btn-reset"), CallbackData::TaskReset(task_id)),
+        back_button(locale, CallbackData::AvailableTasks(tag.clone())),
+    ]);
+    InlineKeyboardMarkup::new(keyboard)
+}
+
+/// Геопозицию можно запросить только кнопкой обычной клавиатуры.
+pub fn make_location_keyboard(locale: Locale) -> KeyboardMarkup {
+    KeyboardMarkup::new([[
+        KeyboardButton::new(label(locale, "btn-send-location")).request(ButtonRequest::Location)
+    ]])
+    .resize_keyboard()
+    .one_time_keyboard()
+}
+
+pub fn make_accept_final_keyboard(locale: Locale) -> InlineKeyboardMarkup {
+    InlineKeyboardMarkup::new([[
+        button(label(locale, "btn-start"), CallbackData::SlotStarts),
+        back_button(locale, CallbackData::Menu),
+    ]])
+}
+
+pub fn make_slot_start_keyboard(locale: Locale, times: &[NaiveTime]) -> InlineKeyboardMarkup {
     let mut keyboard = Vec::new();
     for chunk in times.chunks(3) {
         let row: Vec<_> = chunk
             .iter()
-            .map(|time| KeyboardButton::new(time.format("%H:%M").to_string()))
+            .map(|&time| {
+                button(
+                    time.format("%H:%M").to_string(),
+                    CallbackData::SlotStart(time),
+                )
+            })
             .collect();
         keyboard.push(row);
     }
-    keyboard.push(vec![KeyboardButton::new(BTN_BACK)]);
-    KeyboardMarkup::new(keyboard)
-        .resize_keyboard()
-        .one_time_keyboard()
+    keyboard.push(vec![back_button(locale, CallbackData::Menu)]);
+    InlineKeyboardMarkup::new(keyboard)
 }
 
-pub const BTN_CAN_NOT_ACCEPT_FINAL: StaticStr = "Не смогу прийти на финал";
-pub const BTN_CHANGE_RESERVATION_TIME: StaticStr = "Хочу изменить время";
+pub fn make_cancel_reservation_keyboard(locale: Locale) -> InlineKeyboardMarkup {
+    InlineKeyboardMarkup::new([
+        vec![
+            button(
+                label(locale, "btn-can-not-accept-final"),
+                CallbackData::CancelReservation(false),
+            ),
+            button(
+                label(locale, "btn-change-reservation-time"),
+                CallbackData::CancelReservation(true),
+            ),
+        ],
+        vec![back_button(locale, CallbackData::Menu)],
+    ])
+}
 
-pub fn make_cancel_reservation_keyboard_with_back() -> KeyboardMarkup {
+pub fn make_menu_keyboard(locale: Locale, player: &PlayerDTO) -> InlineKeyboardMarkup {
+    let item = |key: &str, item: MenuItem| button(label(locale, key), CallbackData::MenuItem(item));
     let mut buttons = Vec::new();
 
-    buttons.push(vec![
-        KeyboardButton::new(BTN_CAN_NOT_ACCEPT_FINAL),
-        KeyboardButton::new(BTN_CHANGE_RESERVATION_TIME),
-    ]);
-    buttons.push(vec![KeyboardButton::new(BTN_BACK)]);
+    if player.is_captain && player.is_enabled(MenuItem::FinalReservation) {
+        if !player.reserved_slot {
+            buttons.push(vec![item("btn-reserve-slot", MenuItem::FinalReservation)])
+        } else {
+            buttons.push(vec![item(
+                "btn-cancel-reservation",
+                MenuItem::FinalReservation,
+            )])
+        }
+    }
 
-    KeyboardMarkup::new(buttons)
-        .resize_keyboard()
-        .one_time_keyboard()
-}
+    let mut row = Vec::new();
+    if player.is_enabled(MenuItem::Tracks) {
+        row.push(item("btn-tracks", MenuItem::Tracks));
+    }
+    if !player.solo_team && player.is_enabled(MenuItem::Team) {
+        row.push(item("btn-my-team", MenuItem::Team));
+    }
+    if !row.is_empty() {
+        buttons.push(row);
+    }
 
-pub const BTN_MY_TEAM: StaticStr = "Моя команда";
-pub const BTN_TRACKS: StaticStr = "Треки";
-pub const BTN_CHARACTERS: StaticStr = "Личности";
-pub const BTN_GIVE_FEEDBACK: StaticStr = "Комментарий";
-pub const BTN_RESERVE_SLOT: StaticStr = "Записаться на финал";
-pub const BTN_CANCEL_RESERVATION: StaticStr = "Отменить запись";
+    if player.is_enabled(MenuItem::Characters) {
+        buttons.push(vec![item("btn-characters", MenuItem::Characters)]);
+    }
+    if player.is_enabled(MenuItem::Feedback) {
+        buttons.push(vec![item("btn-give-feedback", MenuItem::Feedback)]);
+    }
 
-pub fn make_menu_keyboard(player: &PlayerDTO) -> KeyboardMarkup {
-    let mut buttons = Vec::new();
+    InlineKeyboardMarkup::new(buttons)
+}
 
-    /*if player.is_captain {
-        if !player.reserved_slot {
-            buttons.push(vec![KeyboardButton::new(BTN_RESERVE_SLOT)])
-        } else {
-            buttons.push(vec![KeyboardButton::new(BTN_CANCEL_RESERVATION)])
+/// Длинный запрос обрезается, чтобы уместиться в callback data.
+fn media_page_callback(page: usize, query: &str) -> String {
+    let mut data = CallbackData::MediaPage(page, String::new()).to_string();
+    for c in query.chars() {
+        if data.len() + c.len_utf8() > CALLBACK_DATA_MAX_LENGTH {
+            break;
         }
-    }*/
+        data.push(c);
+    }
+    data
+}
+
+pub fn make_media_page_keyboard(page: &MediaPageDTO, query: &str) -> InlineKeyboardMarkup {
+    let mut keyboard: Vec<Vec<InlineKeyboardButton>> = page
+        .items
+        .iter()
+        .filter_map(|m| {
+            let data = CallbackData::Media(m.id.clone()).to_string();
+            (data.len() <= CALLBACK_DATA_MAX_LENGTH)
+                .then(|| vec![InlineKeyboardButton::callback(m.id.as_str(), data)])
+        })
+        .collect();
 
-    /*let mut row = vec![KeyboardButton::new(BTN_TRACKS)];
-    if !player.solo_team {
-        row.push(KeyboardButton::new(BTN_MY_TEAM));
+    let mut navigation = Vec::new();
+    if page.page > 0 {
+        navigation.push(InlineKeyboardButton::callback(
+            "⬅️",
+            media_page_callback(page.page - 1, query),
+        ));
     }
-    buttons.push(row);*/
+    if page.page + 1 < page.pages {
+        navigation.push(InlineKeyboardButton::callback(
+            "➡️",
+            media_page_callback(page.page + 1, query),
+        ));
+    }
+    if !navigation.is_empty() {
+        keyboard.push(navigation);
+    }
+    InlineKeyboardMarkup::new(keyboard)
+}
 
-    //buttons.push(vec![KeyboardButton::new(BTN_CHARACTERS)]);
-    buttons.push(vec![KeyboardButton::new(BTN_GIVE_FEEDBACK)]);
+/// `args` — фильтр из команды `/audit`. Если фильтр не помещается в callback data,
+/// листать журнал можно только командой.
+pub fn make_audit_page_keyboard(page: &AuditPageDTO, args: &str) -> InlineKeyboardMarkup {
+    let callback = |page: usize| {
+        let data = CallbackData::AuditPage(page, args.to_string()).to_string();
+        (data.len() <= CALLBACK_DATA_MAX_LENGTH).then_some(data)
+    };
+    let mut navigation = Vec::new();
+    if page.page > 0
+        && let Some(data) = callback(page.page - 1)
+    {
+        navigation.push(InlineKeyboardButton::callback("⬅️", data));
+    }
+    if page.page + 1 < page.pages
+        && let Some(data) = callback(page.page + 1)
+    {
+        navigation.push(InlineKeyboardButton::callback("➡️", data));
+    }
+    InlineKeyboardMarkup::new([navigation])
+}
+
+/// `status` — код фильтра из команды `/inbox`.
+pub fn make_feedback_page_keyboard(page: &FeedbackPageDTO, status: &str) -> InlineKeyboardMarkup {
+    let mut keyboard: Vec<Vec<InlineKeyboardButton>> = page
+        .items
+        .iter()
+        .map(|f| {
+            vec![button(
+                format!("#{} {}", f.id, f.author.full_name.as_str()),
+                CallbackData::Feedback(f.id),
+            )]
+        })
+        .collect();
+
+    let mut navigation = Vec::new();
+    if page.page > 0 {
+        navigation.push(button(
+            "⬅️",
+            CallbackData::FeedbackPage(status.to_string(), page.page - 1),
+        ));
+    }
+    if page.page + 1 < page.pages {
+        navigation.push(button(
+            "➡️",
+            CallbackData::FeedbackPage(status.to_string(), page.page + 1),
+        ));
+    }
+    if !navigation.is_empty() {
+        keyboard.push(navigation);
+    }
+    InlineKeyboardMarkup::new(keyboard)
+}
+
+pub fn make_feedback_keyboard(locale: Locale, feedback: &FeedbackDTO) -> InlineKeyboardMarkup {
+    if feedback.status == FeedbackStatus::Archived {
+        return InlineKeyboardMarkup::default();
+    }
+    InlineKeyboardMarkup::new([[
+        button(
+            label(locale, "btn-reply-feedback"),
+            CallbackData::FeedbackReply(feedback.id),
+        ),
+        button(
+            label(locale, "btn-archive-feedback"),
+            CallbackData::FeedbackArchive(feedback.id),
+        ),
+    ]])
+}
 
-    KeyboardMarkup::new(buttons)
-        .resize_keyboard()
-        .one_time_keyboard()
+pub fn make_photo_review_keyboard(
+    locale: Locale,
+    user_id: UserID,
+    task_id: TaskID,
+) -> InlineKeyboardMarkup {
+    InlineKeyboardMarkup::new([[
+        button(
+            label(locale, "btn-approve-photo"),
+            CallbackData::PhotoReview(user_id, task_id, true),
+        ),
+        button(
+            label(locale, "btn-reject-photo"),
+            CallbackData::PhotoReview(user_id, task_id, false),
+        ),
+    ]])
 }
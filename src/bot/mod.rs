@@ -4,10 +4,15 @@ use teloxide::dispatching::dialogue::{InMemStorageError, PostgresStorageError};
 
 use crate::app::error::AppError;
 
+mod callbacks;
+pub mod command_list;
 pub mod dispatcher;
-mod fsm;
+mod errors;
+pub mod fsm;
 mod handlers;
+pub mod i18n;
 mod keyboards;
+pub mod staff_chat;
 mod texts;
 
 pub type BotHandlerResult = Result<(), AppError>;
@@ -0,0 +1,149 @@
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+
+use teloxide::error_handlers::ErrorHandler;
+use teloxide::prelude::*;
+use teloxide::types::{InlineKeyboardMarkup, InputFile, ParseMode};
+
+use crate::app::error::AppError;
+use crate::app::usecases::GetProfile;
+use crate::app::usecases::dto::Profile;
+use crate::bot::i18n::Locale;
+use crate::bot::keyboards::make_photo_review_keyboard;
+use crate::bot::texts;
+use crate::domain::models::{FeedbackText, FileID, TaskID, TrackTag, UserID};
+
+/// Групповой чат организаторов, куда бот присылает уведомления.
+///
+/// Ошибки отправки только логируются: уведомление не должно ломать сценарий игрока.
+#[derive(Clone)]
+pub struct StaffChat {
+    chat_id: Option<ChatId>,
+    get_profile: GetProfile,
+}
+
+impl StaffChat {
+    pub fn new(chat_id: Option<ChatId>, get_profile: GetProfile) -> Self {
+        Self {
+            chat_id,
+            get_profile,
+        }
+    }
+
+    pub async fn notify_feedback(&self, bot: &Bot, user_id: UserID, text: &FeedbackText) {
+        if let Some(profile) = self.profile(user_id).await {
+            self.notify(bot, texts::staff_feedback(Locale::DEFAULT, &profile, text))
+                .await;
+        }
+    }
+
+    pub async fn notify_photo_answer(
+        &self,
+        bot: &Bot,
+        user_id: UserID,
+        track: &TrackTag,
+        task_id: TaskID,
+        number: usize,
+        file_id: FileID,
+    ) {
+        if let Some(profile) = self.profile(user_id).await {
+            self.notify_with_photo(
+                bot,
+                file_id,
+                texts::staff_photo_answer(Locale::DEFAULT, &profile, track, number),
+                make_photo_review_keyboard(Locale::DEFAULT, user_id, task_id),
+            )
+            .await;
+        }
+    }
+
+    pub async fn notify_reservation_cancelled(
+        &self,
+        bot: &Bot,
+        user_id: UserID,
+        rescheduled: bool,
+    ) {
+        if let Some(profile) = self.profile(user_id).await {
+            self.notify(
+                bot,
+                texts::staff_reservation_cancelled(Locale::DEFAULT, &profile, rescheduled),
+            )
+            .await;
+        }
+    }
+
+    async fn profile(&self, user_id: UserID) -> Option<Profile> {
+        self.chat_id?;
+        match self.get_profile.execute(user_id).await {
+            Ok(profile) => Some(profile),
+            Err(err) => {
+                log::error!("Unable to load profile of {user_id:?} for staff chat: {err}");
+                None
+            }
+        }
+    }
+
+    async fn notify(&self, bot: &Bot, text: String) {
+        let Some(chat_id) = self.chat_id else {
+            return;
+        };
+        if let Err(err) = bot
+            .send_message(chat_id, text)
+            .parse_mode(ParseMode::Html)
+            .await
+        {
+            log::error!("Unable to notify staff chat: {err}");
+        }
+    }
+
+    async fn notify_with_photo(
+        &self,
+        bot: &Bot,
+        file_id: FileID,
+        caption: String,
+        keyboard: InlineKeyboardMarkup,
+    ) {
+        let Some(chat_id) = self.chat_id else {
+            return;
+        };
+        if let Err(err) = bot
+            .send_photo(chat_id, InputFile::file_id(file_id.into()))
+            .caption(caption)
+            .parse_mode(ParseMode::Html)
+            .reply_markup(keyboard)
+            .await
+        {
+            log::error!("Unable to notify staff chat: {err}");
+        }
+    }
+}
+
+/// Логирует ошибки обработчиков и сообщает о них организаторам.
+pub struct StaffErrorHandler {
+    bot: Bot,
+    staff_chat: StaffChat,
+}
+
+impl StaffErrorHandler {
+    pub fn new(bot: Bot, staff_chat: StaffChat) -> Arc<Self> {
+        Arc::new(Self { bot, staff_chat })
+    }
+}
+
+impl ErrorHandler<AppError> for StaffErrorHandler {
+    fn handle_error(
+        self: Arc<Self>,
+        error: AppError,
+    ) -> Pin<Box<dyn Future<Output = ()> + Send + 'static>> {
+        log::error!("Error while handling update: {error}");
+        Box::pin(async move {
+            self.staff_chat
+                .notify(
+                    &self.bot,
+                    texts::staff_internal_error(Locale::DEFAULT, &error),
+                )
+                .await;
+        })
+    }
+}
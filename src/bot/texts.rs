@@ -1,185 +1,1125 @@
+use crate::app::error::AppError;
 use crate::app::usecases::dto::{
-    CharacterDTO, SlotDTO, TaskDTO, TeamWithMembersDTO, TrackDescriptionDTO, TrackInProgressDTO,
+    AuditPageDTO, CharacterCollectionDTO, CharacterDTO, CharacterLinkDTO, CharacterPageDTO,
+    CharacterUnlockDTO, FeedbackDTO, FeedbackPageDTO, MediaPageDTO, Profile, ReservationDTO,
+    StaffMemberDTO, TaskDTO, TeamWithMembersDTO, TrackDescriptionDTO, TrackInProgressDTO, UserDTO,
 };
-use crate::domain::models::{FileID, MediaID, TrackStatus};
-use chrono::{Duration, Utc};
+use crate::bot::i18n::{Locale, tr};
+use crate::config::{EventConfig, PhasesConfig, RegistrationConfig};
+use crate::domain::error::DomainError;
+use crate::domain::models::{
+    AuditAction, DanglingMediaReference, EventPhase, FeedbackID, FeedbackStatus, FeedbackText,
+    FileID, MediaID, MediaType, MediaUsage, Points, Role, ScoreBreakdown, TaskGraphIssue, TaskID,
+    TaskOption, TaskText, TaskType, TrackStatus, TrackTag, UserID, format_cycle,
+};
+use chrono::{Datelike, Duration, NaiveDate, Utc};
 use rand::seq::IndexedRandom;
+use teloxide::utils::html;
 
 type StaticStr = &'static str;
 
-pub const ENTER_MESSAGE_TEXT: StaticStr = "📝 Напиши мне текстовое сообщение";
+// Сами тексты лежат в locales/<язык>/*.ftl, здесь только подстановка параметров.
 
-pub const USE_KEYBOARD: StaticStr =
-    "Пожалуйста, используй кнопки клавиатуры внизу для ответа. Так будет удобнее!";
+pub fn enter_message_text(locale: Locale) -> String {
+    tr(locale, "enter-message-text", &[])
+}
 
-pub const PERMISSION_DENIED: StaticStr = "<b>❌ Доступ запрещен</b>\n\
-    У вас недостаточно прав для использования этой команды.";
+pub fn use_keyboard(locale: Locale) -> String {
+    tr(locale, "use-keyboard", &[])
+}
 
-pub const INTERNAL_ERROR: StaticStr = "⚠️ <b>Неизвестная ошибка</b>\n\
-     Произошла непредвиденная ошибка. Пожалуйста, попробуй повторить действие позже или сообщи \
-     об этом организаторам (см. описание бота).";
+pub fn permission_denied(locale: Locale) -> String {
+    tr(locale, "permission-denied", &[])
+}
 
-pub const MENU_TEXT: StaticStr = "📲 <b>Главное меню</b>\n\
-    Выбери нужный пункт из меню ниже.";
+pub fn internal_error(locale: Locale) -> String {
+    tr(locale, "internal-error", &[])
+}
 
-pub const UNKNOWN_MENU_OPTION: StaticStr = "❌ <b>Непонятная команда</b>\n\
-     К сожалению, я не понимаю этот запрос. Пожалуйста, выбери одну из доступных опций в меню.";
+/// Понятное пользователю описание ошибки. `None` — ошибка неожиданная,
+/// пользователю показывается `internal_error`, а организаторам — подробности.
+pub fn error_message(locale: Locale, error: &AppError) -> Option<String> {
+    let key = match error {
+        AppError::DomainError(error) => match error {
+            DomainError::InvalidValue(_) => "error-invalid-value",
+            DomainError::TeamIsFull(_) => "error-team-is-full",
+            DomainError::UserAlreadyInTeam(..) => "error-already-in-team",
+            DomainError::UserIsNotMemberOfTeam(_) => "error-not-in-team",
+            DomainError::TrackCanNotBeStarted(_) => "error-track-can-not-be-started",
+            DomainError::TrackNotStarted(_) => "track-not-started",
+            DomainError::CanNotReserveSlot(..) => "error-slot-unavailable",
+            DomainError::TeamAlreadyReservedSlot(..) => "error-slot-already-reserved",
+            DomainError::UserNotReservedSlot(..) | DomainError::TeamNotReservedSlot(_) => {
+                "error-slot-not-reserved"
+            }
+            DomainError::FeedbackIsArchived(_) => "feedback-is-archived",
+            DomainError::TrackCanNotBeFinished(_) => return None,
+        },
+        AppError::UserNotFound(_) | AppError::TeamNotFound(_) | AppError::UserNotInTeam(_) => {
+            "error-not-in-team"
+        }
+        AppError::MediaNotFound(_)
+        | AppError::FeedbackNotFound(_)
+        | AppError::TaskNotFound(_)
+        | AppError::AnswerNotFound(_)
+        | AppError::CharacterNotFound(_)
+        | AppError::TrackNotFound(_) => "error-not-found",
+        AppError::AnswerNotPending(_) => "photo-answer-already-reviewed",
+        AppError::PermissionDenied(_) => "permission-denied",
+        AppError::CanNotRevokeOwnRole(..) => "can-not-revoke-own-role",
+        AppError::SlotNotFound(_) => "error-slot-unavailable",
+        AppError::NoAvailableSlots(_, team_size) => return Some(no_slots(locale, *team_size)),
+        AppError::PlacesGreaterThanTeamSize(_, team_size) => {
+            return Some(places_greater_than_team(locale, *team_size));
+        }
+        AppError::MediaAlreadyExists(media_id) => {
+            return Some(media_already_exists(locale, media_id));
+        }
+        AppError::MediaInUse(media_id, usages) => {
+            return Some(media_in_use(locale, media_id, usages));
+        }
+        AppError::Internal(_) => return None,
+    };
+    Some(tr(locale, key, &[]))
+}
 
-pub const SEND_APPROVAL_EXIT_TEAM: StaticStr =
-    "❓ Ты действительно хочешь выйти из своей текущей команды?";
+pub fn menu_text(locale: Locale) -> String {
+    tr(locale, "menu-text", &[])
+}
 
-pub const SUCCESSFUL_EXIT_TEAM: StaticStr = "👋 <b>Ты вышел из команды</b>\n\
-     Ты успешно покинул(а) команду. Теперь ты можешь вступить в новую, используя код-приглашение.";
+pub fn unknown_menu_option(locale: Locale) -> String {
+    tr(locale, "unknown-menu-option", &[])
+}
 
-pub const INVALID_UPLOAD_COMMAND_USAGE: StaticStr = "<b>❌ Неверный формат команды</b>\n\
-    Использование:\n\
-    <code>/upload key</code>";
+pub fn section_closed(locale: Locale) -> String {
+    tr(locale, "section-closed", &[])
+}
 
-pub const INVALID_MEDIA_COMMAND_USAGE: StaticStr = "<b>❌ Неверный формат команды</b>\n\
-    Использование:\n\
-    <code>/media key</code>";
+pub fn operation_cancelled(locale: Locale) -> String {
+    tr(locale, "operation-cancelled", &[])
+}
 
-pub const PROMPT_MEDIA: StaticStr = "📤 <b>Загрузка файла</b>\n\
-    Отправьте файл (изображение или видеосообщение) в чат, чтобы получить его FileID.";
+pub fn nothing_to_cancel(locale: Locale) -> String {
+    tr(locale, "nothing-to-cancel", &[])
+}
 
-pub const UNKNOWN_MEDIA_FORMAT: StaticStr = "❌ <b>Формат не поддерживается</b>
-    Отправьте изображение или видеосообщение.";
+pub fn state_reset(locale: Locale) -> String {
+    tr(locale, "state-reset", &[])
+}
 
-pub const PROMPT_CHARACTER_NAME: StaticStr = "☺️ <b>Отличный выбор!</b>\n\
-    \n\
-    Познакомься с великими учёными, инженерами и конструкторами, которые начинали свой путь здесь, в стенах МГТУ им. Н.Э. Баумана.\n\
-    \n\
-    <b><i>Кого из них ты хочешь узнать лучше?</i></b>";
+pub fn state_reset_by_staff(locale: Locale) -> String {
+    tr(locale, "state-reset-by-staff", &[])
+}
 
-pub const PROMPT_FEEDBACK: StaticStr = "✍️ Теперь ты можешь написать комментарий организаторам!";
+pub fn button_unavailable(locale: Locale) -> String {
+    tr(locale, "button-unavailable", &[])
+}
 
-pub const FEEDBACK_SENT: StaticStr = "📩 <b>Отлично, твой комментарий отправлен!</b>\n\
-    \n\
-    <i>Мы обязательно прочитаем его в ближайшее время!</i>";
+pub fn send_approval_exit_team(locale: Locale) -> String {
+    tr(locale, "send-approval-exit-team", &[])
+}
 
-pub const REGISTRATION_CLOSED: StaticStr = "🥺 <b>К сожалению, зарегистрироваться уже нельзя!</b>\n\
-    \n\
-    👉🏻 <i>Регистрация была доступна до 30 сентября, но не спеши расстраиваться. В следующем год ты сам сможешь стать организатором «Легенд Бауманки» — присоединяйся к <a href=\"https://forms.yandex.ru/u/6897839490fa7b44d1601991\">команде Студенческого совета</a> и вместе мы сделаем так, чтобы о новых «Легендах» говорили ещё громче!</i>";
+pub fn successful_exit_team(locale: Locale) -> String {
+    tr(locale, "successful-exit-team", &[])
+}
 
-pub const PROMPT_TRACK: StaticStr = "✨ <b>Выбери трек</b>";
+fn page_footer(locale: Locale, page: usize, pages: usize, total: usize) -> String {
+    tr(
+        locale,
+        "page-footer",
+        &[
+            ("page", (page + 1).into()),
+            ("pages", pages.into()),
+            ("total", total.into()),
+        ],
+    )
+}
 
-pub const PROMPT_TRACK_START: StaticStr = "
-    Как только вы начнёте этот трек запустится таймер, который покажет, насколько быстро вы способны прокачивать свои навыки!\n\
-    \n\
-    <b>Ваши задачи:</b>\n\
-    💡 Отвечать на вопросы\n\
-    💡 Выбирать верные ответы\n\
-    💡 Присылать фотографии\n\
-    💡 Действовать слаженно и быстро\n\
-    \n\
-    📸 <b>Важно:</b>\n\
-    Помните, что проверка фотографий требует времени — не откладывайте их выполнение!\n\
-    \n\
-    <i>Вы уверены, что готовы начать?</i>";
+pub fn invalid_upload_command_usage(locale: Locale) -> String {
+    tr(locale, "invalid-upload-command-usage", &[])
+}
+
+pub fn invalid_media_command_usage(locale: Locale) -> String {
+    tr(locale, "invalid-media-command-usage", &[])
+}
+
+pub fn invalid_graph_command_usage(locale: Locale) -> String {
+    tr(locale, "invalid-graph-command-usage", &[])
+}
+
+pub fn task_graph_is_valid(locale: Locale) -> String {
+    tr(locale, "task-graph-is-valid", &[])
+}
+
+fn task_graph_issue(locale: Locale, issue: &TaskGraphIssue) -> String {
+    match issue {
+        TaskGraphIssue::MissingDependency {
+            track,
+            task_id,
+            dependency,
+        } => tr(
+            locale,
+            "task-graph-missing-dependency",
+            &[
+                ("track", track.as_str().into()),
+                ("task_id", (*task_id).into()),
+                ("dependency", (*dependency).into()),
+            ],
+        ),
+        TaskGraphIssue::CrossTrackDependency {
+            track,
+            task_id,
+            dependency,
+            dependency_track,
+        } => tr(
+            locale,
+            "task-graph-cross-track-dependency",
+            &[
+                ("track", track.as_str().into()),
+                ("task_id", (*task_id).into()),
+                ("dependency", (*dependency).into()),
+                ("dependency_track", dependency_track.as_str().into()),
+            ],
+        ),
+        TaskGraphIssue::Cycle { track, task_ids } => tr(
+            locale,
+            "task-graph-cycle",
+            &[
+                ("track", track.as_str().into()),
+                ("cycle", format_cycle(task_ids).into()),
+            ],
+        ),
+        TaskGraphIssue::Unreachable { track, task_id } => tr(
+            locale,
+            "task-graph-unreachable",
+            &[
+                ("track", track.as_str().into()),
+                ("task_id", (*task_id).into()),
+            ],
+        ),
+    }
+}
 
-const TASK_CORRECT_ANSWER_1: StaticStr =
-    "✅ <b>Снова прав!</b> Ты уже не участник — ты мастер. Каждый твой шаг ближе к легенде.";
+pub fn task_graph_issues(locale: Locale, issues: &[TaskGraphIssue]) -> String {
+    let list = issues
+        .iter()
+        .map(|issue| format!("• {}", task_graph_issue(locale, issue)))
+        .collect::<Vec<_>>()
+        .join("\n");
+    tr(
+        locale,
+        "task-graph-issues",
+        &[("count", issues.len().into()), ("issues", list.into())],
+    )
+}
 
-const TASK_CORRECT_ANSWER_2: StaticStr =
-    "✅ <b>Ты справился!</b> И справился с достоинством. Такие моменты и делают нас сильнее!";
+pub fn prompt_media(locale: Locale) -> String {
+    tr(locale, "prompt-media", &[])
+}
 
-const TASK_CORRECT_ANSWER_3: StaticStr =
-    "✅ <b>Верно!</b> Бауманка открывает свои секреты только избранным!";
+pub fn unknown_media_format(locale: Locale) -> String {
+    tr(locale, "unknown-media-format", &[])
+}
 
-const TASK_CORRECT_ANSWER_4: StaticStr =
-    "✅ <b>Правильно!</b> Ты чувствуешь дух Бауманки как никто другой!";
+pub fn invalid_media_usage_command_usage(locale: Locale) -> String {
+    tr(locale, "invalid-media-usage-command-usage", &[])
+}
 
-const TASK_CORRECT_ANSWER_5: StaticStr =
-    "✅ <b>Точно!</b> Ты читаешь историю Бауманки как открытую книгу!";
+pub fn invalid_media_rename_command_usage(locale: Locale) -> String {
+    tr(locale, "invalid-media-rename-command-usage", &[])
+}
 
-const TASK_CORRECT_ANSWERS: [StaticStr; 5] = [
-    TASK_CORRECT_ANSWER_1,
-    TASK_CORRECT_ANSWER_2,
-    TASK_CORRECT_ANSWER_3,
-    TASK_CORRECT_ANSWER_4,
-    TASK_CORRECT_ANSWER_5,
-];
+pub fn invalid_media_delete_command_usage(locale: Locale) -> String {
+    tr(locale, "invalid-media-delete-command-usage", &[])
+}
+
+pub fn media_library_is_empty(locale: Locale) -> String {
+    tr(locale, "media-library-is-empty", &[])
+}
+
+pub fn media_references_are_valid(locale: Locale) -> String {
+    tr(locale, "media-references-are-valid", &[])
+}
+
+fn media_type_name(locale: Locale, media_type: MediaType) -> String {
+    let key = match media_type {
+        MediaType::Image => "media-type-image",
+        MediaType::VideoNote => "media-type-video-note",
+        MediaType::Audio => "media-type-audio",
+        MediaType::Voice => "media-type-voice",
+        MediaType::Video => "media-type-video",
+        MediaType::Document => "media-type-document",
+    };
+    tr(locale, key, &[])
+}
+
+pub fn media_page(locale: Locale, page: &MediaPageDTO, query: &str) -> String {
+    let title = if query.is_empty() {
+        tr(locale, "media-page-title", &[])
+    } else {
+        tr(
+            locale,
+            "media-page-search-title",
+            &[("query", query.into())],
+        )
+    };
+    let list = page
+        .items
+        .iter()
+        .map(|m| {
+            format!(
+                "• <code>{}</code> — {}",
+                m.id.as_str(),
+                media_type_name(locale, m.media_type)
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+    format!(
+        "{title}\n{list}\n\n{}",
+        page_footer(locale, page.page, page.pages, page.total)
+    )
+}
+
+fn media_usage(locale: Locale, usage: &MediaUsage) -> String {
+    match usage {
+        MediaUsage::Track(tag) => tr(
+            locale,
+            "media-usage-track",
+            &[("track", tag.as_str().into())],
+        ),
+        MediaUsage::Task(task_id) => tr(
+            locale,
+            "media-usage-task",
+            &[("task_id", (*task_id).into())],
+        ),
+        MediaUsage::Character(id) => tr(
+            locale,
+            "media-usage-character",
+            &[("character", id.as_str().into())],
+        ),
+    }
+}
+
+pub fn media_usages(locale: Locale, media_id: &MediaID, usages: &[MediaUsage]) -> String {
+    if usages.is_empty() {
+        return tr(
+            locale,
+            "media-not-used",
+            &[("media", media_id.as_str().into())],
+        );
+    }
+    let list = usages
+        .iter()
+        .map(|u| format!("• {}", media_usage(locale, u)))
+        .collect::<Vec<_>>()
+        .join("\n");
+    tr(
+        locale,
+        "media-usages",
+        &[("media", media_id.as_str().into()), ("usages", list.into())],
+    )
+}
+
+pub fn media_renamed(locale: Locale, media_id: &MediaID, new_id: &MediaID) -> String {
+    tr(
+        locale,
+        "media-renamed",
+        &[
+            ("media", media_id.as_str().into()),
+            ("new_media", new_id.as_str().into()),
+        ],
+    )
+}
+
+pub fn media_already_exists(locale: Locale, media_id: &MediaID) -> String {
+    tr(
+        locale,
+        "media-already-exists",
+        &[("media", media_id.as_str().into())],
+    )
+}
+
+pub fn media_deleted(locale: Locale, media_id: &MediaID) -> String {
+    tr(
+        locale,
+        "media-deleted",
+        &[("media", media_id.as_str().into())],
+    )
+}
+
+pub fn media_in_use(locale: Locale, media_id: &MediaID, usages: &[MediaUsage]) -> String {
+    tr(
+        locale,
+        "media-in-use",
+        &[("usages", media_usages(locale, media_id, usages).into())],
+    )
+}
+
+pub fn dangling_media_references(locale: Locale, references: &[DanglingMediaReference]) -> String {
+    let list = references
+        .iter()
+        .map(|r| {
+            format!(
+                "• {} → <code>{}</code>",
+                media_usage(locale, &r.usage),
+                r.media_id.as_str()
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+    tr(
+        locale,
+        "dangling-media-references",
+        &[
+            ("count", references.len().into()),
+            ("references", list.into()),
+        ],
+    )
+}
+
+pub fn media_group_uploaded(locale: Locale, media_id: &MediaID, count: usize) -> String {
+    tr(
+        locale,
+        "media-group-uploaded",
+        &[("media", media_id.as_str().into()), ("count", count.into())],
+    )
+}
+
+pub fn prompt_character_name(locale: Locale) -> String {
+    tr(locale, "prompt-character-name", &[])
+}
+
+pub fn characters_not_found(locale: Locale) -> String {
+    tr(locale, "characters-not-found", &[])
+}
+
+pub fn favorite_characters_empty(locale: Locale) -> String {
+    tr(locale, "favorite-characters-empty", &[])
+}
+
+pub fn characters_found(locale: Locale) -> String {
+    tr(locale, "characters-found", &[])
+}
+
+pub fn character_links(locale: Locale) -> String {
+    tr(locale, "character-links", &[])
+}
+
+pub fn character_page(locale: Locale, page: &CharacterPageDTO) -> String {
+    let title = if page.favorites_only {
+        tr(locale, "favorite-character-page-title", &[])
+    } else {
+        tr(locale, "character-page-title", &[])
+    };
+    format!(
+        "{title}\n\n{}\n\n{}",
+        character_collection(locale, &page.collection),
+        page_footer(locale, page.page, page.pages, page.total)
+    )
+}
+
+pub fn character_collection(locale: Locale, collection: &CharacterCollectionDTO) -> String {
+    tr(
+        locale,
+        "character-collection",
+        &[
+            ("collected", collection.collected.into()),
+            ("total", collection.total.into()),
+        ],
+    )
+}
+
+pub fn characters_unlocked(locale: Locale, characters: &[CharacterLinkDTO]) -> String {
+    let names = characters
+        .iter()
+        .map(|c| format!("«{}»", html::escape(c.name.as_str())))
+        .collect::<Vec<_>>()
+        .join(", ");
+    tr(locale, "characters-unlocked", &[("names", names.into())])
+}
+
+pub fn prompt_feedback(locale: Locale) -> String {
+    tr(locale, "prompt-feedback", &[])
+}
+
+pub fn feedback_sent(locale: Locale) -> String {
+    tr(locale, "feedback-sent", &[])
+}
+
+pub fn invalid_inbox_command_usage(locale: Locale) -> String {
+    tr(locale, "invalid-inbox-command-usage", &[])
+}
+
+pub fn feedback_inbox_is_empty(locale: Locale) -> String {
+    tr(locale, "feedback-inbox-is-empty", &[])
+}
+
+pub fn prompt_feedback_reply(locale: Locale) -> String {
+    tr(locale, "prompt-feedback-reply", &[])
+}
+
+pub fn feedback_is_archived(locale: Locale) -> String {
+    tr(locale, "feedback-is-archived", &[])
+}
+
+fn feedback_status_name(locale: Locale, status: FeedbackStatus) -> String {
+    let key = match status {
+        FeedbackStatus::New => "feedback-status-new",
+        FeedbackStatus::Answered => "feedback-status-answered",
+        FeedbackStatus::Archived => "feedback-status-archived",
+    };
+    tr(locale, key, &[])
+}
+
+fn user_with_username(author: &UserDTO) -> String {
+    match &author.username {
+        Some(username) => format!(
+            "{} (@{})",
+            html::escape(author.full_name.as_str()),
+            username.as_str()
+        ),
+        None => html::escape(author.full_name.as_str()),
+    }
+}
+
+pub fn feedback_page(
+    locale: Locale,
+    page: &FeedbackPageDTO,
+    status: Option<FeedbackStatus>,
+) -> String {
+    let title = match status {
+        Some(status) => tr(
+            locale,
+            "feedback-inbox-status-title",
+            &[("status", feedback_status_name(locale, status).into())],
+        ),
+        None => tr(locale, "feedback-inbox-title", &[]),
+    };
+    let list = page
+        .items
+        .iter()
+        .map(|f| {
+            let preview: String = f.text.as_str().chars().take(80).collect();
+            format!(
+                "<b>#{}</b> {} · {} · {}\n{}",
+                f.id,
+                f.created_at.format("%d.%m %H:%M"),
+                user_with_username(&f.author),
+                feedback_status_name(locale, f.status),
+                html::escape(&preview)
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n\n");
+    format!(
+        "{title}\n\n{list}\n\n{}",
+        page_footer(locale, page.page, page.pages, page.total)
+    )
+}
+
+pub fn feedback_thread(locale: Locale, feedback: &FeedbackDTO) -> String {
+    let mut text = format!(
+        "<b>#{}</b> · {}\n{} · {}\n\n{}",
+        feedback.id,
+        feedback_status_name(locale, feedback.status),
+        feedback.created_at.format("%d.%m %H:%M"),
+        user_with_username(&feedback.author),
+        html::escape(feedback.text.as_str())
+    );
+    for reply in &feedback.replies {
+        let header = tr(
+            locale,
+            "feedback-thread-reply",
+            &[(
+                "date",
+                reply.created_at.format("%d.%m %H:%M").to_string().into(),
+            )],
+        );
+        text.push_str(&format!(
+            "\n\n{header}\n{}",
+            html::escape(reply.text.as_str())
+        ));
+    }
+    text
+}
+
+pub fn feedback_reply_to_author(locale: Locale, text: &FeedbackText) -> String {
+    tr(
+        locale,
+        "feedback-reply-to-author",
+        &[("text", html::escape(text.as_str()).into())],
+    )
+}
+
+pub fn feedback_reply_sent(locale: Locale, id: FeedbackID) -> String {
+    tr(locale, "feedback-reply-sent", &[("id", id.into())])
+}
+
+pub fn feedback_reply_not_delivered(locale: Locale, id: FeedbackID) -> String {
+    tr(locale, "feedback-reply-not-delivered", &[("id", id.into())])
+}
+
+pub fn feedback_archived(locale: Locale, id: FeedbackID) -> String {
+    tr(locale, "feedback-archived", &[("id", id.into())])
+}
+
+pub fn can_not_revoke_own_role(locale: Locale) -> String {
+    tr(locale, "can-not-revoke-own-role", &[])
+}
+
+fn role_name(locale: Locale, role: Role) -> String {
+    tr(locale, &format!("role-{}", role.code()), &[])
+}
+
+pub fn invalid_role_command_usage(locale: Locale, command: &str) -> String {
+    let roles = Role::ALL
+        .iter()
+        .map(|role| {
+            format!(
+                "<code>{}</code> — {}",
+                role.code(),
+                role_name(locale, *role)
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+    tr(
+        locale,
+        "invalid-role-command-usage",
+        &[("command", command.into()), ("roles", roles.into())],
+    )
+}
+
+fn role_change(locale: Locale, key: &str, user_id: UserID, role: Role) -> String {
+    tr(
+        locale,
+        key,
+        &[
+            ("user", user_id.as_i64().to_string().into()),
+            ("role", role_name(locale, role).into()),
+        ],
+    )
+}
+
+pub fn role_granted(locale: Locale, user_id: UserID, role: Role) -> String {
+    role_change(locale, "role-granted", user_id, role)
+}
+
+pub fn role_already_granted(locale: Locale, user_id: UserID, role: Role) -> String {
+    role_change(locale, "role-already-granted", user_id, role)
+}
+
+pub fn role_revoked(locale: Locale, user_id: UserID, role: Role) -> String {
+    role_change(locale, "role-revoked", user_id, role)
+}
+
+pub fn role_not_granted(locale: Locale, user_id: UserID, role: Role) -> String {
+    role_change(locale, "role-not-granted", user_id, role)
+}
+
+pub fn role_user_not_found(locale: Locale, user_id: UserID) -> String {
+    tr(
+        locale,
+        "role-user-not-found",
+        &[("user", user_id.as_i64().to_string().into())],
+    )
+}
 
-pub fn correct_answer() -> &'static str {
-    TASK_CORRECT_ANSWERS.choose(&mut rand::rng()).unwrap()
+pub fn staff_roles(locale: Locale, staff: &[StaffMemberDTO]) -> String {
+    if staff.is_empty() {
+        return tr(locale, "staff-roles-empty", &[]);
+    }
+    let list = staff
+        .iter()
+        .map(|member| {
+            let roles = member
+                .roles
+                .iter()
+                .map(|role| role_name(locale, *role))
+                .collect::<Vec<_>>()
+                .join(", ");
+            format!(
+                "<code>{}</code> {} — {}",
+                member.id.as_i64(),
+                user_with_username(&member.user),
+                roles
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+    tr(locale, "staff-roles", &[("staff", list.into())])
 }
 
-pub const TASK_INVALID_ANSWER_1: StaticStr =
-    "❌ Мимо, но каждый промах — это шаг ближе к цели. Вперёд, у тебя получится!";
+pub fn audit_log_is_empty(locale: Locale) -> String {
+    tr(locale, "audit-log-is-empty", &[])
+}
 
-pub const TASK_INVALID_ANSWER_2: StaticStr = "❌ Почти! Но если один путь оказался тупиком — значит, другой точно ведёт к успеху. Давай попробуем снова?";
+fn audit_action_name(locale: Locale, action: AuditAction) -> String {
+    tr(locale, &format!("audit-action-{}", action.code()), &[])
+}
 
-// Пусть дублируется, будем считать, что повышенный шанс на выпадение
-pub const TASK_INVALID_ANSWER_3: StaticStr = "❌ Почти! Но если один путь оказался тупиком — значит, другой точно ведёт к успеху. Давай попробуем снова?";
+pub fn invalid_audit_command_usage(locale: Locale) -> String {
+    let actions = AuditAction::ALL
+        .iter()
+        .map(|action| {
+            format!(
+                "<code>{}</code> — {}",
+                action.code(),
+                audit_action_name(locale, *action)
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+    tr(
+        locale,
+        "invalid-audit-command-usage",
+        &[("actions", actions.into())],
+    )
+}
 
-pub const TASK_INVALID_ANSWER_4: StaticStr =
-    "❌ Близко, но не совсем. Зато теперь голова работает на полную — давай ещё один шанс! ";
+pub fn audit_page(locale: Locale, page: &AuditPageDTO) -> String {
+    let list = page
+        .items
+        .iter()
+        .map(|e| {
+            let change = match (&e.before, &e.after) {
+                (Some(before), Some(after)) => format!(
+                    "\n<code>{}</code> → <code>{}</code>",
+                    html::escape(before),
+                    html::escape(after)
+                ),
+                (Some(before), None) => format!(
+                    "\n{}",
+                    tr(
+                        locale,
+                        "audit-change-before",
+                        &[("before", html::escape(before).into())]
+                    )
+                ),
+                (None, Some(after)) => format!(
+                    "\n{}",
+                    tr(
+                        locale,
+                        "audit-change-after",
+                        &[("after", html::escape(after).into())]
+                    )
+                ),
+                (None, None) => String::new(),
+            };
+            format!(
+                "<b>#{}</b> {} · <code>{}</code> · {}\n<code>{}</code>{}",
+                e.id,
+                e.created_at.format("%d.%m %H:%M"),
+                e.actor_id.as_i64(),
+                audit_action_name(locale, e.action),
+                html::escape(&e.target),
+                change
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n\n");
+    format!(
+        "{}\n\n{list}\n\n{}",
+        tr(locale, "audit-log-title", &[]),
+        page_footer(locale, page.page, page.pages, page.total)
+    )
+}
 
-pub const TASK_INVALID_ANSWER_5: StaticStr =
-    "❌ Это не он, но ты уже почти чувствуешь правильный ответ, верно? Не останавливайся!";
+fn event_phase_name(locale: Locale, phase: EventPhase) -> String {
+    tr(locale, &format!("event-phase-{}", phase.code()), &[])
+}
+
+fn event_phase_list(locale: Locale, phases: &PhasesConfig, current: Option<EventPhase>) -> String {
+    EventPhase::ALL
+        .iter()
+        .map(|phase| {
+            let items = phases.menu_items(*phase);
+            let items = if items.is_empty() {
+                tr(locale, "menu-items-empty", &[])
+            } else {
+                items
+                    .iter()
+                    .map(|item| tr(locale, &format!("menu-item-{}", item.code()), &[]))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            };
+            let marker = if Some(*phase) == current {
+                "▶️"
+            } else {
+                "▫️"
+            };
+            format!(
+                "{marker} <code>{}</code> — {}: {items}",
+                phase.code(),
+                event_phase_name(locale, *phase)
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+pub fn event_phases(locale: Locale, phases: &PhasesConfig, current: EventPhase) -> String {
+    let list = event_phase_list(locale, phases, Some(current));
+    tr(locale, "event-phases", &[("phases", list.into())])
+}
+
+pub fn invalid_phase_command_usage(locale: Locale, phases: &PhasesConfig) -> String {
+    let list = event_phase_list(locale, phases, None);
+    tr(
+        locale,
+        "invalid-phase-command-usage",
+        &[("phases", list.into())],
+    )
+}
+
+pub fn event_phase_switched(locale: Locale, phase: EventPhase) -> String {
+    tr(
+        locale,
+        "event-phase-switched",
+        &[("phase", event_phase_name(locale, phase).into())],
+    )
+}
+
+pub fn event_phase_already_active(locale: Locale, phase: EventPhase) -> String {
+    tr(
+        locale,
+        "event-phase-already-active",
+        &[("phase", event_phase_name(locale, phase).into())],
+    )
+}
+
+pub fn invalid_reset_command_usage(locale: Locale) -> String {
+    tr(locale, "invalid-reset-command-usage", &[])
+}
+
+pub fn dialogue_reset(locale: Locale, user_id: UserID) -> String {
+    tr(
+        locale,
+        "dialogue-reset",
+        &[("user", user_id.as_i64().to_string().into())],
+    )
+}
+
+fn staff_user(locale: Locale, profile: &Profile) -> String {
+    let username = profile
+        .user
+        .username
+        .as_ref()
+        .map(|u| format!(" (@{})", u.as_str()))
+        .unwrap_or_default();
+    let team = match &profile.team_name {
+        Some(team) => tr(
+            locale,
+            "staff-user-team",
+            &[("team", html::escape(team.as_str()).into())],
+        ),
+        None => tr(locale, "staff-user-no-team", &[]),
+    };
+    format!(
+        "👤 {}{username}, {}, {team}, {}",
+        html::escape(profile.user.full_name.as_str()),
+        html::escape(profile.user.group_name.as_str()),
+        character_collection(locale, &profile.characters).to_lowercase(),
+    )
+}
+
+pub fn staff_feedback(locale: Locale, profile: &Profile, text: &FeedbackText) -> String {
+    tr(
+        locale,
+        "staff-feedback",
+        &[
+            ("user", staff_user(locale, profile).into()),
+            ("text", html::escape(text.as_str()).into()),
+        ],
+    )
+}
+
+pub fn staff_photo_answer(
+    locale: Locale,
+    profile: &Profile,
+    track: &TrackTag,
+    number: usize,
+) -> String {
+    tr(
+        locale,
+        "staff-photo-answer",
+        &[
+            ("user", staff_user(locale, profile).into()),
+            ("track", track.as_str().into()),
+            ("number", number.into()),
+        ],
+    )
+}
+
+pub fn photo_answer_already_reviewed(locale: Locale) -> String {
+    tr(locale, "photo-answer-already-reviewed", &[])
+}
+
+pub fn staff_photo_answer_reviewed(
+    locale: Locale,
+    approved: bool,
+    points: Points,
+    reviewer: &str,
+) -> String {
+    if approved {
+        tr(
+            locale,
+            "staff-photo-answer-approved",
+            &[
+                ("points", points.as_i32().into()),
+                ("reviewer", html::escape(reviewer).into()),
+            ],
+        )
+    } else {
+        tr(
+            locale,
+            "staff-photo-answer-rejected",
+            &[("reviewer", html::escape(reviewer).into())],
+        )
+    }
+}
+
+pub fn staff_reservation_cancelled(locale: Locale, profile: &Profile, rescheduled: bool) -> String {
+    tr(
+        locale,
+        "staff-reservation-cancelled",
+        &[
+            ("user", staff_user(locale, profile).into()),
+            ("rescheduled", if rescheduled { "yes" } else { "no" }.into()),
+        ],
+    )
+}
+
+pub fn staff_internal_error(locale: Locale, error: &AppError) -> String {
+    tr(
+        locale,
+        "staff-internal-error",
+        &[("error", html::escape(&error.to_string()).into())],
+    )
+}
+
+pub fn registration_closed(locale: Locale, registration: &RegistrationConfig) -> String {
+    tr(
+        locale,
+        "registration-closed",
+        &[(
+            "date",
+            format_day_month(locale, registration.closes_on).into(),
+        )],
+    )
+}
+
+pub fn registration_not_opened(locale: Locale, registration: &RegistrationConfig) -> String {
+    tr(
+        locale,
+        "registration-not-opened",
+        &[(
+            "date",
+            format_day_month(locale, registration.opens_on).into(),
+        )],
+    )
+}
+
+fn format_day_month(locale: Locale, date: NaiveDate) -> String {
+    tr(
+        locale,
+        "day-month",
+        &[("day", date.day().into()), ("month", date.month().into())],
+    )
+}
+
+pub fn prompt_track(locale: Locale) -> String {
+    tr(locale, "prompt-track", &[])
+}
+
+pub fn prompt_track_start(locale: Locale) -> String {
+    tr(locale, "prompt-track-start", &[])
+}
+
+const TASK_CORRECT_ANSWERS: [StaticStr; 5] = [
+    "task-correct-answer-1",
+    "task-correct-answer-2",
+    "task-correct-answer-3",
+    "task-correct-answer-4",
+    "task-correct-answer-5",
+];
+
+pub fn correct_answer(locale: Locale) -> String {
+    tr(
+        locale,
+        TASK_CORRECT_ANSWERS.choose(&mut rand::rng()).unwrap(),
+        &[],
+    )
+}
 
 const TASK_INVALID_ANSWERS: [StaticStr; 5] = [
-    TASK_INVALID_ANSWER_1,
-    TASK_INVALID_ANSWER_2,
-    TASK_INVALID_ANSWER_3,
-    TASK_INVALID_ANSWER_4,
-    TASK_INVALID_ANSWER_5,
+    "task-invalid-answer-1",
+    "task-invalid-answer-2",
+    "task-invalid-answer-3",
+    "task-invalid-answer-4",
+    "task-invalid-answer-5",
 ];
 
-pub fn invalid_answer() -> &'static str {
-    TASK_INVALID_ANSWERS.choose(&mut rand::rng()).unwrap()
+pub fn invalid_answer(locale: Locale) -> String {
+    tr(
+        locale,
+        TASK_INVALID_ANSWERS.choose(&mut rand::rng()).unwrap(),
+        &[],
+    )
+}
+
+pub fn task_answer_is_close(locale: Locale) -> String {
+    tr(locale, "task-answer-is-close", &[])
+}
+
+pub fn task_hint(locale: Locale, hint: &TaskText) -> String {
+    tr(locale, "task-hint", &[("hint", hint.as_str().into())])
 }
 
-pub const PROMPT_AVAILABLE_TASK: StaticStr = "📲 <b>Выбери задание из списка</b>";
+pub fn partial_credit(locale: Locale, points: Points) -> String {
+    tr(
+        locale,
+        "partial-credit",
+        &[("points", points.as_i32().into())],
+    )
+}
 
-pub const PROMPT_COMPLETED_TASK: StaticStr = "📲 <b>Выбери задание из списка</b>";
+pub fn score_breakdown(locale: Locale, score: &ScoreBreakdown) -> Option<String> {
+    if score.penalty().is_zero() && score.time_bonus().is_zero() {
+        return None;
+    }
+    let line = |key: &str, points: Points| tr(locale, key, &[("points", points.as_i32().into())]);
+    let mut lines = vec![line("score-base", score.base())];
+    if !score.penalty().is_zero() {
+        lines.push(line("score-penalty", score.penalty()));
+    }
+    if !score.time_bonus().is_zero() {
+        lines.push(line("score-time-bonus", score.time_bonus()));
+    }
+    lines.push(line("score-total", score.total()));
+    Some(lines.join("\n"))
+}
 
-pub const ALL_TRACK_TASKS_COMPLETED: StaticStr = "🎉 <b>Трек завершён!</b>\n\
-    Поздравляю! Ты успешно выполнил(а) все задания этого трека. Теперь ты можешь перейти к другим трекам и продолжить своё путешествие по «Легендам Бауманки»!";
+pub fn please_send_location(locale: Locale) -> String {
+    tr(locale, "please-send-location", &[])
+}
 
-pub const NO_COMPLETED_TASKS: StaticStr = "📭 <b>Пусто</b>\n\
-    Здесь пока нет ни одного завершённого задания.";
+pub fn select_at_least_one_option(locale: Locale) -> String {
+    tr(locale, "select-at-least-one-option", &[])
+}
 
-pub const TRACK_NOT_STARTED: StaticStr = "☝🏻<b>Стой! Это трек еще не был запущен вашей командой.</b>\n\
-    \n\
-    ⭐️ Он откроется, как только вы договоритесь командой и капитан начнет его!";
+pub fn task_question(locale: Locale, task: &TaskDTO) -> String {
+    let hint = match task.task_type {
+        TaskType::MultiChoice => "multi-choice-hint",
+        TaskType::Ordering => "ordering-hint",
+        TaskType::Number => "number-hint",
+        TaskType::Location => "location-hint",
+        TaskType::Text | TaskType::Choice | TaskType::Photo => return task.question.to_string(),
+    };
+    format!(
+        "{}\n\n<i>{}</i>",
+        task.question.as_str(),
+        tr(locale, hint, &[])
+    )
+}
 
-pub const PHOTO_TASK_ACCEPTED: StaticStr =
-    "Отлично! Пока что фотография проверяется, а ты можешь двигаться дальше 📸";
+pub fn selected_options(locale: Locale, options: &[&TaskOption]) -> String {
+    let list = options
+        .iter()
+        .map(|o| format!("• {}", o.as_str()))
+        .collect::<Vec<_>>()
+        .join("\n");
+    tr(locale, "selected-options", &[("options", list.into())])
+}
 
-pub const PLEASE_SEND_PHOTO: StaticStr =
-    "☝🏻 Принимается именно фотография, а ты, кажется, скинул что-то не то!";
+pub fn ordered_options(locale: Locale, options: &[&TaskOption]) -> String {
+    let list = options
+        .iter()
+        .enumerate()
+        .map(|(i, o)| format!("{}. {}", i + 1, o.as_str()))
+        .collect::<Vec<_>>()
+        .join("\n");
+    tr(locale, "ordered-options", &[("options", list.into())])
+}
 
-pub const PROMPT_ACCEPT_FINAL: StaticStr = "⭐️ <b><i>Готов попасть на финал?</i></b>";
+pub fn prompt_available_task(locale: Locale) -> String {
+    tr(locale, "prompt-available-task", &[])
+}
 
-pub const PROMPT_SLOT_TIME: StaticStr = "🕐 <b>Выберите время для финала</b>\n\
-    \n\
-    Пожалуйста, выберите удобное время, когда ваша команда сможет подойти на очный финал.\n\
-    \n\
-    ⏱ <i>Мероприятие займёт не более часа.</i>\n\
-    \n\
-    <i>Доступные слоты:</i>";
+pub fn prompt_completed_task(locale: Locale) -> String {
+    tr(locale, "prompt-completed-task", &[])
+}
 
-pub const INVALID_TEAM_PLACES: StaticStr = "❌ <b>Неверный формат</b>\n\
-    \n\
-    Пожалуйста, введите обычное число. Например: 5";
+pub fn all_track_tasks_completed(locale: Locale) -> String {
+    tr(locale, "all-track-tasks-completed", &[])
+}
 
-pub const PROMPT_CANCEL_RESERVATION_REASON: StaticStr = "❓ <b>Уточните причину отмены записи</b>
-    \n\
-    • 🗓 Хочу изменить время\n\
-    • 👋 Не смогу прийти на финал\n\
-    \n\
-    💡 <i>Вы можете свободно перезаписываться на другое время до 21:00 сегодня.</i>";
+pub fn no_completed_tasks(locale: Locale) -> String {
+    tr(locale, "no-completed-tasks", &[])
+}
 
-pub const OCHEN_ZHAL: StaticStr = "👋 <b>Очень жаль, что вы не сможете быть с нами на финале</b>\n\
-    \n\
-    Мы будем скучать! Но полностью понимаем, что обстоятельства бывают разными.\n\
-    \n\
-    🗝 <b>Главное</b> — вы уже стали частью «Легенд Бауманки» и успешно прошли основной этап! Это огромное достижение ✨\n\
-    \n\
-    <i>Надеемся увидеть вас на других наших мероприятиях! Оставайтесь на связи!</i>";
+pub fn track_not_started(locale: Locale) -> String {
+    tr(locale, "track-not-started", &[])
+}
 
-pub fn my_team(team: TeamWithMembersDTO) -> String {
+pub fn photo_task_accepted(locale: Locale) -> String {
+    tr(locale, "photo-task-accepted", &[])
+}
+
+pub fn photo_answer_approved(locale: Locale, number: usize, points: Points) -> String {
+    tr(
+        locale,
+        "photo-answer-approved",
+        &[
+            ("number", number.into()),
+            ("points", points.as_i32().into()),
+        ],
+    )
+}
+
+pub fn photo_answer_rejected(locale: Locale, number: usize) -> String {
+    tr(
+        locale,
+        "photo-answer-rejected",
+        &[("number", number.into())],
+    )
+}
+
+pub fn please_send_photo(locale: Locale) -> String {
+    tr(locale, "please-send-photo", &[])
+}
+
+pub fn prompt_accept_final(locale: Locale) -> String {
+    tr(locale, "prompt-accept-final", &[])
+}
+
+pub fn prompt_slot_time(locale: Locale) -> String {
+    tr(locale, "prompt-slot-time", &[])
+}
+
+pub fn invalid_team_places(locale: Locale) -> String {
+    tr(locale, "invalid-team-places", &[])
+}
+
+pub fn prompt_cancel_reservation_reason(locale: Locale, event: &EventConfig) -> String {
+    let deadline = event
+        .reservation_change_deadline
+        .format("%H:%M")
+        .to_string();
+    tr(
+        locale,
+        "prompt-cancel-reservation-reason",
+        &[("deadline", deadline.into())],
+    )
+}
+
+pub fn final_absence_accepted(locale: Locale) -> String {
+    tr(locale, "final-absence-accepted", &[])
+}
+
+pub fn my_team(locale: Locale, team: TeamWithMembersDTO) -> String {
+    let no_username = tr(locale, "no-username", &[]);
     let usernames_text = team
         .members
         .iter()
@@ -188,53 +1128,84 @@ pub fn my_team(team: TeamWithMembersDTO) -> String {
                 .username
                 .clone()
                 .map(|u| u.to_string())
-                .unwrap_or("(без никнейма)".to_string())
+                .unwrap_or(no_username.clone())
         })
         .fold(String::new(), |acc, username| {
             acc + format!("@{username}\n").as_str()
         });
+    let captain = team
+        .captain
+        .username
+        .map(|u| u.to_string())
+        .unwrap_or(no_username);
 
-    format!(
-        "📊 <b>Информация о команде:</b>\n\
-        • Название: {}\n\
-        • Капитан: @{}\n\
-        • Участники ({}/{}):\n\
-        {}",
-        team.name.as_str(),
-        team.captain
-            .username
-            .map(|u| u.to_string())
-            .unwrap_or("(без никнейма)".to_string()),
-        team.size,
-        team.max_size,
-        usernames_text,
+    tr(
+        locale,
+        "my-team",
+        &[
+            ("name", team.name.as_str().into()),
+            ("captain", captain.into()),
+            ("size", team.size.into()),
+            ("max_size", team.max_size.into()),
+            ("members", usernames_text.into()),
+        ],
     )
 }
 
-pub fn media_uploaded(file_id: &FileID) -> String {
-    format!(
-        "✅ <b>FileID получен!</b>\n<code>{}</code>",
-        file_id.as_str()
+pub fn media_uploaded(locale: Locale, file_id: &FileID) -> String {
+    tr(
+        locale,
+        "media-uploaded",
+        &[("file_id", file_id.as_str().into())],
     )
 }
 
-pub fn character(character: CharacterDTO) -> String {
+pub fn character(locale: Locale, character: &CharacterDTO) -> String {
+    if let Some(unlock) = &character.locked {
+        return locked_character(locale, character, unlock);
+    }
     let facts = character
         .facts
-        .into_iter()
+        .iter()
         .map(|f| format!("🔹 {}\n\n", f.as_str()))
         .fold(String::new(), |acc, s| acc + s.as_str());
-    format!(
-        "<b>{}</b>\n\
-        \n\
-        <blockquote>«{}»</blockquote>\n\
-        \n\
-        {facts}\
-        <b><i>{}</i></b>
-        ",
-        character.name.as_str(),
-        character.quote.as_str(),
-        character.legacy.as_str(),
+    tr(
+        locale,
+        "character",
+        &[
+            ("index", character.index.into()),
+            ("name", character.name.as_str().into()),
+            ("quote", character.quote.as_str().into()),
+            ("facts", facts.into()),
+            ("legacy", character.legacy.as_str().into()),
+        ],
+    )
+}
+
+fn locked_character(
+    locale: Locale,
+    character: &CharacterDTO,
+    unlock: &CharacterUnlockDTO,
+) -> String {
+    let condition = match unlock {
+        CharacterUnlockDTO::Task(task_id) => tr(
+            locale,
+            "locked-character-task",
+            &[("task_id", (*task_id).into())],
+        ),
+        CharacterUnlockDTO::Track(name) => tr(
+            locale,
+            "locked-character-track",
+            &[("track", html::escape(name.as_str()).into())],
+        ),
+    };
+    tr(
+        locale,
+        "locked-character",
+        &[
+            ("index", character.index.into()),
+            ("condition", condition.into()),
+        ],
     )
 }
 
@@ -243,13 +1214,11 @@ pub fn track_description(track: &TrackDescriptionDTO) -> String {
         "<b>{}</b>\n\
         \n\
         {}",
-        track.tag.as_str().to_uppercase(),
+        track.name.as_str().to_uppercase(),
         track.description.as_str()
     )
 }
 
-const CELLS: usize = 15;
-
 fn format_duration(duration: Duration) -> String {
     let total_seconds = duration.num_seconds();
     let hours = total_seconds / 3600;
@@ -258,112 +1227,120 @@ fn format_duration(duration: Duration) -> String {
     format!("{:02}:{:02}:{:02}", hours, minutes, seconds)
 }
 
-pub fn track_menu(track: &TrackInProgressDTO) -> String {
+pub fn track_menu(locale: Locale, track: &TrackInProgressDTO) -> String {
     let timer_str = match track.status {
-        TrackStatus::Started(start) => {
-            format!(
-                "Прошло с момента старта трека: {}",
-                format_duration(Utc::now() - start)
-            )
-        }
-        TrackStatus::Finished(start, finished) => {
-            format!(
-                "Время прохождения трека: {} - {} ({})",
-                start.format("%d.%m %H:%M"),
-                finished.format("%d.%m %H:%M"),
-                format_duration(finished - start),
-            )
-        }
+        TrackStatus::Started(start) => tr(
+            locale,
+            "track-timer-started",
+            &[("elapsed", format_duration(Utc::now() - start).into())],
+        ),
+        TrackStatus::Finished(start, finished) => tr(
+            locale,
+            "track-timer-finished",
+            &[
+                ("start", start.format("%d.%m %H:%M").to_string().into()),
+                ("finish", finished.format("%d.%m %H:%M").to_string().into()),
+                ("duration", format_duration(finished - start).into()),
+            ],
+        ),
     };
 
     let width: usize = 15;
     let filled = (track.percent * width as f32) as usize;
     let empty = width.saturating_sub(filled);
+    let progress = format!(
+        "{}{} {}%",
+        "▰".repeat(filled),
+        "▱".repeat(empty),
+        (track.percent * 100.0) as usize
+    );
 
-    format!(
-        "<b>{}</b>\n\
-        \n\
-        {}\n\
-        \n\
-        <b>Твой прогресс</b>\n\
-        Трек: <i>{}</i>\n\
-        {}\n\
-        \n\
-        <b>✨ ─── ТАЙМЕР ─── ✨</b>\n\
-        🕒 {}",
-        track.tag.as_str().to_uppercase(),
-        track.description.as_str(),
-        track.tag.as_str(),
-        format!(
-            "{}{} {}%",
-            "▰".repeat(filled),
-            "▱".repeat(empty),
-            (track.percent * 100.0) as usize
-        ),
-        timer_str,
+    tr(
+        locale,
+        "track-menu",
+        &[
+            ("title", track.name.as_str().to_uppercase().into()),
+            ("description", track.description.as_str().into()),
+            ("name", track.name.as_str().into()),
+            ("progress", progress.into()),
+            ("timer", timer_str.into()),
+        ],
     )
 }
 
-pub fn task_question_and_explanation(task: &TaskDTO) -> String {
-    let answers = task
-        .correct_answers
-        .iter()
-        .map(|a| a.to_string())
-        .collect::<Vec<String>>()
-        .join(", ");
-    format!(
-        "{}\n\
-        \n\
-        <b>Правильный ответ: </b>{}\n\
-        \n\
-        {}",
-        task.question.as_str(),
-        answers,
-        task.explanation.as_str()
+pub fn task_question_and_explanation(locale: Locale, task: &TaskDTO) -> String {
+    let answers = if let Some(answer) = &task.numeric_answer {
+        answer.value().to_string()
+    } else {
+        let separator = if matches!(task.task_type, TaskType::Ordering) {
+            " → "
+        } else {
+            ", "
+        };
+        task.correct_answers
+            .iter()
+            .map(|a| a.to_string())
+            .collect::<Vec<String>>()
+            .join(separator)
+    };
+    if answers.is_empty() {
+        return format!(
+            "{}\n\
+            \n\
+            {}",
+            task.question.as_str(),
+            task.explanation.as_str()
+        );
+    }
+    tr(
+        locale,
+        "task-explanation",
+        &[
+            ("question", task.question.as_str().into()),
+            ("answers", answers.into()),
+            ("explanation", task.explanation.as_str().into()),
+        ],
     )
 }
 
-pub fn media_not_found(media_id: &MediaID) -> String {
-    format!(
-        "<b>❌ Медиафайл <code>{}</code> не найден</b>",
-        media_id.as_str()
+pub fn media_not_found(locale: Locale, media_id: &MediaID) -> String {
+    tr(
+        locale,
+        "media-not-found",
+        &[("media", media_id.as_str().into())],
     )
 }
 
-pub fn prompt_slot_places(team_size: usize) -> String {
-    format!(
-        "👥 <b>Укажите число участников на финале</b>\n\
-        Пожалуйста, введите, сколько человек из вашей команды планирует прийти на очный финал.\n\
-        \n\
-        💡 <i>В команде сейчас {team_size} участников.</i>"
+pub fn prompt_slot_places(locale: Locale, team_size: usize) -> String {
+    tr(
+        locale,
+        "prompt-slot-places",
+        &[("team_size", team_size.into())],
     )
 }
 
-pub fn places_greater_than_team(team_size: usize) -> String {
-    format!(
-        "❌ <b>Слишком большое число</b>\n\
-        \n\
-        Количество участников на финале не может превышать размер вашей команды ({team_size} человек). Пожалуйста, введите корректное число."
+pub fn places_greater_than_team(locale: Locale, team_size: usize) -> String {
+    tr(
+        locale,
+        "places-greater-than-team",
+        &[("team_size", team_size.into())],
     )
 }
 
-pub fn no_slots(team_size: usize) -> String {
-    format!(
-        "⏰ <b>В это время нет мест</b>\n\
-        К сожалению, на выбранное время не осталось слотов для {team_size} человек.\n\
-        \n\
-        <i>Пожалуйста, выберите другое время для участия в финале.</i>"
-    )
+pub fn no_slots(locale: Locale, team_size: usize) -> String {
+    tr(locale, "no-slots", &[("team_size", team_size.into())])
 }
 
-pub fn slot_successfully_reserved(slot: SlotDTO) -> String {
-    format!(
-        "Отлично! Запись подтверждена.\n\
-        \n\
-        ⏰ Финал пройдет 08 октября в {} в аудитории {} Конгресс-центра (вход B4 со стороны ГЗ)\n\
-        \n\
-        🪧 <b><i>Ждём вашу команду, чтобы вместе поставить красивую точку. До встречи!</i></b>",
-        slot.start.format("%H:%M"),
-        slot.site.as_str(),
+pub fn slot_successfully_reserved(locale: Locale, reservation: &ReservationDTO) -> String {
+    let time = reservation.slot.start.format("%H:%M").to_string();
+    tr(
+        locale,
+        "slot-successfully-reserved",
+        &[
+            ("date", format_day_month(locale, reservation.date).into()),
+            ("time", time.into()),
+            ("site", reservation.slot.site.as_str().into()),
+            ("venue", html::escape(&reservation.venue).into()),
+        ],
     )
 }
@@ -0,0 +1,396 @@
+use std::env;
+use std::fs;
+use std::net::SocketAddr;
+use std::path::{Path, PathBuf};
+
+use chrono::{NaiveDate, NaiveTime};
+use serde::Deserialize;
+use thiserror::Error;
+
+use crate::domain::models::{EventPhase, MenuItem, NormalizationStep, Normalizer};
+
+/// Путь к файлу настроек, если не задан `CONFIG_PATH`.
+const DEFAULT_CONFIG_PATH: &str = "config.toml";
+
+#[derive(Error, Debug)]
+pub enum ConfigError {
+    #[error("unable to read config file {0}: {1}")]
+    Read(String, std::io::Error),
+
+    #[error("unable to parse config file {0}: {1}")]
+    Parse(String, toml::de::Error),
+
+    #[error("invalid environment variable {0}: {1}")]
+    Env(&'static str, String),
+
+    #[error("invalid config: {0}")]
+    Invalid(String),
+}
+
+/// Настройки бота. Читаются из TOML-файла, отдельные значения
+/// переопределяются переменными окружения.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    pub database: DatabaseConfig,
+    pub bot: BotConfig,
+    pub event: EventConfig,
+    pub registration: RegistrationConfig,
+    pub teams: TeamsConfig,
+    pub staff_chat: StaffChatConfig,
+    pub features: FeaturesConfig,
+    pub locales: LocalesConfig,
+    pub phases: PhasesConfig,
+    pub http: HttpConfig,
+    pub normalization: NormalizationConfig,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct DatabaseConfig {
+    /// Переопределяется `DATABASE_URI`.
+    pub uri: String,
+    /// Переопределяется `DATABASE_POOL_SIZE`.
+    pub pool_size: usize,
+    /// Соединения хранилища состояний диалогов.
+    pub dialogue_pool_size: u32,
+}
+
+impl Default for DatabaseConfig {
+    fn default() -> Self {
+        Self {
+            uri: String::new(),
+            pool_size: 16,
+            dialogue_pool_size: 1,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct BotConfig {
+    /// Переопределяется `TELOXIDE_TOKEN`.
+    pub token: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct EventConfig {
+    pub final_date: NaiveDate,
+    /// Где проходит финал, номер аудитории берётся из слота.
+    pub final_venue: String,
+    /// До этого времени в день финала можно перезаписаться на другой слот.
+    pub reservation_change_deadline: NaiveTime,
+}
+
+impl Default for EventConfig {
+    fn default() -> Self {
+        Self {
+            final_date: NaiveDate::from_ymd_opt(2025, 10, 8).unwrap(),
+            final_venue: "Конгресс-центра (вход B4 со стороны ГЗ)".to_string(),
+            reservation_change_deadline: NaiveTime::from_hms_opt(21, 0, 0).unwrap(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct RegistrationConfig {
+    pub opens_on: NaiveDate,
+    /// Последний день регистрации включительно.
+    pub closes_on: NaiveDate,
+}
+
+impl Default for RegistrationConfig {
+    fn default() -> Self {
+        Self {
+            opens_on: NaiveDate::from_ymd_opt(2025, 9, 1).unwrap(),
+            closes_on: NaiveDate::from_ymd_opt(2025, 9, 30).unwrap(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct TeamsConfig {
+    pub max_size: usize,
+}
+
+impl Default for TeamsConfig {
+    fn default() -> Self {
+        Self { max_size: 8 }
+    }
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct StaffChatConfig {
+    /// Переопределяется `STAFF_CHAT_ID`. Без него уведомления не отправляются.
+    pub chat_id: Option<i64>,
+}
+
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct FeaturesConfig {
+    /// Проверять граф зависимостей заданий при запуске.
+    pub validate_task_graph_on_start: bool,
+    /// Отправлять в чат организаторов комментарии, фото-ответы и отмены записи.
+    pub staff_notifications: bool,
+}
+
+impl Default for FeaturesConfig {
+    fn default() -> Self {
+        Self {
+            validate_task_graph_on_start: true,
+            staff_notifications: true,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct LocalesConfig {
+    /// Каталог с шаблонами текстов, по подкаталогу на язык.
+    pub dir: PathBuf,
+}
+
+impl Default for LocalesConfig {
+    fn default() -> Self {
+        Self {
+            dir: PathBuf::from("locales"),
+        }
+    }
+}
+
+/// Разделы меню на каждом этапе мероприятия. Текущий этап переключается командой `/phase`.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct PhasesConfig {
+    /// Этап, пока организаторы ни разу его не переключали.
+    pub initial: EventPhase,
+    pub registration: Vec<MenuItem>,
+    pub quest: Vec<MenuItem>,
+    pub final_booking: Vec<MenuItem>,
+    pub post_event: Vec<MenuItem>,
+}
+
+impl PhasesConfig {
+    pub fn menu_items(&self, phase: EventPhase) -> &[MenuItem] {
+        match phase {
+            EventPhase::Registration => &self.registration,
+            EventPhase::Quest => &self.quest,
+            EventPhase::FinalBooking => &self.final_booking,
+            EventPhase::PostEvent => &self.post_event,
+        }
+    }
+}
+
+impl Default for PhasesConfig {
+    fn default() -> Self {
+        Self {
+            initial: EventPhase::Registration,
+            registration: vec![MenuItem::Team, MenuItem::Feedback],
+            quest: vec![
+                MenuItem::Tracks,
+                MenuItem::Team,
+                MenuItem::Characters,
+                MenuItem::Feedback,
+            ],
+            final_booking: vec![
+                MenuItem::FinalReservation,
+                MenuItem::Tracks,
+                MenuItem::Team,
+                MenuItem::Characters,
+                MenuItem::Feedback,
+            ],
+            post_event: vec![MenuItem::Characters, MenuItem::Feedback],
+        }
+    }
+}
+
+/// Шаги нормализации текстовых ответов и верных ответов перед сравнением.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct NormalizationConfig {
+    /// Шаги применяются в том порядке, в котором перечислены.
+    pub steps: Vec<NormalizationStepConfig>,
+    /// Слова, которые удаляет шаг `remove_stop_words`.
+    pub stop_words: Vec<String>,
+}
+
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum NormalizationStepConfig {
+    Lowercase,
+    FoldYo,
+    StripPunctuation,
+    CollapseWhitespace,
+    RemoveStopWords,
+    CanonicalizeNumerals,
+}
+
+impl NormalizationConfig {
+    pub fn normalizer(&self) -> Normalizer {
+        let steps = self
+            .steps
+            .iter()
+            .map(|step| match step {
+                NormalizationStepConfig::Lowercase => NormalizationStep::Lowercase,
+                NormalizationStepConfig::FoldYo => NormalizationStep::FoldYo,
+                NormalizationStepConfig::StripPunctuation => NormalizationStep::StripPunctuation,
+                NormalizationStepConfig::CollapseWhitespace => {
+                    NormalizationStep::CollapseWhitespace
+                }
+                NormalizationStepConfig::RemoveStopWords => {
+                    NormalizationStep::RemoveStopWords(self.stop_words.clone())
+                }
+                NormalizationStepConfig::CanonicalizeNumerals => {
+                    NormalizationStep::CanonicalizeNumerals
+                }
+            })
+            .collect();
+        Normalizer::new(steps)
+    }
+}
+
+impl Default for NormalizationConfig {
+    fn default() -> Self {
+        Self {
+            steps: vec![
+                NormalizationStepConfig::Lowercase,
+                NormalizationStepConfig::FoldYo,
+                NormalizationStepConfig::StripPunctuation,
+                NormalizationStepConfig::CollapseWhitespace,
+                NormalizationStepConfig::RemoveStopWords,
+                NormalizationStepConfig::CanonicalizeNumerals,
+            ],
+            stop_words: vec!["это".to_string()],
+        }
+    }
+}
+
+/// Встроенный HTTP API для панели организаторов.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct HttpConfig {
+    pub enabled: bool,
+    /// Переопределяется `HTTP_BIND`.
+    pub bind: SocketAddr,
+    /// Запрос с токеном выполняется от имени организатора и с его правами.
+    pub tokens: Vec<ApiTokenConfig>,
+}
+
+impl Default for HttpConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            bind: SocketAddr::from(([127, 0, 0, 1], 8080)),
+            tokens: Vec::new(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ApiTokenConfig {
+    pub token: String,
+    pub user_id: i64,
+}
+
+/// Пустая переменная окружения считается незаданной.
+fn env_var(name: &str) -> Option<String> {
+    env::var(name).ok().filter(|value| !value.is_empty())
+}
+
+impl Config {
+    /// Читает файл из `CONFIG_PATH` (по умолчанию `config.toml`), применяет переменные
+    /// окружения и проверяет результат. Если файла по умолчанию нет, берутся значения по умолчанию.
+    pub fn load() -> Result<Self, ConfigError> {
+        let path = env_var("CONFIG_PATH");
+        let mut config = match &path {
+            Some(path) => Self::from_file(path)?,
+            None if Path::new(DEFAULT_CONFIG_PATH).exists() => {
+                Self::from_file(DEFAULT_CONFIG_PATH)?
+            }
+            None => Self::default(),
+        };
+        config.apply_env()?;
+        config.validate()?;
+        Ok(config)
+    }
+
+    fn from_file(path: &str) -> Result<Self, ConfigError> {
+        let content =
+            fs::read_to_string(path).map_err(|err| ConfigError::Read(path.into(), err))?;
+        toml::from_str(&content).map_err(|err| ConfigError::Parse(path.into(), err))
+    }
+
+    fn apply_env(&mut self) -> Result<(), ConfigError> {
+        if let Some(uri) = env_var("DATABASE_URI") {
+            self.database.uri = uri;
+        }
+        if let Some(size) = env_var("DATABASE_POOL_SIZE") {
+            self.database.pool_size = size
+                .parse()
+                .map_err(|_| ConfigError::Env("DATABASE_POOL_SIZE", size))?;
+        }
+        if let Some(token) = env_var("TELOXIDE_TOKEN") {
+            self.bot.token = token;
+        }
+        if let Some(id) = env_var("STAFF_CHAT_ID") {
+            self.staff_chat.chat_id = Some(
+                id.parse()
+                    .map_err(|_| ConfigError::Env("STAFF_CHAT_ID", id))?,
+            );
+        }
+        if let Some(bind) = env_var("HTTP_BIND") {
+            self.http.bind = bind
+                .parse()
+                .map_err(|_| ConfigError::Env("HTTP_BIND", bind))?;
+        }
+        Ok(())
+    }
+
+    fn validate(&self) -> Result<(), ConfigError> {
+        let invalid = |message: &str| Err(ConfigError::Invalid(message.to_string()));
+        if self.database.uri.is_empty() {
+            return invalid("database.uri (DATABASE_URI) must be set");
+        }
+        if self.database.pool_size == 0 || self.database.dialogue_pool_size == 0 {
+            return invalid("database pool sizes must be positive");
+        }
+        if self.bot.token.is_empty() {
+            return invalid("bot.token (TELOXIDE_TOKEN) must be set");
+        }
+        if self.teams.max_size == 0 {
+            return invalid("teams.max_size must be positive");
+        }
+        if self.registration.opens_on > self.registration.closes_on {
+            return invalid("registration.opens_on must not be after registration.closes_on");
+        }
+        if self.registration.closes_on > self.event.final_date {
+            return invalid("registration must close before event.final_date");
+        }
+        if self.event.final_venue.trim().is_empty() {
+            return invalid("event.final_venue must not be empty");
+        }
+        if self.locales.dir.as_os_str().is_empty() {
+            return invalid("locales.dir must not be empty");
+        }
+        if self.http.enabled && self.http.tokens.is_empty() {
+            return invalid("http.tokens must not be empty when http is enabled");
+        }
+        if self.http.tokens.iter().any(|t| t.token.trim().is_empty()) {
+            return invalid("http.tokens must not contain empty tokens");
+        }
+        if self
+            .normalization
+            .stop_words
+            .iter()
+            .any(|w| w.trim().is_empty())
+        {
+            return invalid("normalization.stop_words must not contain empty words");
+        }
+        Ok(())
+    }
+}
@@ -1,6 +1,6 @@
 use crate::domain::models::Places;
 use crate::domain::models::SlotID;
-use crate::domain::models::{TeamID, TrackTag, UserID};
+use crate::domain::models::{FeedbackID, TeamID, TrackTag, UserID};
 
 #[derive(thiserror::Error, Debug)]
 pub enum DomainError {
@@ -36,4 +36,7 @@ pub enum DomainError {
 
     #[error("team {0:?} not reserved slot")]
     TeamNotReservedSlot(TeamID),
+
+    #[error("feedback {0:?} is archived")]
+    FeedbackIsArchived(FeedbackID),
 }
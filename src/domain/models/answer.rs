@@ -1,7 +1,7 @@
 use chrono::{DateTime, Utc};
 
-use crate::domain::models::TaskID;
 use crate::domain::models::points::Points;
+use crate::domain::models::{ScoreBreakdown, TaskID};
 
 #[derive(Debug, Clone)]
 pub struct AnswerText(String);
@@ -20,16 +20,30 @@ impl AnswerText {
 pub struct Answer {
     task_id: TaskID,
     text: AnswerText,
-    points: Points,
+    score: ScoreBreakdown,
+    completed: bool,
+    /// Фото-ответ ждёт решения проверяющего.
+    pending_review: bool,
+    attempts: i32,
     created_at: DateTime<Utc>,
 }
 
 impl Answer {
-    pub fn new(task_id: TaskID, text: AnswerText, points: Points) -> Self {
+    pub fn new(
+        task_id: TaskID,
+        text: AnswerText,
+        score: ScoreBreakdown,
+        completed: bool,
+        pending_review: bool,
+        attempts: i32,
+    ) -> Self {
         Self {
             task_id,
             text,
-            points,
+            score,
+            completed,
+            pending_review,
+            attempts,
             created_at: Utc::now(),
         }
     }
@@ -37,13 +51,19 @@ impl Answer {
     pub fn restore(
         task_id: TaskID,
         text: AnswerText,
-        points: Points,
+        score: ScoreBreakdown,
+        completed: bool,
+        pending_review: bool,
+        attempts: i32,
         created_at: DateTime<Utc>,
     ) -> Self {
         Self {
             task_id,
             text,
-            points,
+            score,
+            completed,
+            pending_review,
+            attempts,
             created_at,
         }
     }
@@ -57,7 +77,16 @@ impl Answer {
     }
 
     pub fn points(&self) -> Points {
-        self.points
+        self.score.total()
+    }
+
+    pub fn score(&self) -> &ScoreBreakdown {
+        &self.score
+    }
+
+    /// Сколько раз команда отвечала на задание, включая этот ответ.
+    pub fn attempts(&self) -> i32 {
+        self.attempts
     }
 
     pub fn created_at(&self) -> &DateTime<Utc> {
@@ -65,10 +94,14 @@ impl Answer {
     }
 
     pub fn is_ok(&self) -> bool {
-        self.points.is_positive()
+        self.completed
+    }
+
+    pub fn is_pending_review(&self) -> bool {
+        self.pending_review
     }
 
     pub fn is_failed(&self) -> bool {
-        self.points.is_zero()
+        !self.completed
     }
 }
@@ -0,0 +1,156 @@
+use std::str::FromStr;
+
+use chrono::{DateTime, Utc};
+
+use crate::domain::error::DomainError;
+use crate::domain::models::UserID;
+
+pub type AuditEntryID = i32;
+
+/// Действие организатора, попадающее в журнал.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AuditAction {
+    UploadMedia,
+    RenameMedia,
+    DeleteMedia,
+    ReplyToFeedback,
+    ArchiveFeedback,
+    ReviewPhotoAnswer,
+    GrantRole,
+    RevokeRole,
+    SwitchEventPhase,
+    ResetDialogue,
+}
+
+impl AuditAction {
+    pub const ALL: [AuditAction; 10] = [
+        AuditAction::UploadMedia,
+        AuditAction::RenameMedia,
+        AuditAction::DeleteMedia,
+        AuditAction::ReplyToFeedback,
+        AuditAction::ArchiveFeedback,
+        AuditAction::ReviewPhotoAnswer,
+        AuditAction::GrantRole,
+        AuditAction::RevokeRole,
+        AuditAction::SwitchEventPhase,
+        AuditAction::ResetDialogue,
+    ];
+
+    pub fn code(&self) -> &'static str {
+        match self {
+            AuditAction::UploadMedia => "media_upload",
+            AuditAction::RenameMedia => "media_rename",
+            AuditAction::DeleteMedia => "media_delete",
+            AuditAction::ReplyToFeedback => "feedback_reply",
+            AuditAction::ArchiveFeedback => "feedback_archive",
+            AuditAction::ReviewPhotoAnswer => "photo_review",
+            AuditAction::GrantRole => "role_grant",
+            AuditAction::RevokeRole => "role_revoke",
+            AuditAction::SwitchEventPhase => "phase_switch",
+            AuditAction::ResetDialogue => "dialogue_reset",
+        }
+    }
+}
+
+impl FromStr for AuditAction {
+    type Err = DomainError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        AuditAction::ALL
+            .into_iter()
+            .find(|action| action.code() == s)
+            .ok_or_else(|| DomainError::InvalidValue(format!("unknown audit action: {s}")))
+    }
+}
+
+/// Запись журнала: кто, что и с каким объектом сделал, значения до и после.
+/// Записи только добавляются и никогда не меняются.
+#[derive(Debug, Clone)]
+pub struct AuditEntry {
+    /// Появляется после сохранения.
+    id: Option<AuditEntryID>,
+    actor_id: UserID,
+    action: AuditAction,
+    /// Объект действия, например `media:intro` или `feedback:12`.
+    target: String,
+    before: Option<String>,
+    after: Option<String>,
+    created_at: DateTime<Utc>,
+}
+
+impl AuditEntry {
+    pub fn new(
+        actor_id: UserID,
+        action: AuditAction,
+        target: String,
+        before: Option<String>,
+        after: Option<String>,
+    ) -> Self {
+        Self {
+            id: None,
+            actor_id,
+            action,
+            target,
+            before,
+            after,
+            created_at: Utc::now(),
+        }
+    }
+
+    pub fn restore(
+        id: AuditEntryID,
+        actor_id: UserID,
+        action: AuditAction,
+        target: String,
+        before: Option<String>,
+        after: Option<String>,
+        created_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            id: Some(id),
+            actor_id,
+            action,
+            target,
+            before,
+            after,
+            created_at,
+        }
+    }
+
+    pub fn id(&self) -> Option<AuditEntryID> {
+        self.id
+    }
+
+    pub fn actor_id(&self) -> UserID {
+        self.actor_id
+    }
+
+    pub fn action(&self) -> AuditAction {
+        self.action
+    }
+
+    pub fn target(&self) -> &str {
+        &self.target
+    }
+
+    pub fn before(&self) -> Option<&str> {
+        self.before.as_deref()
+    }
+
+    pub fn after(&self) -> Option<&str> {
+        self.after.as_deref()
+    }
+
+    pub fn created_at(&self) -> &DateTime<Utc> {
+        &self.created_at
+    }
+}
+
+/// Фильтр журнала, пустые поля не ограничивают выборку.
+#[derive(Debug, Clone, Default)]
+pub struct AuditFilter {
+    pub actor_id: Option<UserID>,
+    pub action: Option<AuditAction>,
+    /// Подстрока объекта действия.
+    pub target: Option<String>,
+}
@@ -1,11 +1,13 @@
 use crate::domain::error::DomainError;
-use crate::domain::models::MediaID;
+use crate::domain::models::{
+    MediaID, NormalizationStep, Normalizer, TaskID, Team, TrackStatus, TrackTag, is_near_miss,
+};
 use crate::utils::uuid::new_pseudo_uuid;
 use crate::{not_empty_string_impl, pseudo_uuid_impl};
 
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct CharacterID(String);
 pseudo_uuid_impl!(CharacterID, 4);
 
@@ -27,6 +29,27 @@ not_empty_string_impl!(CharacterLegacy);
 
 pub type SerialNumber = u32;
 
+/// Условие, при котором команда открывает карточку персонажа.
+#[derive(Debug, Clone, PartialEq)]
+pub enum CharacterUnlock {
+    /// Команда решила задание.
+    Task(TaskID),
+    /// Команда прошла трек до конца.
+    Track(TrackTag),
+}
+
+impl CharacterUnlock {
+    pub fn is_met(&self, team: &Team) -> bool {
+        match self {
+            CharacterUnlock::Task(task_id) => team.answer(*task_id).is_some_and(|a| a.is_ok()),
+            CharacterUnlock::Track(tag) => matches!(
+                team.started_tracks().get(tag),
+                Some(TrackStatus::Finished(..))
+            ),
+        }
+    }
+}
+
 pub struct Character {
     id: CharacterID,
     index: SerialNumber,
@@ -35,6 +58,10 @@ pub struct Character {
     facts: Vec<CharacterFact>,
     legacy: CharacterLegacy,
     media_id: MediaID,
+    /// Без условия карточка открыта всем.
+    unlock: Option<CharacterUnlock>,
+    /// Картинка закрытой карточки.
+    silhouette_media_id: Option<MediaID>,
 }
 
 impl Character {
@@ -54,6 +81,8 @@ impl Character {
             facts,
             legacy,
             media_id,
+            unlock: None,
+            silhouette_media_id: None,
         }
     }
 
@@ -74,9 +103,22 @@ impl Character {
             facts,
             legacy,
             media_id,
+            unlock: None,
+            silhouette_media_id: None,
         }
     }
 
+    /// Закрывает карточку до выполнения условия, пока показывая силуэт.
+    pub fn with_unlock(
+        mut self,
+        unlock: Option<CharacterUnlock>,
+        silhouette_media_id: Option<MediaID>,
+    ) -> Self {
+        self.unlock = unlock;
+        self.silhouette_media_id = silhouette_media_id;
+        self
+    }
+
     pub fn id(&self) -> &CharacterID {
         &self.id
     }
@@ -104,4 +146,65 @@ impl Character {
     pub fn media_id(&self) -> &MediaID {
         &self.media_id
     }
+
+    pub fn unlock(&self) -> Option<&CharacterUnlock> {
+        self.unlock.as_ref()
+    }
+
+    pub fn silhouette_media_id(&self) -> Option<&MediaID> {
+        self.silhouette_media_id.as_ref()
+    }
+
+    /// Открыта ли карточка для команды; без команды открыты только персонажи без условия.
+    pub fn is_unlocked(&self, team: Option<&Team>) -> bool {
+        match (&self.unlock, team) {
+            (None, _) => true,
+            (Some(unlock), Some(team)) => unlock.is_met(team),
+            (Some(_), None) => false,
+        }
+    }
+}
+
+fn name_normalizer() -> Normalizer {
+    Normalizer::new(vec![
+        NormalizationStep::Lowercase,
+        NormalizationStep::FoldYo,
+        NormalizationStep::StripPunctuation,
+        NormalizationStep::CollapseWhitespace,
+    ])
+}
+
+/// Нечёткий поиск по имени: сначала персонажи, в имени которых есть запрос,
+/// затем те, у кого запрос отличается от имени или одного из его слов на пару букв.
+pub fn search_characters<'a>(characters: &'a [Character], query: &str) -> Vec<&'a Character> {
+    let normalizer = name_normalizer();
+    let query = normalizer.normalize(query);
+    if query.is_empty() {
+        return vec![];
+    }
+    let (mut found, similar): (Vec<_>, Vec<_>) = characters
+        .iter()
+        .filter_map(|c| {
+            let name = normalizer.normalize(c.name.as_str());
+            if name.contains(&query) {
+                Some((c, true))
+            } else {
+                let similar = is_near_miss(&query, &name)
+                    || name.split(' ').any(|word| is_near_miss(&query, word));
+                similar.then_some((c, false))
+            }
+        })
+        .partition(|(_, exact)| *exact);
+    found.extend(similar);
+    found.into_iter().map(|(c, _)| c).collect()
+}
+
+/// Персонажи, имена которых упоминаются в тексте.
+pub fn mentioned_characters<'a>(characters: &'a [Character], text: &str) -> Vec<&'a Character> {
+    let normalizer = name_normalizer();
+    let text = normalizer.normalize(text);
+    characters
+        .iter()
+        .filter(|c| text.contains(&normalizer.normalize(c.name.as_str())))
+        .collect()
 }
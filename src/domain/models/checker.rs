@@ -0,0 +1,336 @@
+use std::collections::BTreeSet;
+
+use crate::domain::error::DomainError;
+use crate::domain::models::{
+    CorrectAnswer, GeoArea, GeoPoint, Normalizer, is_close_enough, is_near_miss,
+};
+
+/// Разделитель элементов в тексте ответа на задания с несколькими вариантами и на порядок.
+pub const ANSWER_ITEMS_SEPARATOR: &str = "\n";
+
+fn parse_number(s: &str) -> Option<f64> {
+    let s: String = s
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .map(|c| match c {
+            ',' => '.',
+            '−' => '-',
+            c => c,
+        })
+        .collect();
+    s.parse::<f64>().ok().filter(|v| v.is_finite())
+}
+
+#[derive(Debug, Clone)]
+pub enum AnswerInput {
+    Text(String),
+    Items(Vec<String>),
+    Location(GeoPoint),
+}
+
+impl AnswerInput {
+    /// Текст, который сохраняется вместе с ответом.
+    pub fn to_text(&self) -> String {
+        match self {
+            Self::Text(text) => text.trim().to_string(),
+            Self::Items(items) => items.join(ANSWER_ITEMS_SEPARATOR),
+            Self::Location(point) => format!("{},{}", point.latitude(), point.longitude()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AnswerGrade {
+    Correct,
+    /// Неверно, но совсем рядом: скорее всего, опечатка.
+    Close,
+    Wrong,
+}
+
+pub trait AnswerChecker {
+    fn check(&self, input: &AnswerInput) -> bool;
+
+    fn grade(&self, input: &AnswerInput) -> AnswerGrade {
+        if self.check(input) {
+            AnswerGrade::Correct
+        } else {
+            AnswerGrade::Wrong
+        }
+    }
+
+    /// Доля верности ответа от 0 до 1, используется для частичного зачёта.
+    fn credit(&self, input: &AnswerInput) -> f64 {
+        if self.check(input) { 1.0 } else { 0.0 }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct NumericAnswer {
+    value: f64,
+    tolerance: f64,
+}
+
+impl NumericAnswer {
+    pub fn new(value: f64, tolerance: f64) -> Result<Self, DomainError> {
+        if !value.is_finite() {
+            return Err(DomainError::InvalidValue(format!(
+                "invalid NumericAnswer: expected finite value, got {value}"
+            )));
+        }
+        if !tolerance.is_finite() || tolerance < 0.0 {
+            return Err(DomainError::InvalidValue(format!(
+                "invalid NumericAnswer: expected non-negative tolerance, got {tolerance}"
+            )));
+        }
+        Ok(Self { value, tolerance })
+    }
+
+    pub fn value(&self) -> f64 {
+        self.value
+    }
+
+    pub fn tolerance(&self) -> f64 {
+        self.tolerance
+    }
+}
+
+pub struct TextChecker<'a> {
+    normalizer: &'a Normalizer,
+    correct_answers: &'a [CorrectAnswer],
+    max_levenshtein_distance: usize,
+}
+
+impl<'a> TextChecker<'a> {
+    pub fn new(
+        normalizer: &'a Normalizer,
+        correct_answers: &'a [CorrectAnswer],
+        max_levenshtein_distance: usize,
+    ) -> Self {
+        Self {
+            normalizer,
+            correct_answers,
+            max_levenshtein_distance,
+        }
+    }
+
+    fn correct_answers(&self) -> impl Iterator<Item = String> {
+        self.correct_answers
+            .iter()
+            .map(|correct| self.normalizer.normalize(correct.as_str()))
+    }
+}
+
+impl AnswerChecker for TextChecker<'_> {
+    fn check(&self, input: &AnswerInput) -> bool {
+        let AnswerInput::Text(text) = input else {
+            return false;
+        };
+        let text = self.normalizer.normalize(text);
+        self.correct_answers()
+            .any(|correct| is_close_enough(&text, &correct, self.max_levenshtein_distance))
+    }
+
+    fn grade(&self, input: &AnswerInput) -> AnswerGrade {
+        if self.check(input) {
+            return AnswerGrade::Correct;
+        }
+        let AnswerInput::Text(text) = input else {
+            return AnswerGrade::Wrong;
+        };
+        let text = self.normalizer.normalize(text);
+        if self
+            .correct_answers()
+            .any(|correct| is_near_miss(&text, &correct))
+        {
+            AnswerGrade::Close
+        } else {
+            AnswerGrade::Wrong
+        }
+    }
+}
+
+/// Засчитывает ответ, только если выбраны все верные варианты и ни одного лишнего.
+pub struct MultiChoiceChecker<'a> {
+    normalizer: &'a Normalizer,
+    correct_answers: &'a [CorrectAnswer],
+}
+
+impl<'a> MultiChoiceChecker<'a> {
+    pub fn new(normalizer: &'a Normalizer, correct_answers: &'a [CorrectAnswer]) -> Self {
+        Self {
+            normalizer,
+            correct_answers,
+        }
+    }
+
+    fn sets(&self, items: &[String]) -> (BTreeSet<String>, BTreeSet<String>) {
+        let selected = items.iter().map(|i| self.normalizer.normalize(i)).collect();
+        let correct = self
+            .correct_answers
+            .iter()
+            .map(|c| self.normalizer.normalize(c.as_str()))
+            .collect();
+        (selected, correct)
+    }
+}
+
+impl AnswerChecker for MultiChoiceChecker<'_> {
+    fn check(&self, input: &AnswerInput) -> bool {
+        let AnswerInput::Items(items) = input else {
+            return false;
+        };
+        let (selected, correct) = self.sets(items);
+        selected == correct
+    }
+
+    /// Каждый лишний вариант отменяет один верный.
+    fn credit(&self, input: &AnswerInput) -> f64 {
+        let AnswerInput::Items(items) = input else {
+            return 0.0;
+        };
+        let (selected, correct) = self.sets(items);
+        if correct.is_empty() {
+            return 0.0;
+        }
+        let hits = selected.intersection(&correct).count() as f64;
+        let misses = selected.difference(&correct).count() as f64;
+        ((hits - misses) / correct.len() as f64).max(0.0)
+    }
+}
+
+/// Верные ответы задания хранятся в правильном порядке.
+pub struct OrderingChecker<'a> {
+    normalizer: &'a Normalizer,
+    correct_answers: &'a [CorrectAnswer],
+}
+
+impl<'a> OrderingChecker<'a> {
+    pub fn new(normalizer: &'a Normalizer, correct_answers: &'a [CorrectAnswer]) -> Self {
+        Self {
+            normalizer,
+            correct_answers,
+        }
+    }
+
+    fn in_place(&self, item: &str, correct: &CorrectAnswer) -> bool {
+        self.normalizer.normalize(item) == self.normalizer.normalize(correct.as_str())
+    }
+}
+
+impl AnswerChecker for OrderingChecker<'_> {
+    fn check(&self, input: &AnswerInput) -> bool {
+        let AnswerInput::Items(items) = input else {
+            return false;
+        };
+        items.len() == self.correct_answers.len()
+            && items
+                .iter()
+                .zip(self.correct_answers)
+                .all(|(item, correct)| self.in_place(item, correct))
+    }
+
+    /// Доля вариантов, стоящих на своих местах.
+    fn credit(&self, input: &AnswerInput) -> f64 {
+        let AnswerInput::Items(items) = input else {
+            return 0.0;
+        };
+        if self.correct_answers.is_empty() || items.len() != self.correct_answers.len() {
+            return 0.0;
+        }
+        let hits = items
+            .iter()
+            .zip(self.correct_answers)
+            .filter(|(item, correct)| self.in_place(item, correct))
+            .count();
+        hits as f64 / self.correct_answers.len() as f64
+    }
+}
+
+pub struct NumberChecker<'a> {
+    normalizer: &'a Normalizer,
+    answer: Option<NumericAnswer>,
+}
+
+impl<'a> NumberChecker<'a> {
+    pub fn new(normalizer: &'a Normalizer, answer: Option<NumericAnswer>) -> Self {
+        Self { normalizer, answer }
+    }
+}
+
+impl AnswerChecker for NumberChecker<'_> {
+    /// Число разбирается из ответа как есть, чтобы не потерять знак и экспоненту.
+    /// Нормализованный текст нужен только для чисел, записанных словами.
+    fn check(&self, input: &AnswerInput) -> bool {
+        let (AnswerInput::Text(text), Some(answer)) = (input, self.answer) else {
+            return false;
+        };
+        parse_number(text)
+            .or_else(|| parse_number(&self.normalizer.normalize(text)))
+            .is_some_and(|v| (v - answer.value()).abs() <= answer.tolerance())
+    }
+}
+
+pub struct LocationChecker {
+    area: Option<GeoArea>,
+}
+
+impl LocationChecker {
+    pub fn new(area: Option<GeoArea>) -> Self {
+        Self { area }
+    }
+}
+
+impl AnswerChecker for LocationChecker {
+    fn check(&self, input: &AnswerInput) -> bool {
+        let (AnswerInput::Location(point), Some(area)) = (input, self.area) else {
+            return false;
+        };
+        area.contains(point)
+    }
+}
+
+/// Фотографии проверяют организаторы, автоматически такой ответ не засчитывается.
+pub struct ManualChecker;
+
+impl AnswerChecker for ManualChecker {
+    fn check(&self, _input: &AnswerInput) -> bool {
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_numbers_as_typed() {
+        let cases = [
+            ("5", 5.0, true),
+            ("-5", -5.0, true),
+            ("−5", -5.0, true),
+            ("- 5", -5.0, true),
+            ("+5", 5.0, true),
+            ("5", -5.0, false),
+            ("-5", 5.0, false),
+            ("3,5", 3.5, true),
+            (" 3.5 ", 3.5, true),
+            ("1e-3", 0.001, true),
+            ("1E3", 1000.0, true),
+            ("1 000", 1000.0, true),
+            ("двадцать пять", 25.0, true),
+            ("пять", -5.0, false),
+            ("пять рублей", 5.0, false),
+            ("", 0.0, false),
+        ];
+        let normalizer = Normalizer::default();
+        for (input, value, expected) in cases {
+            let checker =
+                NumberChecker::new(&normalizer, Some(NumericAnswer::new(value, 0.0).unwrap()));
+            assert_eq!(
+                checker.check(&AnswerInput::Text(input.to_string())),
+                expected,
+                "input: {input:?}, value: {value}"
+            );
+        }
+    }
+}
@@ -0,0 +1,53 @@
+use chrono::{DateTime, Utc};
+
+use crate::domain::models::{
+    Places, Points, Site, TaskID, TeamID, TeamName, TrackName, TrackStatus, TrackTag, percent,
+};
+
+/// Трек без заданий: только то, что нужно для подсчёта процентов на панели.
+#[derive(Debug, Clone)]
+pub struct TrackSummary {
+    pub tag: TrackTag,
+    pub name: TrackName,
+    /// Сумма максимумов заданий трека, как в `Track::max_points`.
+    pub max_points: Points,
+}
+
+impl TrackSummary {
+    /// Доля от максимума трека, которую составляют `points`.
+    pub fn percent(&self, points: Points) -> f32 {
+        percent(points, self.max_points)
+    }
+}
+
+/// Прогресс команды по начатому треку. Считается запросом к базе,
+/// без загрузки команды целиком.
+#[derive(Debug, Clone)]
+pub struct TrackTeamProgress {
+    pub track_tag: TrackTag,
+    pub team_id: TeamID,
+    pub team_name: TeamName,
+    pub status: TrackStatus,
+    pub points: Points,
+}
+
+/// Фото-ответ, который ждёт проверки организатором.
+#[derive(Debug, Clone)]
+pub struct PendingPhotoReview {
+    pub team_id: TeamID,
+    pub team_name: TeamName,
+    pub task_id: TaskID,
+    /// Трек и номер задания в нём, если задание ещё существует.
+    pub track_tag: Option<TrackTag>,
+    pub number: Option<usize>,
+    pub created_at: DateTime<Utc>,
+}
+
+/// Занятость слотов финала на одной площадке.
+#[derive(Debug, Clone)]
+pub struct SiteOccupancy {
+    pub site: Site,
+    pub slots: usize,
+    pub capacity: Places,
+    pub reserved: Places,
+}
@@ -1,18 +1,119 @@
+use chrono::{DateTime, Utc};
+
 use crate::domain::error::DomainError;
 use crate::domain::models::UserID;
 use crate::not_empty_string_impl;
 
+pub type FeedbackID = i32;
+
+#[derive(Debug, Clone)]
 pub struct FeedbackText(String);
 not_empty_string_impl!(FeedbackText);
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FeedbackStatus {
+    New,
+    Answered,
+    Archived,
+}
+
+/// Ответ организатора на обратную связь.
+#[derive(Debug, Clone)]
+pub struct FeedbackReply {
+    author_id: UserID,
+    text: FeedbackText,
+    created_at: DateTime<Utc>,
+}
+
+impl FeedbackReply {
+    pub fn new(author_id: UserID, text: FeedbackText) -> Self {
+        Self {
+            author_id,
+            text,
+            created_at: Utc::now(),
+        }
+    }
+
+    pub fn restore(author_id: UserID, text: FeedbackText, created_at: DateTime<Utc>) -> Self {
+        Self {
+            author_id,
+            text,
+            created_at,
+        }
+    }
+
+    pub fn author_id(&self) -> UserID {
+        self.author_id
+    }
+
+    pub fn text(&self) -> &FeedbackText {
+        &self.text
+    }
+
+    pub fn created_at(&self) -> &DateTime<Utc> {
+        &self.created_at
+    }
+}
+
 pub struct Feedback {
+    /// Появляется после сохранения.
+    id: Option<FeedbackID>,
     author_id: UserID,
     text: FeedbackText,
+    status: FeedbackStatus,
+    created_at: DateTime<Utc>,
+    replies: Vec<FeedbackReply>,
 }
 
 impl Feedback {
     pub fn new(author_id: UserID, text: FeedbackText) -> Self {
-        Self { author_id, text }
+        Self {
+            id: None,
+            author_id,
+            text,
+            status: FeedbackStatus::New,
+            created_at: Utc::now(),
+            replies: vec![],
+        }
+    }
+
+    pub fn restore(
+        id: FeedbackID,
+        author_id: UserID,
+        text: FeedbackText,
+        status: FeedbackStatus,
+        created_at: DateTime<Utc>,
+        replies: Vec<FeedbackReply>,
+    ) -> Self {
+        Self {
+            id: Some(id),
+            author_id,
+            text,
+            status,
+            created_at,
+            replies,
+        }
+    }
+
+    pub fn reply(
+        &mut self,
+        author_id: UserID,
+        text: FeedbackText,
+    ) -> Result<&FeedbackReply, DomainError> {
+        if self.status == FeedbackStatus::Archived {
+            return Err(DomainError::FeedbackIsArchived(self.id.unwrap_or_default()));
+        }
+        self.status = FeedbackStatus::Answered;
+        self.replies.push(FeedbackReply::new(author_id, text));
+        Ok(self.replies.last().unwrap())
+    }
+
+    pub fn archive(&mut self) {
+        self.status = FeedbackStatus::Archived;
+    }
+
+    pub fn id(&self) -> Option<FeedbackID> {
+        self.id
     }
 
     pub fn author_id(&self) -> UserID {
@@ -22,4 +123,16 @@ impl Feedback {
     pub fn text(&self) -> &FeedbackText {
         &self.text
     }
+
+    pub fn status(&self) -> FeedbackStatus {
+        self.status
+    }
+
+    pub fn created_at(&self) -> &DateTime<Utc> {
+        &self.created_at
+    }
+
+    pub fn replies(&self) -> &[FeedbackReply] {
+        &self.replies
+    }
 }
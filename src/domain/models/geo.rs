@@ -0,0 +1,68 @@
+use serde::{Deserialize, Serialize};
+
+use crate::domain::error::DomainError;
+
+const EARTH_RADIUS_METERS: f64 = 6_371_000.0;
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct GeoPoint {
+    latitude: f64,
+    longitude: f64,
+}
+
+impl GeoPoint {
+    pub fn new(latitude: f64, longitude: f64) -> Result<Self, DomainError> {
+        if !(-90.0..=90.0).contains(&latitude) {
+            return Err(DomainError::InvalidValue(format!(
+                "invalid GeoPoint: expected latitude in [-90; 90], got {latitude}"
+            )));
+        }
+        if !(-180.0..=180.0).contains(&longitude) {
+            return Err(DomainError::InvalidValue(format!(
+                "invalid GeoPoint: expected longitude in [-180; 180], got {longitude}"
+            )));
+        }
+        Ok(Self {
+            latitude,
+            longitude,
+        })
+    }
+
+    pub fn latitude(&self) -> f64 {
+        self.latitude
+    }
+
+    pub fn longitude(&self) -> f64 {
+        self.longitude
+    }
+
+    /// Расстояние по дуге большого круга (формула гаверсинусов), в метрах.
+    pub fn distance_to(&self, other: &GeoPoint) -> f64 {
+        let (lat1, lat2) = (self.latitude.to_radians(), other.latitude.to_radians());
+        let d_lat = lat2 - lat1;
+        let d_lon = (other.longitude - self.longitude).to_radians();
+        let a = (d_lat / 2.0).sin().powi(2) + lat1.cos() * lat2.cos() * (d_lon / 2.0).sin().powi(2);
+        2.0 * EARTH_RADIUS_METERS * a.sqrt().asin()
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct GeoArea {
+    center: GeoPoint,
+    radius: f64,
+}
+
+impl GeoArea {
+    pub fn new(center: GeoPoint, radius: f64) -> Result<Self, DomainError> {
+        if !radius.is_finite() || radius <= 0.0 {
+            return Err(DomainError::InvalidValue(format!(
+                "invalid GeoArea: expected positive radius, got {radius}"
+            )));
+        }
+        Ok(Self { center, radius })
+    }
+
+    pub fn contains(&self, point: &GeoPoint) -> bool {
+        self.center.distance_to(point) <= self.radius
+    }
+}
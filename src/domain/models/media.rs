@@ -1,6 +1,7 @@
 use serde::{Deserialize, Serialize};
 
 use crate::domain::error::DomainError;
+use crate::domain::models::{CharacterID, TaskID, TrackTag};
 use crate::not_empty_string_impl;
 
 pub const MEDIA_ID_MAX_LENGTH: usize = 64;
@@ -9,6 +10,23 @@ pub const MEDIA_ID_MAX_LENGTH: usize = 64;
 pub enum MediaType {
     Image,
     VideoNote,
+    Audio,
+    Voice,
+    Video,
+    Document,
+}
+
+impl MediaType {
+    /// Можно ли отправить вместе с `other` одним альбомом.
+    /// Фото и видео смешиваются, аудио и документы группируются только с себе подобными.
+    pub fn groups_with(&self, other: &MediaType) -> bool {
+        matches!(
+            (self, other),
+            (Self::Image | Self::Video, Self::Image | Self::Video)
+                | (Self::Audio, Self::Audio)
+                | (Self::Document, Self::Document)
+        )
+    }
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -66,6 +84,22 @@ impl Media {
         Self::new(id, file_id, MediaType::VideoNote)
     }
 
+    pub fn audio(id: MediaID, file_id: FileID) -> Self {
+        Self::new(id, file_id, MediaType::Audio)
+    }
+
+    pub fn voice(id: MediaID, file_id: FileID) -> Self {
+        Self::new(id, file_id, MediaType::Voice)
+    }
+
+    pub fn video(id: MediaID, file_id: FileID) -> Self {
+        Self::new(id, file_id, MediaType::Video)
+    }
+
+    pub fn document(id: MediaID, file_id: FileID) -> Self {
+        Self::new(id, file_id, MediaType::Document)
+    }
+
     pub fn id(&self) -> &MediaID {
         &self.id
     }
@@ -78,3 +112,55 @@ impl Media {
         self.media_type
     }
 }
+
+/// Исходные байты медиафайла.
+///
+/// `file_id` действителен только для бота, который получил файл, поэтому байты нужны,
+/// чтобы загрузить файл заново после смены токена.
+pub struct MediaContent {
+    file_name: String,
+    data: Vec<u8>,
+}
+
+impl MediaContent {
+    pub fn new(file_name: String, data: Vec<u8>) -> Result<Self, DomainError> {
+        if file_name.is_empty() {
+            return Err(DomainError::InvalidValue(
+                "invalid media content: empty file name".to_string(),
+            ));
+        }
+        if data.is_empty() {
+            return Err(DomainError::InvalidValue(
+                "invalid media content: empty data".to_string(),
+            ));
+        }
+        Ok(Self { file_name, data })
+    }
+
+    pub fn file_name(&self) -> &str {
+        &self.file_name
+    }
+
+    pub fn data(&self) -> &[u8] {
+        &self.data
+    }
+
+    pub fn into_data(self) -> Vec<u8> {
+        self.data
+    }
+}
+
+/// Где используется медиафайл.
+#[derive(Debug, Clone)]
+pub enum MediaUsage {
+    Track(TrackTag),
+    Task(TaskID),
+    Character(CharacterID),
+}
+
+/// Ссылка на медиафайл, которого нет в библиотеке.
+#[derive(Debug, Clone)]
+pub struct DanglingMediaReference {
+    pub media_id: MediaID,
+    pub usage: MediaUsage,
+}
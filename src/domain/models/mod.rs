@@ -1,25 +1,43 @@
 mod answer;
+mod audit;
 mod character;
+mod checker;
+mod dashboard;
 mod feedback;
+mod geo;
 mod media;
+mod normalization;
 mod not_empty_string;
+mod phase;
 mod points;
 mod pseudo_uuid;
 mod reservation;
+mod role;
+mod scoring;
 mod slot;
 mod task;
+mod task_graph;
 mod team;
 mod track;
 mod user;
 
 pub use answer::*;
+pub use audit::*;
 pub use character::*;
+pub use checker::*;
+pub use dashboard::*;
 pub use feedback::*;
+pub use geo::*;
 pub use media::*;
+pub use normalization::*;
+pub use phase::*;
 pub use points::*;
 pub use reservation::*;
+pub use role::*;
+pub use scoring::*;
 pub use slot::*;
 pub use task::*;
+pub use task_graph::*;
 pub use team::*;
 pub use track::*;
 pub use user::*;
@@ -0,0 +1,327 @@
+use levenshtein::levenshtein;
+
+/// Шаг нормализации текста ответа. Шаги применяются в том порядке, в котором заданы.
+#[derive(Debug, Clone)]
+pub enum NormalizationStep {
+    Lowercase,
+    /// «ё» → «е».
+    FoldYo,
+    /// Любая пунктуация, кавычки и тире заменяются пробелом.
+    /// Десятичный разделитель между цифрами сохраняется как точка.
+    StripPunctuation,
+    CollapseWhitespace,
+    /// Удаляет слова-паразиты, если после этого что-то остаётся.
+    RemoveStopWords(Vec<String>),
+    /// Числительные словами заменяются числами: «двадцать пять» → «25».
+    CanonicalizeNumerals,
+}
+
+#[derive(Debug, Clone)]
+pub struct Normalizer {
+    steps: Vec<NormalizationStep>,
+}
+
+impl Default for Normalizer {
+    fn default() -> Self {
+        Self::new(vec![
+            NormalizationStep::Lowercase,
+            NormalizationStep::FoldYo,
+            NormalizationStep::StripPunctuation,
+            NormalizationStep::CollapseWhitespace,
+            NormalizationStep::RemoveStopWords(vec!["это".to_string()]),
+            NormalizationStep::CanonicalizeNumerals,
+        ])
+    }
+}
+
+impl Normalizer {
+    pub fn new(steps: Vec<NormalizationStep>) -> Self {
+        Self { steps }
+    }
+
+    pub fn normalize(&self, s: &str) -> String {
+        let mut s = s.to_string();
+        for step in &self.steps {
+            s = match step {
+                NormalizationStep::Lowercase => s.to_lowercase(),
+                NormalizationStep::FoldYo => s.replace('ё', "е").replace('Ё', "Е"),
+                NormalizationStep::StripPunctuation => strip_punctuation(&s),
+                NormalizationStep::CollapseWhitespace => collapse_whitespace(&s),
+                NormalizationStep::RemoveStopWords(words) => remove_stop_words(&s, words),
+                NormalizationStep::CanonicalizeNumerals => canonicalize_numerals(&s),
+            };
+        }
+        s
+    }
+}
+
+fn strip_punctuation(s: &str) -> String {
+    let chars: Vec<char> = s.chars().collect();
+    chars
+        .iter()
+        .enumerate()
+        .map(|(i, &c)| {
+            let between_digits = i > 0
+                && chars[i - 1].is_ascii_digit()
+                && chars.get(i + 1).is_some_and(|n| n.is_ascii_digit());
+            if (c == '.' || c == ',') && between_digits {
+                '.'
+            } else if c.is_alphanumeric() || c.is_whitespace() {
+                c
+            } else {
+                ' '
+            }
+        })
+        .collect()
+}
+
+fn collapse_whitespace(s: &str) -> String {
+    s.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+fn remove_stop_words(s: &str, stop_words: &[String]) -> String {
+    let words: Vec<&str> = s
+        .split_whitespace()
+        .filter(|w| !stop_words.iter().any(|stop| stop == w))
+        .collect();
+    if words.is_empty() {
+        s.to_string()
+    } else {
+        words.join(" ")
+    }
+}
+
+fn numeral_value(word: &str) -> Option<u64> {
+    let value = match word {
+        "ноль" | "нуль" => 0,
+        "один" | "одна" | "одно" => 1,
+        "два" | "две" => 2,
+        "три" => 3,
+        "четыре" => 4,
+        "пять" => 5,
+        "шесть" => 6,
+        "семь" => 7,
+        "восемь" => 8,
+        "девять" => 9,
+        "десять" => 10,
+        "одиннадцать" => 11,
+        "двенадцать" => 12,
+        "тринадцать" => 13,
+        "четырнадцать" => 14,
+        "пятнадцать" => 15,
+        "шестнадцать" => 16,
+        "семнадцать" => 17,
+        "восемнадцать" => 18,
+        "девятнадцать" => 19,
+        "двадцать" => 20,
+        "тридцать" => 30,
+        "сорок" => 40,
+        "пятьдесят" => 50,
+        "шестьдесят" => 60,
+        "семьдесят" => 70,
+        "восемьдесят" => 80,
+        "девяносто" => 90,
+        "сто" => 100,
+        "двести" => 200,
+        "триста" => 300,
+        "четыреста" => 400,
+        "пятьсот" => 500,
+        "шестьсот" => 600,
+        "семьсот" => 700,
+        "восемьсот" => 800,
+        "девятьсот" => 900,
+        _ => return None,
+    };
+    Some(value)
+}
+
+fn numeral_multiplier(word: &str) -> Option<u64> {
+    match word {
+        "тысяча" | "тысячи" | "тысяч" => Some(1_000),
+        "миллион" | "миллиона" | "миллионов" => Some(1_000_000),
+        _ => None,
+    }
+}
+
+/// Числительное, которое ещё можно дописать к числу: «двадцать» + «три»,
+/// но не «три» + «один». Иначе это следующее число, например код по цифрам.
+fn extends_number((total, current): (u64, u64), value: u64) -> bool {
+    let limit = match (current, current % 100) {
+        // После «тысячи» идут сотни, а «ноль» ни с чем не складывается.
+        (0, _) if total > 0 => 1000,
+        (0, _) => 0,
+        (_, 0) => 100,
+        (_, tens) if tens >= 20 && tens % 10 == 0 => 10,
+        _ => 0,
+    };
+    value > 0 && value < limit
+}
+
+fn canonicalize_numerals(s: &str) -> String {
+    let mut result: Vec<String> = Vec::new();
+    // (сумма завершённых разрядов, текущий разряд)
+    let mut number: Option<(u64, u64)> = None;
+    for word in s.split_whitespace() {
+        if let Some(value) = numeral_value(word) {
+            number = match number {
+                Some((total, current)) if extends_number((total, current), value) => {
+                    Some((total, current + value))
+                }
+                previous => {
+                    if let Some((total, current)) = previous {
+                        result.push((total + current).to_string());
+                    }
+                    Some((0, value))
+                }
+            };
+        } else if let Some(multiplier) = numeral_multiplier(word) {
+            let (total, current) = number.unwrap_or((0, 0));
+            number = Some((total + current.max(1) * multiplier, 0));
+        } else {
+            if let Some((total, current)) = number.take() {
+                result.push((total + current).to_string());
+            }
+            result.push(word.to_string());
+        }
+    }
+    if let Some((total, current)) = number {
+        result.push((total + current).to_string());
+    }
+    result.join(" ")
+}
+
+/// Одна опечатка допускается примерно на каждые `CHARS_PER_TYPO` символов ответа.
+const CHARS_PER_TYPO: usize = 5;
+
+/// Проверяет, что ответ совпадает с верным с точностью до опечаток.
+///
+/// Допустимое расстояние Левенштейна растёт с длиной верного ответа, но не превышает
+/// `max_distance`. В числах опечатки не допускаются.
+pub fn is_close_enough(answer: &str, correct: &str, max_distance: usize) -> bool {
+    if correct.chars().any(|c| c.is_ascii_digit()) {
+        return answer == correct;
+    }
+    let allowed = max_distance.min(correct.chars().count() / CHARS_PER_TYPO);
+    levenshtein(answer, correct) <= allowed
+}
+
+/// При таком числе символов на опечатку ответ считается «почти верным».
+const CHARS_PER_NEAR_MISS_TYPO: usize = 3;
+
+/// Проверяет, что ответ промахнулся всего на несколько букв.
+///
+/// Полоса шире, чем в `is_close_enough`, и не зависит от настроек задания.
+pub fn is_near_miss(answer: &str, correct: &str) -> bool {
+    if correct.chars().any(|c| c.is_ascii_digit()) {
+        return false;
+    }
+    let allowed = (correct.chars().count() / CHARS_PER_NEAR_MISS_TYPO).max(1);
+    levenshtein(answer, correct) <= allowed
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalizes_real_answers() {
+        let cases = [
+            ("Бауманка", "бауманка"),
+            ("  Николай   Бауман ", "николай бауман"),
+            ("Ёлка", "елка"),
+            ("«Лефортово»", "лефортово"),
+            ("“Лефортово”", "лефортово"),
+            ("Санкт-Петербург", "санкт петербург"),
+            ("1830 — 1917", "1830 1917"),
+            ("Это Бауман!", "бауман"),
+            ("это", "это"),
+            ("Это — это", "это это"),
+            ("двадцать пять", "25"),
+            ("Сто двадцать три", "123"),
+            ("две тысячи двадцать пять", "2025"),
+            ("тысяча восемьсот тридцатый", "1800 тридцатый"),
+            ("пять рублей", "5 рублей"),
+            ("три один четыре", "3 1 4"),
+            ("один два", "1 2"),
+            ("ноль ноль семь", "0 0 7"),
+            ("девятнадцать восемьдесят пять", "19 85"),
+            ("тысяча двадцать три", "1023"),
+            ("3,5", "3.5"),
+            ("3.5 км", "3.5 км"),
+            ("конец.", "конец"),
+            ("А. С. Пушкин", "а с пушкин"),
+            ("Щёлково\t\n", "щелково"),
+        ];
+        let normalizer = Normalizer::default();
+        for (input, expected) in cases {
+            assert_eq!(normalizer.normalize(input), expected, "input: {input:?}");
+        }
+    }
+
+    #[test]
+    fn applies_only_configured_steps() {
+        let cases = [
+            (vec![NormalizationStep::Lowercase], "Ёлка", "ёлка"),
+            (vec![NormalizationStep::FoldYo], "Ёлка", "Елка"),
+            (
+                vec![
+                    NormalizationStep::StripPunctuation,
+                    NormalizationStep::CollapseWhitespace,
+                ],
+                "Это — пять!",
+                "Это пять",
+            ),
+            (vec![NormalizationStep::CanonicalizeNumerals], "пять", "5"),
+            (vec![], " Это ", " Это "),
+        ];
+        for (steps, input, expected) in cases {
+            let normalizer = Normalizer::new(steps);
+            assert_eq!(normalizer.normalize(input), expected, "input: {input:?}");
+        }
+    }
+
+    #[test]
+    fn tolerance_depends_on_answer_length() {
+        let cases = [
+            ("да", "да", 2, true),
+            ("до", "да", 2, false),
+            ("бауман", "бауман", 2, true),
+            ("бавман", "бауман", 2, true),
+            ("бавмен", "бауман", 2, false),
+            ("лефортаво", "лефортово", 2, true),
+            ("лефортаво", "лефортово", 0, false),
+            ("николай бавман", "николай бауман", 2, true),
+            ("никалай бавман", "николай бауман", 2, true),
+            ("никалай бавмен", "николай бауман", 2, false),
+            ("1830", "1830", 2, true),
+            ("1831", "1830", 2, false),
+        ];
+        for (answer, correct, max_distance, expected) in cases {
+            assert_eq!(
+                is_close_enough(answer, correct, max_distance),
+                expected,
+                "answer: {answer:?}, correct: {correct:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn detects_near_misses() {
+        let cases = [
+            ("до", "да", true),
+            ("нет", "да", false),
+            ("бавмен", "бауман", true),
+            ("баумановка", "бауман", false),
+            ("лифортава", "лефортово", true),
+            ("кремль", "лефортово", false),
+            ("1831", "1830", false),
+        ];
+        for (answer, correct, expected) in cases {
+            assert_eq!(
+                is_near_miss(answer, correct),
+                expected,
+                "answer: {answer:?}, correct: {correct:?}"
+            );
+        }
+    }
+}
@@ -0,0 +1,103 @@
+use std::str::FromStr;
+
+use serde::Deserialize;
+
+use crate::domain::error::DomainError;
+
+/// Этап мероприятия. От него зависит, какие разделы меню доступны игрокам.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize)]
+#[serde(try_from = "String")]
+pub enum EventPhase {
+    Registration,
+    Quest,
+    FinalBooking,
+    PostEvent,
+}
+
+impl EventPhase {
+    pub const ALL: [EventPhase; 4] = [
+        EventPhase::Registration,
+        EventPhase::Quest,
+        EventPhase::FinalBooking,
+        EventPhase::PostEvent,
+    ];
+
+    pub fn code(&self) -> &'static str {
+        match self {
+            EventPhase::Registration => "registration",
+            EventPhase::Quest => "quest",
+            EventPhase::FinalBooking => "final_booking",
+            EventPhase::PostEvent => "post_event",
+        }
+    }
+}
+
+impl FromStr for EventPhase {
+    type Err = DomainError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        EventPhase::ALL
+            .into_iter()
+            .find(|phase| phase.code() == s)
+            .ok_or_else(|| DomainError::InvalidValue(format!("unknown event phase: {s}")))
+    }
+}
+
+impl TryFrom<String> for EventPhase {
+    type Error = DomainError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+/// Раздел меню игрока вместе с обработчиками его диалогов.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize)]
+#[serde(try_from = "String")]
+pub enum MenuItem {
+    Tracks,
+    Team,
+    Characters,
+    /// Запись на финал и её отмена, только для капитанов.
+    FinalReservation,
+    Feedback,
+}
+
+impl MenuItem {
+    pub const ALL: [MenuItem; 5] = [
+        MenuItem::Tracks,
+        MenuItem::Team,
+        MenuItem::Characters,
+        MenuItem::FinalReservation,
+        MenuItem::Feedback,
+    ];
+
+    pub fn code(&self) -> &'static str {
+        match self {
+            MenuItem::Tracks => "tracks",
+            MenuItem::Team => "team",
+            MenuItem::Characters => "characters",
+            MenuItem::FinalReservation => "final_reservation",
+            MenuItem::Feedback => "feedback",
+        }
+    }
+}
+
+impl FromStr for MenuItem {
+    type Err = DomainError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        MenuItem::ALL
+            .into_iter()
+            .find(|item| item.code() == s)
+            .ok_or_else(|| DomainError::InvalidValue(format!("unknown menu item: {s}")))
+    }
+}
+
+impl TryFrom<String> for MenuItem {
+    type Error = DomainError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
@@ -1,4 +1,4 @@
-use std::ops::{Add, Div};
+use std::ops::{Add, Sub};
 
 use crate::domain::error::DomainError;
 
@@ -30,6 +30,11 @@ impl Points {
     pub fn is_zero(&self) -> bool {
         self.0 == 0
     }
+
+    /// Умножает баллы на неотрицательный коэффициент с округлением.
+    pub fn scaled(self, factor: f64) -> Self {
+        Self((self.0 as f64 * factor.max(0.0)).round() as i32)
+    }
 }
 
 impl Add for Points {
@@ -39,3 +44,11 @@ impl Add for Points {
         Self(self.0 + rhs.0)
     }
 }
+
+impl Sub for Points {
+    type Output = Points;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self((self.0 - rhs.0).max(0))
+    }
+}
@@ -0,0 +1,112 @@
+use std::str::FromStr;
+
+use crate::domain::error::DomainError;
+
+/// Роль организатора.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Role {
+    SuperAdmin,
+    ContentEditor,
+    PhotoModerator,
+    FinalSiteStaff,
+    Analyst,
+}
+
+/// Отдельное действие, доступное организаторам.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Permission {
+    /// Выдача и отзыв ролей.
+    ManageRoles,
+    /// Загрузка, переименование и удаление медиафайлов, правка заданий.
+    EditContent,
+    /// Просмотр медиафайлов и графа заданий.
+    ViewContent,
+    /// Проверка фото-ответов.
+    ReviewPhotos,
+    /// Работа с обратной связью.
+    ManageFeedback,
+    /// Просмотр журнала действий организаторов.
+    ViewAuditLog,
+    /// Переключение этапов мероприятия.
+    ManageEvent,
+    /// Просмотр команд, их прогресса и записей на финал.
+    ViewProgress,
+}
+
+impl Role {
+    pub const ALL: [Role; 5] = [
+        Role::SuperAdmin,
+        Role::ContentEditor,
+        Role::PhotoModerator,
+        Role::FinalSiteStaff,
+        Role::Analyst,
+    ];
+
+    pub fn code(&self) -> &'static str {
+        match self {
+            Role::SuperAdmin => "superadmin",
+            Role::ContentEditor => "editor",
+            Role::PhotoModerator => "moderator",
+            Role::FinalSiteStaff => "staff",
+            Role::Analyst => "analyst",
+        }
+    }
+
+    pub fn permissions(&self) -> &'static [Permission] {
+        match self {
+            Role::SuperAdmin => &[
+                Permission::ManageRoles,
+                Permission::EditContent,
+                Permission::ViewContent,
+                Permission::ReviewPhotos,
+                Permission::ManageFeedback,
+                Permission::ViewAuditLog,
+                Permission::ManageEvent,
+                Permission::ViewProgress,
+            ],
+            Role::ContentEditor => &[Permission::EditContent, Permission::ViewContent],
+            Role::PhotoModerator => &[Permission::ReviewPhotos],
+            Role::FinalSiteStaff => &[Permission::ManageFeedback, Permission::ViewProgress],
+            Role::Analyst => &[
+                Permission::ViewContent,
+                Permission::ViewAuditLog,
+                Permission::ViewProgress,
+            ],
+        }
+    }
+
+    pub fn has_permission(&self, permission: Permission) -> bool {
+        self.permissions().contains(&permission)
+    }
+}
+
+impl FromStr for Role {
+    type Err = DomainError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Role::ALL
+            .into_iter()
+            .find(|role| role.code() == s)
+            .ok_or_else(|| DomainError::InvalidValue(format!("unknown role: {s}")))
+    }
+}
+
+/// Роли пользователя.
+#[derive(Debug, Clone, Default)]
+pub struct UserRoles(Vec<Role>);
+
+impl UserRoles {
+    pub fn new(mut roles: Vec<Role>) -> Self {
+        roles.sort();
+        roles.dedup();
+        Self(roles)
+    }
+
+    pub fn roles(&self) -> &[Role] {
+        &self.0
+    }
+
+    pub fn has_permission(&self, permission: Permission) -> bool {
+        self.0.iter().any(|role| role.has_permission(permission))
+    }
+}
@@ -0,0 +1,155 @@
+use chrono::Duration;
+
+use crate::domain::error::DomainError;
+use crate::domain::models::Points;
+
+/// Правила начисления баллов за задание.
+#[derive(Debug, Clone, Copy)]
+pub struct ScoringRules {
+    /// Сколько процентов от баллов снимается за каждую неверную попытку.
+    attempt_penalty_percent: i32,
+    /// Меньше этого процента от баллов штрафы снять не могут.
+    min_points_percent: i32,
+    /// Бонус за скорость, который линейно убывает до нуля за `time_bonus_window`
+    /// с момента старта трека.
+    time_bonus: Points,
+    time_bonus_window: Duration,
+    /// Засчитывать ли частично верные ответы.
+    partial_credit: bool,
+}
+
+impl Default for ScoringRules {
+    fn default() -> Self {
+        Self {
+            attempt_penalty_percent: 0,
+            min_points_percent: 0,
+            time_bonus: Points::zero(),
+            time_bonus_window: Duration::zero(),
+            partial_credit: false,
+        }
+    }
+}
+
+impl ScoringRules {
+    pub fn new(
+        attempt_penalty_percent: i32,
+        min_points_percent: i32,
+        time_bonus: Points,
+        time_bonus_window: Duration,
+        partial_credit: bool,
+    ) -> Result<Self, DomainError> {
+        for (name, value) in [
+            ("attempt_penalty_percent", attempt_penalty_percent),
+            ("min_points_percent", min_points_percent),
+        ] {
+            if !(0..=100).contains(&value) {
+                return Err(DomainError::InvalidValue(format!(
+                    "invalid ScoringRules: expected {name} in 0..=100, got {value}"
+                )));
+            }
+        }
+        if time_bonus_window < Duration::zero() {
+            return Err(DomainError::InvalidValue(format!(
+                "invalid ScoringRules: expected non-negative time bonus window, got {time_bonus_window}"
+            )));
+        }
+        Ok(Self {
+            attempt_penalty_percent,
+            min_points_percent,
+            time_bonus,
+            time_bonus_window,
+            partial_credit,
+        })
+    }
+
+    /// Считает баллы за ответ.
+    ///
+    /// `credit` — доля верности ответа от 0 до 1, `wrong_attempts` — число неверных
+    /// попыток до этого ответа, `elapsed` — время с момента старта трека.
+    pub fn score(
+        &self,
+        points: Points,
+        credit: f64,
+        wrong_attempts: i32,
+        elapsed: Duration,
+    ) -> ScoreBreakdown {
+        let completed = credit >= 1.0;
+        if credit <= 0.0 || (!completed && !self.partial_credit) {
+            return ScoreBreakdown::zero();
+        }
+
+        let base = points.scaled(credit.min(1.0));
+        let max_penalty = base.scaled(f64::from(100 - self.min_points_percent) / 100.0);
+        let penalty = base
+            .scaled(f64::from(self.attempt_penalty_percent * wrong_attempts) / 100.0)
+            .min(max_penalty);
+
+        // Окно короче секунды бонуса не даёт, иначе доля получится 0/0.
+        let window = self.time_bonus_window.num_seconds();
+        let time_bonus = if completed && window > 0 && elapsed < self.time_bonus_window {
+            let left = (self.time_bonus_window - elapsed).num_seconds() as f64 / window as f64;
+            self.time_bonus.scaled(left)
+        } else {
+            Points::zero()
+        };
+
+        ScoreBreakdown {
+            base,
+            penalty,
+            time_bonus,
+        }
+    }
+
+    pub fn time_bonus(&self) -> Points {
+        self.time_bonus
+    }
+}
+
+/// Из чего сложились баллы за ответ.
+#[derive(Debug, Clone, Copy)]
+pub struct ScoreBreakdown {
+    base: Points,
+    penalty: Points,
+    time_bonus: Points,
+}
+
+impl ScoreBreakdown {
+    pub fn zero() -> Self {
+        Self {
+            base: Points::zero(),
+            penalty: Points::zero(),
+            time_bonus: Points::zero(),
+        }
+    }
+
+    pub fn restore(base: Points, penalty: Points, time_bonus: Points) -> Result<Self, DomainError> {
+        if penalty > base {
+            return Err(DomainError::InvalidValue(format!(
+                "invalid ScoreBreakdown: penalty {} exceeds base points {}",
+                penalty.as_i32(),
+                base.as_i32()
+            )));
+        }
+        Ok(Self {
+            base,
+            penalty,
+            time_bonus,
+        })
+    }
+
+    pub fn base(&self) -> Points {
+        self.base
+    }
+
+    pub fn penalty(&self) -> Points {
+        self.penalty
+    }
+
+    pub fn time_bonus(&self) -> Points {
+        self.time_bonus
+    }
+
+    pub fn total(&self) -> Points {
+        self.base - self.penalty + self.time_bonus
+    }
+}
@@ -1,7 +1,12 @@
+use chrono::{DateTime, Utc};
+
 use crate::domain::error::DomainError;
-use crate::domain::models::{Answer, AnswerText, MediaID, Points};
+use crate::domain::models::{
+    Answer, AnswerChecker, AnswerGrade, AnswerInput, AnswerText, GeoArea, LocationChecker,
+    ManualChecker, MediaID, MultiChoiceChecker, Normalizer, NumberChecker, NumericAnswer,
+    OrderingChecker, Points, ScoringRules, TextChecker,
+};
 use crate::not_empty_string_impl;
-use levenshtein::levenshtein;
 
 pub type TaskID = i32;
 
@@ -9,6 +14,11 @@ pub type TaskID = i32;
 pub struct TaskText(String);
 not_empty_string_impl!(TaskText);
 
+/// Короткое название задания для кнопки в списке заданий.
+#[derive(Debug, Clone)]
+pub struct TaskTitle(String);
+not_empty_string_impl!(TaskTitle);
+
 #[derive(Debug, Clone)]
 pub struct TaskOption(String);
 not_empty_string_impl!(TaskOption);
@@ -18,21 +28,19 @@ pub enum TaskType {
     Text,
     Choice,
     Photo,
+    MultiChoice,
+    Number,
+    Ordering,
+    Location,
 }
 
-fn normalize(s: String) -> String {
-    s.to_lowercase()
-        .chars()
-        .filter(|c| !c.is_ascii_punctuation())
-        .collect()
-}
-
+/// Верный ответ хранится как задан, нормализуется он при проверке вместе с ответом команды.
 #[derive(Debug, Clone)]
 pub struct CorrectAnswer(String);
 
 impl CorrectAnswer {
-    pub fn new(mut s: String) -> Result<Self, DomainError> {
-        s = normalize(s);
+    pub fn new(s: String) -> Result<Self, DomainError> {
+        let s = s.trim().to_string();
         if s.is_empty() {
             Err(DomainError::InvalidValue(
                 "invalid CorrectAnswer: expected not empty string".to_string(),
@@ -51,16 +59,84 @@ impl CorrectAnswer {
     }
 }
 
+/// Подсказка, которая показывается, если в неверном ответе встречается ключевое слово.
+#[derive(Debug, Clone)]
+pub struct TaskHint {
+    keyword: String,
+    text: TaskText,
+}
+
+impl TaskHint {
+    pub fn new(keyword: String, text: TaskText) -> Result<Self, DomainError> {
+        let keyword = keyword.trim().to_string();
+        if keyword.is_empty() {
+            return Err(DomainError::InvalidValue(
+                "invalid TaskHint: expected not empty keyword".to_string(),
+            ));
+        }
+        Ok(Self { keyword, text })
+    }
+
+    /// Ключевое слово ищется в нормализованном ответе целыми словами.
+    fn matches(&self, normalizer: &Normalizer, answer: &str) -> bool {
+        let keyword = normalizer.normalize(&self.keyword);
+        let keyword: Vec<&str> = keyword.split_whitespace().collect();
+        if keyword.is_empty() {
+            return false;
+        }
+        let answer = normalizer.normalize(answer);
+        let words: Vec<&str> = answer.split_whitespace().collect();
+        words
+            .windows(keyword.len())
+            .any(|window| window == keyword.as_slice())
+    }
+
+    pub fn text(&self) -> &TaskText {
+        &self.text
+    }
+}
+
+/// Ответ вместе с оценкой его близости к верному и подсказкой, если она нашлась.
+#[derive(Debug, Clone)]
+pub struct GradedAnswer {
+    answer: Answer,
+    grade: AnswerGrade,
+    hint: Option<TaskText>,
+}
+
+impl GradedAnswer {
+    pub fn answer(&self) -> &Answer {
+        &self.answer
+    }
+
+    pub fn grade(&self) -> AnswerGrade {
+        self.grade
+    }
+
+    pub fn hint(&self) -> Option<&TaskText> {
+        self.hint.as_ref()
+    }
+
+    pub fn into_answer(self) -> Answer {
+        self.answer
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct Task {
     id: TaskID,
+    title: Option<TaskTitle>,
     task_type: TaskType,
     question: TaskText,
     explanation: TaskText,
-    media_id: Option<MediaID>,
+    media_ids: Vec<MediaID>,
     options: Vec<TaskOption>,
     dependencies: Vec<TaskID>,
     correct_answers: Vec<CorrectAnswer>,
+    numeric_answer: Option<NumericAnswer>,
+    area: Option<GeoArea>,
+    scoring: ScoringRules,
+    hints: Vec<TaskHint>,
     points: Points,
     price: Points,
     max_levenshtein_distance: usize,
@@ -69,46 +145,159 @@ pub struct Task {
 impl Task {
     pub fn new(
         id: TaskID,
+        title: Option<TaskTitle>,
         task_type: TaskType,
         question: TaskText,
         explanation: TaskText,
-        media_id: Option<MediaID>,
+        media_ids: Vec<MediaID>,
         options: Vec<TaskOption>,
         dependencies: Vec<TaskID>,
         correct_answers: Vec<CorrectAnswer>,
+        numeric_answer: Option<NumericAnswer>,
+        area: Option<GeoArea>,
+        scoring: ScoringRules,
+        hints: Vec<TaskHint>,
         points: Points,
         price: Points,
         max_levenshtein_distance: usize,
     ) -> Self {
         Self {
             id,
+            title,
             task_type,
             question,
             explanation,
-            media_id,
+            media_ids,
             options,
             dependencies,
             correct_answers,
+            numeric_answer,
+            area,
+            scoring,
+            hints,
             points,
             price,
             max_levenshtein_distance,
         }
     }
 
-    pub fn answer(&self, answer: &str) -> Answer {
-        let answer = AnswerText::new(normalize(answer.to_string()));
-        for correct in self.correct_answers.iter() {
-            if levenshtein(answer.as_str(), correct.as_str()) <= self.max_levenshtein_distance {
-                return Answer::new(self.id, answer, self.points);
+    fn checker<'a>(&'a self, normalizer: &'a Normalizer) -> Box<dyn AnswerChecker + 'a> {
+        match self.task_type {
+            TaskType::Text | TaskType::Choice => Box::new(TextChecker::new(
+                normalizer,
+                &self.correct_answers,
+                self.max_levenshtein_distance,
+            )),
+            TaskType::MultiChoice => {
+                Box::new(MultiChoiceChecker::new(normalizer, &self.correct_answers))
             }
+            TaskType::Ordering => Box::new(OrderingChecker::new(normalizer, &self.correct_answers)),
+            TaskType::Number => Box::new(NumberChecker::new(normalizer, self.numeric_answer)),
+            TaskType::Location => Box::new(LocationChecker::new(self.area)),
+            TaskType::Photo => Box::new(ManualChecker),
+        }
+    }
+
+    /// Проверяет ответ и начисляет баллы по правилам задания.
+    ///
+    /// `previous` — прошлый ответ команды на это задание, `started_at` — время старта трека.
+    pub fn answer(
+        &self,
+        normalizer: &Normalizer,
+        input: AnswerInput,
+        previous: Option<&Answer>,
+        started_at: DateTime<Utc>,
+    ) -> GradedAnswer {
+        let checker = self.checker(normalizer);
+        let credit = checker.credit(&input);
+        let grade = checker.grade(&input);
+        // Решённое задание остаётся решённым, повторный ответ ничего не меняет.
+        if let Some(previous) = previous.filter(|a| a.is_ok()) {
+            return GradedAnswer {
+                answer: previous.clone(),
+                grade,
+                hint: None,
+            };
+        }
+        let completed = credit >= 1.0;
+        let wrong_attempts = previous.map_or(0, |a| a.attempts());
+        let elapsed = Utc::now() - started_at;
+        let mut score = self
+            .scoring
+            .score(self.points, credit, wrong_attempts, elapsed);
+        // Неудачная попытка не должна отнимать уже заработанный частичный зачёт
+        if !completed
+            && let Some(previous) = previous
+            && previous.points() > score.total()
+        {
+            score = *previous.score();
+        }
+        let text = input.to_text();
+        let hint = if completed {
+            None
+        } else {
+            self.hints
+                .iter()
+                .find(|hint| hint.matches(normalizer, &text))
+                .map(|hint| hint.text().clone())
+        };
+        // Фото проверяет организатор, до этого ответ не считается неверным.
+        let pending_review = !completed && matches!(self.task_type, TaskType::Photo);
+        // Новое фото вместо ещё не проверенного — та же попытка, а не неверная.
+        let attempts = match previous {
+            Some(previous) if pending_review && previous.is_pending_review() => wrong_attempts,
+            _ => wrong_attempts + 1,
+        };
+        let answer = Answer::new(
+            self.id,
+            AnswerText::new(text),
+            score,
+            completed,
+            pending_review,
+            attempts,
+        );
+        GradedAnswer {
+            answer,
+            grade,
+            hint,
         }
-        Answer::new(self.id, answer, Points::zero())
+    }
+
+    /// Засчитывает или отклоняет ответ, который проверяется вручную.
+    ///
+    /// Бонус за скорость считается на момент отправки ответа, а не проверки.
+    /// Вызывающий проверяет, что ответ ещё ждёт проверки.
+    pub fn review(&self, answer: &Answer, approved: bool, started_at: DateTime<Utc>) -> Answer {
+        let credit = if approved { 1.0 } else { 0.0 };
+        let wrong_attempts = answer.attempts() - 1;
+        let elapsed = *answer.created_at() - started_at;
+        let score = self
+            .scoring
+            .score(self.points, credit, wrong_attempts, elapsed);
+        Answer::restore(
+            self.id,
+            answer.text().clone(),
+            score,
+            approved,
+            false,
+            answer.attempts(),
+            *answer.created_at(),
+        )
+    }
+
+    /// Максимум баллов за задание с учётом бонуса за скорость.
+    pub fn max_points(&self) -> Points {
+        self.points + self.scoring.time_bonus()
     }
 
     pub fn id(&self) -> TaskID {
         self.id
     }
 
+    pub fn title(&self) -> Option<&TaskTitle> {
+        self.title.as_ref()
+    }
+
     pub fn task_type(&self) -> TaskType {
         self.task_type
     }
@@ -121,8 +310,9 @@ impl Task {
         &self.explanation
     }
 
-    pub fn media_id(&self) -> Option<&MediaID> {
-        self.media_id.as_ref()
+    /// Медиафайлы задания в том порядке, в котором их нужно показать.
+    pub fn media_ids(&self) -> &Vec<MediaID> {
+        &self.media_ids
     }
 
     pub fn options(&self) -> &Vec<TaskOption> {
@@ -133,10 +323,6 @@ impl Task {
         &self.dependencies
     }
 
-    pub fn points(&self) -> Points {
-        self.points
-    }
-
     pub fn price(&self) -> Points {
         self.price
     }
@@ -144,4 +330,8 @@ impl Task {
     pub fn correct_answers(&self) -> &Vec<CorrectAnswer> {
         &self.correct_answers
     }
+
+    pub fn numeric_answer(&self) -> Option<&NumericAnswer> {
+        self.numeric_answer.as_ref()
+    }
 }
@@ -0,0 +1,286 @@
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::fmt::{Display, Formatter};
+
+use crate::domain::models::{TaskID, Track, TrackTag};
+
+#[derive(Debug, Clone)]
+pub enum TaskGraphIssue {
+    /// Зависимость ссылается на задание, которого нет ни в одном треке.
+    MissingDependency {
+        track: TrackTag,
+        task_id: TaskID,
+        dependency: TaskID,
+    },
+    /// Зависимость ссылается на задание из другого трека, такое задание никогда не откроется.
+    CrossTrackDependency {
+        track: TrackTag,
+        task_id: TaskID,
+        dependency: TaskID,
+        dependency_track: TrackTag,
+    },
+    Cycle {
+        track: TrackTag,
+        task_ids: Vec<TaskID>,
+    },
+    /// Задание невозможно открыть, потому что одна из цепочек зависимостей оборвана.
+    Unreachable { track: TrackTag, task_id: TaskID },
+}
+
+impl Display for TaskGraphIssue {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::MissingDependency {
+                track,
+                task_id,
+                dependency,
+            } => write!(
+                f,
+                "track {}: task {task_id} depends on missing task {dependency}",
+                track.as_str()
+            ),
+            Self::CrossTrackDependency {
+                track,
+                task_id,
+                dependency,
+                dependency_track,
+            } => write!(
+                f,
+                "track {}: task {task_id} depends on task {dependency} from track {}",
+                track.as_str(),
+                dependency_track.as_str()
+            ),
+            Self::Cycle { track, task_ids } => write!(
+                f,
+                "track {}: dependency cycle {}",
+                track.as_str(),
+                format_cycle(task_ids)
+            ),
+            Self::Unreachable { track, task_id } => {
+                write!(f, "track {}: task {task_id} is unreachable", track.as_str())
+            }
+        }
+    }
+}
+
+/// Цикл в виде «1 → 2 → 3 → 1».
+pub fn format_cycle(task_ids: &[TaskID]) -> String {
+    task_ids
+        .iter()
+        .chain(task_ids.first())
+        .map(|id| id.to_string())
+        .collect::<Vec<_>>()
+        .join(" → ")
+}
+
+/// Проверяет граф зависимостей заданий всех треков.
+pub fn validate_task_graph(tracks: &[Track]) -> Vec<TaskGraphIssue> {
+    let task_tracks: HashMap<TaskID, &TrackTag> = tracks
+        .iter()
+        .flat_map(|track| {
+            track
+                .tasks()
+                .into_iter()
+                .map(move |t| (t.id(), track.tag()))
+        })
+        .collect();
+
+    let mut issues = Vec::new();
+    for track in tracks {
+        let tag = track.tag();
+        for task in track.tasks() {
+            for &dependency in task.dependencies() {
+                match task_tracks.get(&dependency) {
+                    None => issues.push(TaskGraphIssue::MissingDependency {
+                        track: tag.clone(),
+                        task_id: task.id(),
+                        dependency,
+                    }),
+                    Some(&dependency_track) if dependency_track != tag => {
+                        issues.push(TaskGraphIssue::CrossTrackDependency {
+                            track: tag.clone(),
+                            task_id: task.id(),
+                            dependency,
+                            dependency_track: dependency_track.clone(),
+                        })
+                    }
+                    Some(_) => {}
+                }
+            }
+        }
+
+        for task_ids in find_cycles(track) {
+            issues.push(TaskGraphIssue::Cycle {
+                track: tag.clone(),
+                task_ids,
+            });
+        }
+
+        let reachable = reachable_tasks(track);
+        for task in track.tasks() {
+            if !reachable.contains(&task.id()) {
+                issues.push(TaskGraphIssue::Unreachable {
+                    track: tag.clone(),
+                    task_id: task.id(),
+                });
+            }
+        }
+    }
+    issues
+}
+
+/// Зависимости внутри трека, отсортированные для детерминированного обхода.
+fn track_edges(track: &Track) -> BTreeMap<TaskID, Vec<TaskID>> {
+    track
+        .tasks()
+        .into_iter()
+        .map(|task| {
+            let mut dependencies: Vec<TaskID> = task
+                .dependencies()
+                .iter()
+                .copied()
+                .filter(|dep| track.task(dep).is_some())
+                .collect();
+            dependencies.sort();
+            (task.id(), dependencies)
+        })
+        .collect()
+}
+
+fn find_cycles(track: &Track) -> Vec<Vec<TaskID>> {
+    #[derive(Clone, Copy, PartialEq)]
+    enum Mark {
+        InStack,
+        Done,
+    }
+
+    fn visit(
+        id: TaskID,
+        edges: &BTreeMap<TaskID, Vec<TaskID>>,
+        marks: &mut HashMap<TaskID, Mark>,
+        stack: &mut Vec<TaskID>,
+        cycles: &mut Vec<Vec<TaskID>>,
+    ) {
+        marks.insert(id, Mark::InStack);
+        stack.push(id);
+        for &next in &edges[&id] {
+            match marks.get(&next) {
+                Some(Mark::InStack) => {
+                    let start = stack.iter().position(|&s| s == next).unwrap();
+                    cycles.push(stack[start..].to_vec());
+                }
+                Some(Mark::Done) => {}
+                None => visit(next, edges, marks, stack, cycles),
+            }
+        }
+        stack.pop();
+        marks.insert(id, Mark::Done);
+    }
+
+    let edges = track_edges(track);
+    let mut marks = HashMap::new();
+    let mut cycles = Vec::new();
+    for &id in edges.keys() {
+        if !marks.contains_key(&id) {
+            visit(id, &edges, &mut marks, &mut Vec::new(), &mut cycles);
+        }
+    }
+    cycles
+}
+
+/// Задания, которые можно открыть, решая задания этого же трека.
+fn reachable_tasks(track: &Track) -> BTreeSet<TaskID> {
+    let mut reachable = BTreeSet::new();
+    loop {
+        let next: Vec<TaskID> = track
+            .tasks()
+            .into_iter()
+            .filter(|task| !reachable.contains(&task.id()))
+            .filter(|task| {
+                task.dependencies()
+                    .iter()
+                    .all(|dep| reachable.contains(dep))
+            })
+            .map(|task| task.id())
+            .collect();
+        if next.is_empty() {
+            return reachable;
+        }
+        reachable.extend(next);
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum TaskGraphFormat {
+    Dot,
+    Mermaid,
+}
+
+impl TaskGraphFormat {
+    pub fn file_extension(&self) -> &'static str {
+        match self {
+            Self::Dot => "dot",
+            Self::Mermaid => "mmd",
+        }
+    }
+}
+
+/// Рисует граф зависимостей: стрелка ведёт от задания к тем, которые оно открывает.
+pub fn render_task_graph(tracks: &[Track], format: TaskGraphFormat) -> String {
+    let mut lines = Vec::new();
+    match format {
+        TaskGraphFormat::Dot => {
+            lines.push("digraph quest {".to_string());
+            lines.push("    rankdir=LR;".to_string());
+            for track in tracks {
+                lines.push(format!(
+                    "    subgraph \"cluster_{}\" {{",
+                    track.tag().as_str()
+                ));
+                lines.push(format!(
+                    "        label=\"{}\";",
+                    escape(track.name().as_str())
+                ));
+                for task in track.tasks() {
+                    lines.push(format!("        {};", task.id()));
+                }
+                lines.push("    }".to_string());
+            }
+            for (dependency, task_id) in all_edges(tracks) {
+                lines.push(format!("    {dependency} -> {task_id};"));
+            }
+            lines.push("}".to_string());
+        }
+        TaskGraphFormat::Mermaid => {
+            lines.push("flowchart LR".to_string());
+            for track in tracks {
+                lines.push(format!(
+                    "    subgraph {}[\"{}\"]",
+                    track.tag().as_str(),
+                    escape(track.name().as_str())
+                ));
+                for task in track.tasks() {
+                    lines.push(format!("        t{0}[\"{0}\"]", task.id()));
+                }
+                lines.push("    end".to_string());
+            }
+            for (dependency, task_id) in all_edges(tracks) {
+                lines.push(format!("    t{dependency} --> t{task_id}"));
+            }
+        }
+    }
+    lines.join("\n")
+}
+
+fn all_edges(tracks: &[Track]) -> Vec<(TaskID, TaskID)> {
+    let mut edges: Vec<(TaskID, TaskID)> = tracks
+        .iter()
+        .flat_map(|track| track.tasks())
+        .flat_map(|task| task.dependencies().iter().map(move |&dep| (dep, task.id())))
+        .collect();
+    edges.sort();
+    edges
+}
+
+fn escape(s: &str) -> String {
+    s.replace('"', "'")
+}
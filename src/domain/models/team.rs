@@ -1,5 +1,5 @@
 use crate::domain::models::{Points, SlotID};
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, Duration, Utc};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
@@ -18,14 +18,28 @@ pseudo_uuid_impl!(TeamID, 6);
 pub struct TeamName(String);
 not_empty_string_impl!(TeamName);
 
-pub const MAX_TEAM_SIZE: usize = 8;
-
 #[derive(Debug, Clone)]
 pub enum TrackStatus {
     Started(DateTime<Utc>),
     Finished(DateTime<Utc>, DateTime<Utc>),
 }
 
+impl TrackStatus {
+    pub fn started_at(&self) -> DateTime<Utc> {
+        match *self {
+            Self::Started(start) | Self::Finished(start, _) => start,
+        }
+    }
+
+    /// Сколько команда провела на треке: до финиша или до `now`, если трек ещё идёт.
+    pub fn elapsed(&self, now: DateTime<Utc>) -> Duration {
+        match *self {
+            Self::Started(start) => now - start,
+            Self::Finished(start, finish) => finish - start,
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct Team {
     id: TeamID,
@@ -58,13 +72,6 @@ impl Team {
                 member_ids.as_slice()
             )));
         }
-        if member_ids.len() > MAX_TEAM_SIZE {
-            return Err(DomainError::InvalidValue(format!(
-                "team is too large: {} > {}",
-                member_ids.len(),
-                MAX_TEAM_SIZE
-            )));
-        }
         let answers_map = HashMap::from_iter(answers.into_iter().map(|a| (a.task_id(), a)));
         Ok(Self {
             id,
@@ -127,23 +134,14 @@ impl Team {
         self.member_ids.len() == 1
     }
 
-    pub fn available_tracks(&self) -> &'static [TrackTag] {
-        if self.is_solo() {
-            &[TrackTag::Universitet]
-        } else {
-            &[
-                TrackTag::Muzhestvo,
-                TrackTag::Volya,
-                TrackTag::Trud,
-                TrackTag::Uporstvo,
-            ]
-        }
-    }
-
     pub fn answers(&self) -> Vec<&Answer> {
         self.answers.values().collect()
     }
 
+    pub fn answer(&self, task_id: TaskID) -> Option<&Answer> {
+        self.answers.get(&task_id)
+    }
+
     pub fn started_tracks(&self) -> &HashMap<TrackTag, TrackStatus> {
         &self.started_tracks
     }
@@ -157,13 +155,15 @@ impl Team {
         Ok(())
     }
 
-    pub fn finish_track(&mut self, tag: TrackTag) -> Result<(), DomainError> {
-        match self.started_tracks.get(&tag) {
-            None => Err(DomainError::TrackCanNotBeFinished(tag)),
-            Some(TrackStatus::Finished(_, _)) => Err(DomainError::TrackCanNotBeFinished(tag)),
-            Some(TrackStatus::Started(start)) => {
+    pub fn finish_track(&mut self, tag: &TrackTag) -> Result<(), DomainError> {
+        match self.started_tracks.get(tag) {
+            None => Err(DomainError::TrackCanNotBeFinished(tag.clone())),
+            Some(TrackStatus::Finished(_, _)) => {
+                Err(DomainError::TrackCanNotBeFinished(tag.clone()))
+            }
+            Some(&TrackStatus::Started(start)) => {
                 self.started_tracks
-                    .insert(tag, TrackStatus::Finished(start.clone(), Utc::now()));
+                    .insert(tag.clone(), TrackStatus::Finished(start, Utc::now()));
                 Ok(())
             }
         }
@@ -173,14 +173,14 @@ impl Team {
         self.answers.insert(answer.task_id(), answer);
     }
 
-    pub fn track_status(&self, tag: TrackTag) -> Result<&TrackStatus, DomainError> {
+    pub fn track_status(&self, tag: &TrackTag) -> Result<&TrackStatus, DomainError> {
         self.started_tracks
-            .get(&tag)
-            .ok_or(DomainError::TrackNotStarted(tag))
+            .get(tag)
+            .ok_or_else(|| DomainError::TrackNotStarted(tag.clone()))
     }
 
-    pub fn track_is_started(&self, tag: TrackTag) -> bool {
-        self.started_tracks.contains_key(&tag)
+    pub fn track_is_started(&self, tag: &TrackTag) -> bool {
+        self.started_tracks.contains_key(tag)
     }
 
     pub fn is_captain(&self, user_id: UserID) -> bool {
@@ -2,51 +2,69 @@ use crate::domain::error::DomainError;
 use crate::domain::models::Points;
 use crate::domain::models::Task;
 use crate::domain::models::TaskID;
+use crate::domain::models::Team;
 use crate::{
     domain::models::{Answer, MediaID},
     not_empty_string_impl,
 };
+use regex::Regex;
 use serde::{Deserialize, Serialize};
 use std::cmp::PartialEq;
 use std::collections::HashMap;
+use std::sync::LazyLock;
 
-#[derive(Debug, Clone, Copy, Serialize, Deserialize, Hash, PartialEq, Eq)]
-pub enum TrackTag {
-    Muzhestvo,
-    Volya,
-    Trud,
-    Uporstvo,
-    Universitet,
-}
+pub const TRACK_TAG_MAX_LENGTH: usize = 32;
+
+static SLUG: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^[a-z][a-z0-9_]*$").unwrap());
+
+/// Slug трека из таблицы `tracks`, например `muzhestvo`.
+#[derive(Debug, Clone, Serialize, Deserialize, Hash, PartialEq, Eq)]
+#[serde(try_from = "String")]
+pub struct TrackTag(String);
 
 impl TrackTag {
-    pub fn as_str(&self) -> &'static str {
-        match self {
-            Self::Muzhestvo => "Мужество",
-            Self::Volya => "Воля",
-            Self::Trud => "Труд",
-            Self::Uporstvo => "Упорство",
-            Self::Universitet => "Университет",
+    pub fn new(tag: impl Into<String>) -> Result<Self, DomainError> {
+        // Приводим к нижнему регистру, чтобы читались состояния диалогов,
+        // сохранённые ещё во времена enum-а (`"Muzhestvo"`).
+        let tag = tag.into().to_lowercase();
+        if tag.len() > TRACK_TAG_MAX_LENGTH {
+            return Err(DomainError::InvalidValue(format!(
+                "invalid TrackTag: expected length <= {TRACK_TAG_MAX_LENGTH}, got {}",
+                tag.len()
+            )));
         }
+        if !SLUG.is_match(&tag) {
+            return Err(DomainError::InvalidValue(format!(
+                "invalid TrackTag: expected slug, got {tag:?}"
+            )));
+        }
+        Ok(Self(tag))
     }
 
-    pub fn try_parse(s: &str) -> Option<Self> {
-        match s {
-            "Мужество" => Some(Self::Muzhestvo),
-            "Воля" => Some(Self::Volya),
-            "Труд" => Some(Self::Trud),
-            "Упорство" => Some(Self::Uporstvo),
-            "Университет" => Some(Self::Universitet),
-            _ => None,
-        }
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl TryFrom<String> for TrackTag {
+    type Error = DomainError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::new(value)
     }
 }
 
+#[derive(Debug, Clone, PartialEq)]
+pub struct TrackName(String);
+not_empty_string_impl!(TrackName);
+
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum TaskStatus {
     NotAvailable,
     Available,
     InProgress,
+    /// Фото-ответ отправлен и ждёт проверки.
+    UnderReview,
     Completed,
 }
 
@@ -54,9 +72,20 @@ pub enum TaskStatus {
 pub struct TrackDescription(String);
 not_empty_string_impl!(TrackDescription);
 
+/// Доля `points` от `max_points`; трек без баллов даёт 0.
+pub fn percent(points: Points, max_points: Points) -> f32 {
+    if max_points.is_zero() {
+        0.0
+    } else {
+        (points.as_i32() as f32) / (max_points.as_i32() as f32)
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct Track {
     tag: TrackTag,
+    name: TrackName,
+    solo: bool,
     description: TrackDescription,
     media_id: MediaID,
     tasks: HashMap<TaskID, Task>,
@@ -65,6 +94,8 @@ pub struct Track {
 impl Track {
     pub fn new(
         tag: TrackTag,
+        name: TrackName,
+        solo: bool,
         description: TrackDescription,
         media_id: MediaID,
         tasks: Vec<Task>,
@@ -72,6 +103,8 @@ impl Track {
         let tasks = HashMap::from_iter(tasks.into_iter().map(|t| (t.id(), t)));
         Self {
             tag,
+            name,
+            solo,
             description,
             media_id,
             tasks,
@@ -82,12 +115,47 @@ impl Track {
         self.tasks.get(id)
     }
 
+    /// Задания трека в порядке возрастания ID.
+    pub fn tasks(&self) -> Vec<&Task> {
+        let mut tasks: Vec<&Task> = self.tasks.values().collect();
+        tasks.sort_by_key(|t| t.id());
+        tasks
+    }
+
+    /// Порядковый номер задания внутри трека, начиная с 1.
+    pub fn task_number(&self, id: &TaskID) -> Option<usize> {
+        self.tasks()
+            .iter()
+            .position(|t| t.id() == *id)
+            .map(|i| i + 1)
+    }
+
+    pub fn max_points(&self) -> Points {
+        self.tasks
+            .values()
+            .fold(Points::zero(), |acc, t| acc + t.max_points())
+    }
+
+    /// Доля от максимума трека, которую составляют `points`.
+    pub fn percent(&self, points: Points) -> f32 {
+        percent(points, self.max_points())
+    }
+
     pub fn progress(&'_ self, answers: &[&Answer]) -> TrackProgress<'_> {
         TrackProgress::new(self, answers)
     }
 
-    pub fn tag(&self) -> TrackTag {
-        self.tag
+    pub fn tag(&self) -> &TrackTag {
+        &self.tag
+    }
+
+    pub fn name(&self) -> &TrackName {
+        &self.name
+    }
+
+    /// Одиночные треки доступны только одиночным игрокам, командные — только командам.
+    pub fn is_available_for(&self, team: &Team) -> bool {
+        self.solo == team.is_solo()
     }
 
     pub fn description(&self) -> &TrackDescription {
@@ -101,7 +169,14 @@ impl Track {
 
 pub struct TrackProgress<'a> {
     track: &'a Track,
-    answers: HashMap<TaskID, Points>,
+    answers: HashMap<TaskID, AnswerResult>,
+}
+
+/// Итог ответа команды на задание.
+struct AnswerResult {
+    points: Points,
+    completed: bool,
+    pending_review: bool,
 }
 
 impl<'a> TrackProgress<'a> {
@@ -109,7 +184,14 @@ impl<'a> TrackProgress<'a> {
         let answers_map = answers
             .iter()
             .filter(|&&a| track.tasks.contains_key(&a.task_id()))
-            .map(|&a| (a.task_id().clone(), a.points()))
+            .map(|&a| {
+                let result = AnswerResult {
+                    points: a.points(),
+                    completed: a.is_ok(),
+                    pending_review: a.is_pending_review(),
+                };
+                (a.task_id(), result)
+            })
             .collect();
 
         Self {
@@ -125,9 +207,11 @@ impl<'a> TrackProgress<'a> {
         };
 
         match self.answers.get(task_id) {
-            Some(points) => {
-                if points.is_positive() {
+            Some(result) => {
+                if result.completed {
                     Some(TaskStatus::Completed)
+                } else if result.pending_review {
+                    Some(TaskStatus::UnderReview)
                 } else {
                     Some(TaskStatus::InProgress)
                 }
@@ -136,7 +220,7 @@ impl<'a> TrackProgress<'a> {
                 if task.dependencies().iter().all(|dep_id| {
                     self.answers
                         .get(dep_id)
-                        .is_some_and(|points| points.is_positive())
+                        .is_some_and(|result| result.completed)
                 }) {
                     Some(TaskStatus::Available)
                 } else {
@@ -161,32 +245,26 @@ impl<'a> TrackProgress<'a> {
             .filter(|&t| {
                 matches!(
                     self.task_status(&t.id()),
-                    Some(TaskStatus::Available) | Some(TaskStatus::InProgress)
+                    Some(TaskStatus::Available)
+                        | Some(TaskStatus::InProgress)
+                        | Some(TaskStatus::UnderReview)
                 )
             })
             .collect()
     }
 
     pub fn max_points(&self) -> Points {
-        self.track
-            .tasks
-            .values()
-            .fold(Points::zero(), |acc, t| acc + t.points())
+        self.track.max_points()
     }
 
     pub fn points(&self) -> Points {
         self.answers
             .values()
-            .fold(Points::zero(), |acc, &a| acc + a)
+            .fold(Points::zero(), |acc, a| acc + a.points)
     }
 
     pub fn percent(&self) -> f32 {
-        let max = self.max_points();
-        if max.is_zero() {
-            0.0
-        } else {
-            (self.points().as_i32() as f32) / (max.as_i32() as f32)
-        }
+        self.track.percent(self.points())
     }
 
     pub fn full_completed(&self) -> bool {
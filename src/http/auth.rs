@@ -0,0 +1,30 @@
+use std::sync::Arc;
+
+use axum::extract::FromRequestParts;
+use axum::http::header::AUTHORIZATION;
+use axum::http::request::Parts;
+
+use crate::domain::models::UserID;
+use crate::http::ApiState;
+use crate::http::error::ApiError;
+
+/// Организатор, которому принадлежит токен из заголовка `Authorization: Bearer <token>`.
+pub struct Actor(pub UserID);
+
+impl FromRequestParts<Arc<ApiState>> for Actor {
+    type Rejection = ApiError;
+
+    async fn from_request_parts(
+        parts: &mut Parts,
+        state: &Arc<ApiState>,
+    ) -> Result<Self, Self::Rejection> {
+        parts
+            .headers
+            .get(AUTHORIZATION)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.strip_prefix("Bearer "))
+            .and_then(|token| state.tokens.get(token.trim()))
+            .map(|&user_id| Actor(user_id))
+            .ok_or(ApiError::Unauthorized)
+    }
+}
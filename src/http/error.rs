@@ -0,0 +1,72 @@
+use axum::Json;
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use serde::Serialize;
+
+use crate::app::error::AppError;
+use crate::domain::error::DomainError;
+
+pub enum ApiError {
+    Unauthorized,
+    App(AppError),
+}
+
+impl From<AppError> for ApiError {
+    fn from(error: AppError) -> Self {
+        Self::App(error)
+    }
+}
+
+impl From<DomainError> for ApiError {
+    fn from(error: DomainError) -> Self {
+        Self::App(error.into())
+    }
+}
+
+#[derive(Serialize)]
+struct ErrorResponse {
+    error: String,
+}
+
+impl IntoResponse for ApiError {
+    fn into_response(self) -> Response {
+        let (status, message) = match self {
+            ApiError::Unauthorized => (StatusCode::UNAUTHORIZED, "unauthorized".to_string()),
+            ApiError::App(error) => {
+                let status = status_code(&error);
+                if status == StatusCode::INTERNAL_SERVER_ERROR {
+                    // Подробности внутренних ошибок остаются в логе.
+                    log::error!("HTTP API request failed: {}", error);
+                    (status, "internal error".to_string())
+                } else {
+                    (status, error.to_string())
+                }
+            }
+        };
+        (status, Json(ErrorResponse { error: message })).into_response()
+    }
+}
+
+fn status_code(error: &AppError) -> StatusCode {
+    match error {
+        AppError::DomainError(DomainError::InvalidValue(_))
+        | AppError::PlacesGreaterThanTeamSize(..) => StatusCode::BAD_REQUEST,
+        AppError::PermissionDenied(_) | AppError::CanNotRevokeOwnRole(..) => StatusCode::FORBIDDEN,
+        AppError::UserNotFound(_)
+        | AppError::TeamNotFound(_)
+        | AppError::MediaNotFound(_)
+        | AppError::FeedbackNotFound(_)
+        | AppError::TaskNotFound(_)
+        | AppError::AnswerNotFound(_)
+        | AppError::CharacterNotFound(_)
+        | AppError::TrackNotFound(_)
+        | AppError::SlotNotFound(_) => StatusCode::NOT_FOUND,
+        AppError::DomainError(_)
+        | AppError::MediaAlreadyExists(_)
+        | AppError::MediaInUse(..)
+        | AppError::UserNotInTeam(_)
+        | AppError::AnswerNotPending(_)
+        | AppError::NoAvailableSlots(..) => StatusCode::CONFLICT,
+        AppError::Internal(_) => StatusCode::INTERNAL_SERVER_ERROR,
+    }
+}
@@ -0,0 +1,24 @@
+use std::sync::Arc;
+
+use axum::Json;
+use axum::extract::State;
+use axum::response::Html;
+
+use crate::http::ApiState;
+use crate::http::auth::Actor;
+use crate::http::error::ApiError;
+use crate::http::responses::DashboardResponse;
+
+/// Страница без данных: токен берётся из `#token=...` в адресе,
+/// а сводка запрашивается из `/api/dashboard` раз в несколько секунд.
+pub async fn page() -> Html<&'static str> {
+    Html(include_str!("dashboard.html"))
+}
+
+pub async fn data(
+    State(state): State<Arc<ApiState>>,
+    Actor(actor_id): Actor,
+) -> Result<Json<DashboardResponse>, ApiError> {
+    let dashboard = state.app.get_dashboard.execute(actor_id).await?;
+    Ok(Json(dashboard.into()))
+}
@@ -0,0 +1,51 @@
+use std::sync::Arc;
+
+use axum::Json;
+use axum::extract::{Path, Query, State};
+use axum::http::StatusCode;
+use serde::Deserialize;
+
+use crate::domain::models::FeedbackID;
+use crate::http::ApiState;
+use crate::http::auth::Actor;
+use crate::http::error::ApiError;
+use crate::http::responses::{FeedbackResponse, FeedbackStatus, PageResponse};
+
+#[derive(Deserialize)]
+pub struct FeedbackQuery {
+    /// Без статуса — обратная связь в любом статусе.
+    status: Option<FeedbackStatus>,
+    #[serde(default)]
+    page: usize,
+}
+
+pub async fn list(
+    State(state): State<Arc<ApiState>>,
+    Actor(actor_id): Actor,
+    Query(query): Query<FeedbackQuery>,
+) -> Result<Json<PageResponse<FeedbackResponse>>, ApiError> {
+    let page = state
+        .app
+        .get_feedback_inbox
+        .execute(actor_id, query.status.map(Into::into), query.page)
+        .await?;
+    Ok(Json(page.into()))
+}
+
+pub async fn details(
+    State(state): State<Arc<ApiState>>,
+    Actor(actor_id): Actor,
+    Path(id): Path<FeedbackID>,
+) -> Result<Json<FeedbackResponse>, ApiError> {
+    let feedback = state.app.get_feedback.execute(actor_id, id).await?;
+    Ok(Json(feedback.into()))
+}
+
+pub async fn archive(
+    State(state): State<Arc<ApiState>>,
+    Actor(actor_id): Actor,
+    Path(id): Path<FeedbackID>,
+) -> Result<StatusCode, ApiError> {
+    state.app.archive_feedback.execute(actor_id, id).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
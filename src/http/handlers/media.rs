@@ -0,0 +1,50 @@
+use std::sync::Arc;
+
+use axum::Json;
+use axum::extract::{Path, Query, State};
+use serde::Deserialize;
+
+use crate::app::usecases::dto::MediaDTO;
+use crate::domain::models::MediaID;
+use crate::http::ApiState;
+use crate::http::auth::Actor;
+use crate::http::error::ApiError;
+use crate::http::responses::{
+    MediaDetailsResponse, MediaResponse, MediaUsageResponse, PageResponse,
+};
+
+#[derive(Deserialize)]
+pub struct MediaQuery {
+    /// Часть ID медиафайла, пустая строка — все файлы.
+    #[serde(default)]
+    query: String,
+    #[serde(default)]
+    page: usize,
+}
+
+pub async fn list(
+    State(state): State<Arc<ApiState>>,
+    Actor(actor_id): Actor,
+    Query(query): Query<MediaQuery>,
+) -> Result<Json<PageResponse<MediaResponse>>, ApiError> {
+    let page = state
+        .app
+        .find_media
+        .execute(actor_id, &query.query, query.page)
+        .await?;
+    Ok(Json(page.into()))
+}
+
+pub async fn details(
+    State(state): State<Arc<ApiState>>,
+    Actor(actor_id): Actor,
+    Path(id): Path<String>,
+) -> Result<Json<MediaDetailsResponse>, ApiError> {
+    let id = MediaID::new(id)?;
+    let media = state.app.get_media.execute(actor_id, id.clone()).await?;
+    let usages = state.app.get_media_usages.execute(actor_id, id).await?;
+    Ok(Json(MediaDetailsResponse {
+        media: MediaDTO::from(media).into(),
+        usages: usages.into_iter().map(MediaUsageResponse::from).collect(),
+    }))
+}
@@ -0,0 +1,5 @@
+pub mod dashboard;
+pub mod feedback;
+pub mod media;
+pub mod slots;
+pub mod teams;
@@ -0,0 +1,17 @@
+use std::sync::Arc;
+
+use axum::Json;
+use axum::extract::State;
+
+use crate::http::ApiState;
+use crate::http::auth::Actor;
+use crate::http::error::ApiError;
+use crate::http::responses::SlotResponse;
+
+pub async fn list(
+    State(state): State<Arc<ApiState>>,
+    Actor(actor_id): Actor,
+) -> Result<Json<Vec<SlotResponse>>, ApiError> {
+    let slots = state.app.get_slots.execute(actor_id).await?;
+    Ok(Json(slots.into_iter().map(SlotResponse::from).collect()))
+}
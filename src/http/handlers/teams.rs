@@ -0,0 +1,71 @@
+use std::sync::Arc;
+
+use axum::Json;
+use axum::extract::{Path, State};
+
+use crate::domain::models::TeamID;
+use crate::http::ApiState;
+use crate::http::auth::Actor;
+use crate::http::error::ApiError;
+use crate::http::responses::{
+    AnswerResponse, TeamMembersResponse, TeamResponse, TrackProgressResponse,
+};
+
+pub async fn list(
+    State(state): State<Arc<ApiState>>,
+    Actor(actor_id): Actor,
+) -> Result<Json<Vec<TeamResponse>>, ApiError> {
+    let teams = state.app.get_teams.execute(actor_id).await?;
+    Ok(Json(teams.into_iter().map(TeamResponse::from).collect()))
+}
+
+pub async fn members(
+    State(state): State<Arc<ApiState>>,
+    Actor(actor_id): Actor,
+    Path(id): Path<String>,
+) -> Result<Json<TeamMembersResponse>, ApiError> {
+    let team = state
+        .app
+        .get_team_with_members
+        .execute(actor_id, TeamID::try_from(id)?)
+        .await?;
+    Ok(Json(team.into()))
+}
+
+pub async fn progress(
+    State(state): State<Arc<ApiState>>,
+    Actor(actor_id): Actor,
+    Path(id): Path<String>,
+) -> Result<Json<Vec<TrackProgressResponse>>, ApiError> {
+    let progress = state
+        .app
+        .get_team_progress
+        .execute(actor_id, TeamID::try_from(id)?)
+        .await?;
+    Ok(Json(
+        progress
+            .tracks
+            .into_iter()
+            .map(TrackProgressResponse::from)
+            .collect(),
+    ))
+}
+
+pub async fn answers(
+    State(state): State<Arc<ApiState>>,
+    Actor(actor_id): Actor,
+    Path(id): Path<String>,
+) -> Result<Json<Vec<AnswerResponse>>, ApiError> {
+    let progress = state
+        .app
+        .get_team_progress
+        .execute(actor_id, TeamID::try_from(id)?)
+        .await?;
+    Ok(Json(
+        progress
+            .answers
+            .into_iter()
+            .map(AnswerResponse::from)
+            .collect(),
+    ))
+}
@@ -0,0 +1,55 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use axum::Router;
+use axum::routing::{get, post};
+use tokio::net::TcpListener;
+
+use crate::app::usecases::app::App;
+use crate::config::HttpConfig;
+use crate::domain::models::UserID;
+use crate::http::handlers::{dashboard, feedback, media, slots, teams};
+
+mod auth;
+mod error;
+mod handlers;
+mod responses;
+
+/// Общее состояние обработчиков API.
+pub struct ApiState {
+    app: App,
+    /// Токен доступа → организатор, от имени которого выполняется запрос.
+    tokens: HashMap<String, UserID>,
+}
+
+/// HTTP API для панели организаторов. Работает на том же рантайме, что и бот,
+/// поверх тех же сценариев, поэтому права проверяются так же, как в Telegram.
+pub async fn serve(config: HttpConfig, app: App) -> std::io::Result<()> {
+    let tokens = config
+        .tokens
+        .into_iter()
+        .map(|t| (t.token, UserID::new(t.user_id)))
+        .collect();
+    let state = Arc::new(ApiState { app, tokens });
+
+    let listener = TcpListener::bind(config.bind).await?;
+    log::info!("HTTP API is listening on {}", config.bind);
+    axum::serve(listener, router(state)).await
+}
+
+fn router(state: Arc<ApiState>) -> Router {
+    Router::new()
+        .route("/dashboard", get(dashboard::page))
+        .route("/api/dashboard", get(dashboard::data))
+        .route("/api/teams", get(teams::list))
+        .route("/api/teams/{id}", get(teams::members))
+        .route("/api/teams/{id}/progress", get(teams::progress))
+        .route("/api/teams/{id}/answers", get(teams::answers))
+        .route("/api/slots", get(slots::list))
+        .route("/api/feedback", get(feedback::list))
+        .route("/api/feedback/{id}", get(feedback::details))
+        .route("/api/feedback/{id}/archive", post(feedback::archive))
+        .route("/api/media", get(media::list))
+        .route("/api/media/{id}", get(media::details))
+        .with_state(state)
+}
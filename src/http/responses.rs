@@ -0,0 +1,543 @@
+use chrono::{DateTime, NaiveTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::app::usecases::dto::{
+    DashboardDTO, DashboardTeamDTO, DashboardTrackDTO, FeedbackDTO, FeedbackPageDTO,
+    FeedbackReplyDTO, MediaDTO, MediaPageDTO, PendingReviewDTO, SlotOverviewDTO, TaskItemDTO,
+    TeamAnswerDTO, TeamOverviewDTO, TeamTrackProgressDTO, TeamWithMembersDTO, UserDTO,
+};
+use crate::domain::models::{
+    FeedbackStatus as DomainFeedbackStatus, MediaType as DomainMediaType, MediaUsage,
+    SiteOccupancy, TaskStatus as DomainTaskStatus, TrackStatus as DomainTrackStatus,
+};
+
+#[derive(Serialize)]
+pub struct PageResponse<T> {
+    pub items: Vec<T>,
+    pub page: usize,
+    pub pages: usize,
+    pub total: usize,
+}
+
+#[derive(Serialize)]
+pub struct TeamResponse {
+    pub id: String,
+    pub name: String,
+    pub solo: bool,
+    pub size: usize,
+    pub captain_id: i64,
+    pub points: i32,
+    pub started_tracks: usize,
+    pub finished_tracks: usize,
+    pub reserved_slot: Option<String>,
+}
+
+impl From<TeamOverviewDTO> for TeamResponse {
+    fn from(t: TeamOverviewDTO) -> Self {
+        Self {
+            id: t.id.to_string(),
+            name: t.name.to_string(),
+            solo: t.solo,
+            size: t.size,
+            captain_id: t.captain_id.as_i64(),
+            points: t.points.as_i32(),
+            started_tracks: t.started_tracks,
+            finished_tracks: t.finished_tracks,
+            reserved_slot: t.reserved_slot.map(|s| s.to_string()),
+        }
+    }
+}
+
+#[derive(Serialize)]
+pub struct MemberResponse {
+    pub username: Option<String>,
+    pub full_name: String,
+    pub group_name: String,
+}
+
+impl From<UserDTO> for MemberResponse {
+    fn from(u: UserDTO) -> Self {
+        Self {
+            username: u.username.map(|n| n.to_string()),
+            full_name: u.full_name.to_string(),
+            group_name: u.group_name.to_string(),
+        }
+    }
+}
+
+#[derive(Serialize)]
+pub struct TeamMembersResponse {
+    pub id: String,
+    pub name: String,
+    pub solo: bool,
+    pub size: usize,
+    pub max_size: usize,
+    pub captain: MemberResponse,
+    pub members: Vec<MemberResponse>,
+}
+
+impl From<TeamWithMembersDTO> for TeamMembersResponse {
+    fn from(t: TeamWithMembersDTO) -> Self {
+        Self {
+            id: t.id.to_string(),
+            name: t.name.to_string(),
+            solo: t.solo,
+            size: t.size,
+            max_size: t.max_size,
+            captain: t.captain.into(),
+            members: t.members.into_iter().map(MemberResponse::from).collect(),
+        }
+    }
+}
+
+#[derive(Serialize)]
+#[serde(tag = "state", rename_all = "snake_case")]
+pub enum TrackStatusResponse {
+    Started {
+        started_at: DateTime<Utc>,
+    },
+    Finished {
+        started_at: DateTime<Utc>,
+        finished_at: DateTime<Utc>,
+    },
+}
+
+impl From<DomainTrackStatus> for TrackStatusResponse {
+    fn from(s: DomainTrackStatus) -> Self {
+        match s {
+            DomainTrackStatus::Started(started_at) => Self::Started { started_at },
+            DomainTrackStatus::Finished(started_at, finished_at) => Self::Finished {
+                started_at,
+                finished_at,
+            },
+        }
+    }
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TaskStatus {
+    NotAvailable,
+    Available,
+    InProgress,
+    UnderReview,
+    Completed,
+}
+
+impl From<DomainTaskStatus> for TaskStatus {
+    fn from(s: DomainTaskStatus) -> Self {
+        match s {
+            DomainTaskStatus::NotAvailable => Self::NotAvailable,
+            DomainTaskStatus::Available => Self::Available,
+            DomainTaskStatus::InProgress => Self::InProgress,
+            DomainTaskStatus::UnderReview => Self::UnderReview,
+            DomainTaskStatus::Completed => Self::Completed,
+        }
+    }
+}
+
+#[derive(Serialize)]
+pub struct TaskResponse {
+    pub id: i32,
+    pub number: usize,
+    pub title: Option<String>,
+    pub status: TaskStatus,
+}
+
+impl From<TaskItemDTO> for TaskResponse {
+    fn from(t: TaskItemDTO) -> Self {
+        Self {
+            id: t.id,
+            number: t.number,
+            title: t.title.map(|title| title.to_string()),
+            status: t.status.into(),
+        }
+    }
+}
+
+#[derive(Serialize)]
+pub struct TrackProgressResponse {
+    pub tag: String,
+    pub name: String,
+    pub status: Option<TrackStatusResponse>,
+    pub points: i32,
+    pub max_points: i32,
+    pub percent: f32,
+    pub tasks: Vec<TaskResponse>,
+}
+
+impl From<TeamTrackProgressDTO> for TrackProgressResponse {
+    fn from(t: TeamTrackProgressDTO) -> Self {
+        Self {
+            tag: t.tag.as_str().to_string(),
+            name: t.name.to_string(),
+            status: t.status.map(TrackStatusResponse::from),
+            points: t.points.as_i32(),
+            max_points: t.max_points.as_i32(),
+            percent: t.percent,
+            tasks: t.tasks.into_iter().map(TaskResponse::from).collect(),
+        }
+    }
+}
+
+#[derive(Serialize)]
+pub struct AnswerResponse {
+    pub task_id: i32,
+    pub track: Option<String>,
+    pub number: Option<usize>,
+    pub text: String,
+    pub points: i32,
+    pub base_points: i32,
+    pub penalty_points: i32,
+    pub time_bonus_points: i32,
+    pub completed: bool,
+    pub pending_review: bool,
+    pub attempts: i32,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<TeamAnswerDTO> for AnswerResponse {
+    fn from(a: TeamAnswerDTO) -> Self {
+        Self {
+            task_id: a.task_id,
+            track: a.track.map(|t| t.as_str().to_string()),
+            number: a.number,
+            text: a.text,
+            points: a.score.total().as_i32(),
+            base_points: a.score.base().as_i32(),
+            penalty_points: a.score.penalty().as_i32(),
+            time_bonus_points: a.score.time_bonus().as_i32(),
+            completed: a.completed,
+            pending_review: a.pending_review,
+            attempts: a.attempts,
+            created_at: a.created_at,
+        }
+    }
+}
+
+#[derive(Serialize)]
+pub struct ReservationResponse {
+    pub team_id: String,
+    pub team_name: String,
+    pub places: usize,
+}
+
+#[derive(Serialize)]
+pub struct SlotResponse {
+    pub id: String,
+    pub start: NaiveTime,
+    pub site: String,
+    pub capacity: usize,
+    pub reserved: usize,
+    pub reservations: Vec<ReservationResponse>,
+}
+
+impl From<SlotOverviewDTO> for SlotResponse {
+    fn from(s: SlotOverviewDTO) -> Self {
+        Self {
+            id: s.id.to_string(),
+            start: s.start,
+            site: s.site.to_string(),
+            capacity: s.capacity,
+            reserved: s.reserved,
+            reservations: s
+                .reservations
+                .into_iter()
+                .map(|r| ReservationResponse {
+                    team_id: r.team_id.to_string(),
+                    team_name: r.team_name.to_string(),
+                    places: r.places,
+                })
+                .collect(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+pub enum FeedbackStatus {
+    New,
+    Answered,
+    Archived,
+}
+
+impl From<DomainFeedbackStatus> for FeedbackStatus {
+    fn from(s: DomainFeedbackStatus) -> Self {
+        match s {
+            DomainFeedbackStatus::New => Self::New,
+            DomainFeedbackStatus::Answered => Self::Answered,
+            DomainFeedbackStatus::Archived => Self::Archived,
+        }
+    }
+}
+
+impl From<FeedbackStatus> for DomainFeedbackStatus {
+    fn from(s: FeedbackStatus) -> Self {
+        match s {
+            FeedbackStatus::New => Self::New,
+            FeedbackStatus::Answered => Self::Answered,
+            FeedbackStatus::Archived => Self::Archived,
+        }
+    }
+}
+
+#[derive(Serialize)]
+pub struct FeedbackReplyResponse {
+    pub author_id: i64,
+    pub text: String,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<FeedbackReplyDTO> for FeedbackReplyResponse {
+    fn from(r: FeedbackReplyDTO) -> Self {
+        Self {
+            author_id: r.author_id.as_i64(),
+            text: r.text.to_string(),
+            created_at: r.created_at,
+        }
+    }
+}
+
+#[derive(Serialize)]
+pub struct FeedbackResponse {
+    pub id: i32,
+    pub author_id: i64,
+    pub author: MemberResponse,
+    pub text: String,
+    pub status: FeedbackStatus,
+    pub created_at: DateTime<Utc>,
+    pub replies: Vec<FeedbackReplyResponse>,
+}
+
+impl From<FeedbackDTO> for FeedbackResponse {
+    fn from(f: FeedbackDTO) -> Self {
+        Self {
+            id: f.id,
+            author_id: f.author_id.as_i64(),
+            author: f.author.into(),
+            text: f.text.to_string(),
+            status: f.status.into(),
+            created_at: f.created_at,
+            replies: f
+                .replies
+                .into_iter()
+                .map(FeedbackReplyResponse::from)
+                .collect(),
+        }
+    }
+}
+
+impl From<FeedbackPageDTO> for PageResponse<FeedbackResponse> {
+    fn from(p: FeedbackPageDTO) -> Self {
+        Self {
+            items: p.items.into_iter().map(FeedbackResponse::from).collect(),
+            page: p.page,
+            pages: p.pages,
+            total: p.total,
+        }
+    }
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MediaType {
+    Image,
+    VideoNote,
+    Audio,
+    Voice,
+    Video,
+    Document,
+}
+
+impl From<DomainMediaType> for MediaType {
+    fn from(t: DomainMediaType) -> Self {
+        match t {
+            DomainMediaType::Image => Self::Image,
+            DomainMediaType::VideoNote => Self::VideoNote,
+            DomainMediaType::Audio => Self::Audio,
+            DomainMediaType::Voice => Self::Voice,
+            DomainMediaType::Video => Self::Video,
+            DomainMediaType::Document => Self::Document,
+        }
+    }
+}
+
+#[derive(Serialize)]
+pub struct MediaResponse {
+    pub id: String,
+    pub file_id: String,
+    pub media_type: MediaType,
+}
+
+impl From<MediaDTO> for MediaResponse {
+    fn from(m: MediaDTO) -> Self {
+        Self {
+            id: m.id.to_string(),
+            file_id: m.file_id.to_string(),
+            media_type: m.media_type.into(),
+        }
+    }
+}
+
+impl From<MediaPageDTO> for PageResponse<MediaResponse> {
+    fn from(p: MediaPageDTO) -> Self {
+        Self {
+            items: p.items.into_iter().map(MediaResponse::from).collect(),
+            page: p.page,
+            pages: p.pages,
+            total: p.total,
+        }
+    }
+}
+
+#[derive(Serialize)]
+#[serde(tag = "kind", content = "id", rename_all = "snake_case")]
+pub enum MediaUsageResponse {
+    Track(String),
+    Task(i32),
+    Character(String),
+}
+
+impl From<MediaUsage> for MediaUsageResponse {
+    fn from(u: MediaUsage) -> Self {
+        match u {
+            MediaUsage::Track(tag) => Self::Track(tag.as_str().to_string()),
+            MediaUsage::Task(id) => Self::Task(id),
+            MediaUsage::Character(id) => Self::Character(id.to_string()),
+        }
+    }
+}
+
+#[derive(Serialize)]
+pub struct MediaDetailsResponse {
+    #[serde(flatten)]
+    pub media: MediaResponse,
+    pub usages: Vec<MediaUsageResponse>,
+}
+
+#[derive(Serialize)]
+pub struct DashboardTeamResponse {
+    pub team_id: String,
+    pub team_name: String,
+    pub points: i32,
+    pub percent: f32,
+    pub finished: bool,
+    pub started_at: DateTime<Utc>,
+    pub elapsed_seconds: i64,
+}
+
+impl From<DashboardTeamDTO> for DashboardTeamResponse {
+    fn from(t: DashboardTeamDTO) -> Self {
+        Self {
+            team_id: t.team_id.to_string(),
+            team_name: t.team_name.to_string(),
+            points: t.points.as_i32(),
+            percent: t.percent,
+            finished: t.finished,
+            started_at: t.started_at,
+            elapsed_seconds: t.elapsed.num_seconds(),
+        }
+    }
+}
+
+#[derive(Serialize)]
+pub struct DashboardTrackResponse {
+    pub tag: String,
+    pub name: String,
+    pub teams: Vec<DashboardTeamResponse>,
+}
+
+impl From<DashboardTrackDTO> for DashboardTrackResponse {
+    fn from(t: DashboardTrackDTO) -> Self {
+        Self {
+            tag: t.tag.as_str().to_string(),
+            name: t.name.to_string(),
+            teams: t
+                .teams
+                .into_iter()
+                .map(DashboardTeamResponse::from)
+                .collect(),
+        }
+    }
+}
+
+#[derive(Serialize)]
+pub struct PendingReviewResponse {
+    pub team_id: String,
+    pub team_name: String,
+    pub task_id: i32,
+    pub track: Option<String>,
+    pub number: Option<usize>,
+    pub created_at: DateTime<Utc>,
+    pub waiting_seconds: i64,
+}
+
+impl From<PendingReviewDTO> for PendingReviewResponse {
+    fn from(r: PendingReviewDTO) -> Self {
+        Self {
+            team_id: r.team_id.to_string(),
+            team_name: r.team_name.to_string(),
+            task_id: r.task_id,
+            track: r.track.map(|t| t.as_str().to_string()),
+            number: r.number,
+            created_at: r.created_at,
+            waiting_seconds: r.waiting.num_seconds(),
+        }
+    }
+}
+
+#[derive(Serialize)]
+pub struct SiteOccupancyResponse {
+    pub site: String,
+    pub slots: usize,
+    pub capacity: usize,
+    pub reserved: usize,
+}
+
+impl From<SiteOccupancy> for SiteOccupancyResponse {
+    fn from(s: SiteOccupancy) -> Self {
+        Self {
+            site: s.site.to_string(),
+            slots: s.slots,
+            capacity: s.capacity,
+            reserved: s.reserved,
+        }
+    }
+}
+
+#[derive(Serialize)]
+pub struct DashboardResponse {
+    pub generated_at: DateTime<Utc>,
+    pub tracks: Vec<DashboardTrackResponse>,
+    pub pending_reviews: Vec<PendingReviewResponse>,
+    pub sites: Vec<SiteOccupancyResponse>,
+    pub latest_feedback: Vec<FeedbackResponse>,
+}
+
+impl From<DashboardDTO> for DashboardResponse {
+    fn from(d: DashboardDTO) -> Self {
+        Self {
+            generated_at: d.generated_at,
+            tracks: d
+                .tracks
+                .into_iter()
+                .map(DashboardTrackResponse::from)
+                .collect(),
+            pending_reviews: d
+                .pending_reviews
+                .into_iter()
+                .map(PendingReviewResponse::from)
+                .collect(),
+            sites: d
+                .sites
+                .into_iter()
+                .map(SiteOccupancyResponse::from)
+                .collect(),
+            latest_feedback: d
+                .latest_feedback
+                .into_iter()
+                .map(FeedbackResponse::from)
+                .collect(),
+        }
+    }
+}
@@ -1,9 +1,11 @@
 use crate::domain::models::{
-    Answer, AnswerText, CorrectAnswer, Points, Reservation, Site, Slot, SlotID, Task, TaskID,
-    TaskText, TrackStatus,
+    Answer, AnswerText, CorrectAnswer, DanglingMediaReference, GeoArea, GeoPoint, MediaContent,
+    MediaUsage, NumericAnswer, PendingPhotoReview, Points, Reservation, ScoreBreakdown,
+    ScoringRules, Site, SiteOccupancy, Slot, SlotID, Task, TaskHint, TaskID, TaskText, TaskTitle,
+    TrackStatus, TrackSummary, TrackTeamProgress,
 };
 use async_trait::async_trait;
-use chrono::{DateTime, NaiveTime, Utc};
+use chrono::{DateTime, Duration, NaiveTime, Utc};
 use deadpool_postgres::Pool;
 use postgres_types::{FromSql, ToSql};
 use std::collections::HashMap;
@@ -12,17 +14,22 @@ use tokio_postgres::{Row, Transaction};
 
 use crate::app::error::AppError;
 use crate::app::ports::{
-    CharactersProvider, FeedbackRepository, IsAdminProvider, IsRegisteredUserProvider,
-    MediaProvider, MediaRepository, SlotProvider, SlotRepository, SlotsProvider, TaskProvider,
-    TeamByMemberProvider, TeamProvider, TeamRepository, TrackProvider, UserProvider,
+    AuditLogRepository, CharactersProvider, DashboardProvider, EventPhaseProvider,
+    EventPhaseRepository, FavoriteCharactersRepository, FeedbackRepository,
+    IsRegisteredUserProvider, MediaProvider, MediaRepository, RolesProvider, RolesRepository,
+    SlotProvider, SlotRepository, SlotsProvider, TaskProvider, TaskReferenceProvider,
+    TeamByMemberProvider, TeamProvider, TeamRepository, TeamsProvider, TrackProvider, UserProvider,
     UserRepository,
 };
 use crate::app::usecases::AnswerTask;
 use crate::domain::models::{
-    Character, CharacterFact, CharacterID, CharacterLegacy, CharacterName, CharacterQuote,
-    Feedback, FileID, FullName, GroupName, Media, MediaID, MediaType as DomainMediaType,
-    SerialNumber, TaskOption, TaskType as DomainTaskType, Team, TeamID, TeamName, Track,
-    TrackDescription, TrackTag as DomainTrackTag, User, UserID, Username,
+    AuditAction as DomainAuditAction, AuditEntry as DomainAuditEntry, AuditFilter, Character,
+    CharacterFact, CharacterID, CharacterLegacy, CharacterName, CharacterQuote, CharacterUnlock,
+    EventPhase as DomainEventPhase, Feedback, FeedbackID, FeedbackReply,
+    FeedbackStatus as DomainFeedbackStatus, FeedbackText, FileID, FullName, GroupName, Media,
+    MediaID, MediaType as DomainMediaType, Role as DomainRole, SerialNumber, TaskOption,
+    TaskType as DomainTaskType, Team, TeamID, TeamName, Track, TrackDescription, TrackName,
+    TrackTag, User, UserID, UserRoles, Username,
 };
 use crate::{with_client, with_transaction};
 
@@ -78,7 +85,12 @@ struct AnswerRow {
     team_id: String,
     task_id: i32,
     text: String,
-    points: i32,
+    base_points: i32,
+    penalty_points: i32,
+    time_bonus_points: i32,
+    completed: bool,
+    pending_review: bool,
+    attempts: i32,
     created_at: DateTime<Utc>,
 }
 
@@ -88,7 +100,12 @@ impl AnswerRow {
             team_id: row.try_get("team_id")?,
             task_id: row.try_get("task_id")?,
             text: row.try_get("text")?,
-            points: row.try_get("points")?,
+            base_points: row.try_get("base_points")?,
+            penalty_points: row.try_get("penalty_points")?,
+            time_bonus_points: row.try_get("time_bonus_points")?,
+            completed: row.try_get("completed")?,
+            pending_review: row.try_get("pending_review")?,
+            attempts: row.try_get("attempts")?,
             created_at: row.try_get("created_at")?,
         })
     }
@@ -96,7 +113,7 @@ impl AnswerRow {
 
 struct TeamStartedTrackRow {
     team_id: String,
-    track_tag: TrackTag,
+    track_tag: String,
     started_at: DateTime<Utc>,
     finished_at: Option<DateTime<Utc>>,
 }
@@ -117,6 +134,10 @@ impl TeamStartedTrackRow {
 enum MediaType {
     Image,
     VideoNote,
+    Audio,
+    Voice,
+    Video,
+    Document,
 }
 
 impl Into<DomainMediaType> for MediaType {
@@ -124,6 +145,10 @@ impl Into<DomainMediaType> for MediaType {
         match self {
             MediaType::Image => DomainMediaType::Image,
             MediaType::VideoNote => DomainMediaType::VideoNote,
+            MediaType::Audio => DomainMediaType::Audio,
+            MediaType::Voice => DomainMediaType::Voice,
+            MediaType::Video => DomainMediaType::Video,
+            MediaType::Document => DomainMediaType::Document,
         }
     }
 }
@@ -133,6 +158,10 @@ impl From<DomainMediaType> for MediaType {
         match value {
             DomainMediaType::Image => MediaType::Image,
             DomainMediaType::VideoNote => MediaType::VideoNote,
+            DomainMediaType::Audio => MediaType::Audio,
+            DomainMediaType::Voice => MediaType::Voice,
+            DomainMediaType::Video => MediaType::Video,
+            DomainMediaType::Document => MediaType::Document,
         }
     }
 }
@@ -160,6 +189,9 @@ struct CharacterRow {
     quote: String,
     legacy: String,
     media_id: String,
+    unlock_task_id: Option<i32>,
+    unlock_track_tag: Option<String>,
+    silhouette_media_id: Option<String>,
 }
 
 impl CharacterRow {
@@ -171,6 +203,9 @@ impl CharacterRow {
             quote: row.try_get("quote")?,
             legacy: row.try_get("legacy")?,
             media_id: row.try_get("media_id")?,
+            unlock_task_id: row.try_get("unlock_task_id")?,
+            unlock_track_tag: row.try_get("unlock_track_tag")?,
+            silhouette_media_id: row.try_get("silhouette_media_id")?,
         })
     }
 }
@@ -189,42 +224,10 @@ impl CharacterFactRow {
     }
 }
 
-#[derive(Debug, ToSql, FromSql)]
-#[postgres(name = "track_tag", rename_all = "snake_case")]
-enum TrackTag {
-    Muzhestvo,
-    Volya,
-    Trud,
-    Uporstvo,
-    Universitet,
-}
-
-impl From<DomainTrackTag> for TrackTag {
-    fn from(v: DomainTrackTag) -> Self {
-        match v {
-            DomainTrackTag::Muzhestvo => Self::Muzhestvo,
-            DomainTrackTag::Volya => Self::Volya,
-            DomainTrackTag::Trud => Self::Trud,
-            DomainTrackTag::Uporstvo => Self::Uporstvo,
-            DomainTrackTag::Universitet => Self::Universitet,
-        }
-    }
-}
-
-impl Into<DomainTrackTag> for TrackTag {
-    fn into(self) -> DomainTrackTag {
-        match self {
-            Self::Muzhestvo => DomainTrackTag::Muzhestvo,
-            Self::Volya => DomainTrackTag::Volya,
-            Self::Trud => DomainTrackTag::Trud,
-            Self::Uporstvo => DomainTrackTag::Uporstvo,
-            Self::Universitet => DomainTrackTag::Universitet,
-        }
-    }
-}
-
 struct TrackRow {
-    tag: TrackTag,
+    tag: String,
+    name: String,
+    solo: bool,
     description: String,
     media_id: String,
 }
@@ -233,6 +236,8 @@ impl TrackRow {
     pub fn fetch_from_row(row: &Row) -> Result<TrackRow, tokio_postgres::Error> {
         Ok(Self {
             tag: row.try_get("tag")?,
+            name: row.try_get("name")?,
+            solo: row.try_get("solo")?,
             description: row.try_get("description")?,
             media_id: row.try_get("media_id")?,
         })
@@ -245,6 +250,10 @@ enum TaskType {
     Text,
     Choice,
     Photo,
+    MultiChoice,
+    Number,
+    Ordering,
+    Location,
 }
 
 impl From<DomainTaskType> for TaskType {
@@ -253,6 +262,10 @@ impl From<DomainTaskType> for TaskType {
             DomainTaskType::Text => TaskType::Text,
             DomainTaskType::Choice => TaskType::Choice,
             DomainTaskType::Photo => TaskType::Photo,
+            DomainTaskType::MultiChoice => TaskType::MultiChoice,
+            DomainTaskType::Number => TaskType::Number,
+            DomainTaskType::Ordering => TaskType::Ordering,
+            DomainTaskType::Location => TaskType::Location,
         }
     }
 }
@@ -263,32 +276,134 @@ impl Into<DomainTaskType> for TaskType {
             TaskType::Text => DomainTaskType::Text,
             TaskType::Choice => DomainTaskType::Choice,
             TaskType::Photo => DomainTaskType::Photo,
+            TaskType::MultiChoice => DomainTaskType::MultiChoice,
+            TaskType::Number => DomainTaskType::Number,
+            TaskType::Ordering => DomainTaskType::Ordering,
+            TaskType::Location => DomainTaskType::Location,
         }
     }
 }
 
 struct TaskRow {
     id: i32,
+    title: Option<String>,
     task_type: TaskType,
     question: String,
     explanation: String,
-    media_id: Option<String>,
     points: i32,
     price: i32,
     max_lvnsht_d: i32,
+    numeric_answer: Option<f64>,
+    numeric_tolerance: f64,
+    latitude: Option<f64>,
+    longitude: Option<f64>,
+    radius: Option<f64>,
+    attempt_penalty_percent: i32,
+    min_points_percent: i32,
+    time_bonus: i32,
+    time_bonus_minutes: i32,
+    partial_credit: bool,
 }
 
 impl TaskRow {
     pub fn fetch_from_row(row: &Row) -> Result<TaskRow, tokio_postgres::Error> {
         Ok(Self {
             id: row.try_get("id")?,
+            title: row.try_get("title")?,
             task_type: row.try_get("task_type")?,
             question: row.try_get("question")?,
             explanation: row.try_get("explanation")?,
-            media_id: row.try_get("media_id")?,
             points: row.try_get("points")?,
             price: row.try_get("price")?,
             max_lvnsht_d: row.try_get("max_lvnsht_d")?,
+            numeric_answer: row.try_get("numeric_answer")?,
+            numeric_tolerance: row.try_get("numeric_tolerance")?,
+            latitude: row.try_get("latitude")?,
+            longitude: row.try_get("longitude")?,
+            radius: row.try_get("radius")?,
+            attempt_penalty_percent: row.try_get("attempt_penalty_percent")?,
+            min_points_percent: row.try_get("min_points_percent")?,
+            time_bonus: row.try_get("time_bonus")?,
+            time_bonus_minutes: row.try_get("time_bonus_minutes")?,
+            partial_credit: row.try_get("partial_credit")?,
+        })
+    }
+}
+
+struct TrackSummaryRow {
+    tag: String,
+    name: String,
+    max_points: i32,
+}
+
+impl TrackSummaryRow {
+    pub fn fetch_from_row(row: &Row) -> Result<TrackSummaryRow, tokio_postgres::Error> {
+        Ok(Self {
+            tag: row.try_get("tag")?,
+            name: row.try_get("name")?,
+            max_points: row.try_get("max_points")?,
+        })
+    }
+}
+
+struct TrackTeamProgressRow {
+    track_tag: String,
+    team_id: String,
+    team_name: String,
+    started_at: DateTime<Utc>,
+    finished_at: Option<DateTime<Utc>>,
+    points: i32,
+}
+
+impl TrackTeamProgressRow {
+    pub fn fetch_from_row(row: &Row) -> Result<TrackTeamProgressRow, tokio_postgres::Error> {
+        Ok(Self {
+            track_tag: row.try_get("track_tag")?,
+            team_id: row.try_get("team_id")?,
+            team_name: row.try_get("team_name")?,
+            started_at: row.try_get("started_at")?,
+            finished_at: row.try_get("finished_at")?,
+            points: row.try_get("points")?,
+        })
+    }
+}
+
+struct PendingPhotoReviewRow {
+    team_id: String,
+    team_name: String,
+    task_id: i32,
+    track_tag: Option<String>,
+    number: Option<i32>,
+    created_at: DateTime<Utc>,
+}
+
+impl PendingPhotoReviewRow {
+    pub fn fetch_from_row(row: &Row) -> Result<PendingPhotoReviewRow, tokio_postgres::Error> {
+        Ok(Self {
+            team_id: row.try_get("team_id")?,
+            team_name: row.try_get("team_name")?,
+            task_id: row.try_get("task_id")?,
+            track_tag: row.try_get("track_tag")?,
+            number: row.try_get("number")?,
+            created_at: row.try_get("created_at")?,
+        })
+    }
+}
+
+struct SiteOccupancyRow {
+    site: String,
+    slots: i32,
+    capacity: i32,
+    reserved: i32,
+}
+
+impl SiteOccupancyRow {
+    pub fn fetch_from_row(row: &Row) -> Result<SiteOccupancyRow, tokio_postgres::Error> {
+        Ok(Self {
+            site: row.try_get("site")?,
+            slots: row.try_get("slots")?,
+            capacity: row.try_get("capacity")?,
+            reserved: row.try_get("reserved")?,
         })
     }
 }
@@ -327,6 +442,210 @@ impl ReservationRow {
     }
 }
 
+#[derive(Debug, ToSql, FromSql)]
+#[postgres(name = "feedback_status", rename_all = "snake_case")]
+enum FeedbackStatus {
+    New,
+    Answered,
+    Archived,
+}
+
+impl From<DomainFeedbackStatus> for FeedbackStatus {
+    fn from(v: DomainFeedbackStatus) -> Self {
+        match v {
+            DomainFeedbackStatus::New => Self::New,
+            DomainFeedbackStatus::Answered => Self::Answered,
+            DomainFeedbackStatus::Archived => Self::Archived,
+        }
+    }
+}
+
+impl From<FeedbackStatus> for DomainFeedbackStatus {
+    fn from(v: FeedbackStatus) -> Self {
+        match v {
+            FeedbackStatus::New => Self::New,
+            FeedbackStatus::Answered => Self::Answered,
+            FeedbackStatus::Archived => Self::Archived,
+        }
+    }
+}
+
+#[derive(Debug, ToSql, FromSql)]
+#[postgres(name = "user_role", rename_all = "snake_case")]
+enum UserRole {
+    SuperAdmin,
+    ContentEditor,
+    PhotoModerator,
+    FinalSiteStaff,
+    Analyst,
+}
+
+impl From<DomainRole> for UserRole {
+    fn from(v: DomainRole) -> Self {
+        match v {
+            DomainRole::SuperAdmin => Self::SuperAdmin,
+            DomainRole::ContentEditor => Self::ContentEditor,
+            DomainRole::PhotoModerator => Self::PhotoModerator,
+            DomainRole::FinalSiteStaff => Self::FinalSiteStaff,
+            DomainRole::Analyst => Self::Analyst,
+        }
+    }
+}
+
+impl From<UserRole> for DomainRole {
+    fn from(v: UserRole) -> Self {
+        match v {
+            UserRole::SuperAdmin => Self::SuperAdmin,
+            UserRole::ContentEditor => Self::ContentEditor,
+            UserRole::PhotoModerator => Self::PhotoModerator,
+            UserRole::FinalSiteStaff => Self::FinalSiteStaff,
+            UserRole::Analyst => Self::Analyst,
+        }
+    }
+}
+
+#[derive(Debug, ToSql, FromSql)]
+#[postgres(name = "audit_action", rename_all = "snake_case")]
+enum AuditAction {
+    UploadMedia,
+    RenameMedia,
+    DeleteMedia,
+    ReplyToFeedback,
+    ArchiveFeedback,
+    ReviewPhotoAnswer,
+    GrantRole,
+    RevokeRole,
+    SwitchEventPhase,
+    ResetDialogue,
+}
+
+impl From<DomainAuditAction> for AuditAction {
+    fn from(v: DomainAuditAction) -> Self {
+        match v {
+            DomainAuditAction::UploadMedia => Self::UploadMedia,
+            DomainAuditAction::RenameMedia => Self::RenameMedia,
+            DomainAuditAction::DeleteMedia => Self::DeleteMedia,
+            DomainAuditAction::ReplyToFeedback => Self::ReplyToFeedback,
+            DomainAuditAction::ArchiveFeedback => Self::ArchiveFeedback,
+            DomainAuditAction::ReviewPhotoAnswer => Self::ReviewPhotoAnswer,
+            DomainAuditAction::GrantRole => Self::GrantRole,
+            DomainAuditAction::RevokeRole => Self::RevokeRole,
+            DomainAuditAction::SwitchEventPhase => Self::SwitchEventPhase,
+            DomainAuditAction::ResetDialogue => Self::ResetDialogue,
+        }
+    }
+}
+
+impl From<AuditAction> for DomainAuditAction {
+    fn from(v: AuditAction) -> Self {
+        match v {
+            AuditAction::UploadMedia => Self::UploadMedia,
+            AuditAction::RenameMedia => Self::RenameMedia,
+            AuditAction::DeleteMedia => Self::DeleteMedia,
+            AuditAction::ReplyToFeedback => Self::ReplyToFeedback,
+            AuditAction::ArchiveFeedback => Self::ArchiveFeedback,
+            AuditAction::ReviewPhotoAnswer => Self::ReviewPhotoAnswer,
+            AuditAction::GrantRole => Self::GrantRole,
+            AuditAction::RevokeRole => Self::RevokeRole,
+            AuditAction::SwitchEventPhase => Self::SwitchEventPhase,
+            AuditAction::ResetDialogue => Self::ResetDialogue,
+        }
+    }
+}
+
+#[derive(Debug, ToSql, FromSql)]
+#[postgres(name = "event_phase", rename_all = "snake_case")]
+enum EventPhase {
+    Registration,
+    Quest,
+    FinalBooking,
+    PostEvent,
+}
+
+impl From<DomainEventPhase> for EventPhase {
+    fn from(v: DomainEventPhase) -> Self {
+        match v {
+            DomainEventPhase::Registration => Self::Registration,
+            DomainEventPhase::Quest => Self::Quest,
+            DomainEventPhase::FinalBooking => Self::FinalBooking,
+            DomainEventPhase::PostEvent => Self::PostEvent,
+        }
+    }
+}
+
+impl From<EventPhase> for DomainEventPhase {
+    fn from(v: EventPhase) -> Self {
+        match v {
+            EventPhase::Registration => Self::Registration,
+            EventPhase::Quest => Self::Quest,
+            EventPhase::FinalBooking => Self::FinalBooking,
+            EventPhase::PostEvent => Self::PostEvent,
+        }
+    }
+}
+
+struct AuditEntryRow {
+    id: i32,
+    actor_id: i64,
+    action: AuditAction,
+    target: String,
+    before: Option<String>,
+    after: Option<String>,
+    created_at: DateTime<Utc>,
+}
+
+impl AuditEntryRow {
+    pub fn fetch_from_row(row: &Row) -> Result<AuditEntryRow, tokio_postgres::Error> {
+        Ok(AuditEntryRow {
+            id: row.try_get("id")?,
+            actor_id: row.try_get("actor_id")?,
+            action: row.try_get("action")?,
+            target: row.try_get("target")?,
+            before: row.try_get("before")?,
+            after: row.try_get("after")?,
+            created_at: row.try_get("created_at")?,
+        })
+    }
+}
+
+struct FeedbackRow {
+    id: i32,
+    author_id: i64,
+    text: String,
+    status: FeedbackStatus,
+    created_at: DateTime<Utc>,
+}
+
+impl FeedbackRow {
+    pub fn fetch_from_row(row: &Row) -> Result<FeedbackRow, tokio_postgres::Error> {
+        Ok(Self {
+            id: row.try_get("id")?,
+            author_id: row.try_get("author_id")?,
+            text: row.try_get("text")?,
+            status: row.try_get("status")?,
+            created_at: row.try_get("created_at")?,
+        })
+    }
+}
+
+struct FeedbackReplyRow {
+    feedback_id: i32,
+    author_id: i64,
+    text: String,
+    created_at: DateTime<Utc>,
+}
+
+impl FeedbackReplyRow {
+    pub fn fetch_from_row(row: &Row) -> Result<FeedbackReplyRow, tokio_postgres::Error> {
+        Ok(Self {
+            feedback_id: row.try_get("feedback_id")?,
+            author_id: row.try_get("author_id")?,
+            text: row.try_get("text")?,
+            created_at: row.try_get("created_at")?,
+        })
+    }
+}
+
 #[async_trait::async_trait]
 impl UserProvider for PostgresRepository {
     async fn user(&self, id: UserID) -> Result<User, AppError> {
@@ -451,7 +770,12 @@ impl TeamProvider for PostgresRepository {
                         team_id,
                         task_id,
                         text,
-                        points,
+                        base_points,
+                        penalty_points,
+                        time_bonus_points,
+                        completed,
+                        pending_review,
+                        attempts,
                         created_at
                     FROM answers
                     WHERE
@@ -470,10 +794,18 @@ impl TeamProvider for PostgresRepository {
 
             let mut answers = Vec::new();
             for row in answer_rows {
+                let score = ScoreBreakdown::restore(
+                    Points::new(row.base_points)?,
+                    Points::new(row.penalty_points)?,
+                    Points::new(row.time_bonus_points)?,
+                )?;
                 let answer = Answer::restore(
                     row.task_id,
                     AnswerText::new(row.text),
-                    Points::new(row.points)?,
+                    score,
+                    row.completed,
+                    row.pending_review,
+                    row.attempts,
                     row.created_at,
                 );
                 answers.push(answer);
@@ -509,7 +841,7 @@ impl TeamProvider for PostgresRepository {
                 } else {
                     TrackStatus::Started(row.started_at)
                 };
-                let tag: DomainTrackTag = row.track_tag.into();
+                let tag = TrackTag::new(row.track_tag)?;
                 started_tracks.insert(tag, track_status);
             }
 
@@ -531,6 +863,35 @@ impl TeamProvider for PostgresRepository {
     }
 }
 
+#[async_trait::async_trait]
+impl TeamsProvider for PostgresRepository {
+    async fn teams(&self) -> Result<Vec<Team>, AppError> {
+        let ids = with_client!(self.pool, async |client: &Client| {
+            let rows = client
+                .query(
+                    r#"
+                    SELECT id
+                    FROM teams
+                    ORDER BY name
+                    "#,
+                    &[],
+                )
+                .await
+                .map_err(|err| AppError::Internal(err.into()))?;
+            rows.iter()
+                .map(|row| row.try_get::<&str, String>("id"))
+                .collect::<Result<Vec<_>, tokio_postgres::Error>>()
+                .map_err(|err| AppError::Internal(err.into()))
+        })?;
+
+        let mut teams = Vec::with_capacity(ids.len());
+        for id in ids {
+            teams.push(self.team(&TeamID::try_from(id)?).await?);
+        }
+        Ok(teams)
+    }
+}
+
 #[async_trait::async_trait]
 impl TeamByMemberProvider for PostgresRepository {
     async fn team_by_member(&self, member_id: UserID) -> Result<Option<Team>, AppError> {
@@ -594,7 +955,12 @@ impl TeamByMemberProvider for PostgresRepository {
                         team_id,
                         task_id,
                         text,
-                        points,
+                        base_points,
+                        penalty_points,
+                        time_bonus_points,
+                        completed,
+                        pending_review,
+                        attempts,
                         created_at
                     FROM answers
                     WHERE
@@ -613,10 +979,18 @@ impl TeamByMemberProvider for PostgresRepository {
 
             let mut answers = Vec::new();
             for row in answer_rows {
+                let score = ScoreBreakdown::restore(
+                    Points::new(row.base_points)?,
+                    Points::new(row.penalty_points)?,
+                    Points::new(row.time_bonus_points)?,
+                )?;
                 let answer = Answer::restore(
                     row.task_id,
                     AnswerText::new(row.text),
-                    Points::new(row.points)?,
+                    score,
+                    row.completed,
+                    row.pending_review,
+                    row.attempts,
                     row.created_at,
                 );
                 answers.push(answer);
@@ -652,7 +1026,7 @@ impl TeamByMemberProvider for PostgresRepository {
                 } else {
                     TrackStatus::Started(row.started_at)
                 };
-                let tag: DomainTrackTag = row.track_tag.into();
+                let tag = TrackTag::new(row.track_tag)?;
                 started_tracks.insert(tag, track_status);
             }
 
@@ -768,7 +1142,7 @@ impl TeamRepository for PostgresRepository {
                 .map_err(|err| AppError::Internal(err.into()))?;
             }
 
-            for (&track_tag, track_status) in team.started_tracks() {
+            for (track_tag, track_status) in team.started_tracks() {
                 match track_status {
                     TrackStatus::Started(started_at) => {
                         tx.execute(
@@ -784,7 +1158,7 @@ impl TeamRepository for PostgresRepository {
                             ON CONFLICT (team_id, track_tag)
                             DO NOTHING
                             "#,
-                            &[&team.id().as_str(), &TrackTag::from(track_tag), &started_at],
+                            &[&team.id().as_str(), &track_tag.as_str(), &started_at],
                         )
                         .await
                         .map_err(|err| AppError::Internal(err.into()))?;
@@ -807,7 +1181,7 @@ impl TeamRepository for PostgresRepository {
                             "#,
                             &[
                                 &team.id().as_str(),
-                                &TrackTag::from(track_tag),
+                                &track_tag.as_str(),
                                 &started_at,
                                 &finished_at,
                             ],
@@ -827,21 +1201,39 @@ impl TeamRepository for PostgresRepository {
                             task_id,
                             text,
                             points,
+                            base_points,
+                            penalty_points,
+                            time_bonus_points,
+                            completed,
+                            pending_review,
+                            attempts,
                             created_at
                         )
                     VALUES
-                        ($1, $2, $3, $4, $5)
+                        ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11)
                     ON CONFLICT (team_id, task_id)
                     DO UPDATE SET
                         text = $3,
                         points = $4,
-                        created_at = $5
+                        base_points = $5,
+                        penalty_points = $6,
+                        time_bonus_points = $7,
+                        completed = $8,
+                        pending_review = $9,
+                        attempts = $10,
+                        created_at = $11
                     "#,
                     &[
                         &team.id().as_str(),
                         &answer.task_id(),
                         &answer.text().as_str(),
                         &answer.points().as_i32(),
+                        &answer.score().base().as_i32(),
+                        &answer.score().penalty().as_i32(),
+                        &answer.score().time_bonus().as_i32(),
+                        &answer.is_ok(),
+                        &answer.is_pending_review(),
+                        &answer.attempts(),
                         &answer.created_at(),
                     ],
                 )
@@ -933,100 +1325,498 @@ impl MediaRepository for PostgresRepository {
             Ok(())
         })
     }
-}
 
-#[async_trait::async_trait]
-impl IsAdminProvider for PostgresRepository {
-    async fn is_admin(&self, user_id: UserID) -> Result<bool, AppError> {
+    async fn update_media_file_id(&self, id: &MediaID, file_id: &FileID) -> Result<(), AppError> {
         with_client!(self.pool, async |client: &Client| {
-            let row = client
-                .query_opt(
+            let updated = client
+                .execute(
+                    "UPDATE media SET file_id = $2 WHERE id = $1",
+                    &[&id.as_str(), &file_id.as_str()],
+                )
+                .await
+                .map_err(|err| AppError::Internal(err.into()))?;
+            if updated == 0 {
+                Err(AppError::MediaNotFound(id.clone()))
+            } else {
+                Ok(())
+            }
+        })
+    }
+
+    async fn save_media_content(
+        &self,
+        id: &MediaID,
+        content: &MediaContent,
+    ) -> Result<(), AppError> {
+        with_client!(self.pool, async |client: &Client| {
+            client
+                .execute(
                     r#"
-                    SELECT 1
-                    FROM admins
-                    WHERE
-                        user_id = $1
-                    LIMIT 1
+                    INSERT INTO
+                        media_content (
+                            media_id,
+                            file_name,
+                            data
+                        )
+                    VALUES
+                        ($1, $2, $3)
+                    ON CONFLICT (media_id) DO UPDATE SET
+                        file_name = $2,
+                        data = $3
                     "#,
-                    &[&user_id.as_i64()],
+                    &[&id.as_str(), &content.file_name(), &content.data()],
                 )
                 .await
                 .map_err(|err| AppError::Internal(err.into()))?;
-            Ok(row.is_some())
+            Ok(())
         })
     }
-}
 
-#[async_trait::async_trait]
-impl CharactersProvider for PostgresRepository {
-    async fn characters(&self) -> Result<Vec<Character>, AppError> {
-        with_transaction!(self.pool, async |tx: &Transaction| {
-            let rows = tx
+    async fn media_content(&self, id: &MediaID) -> Result<Option<MediaContent>, AppError> {
+        with_client!(self.pool, async |client: &Client| {
+            let row_opt = client
+                .query_opt(
+                    "SELECT file_name, data FROM media_content WHERE media_id = $1",
+                    &[&id.as_str()],
+                )
+                .await
+                .map_err(|err| AppError::Internal(err.into()))?;
+            let Some(row) = row_opt else {
+                return Ok(None);
+            };
+            let file_name: String = row
+                .try_get("file_name")
+                .map_err(|err| AppError::Internal(err.into()))?;
+            let data: Vec<u8> = row
+                .try_get("data")
+                .map_err(|err| AppError::Internal(err.into()))?;
+            Ok(Some(MediaContent::new(file_name, data)?))
+        })
+    }
+
+    async fn find_media(
+        &self,
+        query: &str,
+        offset: usize,
+        limit: usize,
+    ) -> Result<(Vec<Media>, usize), AppError> {
+        with_client!(self.pool, async |client: &Client| {
+            let pattern = format!("%{}%", query.replace('%', "\\%").replace('_', "\\_"));
+            let rows = client
                 .query(
                     r#"
                     SELECT
                         id,
-                        index,
-                        name,
-                        quote,
-                        legacy,
-                        media_id
-                    FROM characters
-                    ORDER BY index ASC
+                        file_id,
+                        media_type
+                    FROM media
+                    WHERE id ILIKE $1
+                    ORDER BY id ASC
+                    OFFSET $2
+                    LIMIT $3
                     "#,
-                    &[],
+                    &[&pattern, &(offset as i64), &(limit as i64)],
                 )
                 .await
                 .map_err(|err| AppError::Internal(err.into()))?;
 
-            let mut characters = Vec::new();
+            let mut media = Vec::new();
             for row in rows {
-                let char_row = CharacterRow::fetch_from_row(&row)
-                    .map_err(|err| AppError::Internal(err.into()))?;
-                let char_id = CharacterID::try_from(char_row.id.clone())?;
+                let media_row =
+                    MediaRow::fetch_from_row(&row).map_err(|err| AppError::Internal(err.into()))?;
+                media.push(Media::new(
+                    MediaID::new(media_row.id)?,
+                    FileID::new(media_row.file_id)?,
+                    media_row.media_type.into(),
+                ));
+            }
 
-                let fact_rows = tx
-                    .query(
-                        r#"
-                        SELECT
-                            character_id,
-                            fact
-                        FROM character_facts
-                        WHERE 
-                            character_id = $1
-                        "#,
-                        &[&char_id.as_str()],
-                    )
-                    .await
+            let total: i64 = client
+                .query_one("SELECT COUNT(*) FROM media WHERE id ILIKE $1", &[&pattern])
+                .await
+                .and_then(|row| row.try_get(0))
+                .map_err(|err| AppError::Internal(err.into()))?;
+
+            Ok((media, total as usize))
+        })
+    }
+
+    async fn rename_media(&self, id: &MediaID, new_id: &MediaID) -> Result<(), AppError> {
+        with_transaction!(self.pool, async |tx: &Transaction| {
+            let inserted = tx
+                .execute(
+                    r#"
+                    INSERT INTO media (id, file_id, media_type)
+                    SELECT $2, file_id, media_type
+                    FROM media
+                    WHERE id = $1
+                    "#,
+                    &[&id.as_str(), &new_id.as_str()],
+                )
+                .await
+                .map_err(|err| AppError::Internal(err.into()))?;
+            if inserted == 0 {
+                return Err(AppError::MediaNotFound(id.clone()));
+            }
+
+            for query in [
+                "UPDATE tracks SET media_id = $2 WHERE media_id = $1",
+                "UPDATE task_media SET media_id = $2 WHERE media_id = $1",
+                "UPDATE characters SET media_id = $2 WHERE media_id = $1",
+                "UPDATE characters SET silhouette_media_id = $2 WHERE silhouette_media_id = $1",
+                "UPDATE media_content SET media_id = $2 WHERE media_id = $1",
+                "DELETE FROM media WHERE id = $1",
+            ] {
+                tx.execute(query, &[&id.as_str(), &new_id.as_str()])
+                    .await
                     .map_err(|err| AppError::Internal(err.into()))?;
+            }
+            Ok(())
+        })
+    }
 
-                let mut facts = Vec::new();
-                for fact_row in fact_rows {
-                    let fact_row = CharacterFactRow::fetch_from_row(&fact_row)
+    async fn delete_media(&self, id: &MediaID) -> Result<(), AppError> {
+        with_client!(self.pool, async |client: &Client| {
+            let deleted = client
+                .execute("DELETE FROM media WHERE id = $1", &[&id.as_str()])
+                .await
+                .map_err(|err| AppError::Internal(err.into()))?;
+            if deleted == 0 {
+                Err(AppError::MediaNotFound(id.clone()))
+            } else {
+                Ok(())
+            }
+        })
+    }
+
+    async fn media_usages(&self, id: &MediaID) -> Result<Vec<MediaUsage>, AppError> {
+        with_client!(self.pool, async |client: &Client| {
+            let rows = client
+                .query(
+                    r#"
+                    SELECT 'track' AS kind, tag AS target FROM tracks WHERE media_id = $1
+                    UNION ALL
+                    SELECT 'task', task_id::TEXT FROM task_media WHERE media_id = $1
+                    UNION ALL
+                    SELECT 'character', id FROM characters WHERE media_id = $1
+                    UNION ALL
+                    SELECT 'character', id FROM characters WHERE silhouette_media_id = $1
+                    ORDER BY kind, target
+                    "#,
+                    &[&id.as_str()],
+                )
+                .await
+                .map_err(|err| AppError::Internal(err.into()))?;
+
+            rows.iter().map(media_usage_from_row).collect()
+        })
+    }
+
+    async fn dangling_media_references(&self) -> Result<Vec<DanglingMediaReference>, AppError> {
+        with_client!(self.pool, async |client: &Client| {
+            let rows = client
+                .query(
+                    r#"
+                    SELECT 'track' AS kind, t.tag AS target, t.media_id
+                    FROM tracks t
+                    LEFT JOIN media m ON m.id = t.media_id
+                    WHERE m.id IS NULL
+                    UNION ALL
+                    SELECT 'task', tm.task_id::TEXT, tm.media_id
+                    FROM task_media tm
+                    LEFT JOIN media m ON m.id = tm.media_id
+                    WHERE m.id IS NULL
+                    UNION ALL
+                    SELECT 'character', c.id, c.media_id
+                    FROM characters c
+                    LEFT JOIN media m ON m.id = c.media_id
+                    WHERE m.id IS NULL
+                    UNION ALL
+                    SELECT 'character', c.id, c.silhouette_media_id
+                    FROM characters c
+                    LEFT JOIN media m ON m.id = c.silhouette_media_id
+                    WHERE c.silhouette_media_id IS NOT NULL AND m.id IS NULL
+                    ORDER BY kind, target
+                    "#,
+                    &[],
+                )
+                .await
+                .map_err(|err| AppError::Internal(err.into()))?;
+
+            let mut references = Vec::new();
+            for row in rows {
+                let media_id: String = row
+                    .try_get("media_id")
+                    .map_err(|err| AppError::Internal(err.into()))?;
+                references.push(DanglingMediaReference {
+                    media_id: MediaID::new(media_id)?,
+                    usage: media_usage_from_row(&row)?,
+                });
+            }
+            Ok(references)
+        })
+    }
+}
+
+fn media_usage_from_row(row: &Row) -> Result<MediaUsage, AppError> {
+    let kind: String = row
+        .try_get("kind")
+        .map_err(|err| AppError::Internal(err.into()))?;
+    let target: String = row
+        .try_get("target")
+        .map_err(|err| AppError::Internal(err.into()))?;
+    match kind.as_str() {
+        "track" => Ok(MediaUsage::Track(TrackTag::new(target)?)),
+        "task" => {
+            let task_id = target
+                .parse::<TaskID>()
+                .map_err(|err| AppError::Internal(err.into()))?;
+            Ok(MediaUsage::Task(task_id))
+        }
+        "character" => Ok(MediaUsage::Character(CharacterID::try_from(target)?)),
+        _ => Err(AppError::Internal(
+            format!("unknown media usage kind: {kind}").into(),
+        )),
+    }
+}
+
+#[async_trait::async_trait]
+impl AuditLogRepository for PostgresRepository {
+    async fn append_audit_entry(&self, entry: DomainAuditEntry) -> Result<(), AppError> {
+        with_client!(self.pool, async |client: &Client| {
+            client
+                .execute(
+                    r#"
+                    INSERT INTO audit_log (actor_id, action, target, before, after, created_at)
+                    VALUES ($1, $2, $3, $4, $5, $6)
+                    "#,
+                    &[
+                        &entry.actor_id().as_i64(),
+                        &AuditAction::from(entry.action()),
+                        &entry.target(),
+                        &entry.before(),
+                        &entry.after(),
+                        entry.created_at(),
+                    ],
+                )
+                .await
+                .map_err(|err| AppError::Internal(err.into()))?;
+            Ok(())
+        })
+    }
+
+    async fn audit_entries(
+        &self,
+        filter: &AuditFilter,
+        offset: usize,
+        limit: usize,
+    ) -> Result<(Vec<DomainAuditEntry>, usize), AppError> {
+        let actor_id = filter.actor_id.map(|id| id.as_i64());
+        let action = filter.action.map(AuditAction::from);
+        let pattern = filter
+            .target
+            .as_ref()
+            .map(|target| format!("%{}%", target.replace('%', "\\%").replace('_', "\\_")));
+        with_client!(self.pool, async |client: &Client| {
+            const FILTER: &str = r#"
+                ($1::BIGINT IS NULL OR actor_id = $1)
+                AND ($2::AUDIT_ACTION IS NULL OR action = $2)
+                AND ($3::VARCHAR IS NULL OR target ILIKE $3)
+            "#;
+            let rows = client
+                .query(
+                    &format!(
+                        r#"
+                        SELECT
+                            id,
+                            actor_id,
+                            action,
+                            target,
+                            before,
+                            after,
+                            created_at
+                        FROM audit_log
+                        WHERE {FILTER}
+                        ORDER BY created_at DESC, id DESC
+                        OFFSET $4
+                        LIMIT $5
+                        "#
+                    ),
+                    &[
+                        &actor_id,
+                        &action,
+                        &pattern,
+                        &(offset as i64),
+                        &(limit as i64),
+                    ],
+                )
+                .await
+                .map_err(|err| AppError::Internal(err.into()))?;
+            let total: i64 = client
+                .query_one(
+                    &format!("SELECT COUNT(*) FROM audit_log WHERE {FILTER}"),
+                    &[&actor_id, &action, &pattern],
+                )
+                .await
+                .map_err(|err| AppError::Internal(err.into()))?
+                .get(0);
+
+            let entries = rows
+                .iter()
+                .map(|row| {
+                    let row = AuditEntryRow::fetch_from_row(row)
                         .map_err(|err| AppError::Internal(err.into()))?;
-                    let fact = CharacterFact::new(fact_row.fact)?;
-                    facts.push(fact);
-                }
+                    Ok(DomainAuditEntry::restore(
+                        row.id,
+                        UserID::new(row.actor_id),
+                        row.action.into(),
+                        row.target,
+                        row.before,
+                        row.after,
+                        row.created_at,
+                    ))
+                })
+                .collect::<Result<Vec<_>, AppError>>()?;
+            Ok((entries, total as usize))
+        })
+    }
+}
 
-                let char = Character::restore(
-                    char_id,
-                    char_row.index as SerialNumber,
-                    CharacterName::new(char_row.name)?,
-                    CharacterQuote::new(char_row.quote)?,
-                    facts,
-                    CharacterLegacy::new(char_row.legacy)?,
-                    MediaID::new(char_row.media_id)?,
+#[async_trait::async_trait]
+impl RolesProvider for PostgresRepository {
+    async fn roles(&self, user_id: UserID) -> Result<UserRoles, AppError> {
+        with_client!(self.pool, async |client: &Client| {
+            let rows = client
+                .query(
+                    "SELECT role FROM user_roles WHERE user_id = $1",
+                    &[&user_id.as_i64()],
+                )
+                .await
+                .map_err(|err| AppError::Internal(err.into()))?;
+            let roles = rows
+                .iter()
+                .map(|row| row.try_get::<_, UserRole>("role").map(DomainRole::from))
+                .collect::<Result<Vec<_>, _>>()
+                .map_err(|err| AppError::Internal(err.into()))?;
+            Ok(UserRoles::new(roles))
+        })
+    }
+
+    async fn staff(&self) -> Result<Vec<(UserID, UserRoles)>, AppError> {
+        with_client!(self.pool, async |client: &Client| {
+            let rows = client
+                .query(
+                    "SELECT user_id, role FROM user_roles ORDER BY user_id, role",
+                    &[],
+                )
+                .await
+                .map_err(|err| AppError::Internal(err.into()))?;
+            let mut staff: Vec<(UserID, Vec<DomainRole>)> = vec![];
+            for row in rows {
+                let user_id = UserID::new(
+                    row.try_get("user_id")
+                        .map_err(|err| AppError::Internal(err.into()))?,
                 );
-                characters.push(char);
+                let role: UserRole = row
+                    .try_get("role")
+                    .map_err(|err| AppError::Internal(err.into()))?;
+                match staff.last_mut() {
+                    Some((id, roles)) if *id == user_id => roles.push(role.into()),
+                    _ => staff.push((user_id, vec![role.into()])),
+                }
             }
-            Ok::<_, AppError>(characters)
+            Ok(staff
+                .into_iter()
+                .map(|(id, roles)| (id, UserRoles::new(roles)))
+                .collect())
         })
     }
+}
 
-    async fn character_by_name(&self, name: &CharacterName) -> Result<Option<Character>, AppError> {
+#[async_trait::async_trait]
+impl RolesRepository for PostgresRepository {
+    async fn grant_role(
+        &self,
+        user_id: UserID,
+        role: DomainRole,
+        actor_id: UserID,
+    ) -> Result<bool, AppError> {
+        with_client!(self.pool, async |client: &Client| {
+            let granted = client
+                .execute(
+                    r#"
+                    INSERT INTO user_roles (user_id, role, granted_by)
+                    VALUES ($1, $2, $3)
+                    ON CONFLICT DO NOTHING
+                    "#,
+                    &[&user_id.as_i64(), &UserRole::from(role), &actor_id.as_i64()],
+                )
+                .await
+                .map_err(|err| AppError::Internal(err.into()))?;
+            Ok(granted > 0)
+        })
+    }
+
+    async fn revoke_role(&self, user_id: UserID, role: DomainRole) -> Result<bool, AppError> {
+        with_client!(self.pool, async |client: &Client| {
+            let revoked = client
+                .execute(
+                    "DELETE FROM user_roles WHERE user_id = $1 AND role = $2",
+                    &[&user_id.as_i64(), &UserRole::from(role)],
+                )
+                .await
+                .map_err(|err| AppError::Internal(err.into()))?;
+            Ok(revoked > 0)
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl EventPhaseProvider for PostgresRepository {
+    async fn event_phase(&self) -> Result<Option<DomainEventPhase>, AppError> {
+        with_client!(self.pool, async |client: &Client| {
+            let row = client
+                .query_opt("SELECT phase FROM event_phase", &[])
+                .await
+                .map_err(|err| AppError::Internal(err.into()))?;
+            Ok(row.map(|row| row.get::<_, EventPhase>("phase").into()))
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl EventPhaseRepository for PostgresRepository {
+    async fn set_event_phase(
+        &self,
+        phase: DomainEventPhase,
+        actor_id: UserID,
+    ) -> Result<(), AppError> {
+        with_client!(self.pool, async |client: &Client| {
+            client
+                .execute(
+                    r#"
+                    INSERT INTO event_phase (phase, changed_by)
+                    VALUES ($1, $2)
+                    ON CONFLICT (id) DO UPDATE SET
+                        phase = EXCLUDED.phase,
+                        changed_by = EXCLUDED.changed_by,
+                        changed_at = NOW()
+                    "#,
+                    &[&EventPhase::from(phase), &actor_id.as_i64()],
+                )
+                .await
+                .map_err(|err| AppError::Internal(err.into()))?;
+            Ok(())
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl CharactersProvider for PostgresRepository {
+    async fn characters(&self) -> Result<Vec<Character>, AppError> {
         with_transaction!(self.pool, async |tx: &Transaction| {
-            let row_opt = tx
-                .query_opt(
+            let rows = tx
+                .query(
                     r#"
                     SELECT
                         id,
@@ -1034,19 +1824,23 @@ impl CharactersProvider for PostgresRepository {
                         name,
                         quote,
                         legacy,
-                        media_id
+                        media_id,
+                        unlock_task_id,
+                        unlock_track_tag,
+                        silhouette_media_id
                     FROM characters
-                    WHERE 
-                        name = $1
+                    ORDER BY index ASC
                     "#,
-                    &[&name.as_str()],
+                    &[],
                 )
                 .await
                 .map_err(|err| AppError::Internal(err.into()))?;
 
-            if let Some(row) = row_opt {
+            let mut characters = Vec::new();
+            for row in rows {
                 let char_row = CharacterRow::fetch_from_row(&row)
                     .map_err(|err| AppError::Internal(err.into()))?;
+                let char_id = CharacterID::try_from(char_row.id.clone())?;
 
                 let fact_rows = tx
                     .query(
@@ -1058,7 +1852,7 @@ impl CharactersProvider for PostgresRepository {
                         WHERE 
                             character_id = $1
                         "#,
-                        &[&char_row.id],
+                        &[&char_id.as_str()],
                     )
                     .await
                     .map_err(|err| AppError::Internal(err.into()))?;
@@ -1071,297 +1865,612 @@ impl CharactersProvider for PostgresRepository {
                     facts.push(fact);
                 }
 
+                let unlock = match (char_row.unlock_task_id, char_row.unlock_track_tag) {
+                    (Some(task_id), _) => Some(CharacterUnlock::Task(task_id)),
+                    (None, Some(tag)) => Some(CharacterUnlock::Track(TrackTag::new(tag)?)),
+                    (None, None) => None,
+                };
                 let char = Character::restore(
-                    CharacterID::try_from(char_row.id)?,
+                    char_id,
                     char_row.index as SerialNumber,
                     CharacterName::new(char_row.name)?,
                     CharacterQuote::new(char_row.quote)?,
                     facts,
                     CharacterLegacy::new(char_row.legacy)?,
                     MediaID::new(char_row.media_id)?,
+                )
+                .with_unlock(
+                    unlock,
+                    char_row.silhouette_media_id.map(MediaID::new).transpose()?,
                 );
-                Ok::<_, AppError>(Some(char))
-            } else {
-                Ok(None)
+                characters.push(char);
             }
+            Ok::<_, AppError>(characters)
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl FavoriteCharactersRepository for PostgresRepository {
+    async fn favorite_characters(&self, user_id: UserID) -> Result<Vec<CharacterID>, AppError> {
+        with_client!(self.pool, async |client: &Client| {
+            let rows = client
+                .query(
+                    "SELECT character_id FROM character_favorites WHERE user_id = $1",
+                    &[&user_id.as_i64()],
+                )
+                .await
+                .map_err(|err| AppError::Internal(err.into()))?;
+            let mut ids = Vec::with_capacity(rows.len());
+            for row in rows {
+                let id: String = row
+                    .try_get("character_id")
+                    .map_err(|err| AppError::Internal(err.into()))?;
+                ids.push(CharacterID::try_from(id)?);
+            }
+            Ok(ids)
+        })
+    }
+
+    async fn set_favorite_character(
+        &self,
+        user_id: UserID,
+        character_id: &CharacterID,
+        favorite: bool,
+    ) -> Result<(), AppError> {
+        let query = if favorite {
+            r#"
+            INSERT INTO character_favorites (user_id, character_id)
+            VALUES ($1, $2)
+            ON CONFLICT DO NOTHING
+            "#
+        } else {
+            "DELETE FROM character_favorites WHERE user_id = $1 AND character_id = $2"
+        };
+        with_client!(self.pool, async |client: &Client| {
+            client
+                .execute(query, &[&user_id.as_i64(), &character_id.as_str()])
+                .await
+                .map_err(|err| AppError::Internal(err.into()))?;
+            Ok(())
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl FeedbackRepository for PostgresRepository {
+    async fn save_feedback(&self, feedback: Feedback) -> Result<(), AppError> {
+        with_client!(self.pool, async |client: &Client| {
+            client
+                .execute(
+                    r#"
+                    INSERT INTO feedbacks (
+                        author_id,
+                        text,
+                        status,
+                        created_at
+                    )
+                    VALUES
+                        ($1, $2, $3, $4)
+                    "#,
+                    &[
+                        &feedback.author_id().as_i64(),
+                        &feedback.text().as_str(),
+                        &FeedbackStatus::from(feedback.status()),
+                        feedback.created_at(),
+                    ],
+                )
+                .await
+                .map_err(|err| AppError::Internal(err.into()))?;
+            Ok(())
+        })
+    }
+
+    async fn feedback(&self, id: FeedbackID) -> Result<Feedback, AppError> {
+        with_client!(self.pool, async |client: &Client| {
+            let row = client
+                .query_opt(
+                    r#"
+                    SELECT
+                        id,
+                        author_id,
+                        text,
+                        status,
+                        created_at
+                    FROM feedbacks
+                    WHERE id = $1
+                    "#,
+                    &[&id],
+                )
+                .await
+                .map_err(|err| AppError::Internal(err.into()))?
+                .ok_or(AppError::FeedbackNotFound(id))?;
+            let feedback_row =
+                FeedbackRow::fetch_from_row(&row).map_err(|err| AppError::Internal(err.into()))?;
+            let mut feedbacks = restore_feedbacks(client, vec![feedback_row]).await?;
+            Ok(feedbacks.remove(0))
+        })
+    }
+
+    async fn feedbacks(
+        &self,
+        status: Option<DomainFeedbackStatus>,
+        offset: usize,
+        limit: usize,
+    ) -> Result<(Vec<Feedback>, usize), AppError> {
+        let status = status.map(FeedbackStatus::from);
+        with_client!(self.pool, async |client: &Client| {
+            let rows = client
+                .query(
+                    r#"
+                    SELECT
+                        id,
+                        author_id,
+                        text,
+                        status,
+                        created_at
+                    FROM feedbacks
+                    WHERE $1::FEEDBACK_STATUS IS NULL OR status = $1
+                    ORDER BY created_at DESC, id DESC
+                    OFFSET $2
+                    LIMIT $3
+                    "#,
+                    &[&status, &(offset as i64), &(limit as i64)],
+                )
+                .await
+                .map_err(|err| AppError::Internal(err.into()))?;
+            let total: i64 = client
+                .query_one(
+                    "SELECT COUNT(*) FROM feedbacks WHERE $1::FEEDBACK_STATUS IS NULL OR status = $1",
+                    &[&status],
+                )
+                .await
+                .map_err(|err| AppError::Internal(err.into()))?
+                .get(0);
+
+            let feedback_rows = rows
+                .iter()
+                .map(FeedbackRow::fetch_from_row)
+                .collect::<Result<Vec<_>, _>>()
+                .map_err(|err| AppError::Internal(err.into()))?;
+            let feedbacks = restore_feedbacks(client, feedback_rows).await?;
+            Ok((feedbacks, total as usize))
+        })
+    }
+
+    async fn save_feedback_reply(
+        &self,
+        id: FeedbackID,
+        reply: &FeedbackReply,
+    ) -> Result<(), AppError> {
+        with_transaction!(self.pool, async |tx: &Transaction| {
+            tx.execute(
+                r#"
+                INSERT INTO feedback_replies (
+                    feedback_id,
+                    author_id,
+                    text,
+                    created_at
+                )
+                VALUES
+                    ($1, $2, $3, $4)
+                "#,
+                &[
+                    &id,
+                    &reply.author_id().as_i64(),
+                    &reply.text().as_str(),
+                    reply.created_at(),
+                ],
+            )
+            .await
+            .map_err(|err| AppError::Internal(err.into()))?;
+            tx.execute(
+                "UPDATE feedbacks SET status = $2 WHERE id = $1",
+                &[&id, &FeedbackStatus::Answered],
+            )
+            .await
+            .map_err(|err| AppError::Internal(err.into()))?;
+            Ok::<(), AppError>(())
         })
     }
-}
 
-#[async_trait::async_trait]
-impl FeedbackRepository for PostgresRepository {
-    async fn save_feedback(&self, feedback: Feedback) -> Result<(), AppError> {
+    async fn update_feedback_status(
+        &self,
+        id: FeedbackID,
+        status: DomainFeedbackStatus,
+    ) -> Result<(), AppError> {
         with_client!(self.pool, async |client: &Client| {
-            client
+            let updated = client
                 .execute(
-                    r#"
-                    INSERT INTO feedbacks (
-                        author_id,
-                        text
-                    )
-                    VALUES
-                        ($1, $2)
-                    "#,
-                    &[&feedback.author_id().as_i64(), &feedback.text().as_str()],
+                    "UPDATE feedbacks SET status = $2 WHERE id = $1",
+                    &[&id, &FeedbackStatus::from(status)],
                 )
                 .await
                 .map_err(|err| AppError::Internal(err.into()))?;
-            Ok(())
+            if updated == 0 {
+                Err(AppError::FeedbackNotFound(id))
+            } else {
+                Ok(())
+            }
+        })
+    }
+}
+
+/// Собирает обратную связь вместе с перепиской, сохраняя порядок строк.
+async fn restore_feedbacks(
+    client: &Client,
+    feedback_rows: Vec<FeedbackRow>,
+) -> Result<Vec<Feedback>, AppError> {
+    let ids: Vec<i32> = feedback_rows.iter().map(|f| f.id).collect();
+    let rows = client
+        .query(
+            r#"
+            SELECT
+                feedback_id,
+                author_id,
+                text,
+                created_at
+            FROM feedback_replies
+            WHERE feedback_id = ANY($1)
+            ORDER BY created_at ASC, id ASC
+            "#,
+            &[&ids],
+        )
+        .await
+        .map_err(|err| AppError::Internal(err.into()))?;
+
+    let mut replies: HashMap<FeedbackID, Vec<FeedbackReply>> = HashMap::new();
+    for row in &rows {
+        let reply_row =
+            FeedbackReplyRow::fetch_from_row(row).map_err(|err| AppError::Internal(err.into()))?;
+        replies
+            .entry(reply_row.feedback_id)
+            .or_default()
+            .push(FeedbackReply::restore(
+                UserID::new(reply_row.author_id),
+                FeedbackText::new(reply_row.text)?,
+                reply_row.created_at,
+            ));
+    }
+
+    feedback_rows
+        .into_iter()
+        .map(|f| {
+            Ok(Feedback::restore(
+                f.id,
+                UserID::new(f.author_id),
+                FeedbackText::new(f.text)?,
+                f.status.into(),
+                f.created_at,
+                replies.remove(&f.id).unwrap_or_default(),
+            ))
         })
+        .collect()
+}
+
+const TASK_COLUMNS: &str = r#"
+    id,
+    title,
+    task_type,
+    question,
+    explanation,
+    points,
+    price,
+    max_lvnsht_d,
+    numeric_answer,
+    numeric_tolerance,
+    latitude,
+    longitude,
+    radius,
+    attempt_penalty_percent,
+    min_points_percent,
+    time_bonus,
+    time_bonus_minutes,
+    partial_credit
+"#;
+
+async fn fetch_task(tx: &Transaction<'_>, row: &Row) -> Result<Task, AppError> {
+    let task_row = TaskRow::fetch_from_row(row).map_err(|err| AppError::Internal(err.into()))?;
+
+    let option_rows = tx
+        .query(
+            r#"
+            SELECT option
+            FROM task_options
+            WHERE task_id = $1
+            ORDER BY index ASC, option ASC
+            "#,
+            &[&task_row.id],
+        )
+        .await
+        .map_err(|err| AppError::Internal(err.into()))?;
+
+    let mut options = Vec::new();
+    for option_row in option_rows {
+        let option = TaskOption::new(
+            option_row
+                .try_get("option")
+                .map_err(|err| AppError::Internal(err.into()))?,
+        )?;
+        options.push(option);
+    }
+
+    let dependencies_rows = tx
+        .query(
+            r#"
+            SELECT dependency
+            FROM   task_dependencies
+            WHERE  task_id = $1
+            "#,
+            &[&task_row.id],
+        )
+        .await
+        .map_err(|err| AppError::Internal(err.into()))?;
+
+    let mut dependencies = Vec::new();
+    for dependencies_row in dependencies_rows {
+        let dependency: i32 = dependencies_row
+            .try_get("dependency")
+            .map_err(|err| AppError::Internal(err.into()))?;
+        dependencies.push(dependency as TaskID);
+    }
+
+    // Для заданий на порядок важна последовательность верных ответов.
+    let correct_answer_rows = tx
+        .query(
+            r#"
+            SELECT answer
+            FROM   task_correct_answers
+            WHERE  task_id = $1
+            ORDER BY index ASC
+            "#,
+            &[&task_row.id],
+        )
+        .await
+        .map_err(|err| AppError::Internal(err.into()))?;
+
+    let mut correct_answers = Vec::new();
+    for row in correct_answer_rows {
+        let text = row
+            .try_get("answer")
+            .map_err(|err| AppError::Internal(err.into()))?;
+        let answer = CorrectAnswer::new(text)?;
+        correct_answers.push(answer);
+    }
+
+    let media_rows = tx
+        .query(
+            r#"
+            SELECT media_id
+            FROM   task_media
+            WHERE  task_id = $1
+            ORDER BY index ASC
+            "#,
+            &[&task_row.id],
+        )
+        .await
+        .map_err(|err| AppError::Internal(err.into()))?;
+
+    let mut media_ids = Vec::new();
+    for row in media_rows {
+        let media_id: String = row
+            .try_get("media_id")
+            .map_err(|err| AppError::Internal(err.into()))?;
+        media_ids.push(MediaID::new(media_id)?);
     }
+
+    let hint_rows = tx
+        .query(
+            r#"
+            SELECT keyword, hint
+            FROM   task_hints
+            WHERE  task_id = $1
+            ORDER BY keyword ASC
+            "#,
+            &[&task_row.id],
+        )
+        .await
+        .map_err(|err| AppError::Internal(err.into()))?;
+
+    let mut hints = Vec::new();
+    for row in hint_rows {
+        let keyword: String = row
+            .try_get("keyword")
+            .map_err(|err| AppError::Internal(err.into()))?;
+        let hint: String = row
+            .try_get("hint")
+            .map_err(|err| AppError::Internal(err.into()))?;
+        hints.push(TaskHint::new(keyword, TaskText::new(hint)?)?);
+    }
+
+    let numeric_answer = task_row
+        .numeric_answer
+        .map(|value| NumericAnswer::new(value, task_row.numeric_tolerance))
+        .transpose()?;
+
+    let area = match (task_row.latitude, task_row.longitude, task_row.radius) {
+        (Some(latitude), Some(longitude), Some(radius)) => {
+            Some(GeoArea::new(GeoPoint::new(latitude, longitude)?, radius)?)
+        }
+        _ => None,
+    };
+
+    let scoring = ScoringRules::new(
+        task_row.attempt_penalty_percent,
+        task_row.min_points_percent,
+        Points::new(task_row.time_bonus)?,
+        Duration::minutes(task_row.time_bonus_minutes as i64),
+        task_row.partial_credit,
+    )?;
+
+    Ok(Task::new(
+        task_row.id as TaskID,
+        task_row.title.map(TaskTitle::new).transpose()?,
+        task_row.task_type.into(),
+        TaskText::new(task_row.question)?,
+        TaskText::new(task_row.explanation)?,
+        media_ids,
+        options,
+        dependencies,
+        correct_answers,
+        numeric_answer,
+        area,
+        scoring,
+        hints,
+        Points::new(task_row.points)?,
+        Points::new(task_row.price)?,
+        task_row.max_lvnsht_d as usize,
+    ))
+}
+
+async fn fetch_track_tasks(tx: &Transaction<'_>, tag: &str) -> Result<Vec<Task>, AppError> {
+    let rows = tx
+        .query(
+            &format!("SELECT {TASK_COLUMNS} FROM tasks WHERE track_tag = $1"),
+            &[&tag],
+        )
+        .await
+        .map_err(|err| AppError::Internal(err.into()))?;
+
+    let mut tasks = Vec::new();
+    for row in rows {
+        tasks.push(fetch_task(tx, &row).await?);
+    }
+    Ok(tasks)
+}
+
+async fn fetch_track(tx: &Transaction<'_>, row: &Row) -> Result<Track, AppError> {
+    let track_row = TrackRow::fetch_from_row(row).map_err(|err| AppError::Internal(err.into()))?;
+    let tasks = fetch_track_tasks(tx, &track_row.tag).await?;
+    Ok(Track::new(
+        TrackTag::new(track_row.tag)?,
+        TrackName::new(track_row.name)?,
+        track_row.solo,
+        TrackDescription::new(track_row.description)?,
+        MediaID::new(track_row.media_id)?,
+        tasks,
+    ))
 }
 
 #[async_trait::async_trait]
 impl TrackProvider for PostgresRepository {
-    async fn track(&self, domain_tag: DomainTrackTag) -> Result<Track, AppError> {
+    async fn track(&self, tag: &TrackTag) -> Result<Track, AppError> {
         with_transaction!(self.pool, async |tx: &Transaction| {
-            let tag = TrackTag::from(domain_tag);
             let row_opt = tx
                 .query_opt(
                     r#"
                     SELECT
                         tag,
+                        name,
+                        solo,
                         description,
                         media_id
                     FROM tracks
                     WHERE
                         tag = $1
                     "#,
-                    &[&tag],
+                    &[&tag.as_str()],
                 )
                 .await
                 .map_err(|err| AppError::Internal(err.into()))?;
 
             if let Some(row) = row_opt {
-                let track_row =
-                    TrackRow::fetch_from_row(&row).map_err(|err| AppError::Internal(err.into()))?;
-
-                let rows = tx
-                    .query(
-                        r#"
-                        SELECT
-                            id,
-                            task_type,
-                            question,
-                            explanation,
-                            media_id,
-                            points,
-                            price,
-                            max_lvnsht_d
-                        FROM tasks
-                        WHERE
-                            track_tag = $1
-                        "#,
-                        &[&tag],
-                    )
-                    .await
-                    .map_err(|err| AppError::Internal(err.into()))?;
-
-                let mut tasks = Vec::new();
-                for row in rows {
-                    let task_row = TaskRow::fetch_from_row(&row)
-                        .map_err(|err| AppError::Internal(err.into()))?;
-
-                    let option_rows = tx
-                        .query(
-                            r#"
-                            SELECT option
-                            FROM task_options
-                            WHERE task_id = $1
-                            "#,
-                            &[&task_row.id],
-                        )
-                        .await
-                        .map_err(|err| AppError::Internal(err.into()))?;
-
-                    let mut options = Vec::new();
-                    for option_row in option_rows {
-                        let option = TaskOption::new(
-                            option_row
-                                .try_get("option")
-                                .map_err(|err| AppError::Internal(err.into()))?,
-                        )?;
-                        options.push(option);
-                    }
-
-                    let dependencies_rows = tx
-                        .query(
-                            r#"
-                            SELECT dependency
-                            FROM   task_dependencies
-                            WHERE  task_id = $1
-                            "#,
-                            &[&task_row.id],
-                        )
-                        .await
-                        .map_err(|err| AppError::Internal(err.into()))?;
-
-                    let mut dependencies = Vec::new();
-                    for dependencies_row in dependencies_rows {
-                        let dependency: i32 = dependencies_row
-                            .try_get("dependency")
-                            .map_err(|err| AppError::Internal(err.into()))?;
-                        dependencies.push(dependency as TaskID);
-                    }
-
-                    let correct_answer_rows = tx
-                        .query(
-                            r#"
-                            SELECT answer
-                            FROM   task_correct_answers
-                            WHERE  task_id = $1
-                            "#,
-                            &[&task_row.id],
-                        )
-                        .await
-                        .map_err(|err| AppError::Internal(err.into()))?;
-
-                    let mut correct_answers = Vec::new();
-                    for row in correct_answer_rows {
-                        let text = row
-                            .try_get("answer")
-                            .map_err(|err| AppError::Internal(err.into()))?;
-                        let answer = CorrectAnswer::new(text)?;
-                        correct_answers.push(answer);
-                    }
-
-                    let task = Task::new(
-                        task_row.id as TaskID,
-                        task_row.task_type.into(),
-                        TaskText::new(task_row.question)?,
-                        TaskText::new(task_row.explanation)?,
-                        task_row.media_id.map(|m| MediaID::new(m)).transpose()?,
-                        options,
-                        dependencies,
-                        correct_answers,
-                        Points::new(task_row.points)?,
-                        Points::new(task_row.price)?,
-                        task_row.max_lvnsht_d as usize,
-                    );
-                    tasks.push(task);
-                }
+                fetch_track(tx, &row).await
+            } else {
+                Err(AppError::TrackNotFound(tag.clone()))
+            }
+        })
+    }
 
-                let track = Track::new(
-                    domain_tag,
-                    TrackDescription::new(track_row.description)?,
-                    MediaID::new(track_row.media_id)?,
-                    tasks,
-                );
+    async fn tracks(&self) -> Result<Vec<Track>, AppError> {
+        with_transaction!(self.pool, async |tx: &Transaction| {
+            let rows = tx
+                .query(
+                    r#"
+                    SELECT
+                        tag,
+                        name,
+                        solo,
+                        description,
+                        media_id
+                    FROM tracks
+                    ORDER BY index ASC
+                    "#,
+                    &[],
+                )
+                .await
+                .map_err(|err| AppError::Internal(err.into()))?;
 
-                Ok::<_, AppError>(track)
-            } else {
-                Err(AppError::TrackNotFound(domain_tag))
+            let mut tracks = Vec::new();
+            for row in rows {
+                tracks.push(fetch_track(tx, &row).await?);
             }
+            Ok::<_, AppError>(tracks)
         })
     }
 }
 
 #[async_trait::async_trait]
-impl TaskProvider for PostgresRepository {
-    async fn task(&self, task_id: TaskID) -> Result<Task, AppError> {
-        with_transaction!(self.pool, async |tx: &Transaction| {
-            let row_opt = tx
+impl TaskReferenceProvider for PostgresRepository {
+    async fn track_exists(&self, tag: &TrackTag) -> Result<bool, AppError> {
+        with_client!(self.pool, async |client: &Client| {
+            let row = client
+                .query_one(
+                    "SELECT EXISTS (SELECT 1 FROM tracks WHERE tag = $1)",
+                    &[&tag.as_str()],
+                )
+                .await
+                .map_err(|err| AppError::Internal(err.into()))?;
+            row.try_get(0).map_err(|err| AppError::Internal(err.into()))
+        })
+    }
+
+    async fn task_options_count(
+        &self,
+        tag: &TrackTag,
+        task_id: TaskID,
+    ) -> Result<Option<usize>, AppError> {
+        with_client!(self.pool, async |client: &Client| {
+            let row_opt = client
                 .query_opt(
                     r#"
                     SELECT
-                        id,
-                        task_type,
-                        question,
-                        explanation,
-                        media_id,
-                        points,
-                        price,
-                        max_lvnsht_d
+                        (SELECT count(*) FROM task_options WHERE task_id = tasks.id) AS options
                     FROM tasks
+                    JOIN tracks ON tracks.tag = tasks.track_tag
                     WHERE
-                        id = $1
+                        tasks.id = $1
+                        AND tasks.track_tag = $2
                     "#,
+                    &[&task_id, &tag.as_str()],
+                )
+                .await
+                .map_err(|err| AppError::Internal(err.into()))?;
+            row_opt
+                .map(|row| {
+                    row.try_get::<_, i64>("options")
+                        .map(|count| count as usize)
+                        .map_err(|err| AppError::Internal(err.into()))
+                })
+                .transpose()
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl TaskProvider for PostgresRepository {
+    async fn task(&self, task_id: TaskID) -> Result<Task, AppError> {
+        with_transaction!(self.pool, async |tx: &Transaction| {
+            let row_opt = tx
+                .query_opt(
+                    &format!("SELECT {TASK_COLUMNS} FROM tasks WHERE id = $1"),
                     &[&task_id],
                 )
                 .await
                 .map_err(|err| AppError::Internal(err.into()))?;
 
             if let Some(row) = row_opt {
-                let task_row =
-                    TaskRow::fetch_from_row(&row).map_err(|err| AppError::Internal(err.into()))?;
-
-                let option_rows = tx
-                    .query(
-                        r#"
-                        SELECT option
-                        FROM task_options
-                        WHERE task_id = $1
-                        "#,
-                        &[&task_row.id],
-                    )
-                    .await
-                    .map_err(|err| AppError::Internal(err.into()))?;
-
-                let mut options = Vec::new();
-                for option_row in option_rows {
-                    let option = TaskOption::new(
-                        option_row
-                            .try_get("option")
-                            .map_err(|err| AppError::Internal(err.into()))?,
-                    )?;
-                    options.push(option);
-                }
-
-                let dependencies_rows = tx
-                    .query(
-                        r#"
-                            SELECT dependency
-                            FROM   task_dependencies
-                            WHERE  task_id = $1
-                            "#,
-                        &[&task_row.id],
-                    )
-                    .await
-                    .map_err(|err| AppError::Internal(err.into()))?;
-
-                let mut dependencies = Vec::new();
-                for dependencies_row in dependencies_rows {
-                    let dependency: i32 = dependencies_row
-                        .try_get("dependency")
-                        .map_err(|err| AppError::Internal(err.into()))?;
-                    dependencies.push(dependency as TaskID);
-                }
-
-                let correct_answer_rows = tx
-                    .query(
-                        r#"
-                            SELECT answer
-                            FROM   task_correct_answers
-                            WHERE  task_id = $1
-                            "#,
-                        &[&task_row.id],
-                    )
-                    .await
-                    .map_err(|err| AppError::Internal(err.into()))?;
-
-                let mut correct_answers = Vec::new();
-                for row in correct_answer_rows {
-                    let text = row
-                        .try_get("answer")
-                        .map_err(|err| AppError::Internal(err.into()))?;
-                    let answer = CorrectAnswer::new(text)?;
-                    correct_answers.push(answer);
-                }
-
-                let task = Task::new(
-                    task_row.id as TaskID,
-                    task_row.task_type.into(),
-                    TaskText::new(task_row.question)?,
-                    TaskText::new(task_row.explanation)?,
-                    task_row.media_id.map(|m| MediaID::new(m)).transpose()?,
-                    options,
-                    dependencies,
-                    correct_answers,
-                    Points::new(task_row.points)?,
-                    Points::new(task_row.price)?,
-                    task_row.max_lvnsht_d as usize,
-                );
-                Ok(task)
+                fetch_task(tx, &row).await
             } else {
                 Err(AppError::TaskNotFound(task_id))
             }
@@ -1617,3 +2726,200 @@ impl SlotRepository for PostgresRepository {
         })
     }
 }
+
+#[async_trait::async_trait]
+impl DashboardProvider for PostgresRepository {
+    async fn track_summaries(&self) -> Result<Vec<TrackSummary>, AppError> {
+        with_client!(self.pool, async |client: &Client| {
+            let rows = client
+                .query(
+                    r#"
+                    SELECT
+                        r.tag,
+                        r.name,
+                        COALESCE(SUM(k.points + k.time_bonus), 0)::INTEGER AS max_points
+                    FROM tracks r
+                    LEFT JOIN tasks k
+                        ON k.track_tag = r.tag
+                    GROUP BY
+                        r.tag,
+                        r.name,
+                        r.index
+                    ORDER BY r.index
+                    "#,
+                    &[],
+                )
+                .await
+                .map_err(|err| AppError::Internal(err.into()))?;
+
+            let summary_rows = rows
+                .iter()
+                .map(TrackSummaryRow::fetch_from_row)
+                .collect::<Result<Vec<_>, tokio_postgres::Error>>()
+                .map_err(|err| AppError::Internal(err.into()))?;
+
+            let mut summaries = Vec::with_capacity(summary_rows.len());
+            for row in summary_rows {
+                summaries.push(TrackSummary {
+                    tag: TrackTag::new(row.tag)?,
+                    name: TrackName::new(row.name)?,
+                    max_points: Points::new(row.max_points)?,
+                });
+            }
+            Ok(summaries)
+        })
+    }
+
+    async fn track_team_progress(&self) -> Result<Vec<TrackTeamProgress>, AppError> {
+        with_client!(self.pool, async |client: &Client| {
+            let rows = client
+                .query(
+                    r#"
+                    SELECT
+                        s.track_tag,
+                        s.team_id,
+                        t.name AS team_name,
+                        s.started_at,
+                        s.finished_at,
+                        COALESCE(SUM(a.points), 0)::INTEGER AS points
+                    FROM team_started_tracks s
+                    JOIN teams t
+                        ON t.id = s.team_id
+                    LEFT JOIN tasks k
+                        ON k.track_tag = s.track_tag
+                    LEFT JOIN answers a
+                        ON a.task_id = k.id AND a.team_id = s.team_id
+                    GROUP BY
+                        s.track_tag,
+                        s.team_id,
+                        t.name,
+                        s.started_at,
+                        s.finished_at
+                    "#,
+                    &[],
+                )
+                .await
+                .map_err(|err| AppError::Internal(err.into()))?;
+
+            let progress_rows = rows
+                .iter()
+                .map(TrackTeamProgressRow::fetch_from_row)
+                .collect::<Result<Vec<_>, tokio_postgres::Error>>()
+                .map_err(|err| AppError::Internal(err.into()))?;
+
+            let mut progress = Vec::with_capacity(progress_rows.len());
+            for row in progress_rows {
+                let status = match row.finished_at {
+                    Some(finished_at) => TrackStatus::Finished(row.started_at, finished_at),
+                    None => TrackStatus::Started(row.started_at),
+                };
+                progress.push(TrackTeamProgress {
+                    track_tag: TrackTag::new(row.track_tag)?,
+                    team_id: TeamID::try_from(row.team_id)?,
+                    team_name: TeamName::new(row.team_name)?,
+                    status,
+                    points: Points::new(row.points)?,
+                });
+            }
+            Ok(progress)
+        })
+    }
+
+    async fn pending_photo_reviews(&self) -> Result<Vec<PendingPhotoReview>, AppError> {
+        with_client!(self.pool, async |client: &Client| {
+            let rows = client
+                .query(
+                    r#"
+                    SELECT
+                        a.team_id,
+                        t.name AS team_name,
+                        a.task_id,
+                        k.track_tag,
+                        k.number,
+                        a.created_at
+                    FROM answers a
+                    JOIN teams t
+                        ON t.id = a.team_id
+                    LEFT JOIN (
+                        SELECT
+                            id,
+                            track_tag,
+                            ROW_NUMBER() OVER (PARTITION BY track_tag ORDER BY id)::INTEGER AS number
+                        FROM tasks
+                    ) k
+                        ON k.id = a.task_id
+                    WHERE a.pending_review
+                    ORDER BY a.created_at
+                    "#,
+                    &[],
+                )
+                .await
+                .map_err(|err| AppError::Internal(err.into()))?;
+
+            let review_rows = rows
+                .iter()
+                .map(PendingPhotoReviewRow::fetch_from_row)
+                .collect::<Result<Vec<_>, tokio_postgres::Error>>()
+                .map_err(|err| AppError::Internal(err.into()))?;
+
+            let mut reviews = Vec::with_capacity(review_rows.len());
+            for row in review_rows {
+                reviews.push(PendingPhotoReview {
+                    team_id: TeamID::try_from(row.team_id)?,
+                    team_name: TeamName::new(row.team_name)?,
+                    task_id: row.task_id,
+                    track_tag: row.track_tag.map(TrackTag::new).transpose()?,
+                    number: row.number.map(|number| number as usize),
+                    created_at: row.created_at,
+                });
+            }
+            Ok(reviews)
+        })
+    }
+
+    async fn site_occupancy(&self) -> Result<Vec<SiteOccupancy>, AppError> {
+        with_client!(self.pool, async |client: &Client| {
+            let rows = client
+                .query(
+                    r#"
+                    SELECT
+                        s.site,
+                        COUNT(*)::INTEGER AS slots,
+                        SUM(s.capacity)::INTEGER AS capacity,
+                        COALESCE(SUM(r.places), 0)::INTEGER AS reserved
+                    FROM slots s
+                    LEFT JOIN (
+                        SELECT
+                            slot_id,
+                            SUM(places) AS places
+                        FROM reservations
+                        GROUP BY slot_id
+                    ) r
+                        ON r.slot_id = s.id
+                    GROUP BY s.site
+                    ORDER BY s.site
+                    "#,
+                    &[],
+                )
+                .await
+                .map_err(|err| AppError::Internal(err.into()))?;
+
+            let occupancy_rows = rows
+                .iter()
+                .map(SiteOccupancyRow::fetch_from_row)
+                .collect::<Result<Vec<_>, tokio_postgres::Error>>()
+                .map_err(|err| AppError::Internal(err.into()))?;
+
+            let mut occupancy = Vec::with_capacity(occupancy_rows.len());
+            for row in occupancy_rows {
+                occupancy.push(SiteOccupancy {
+                    site: Site::new(row.site)?,
+                    slots: row.slots as usize,
+                    capacity: row.capacity as usize,
+                    reserved: row.reserved as usize,
+                });
+            }
+            Ok(occupancy)
+        })
+    }
+}
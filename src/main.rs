@@ -1,23 +1,34 @@
-use std::env;
 use std::sync::Arc;
-use teloxide::dispatching::dialogue::{PostgresStorage, serializer};
+use teloxide::dispatching::dialogue::PostgresStorage;
 use teloxide::prelude::*;
 
 use crate::app::usecases::app::App;
 use crate::app::usecases::{
-    AnswerTask, CancelReservation, CheckAdmin, CheckCaptain, CheckRegistered, CheckStartedTrack,
-    GetAvailableSlotStarts, GetAvailableTasks, GetAvailableTracks, GetCharacter, GetCharacterNames,
-    GetCompletedTasks, GetMedia, GetPlayer, GetProfile, GetTask, GetTeamReservedSlot,
-    GetTeamWithMembers, GetTrackInProgress, GetUser, GetUserTeam, GiveFeedback, ReserveSlot,
-    StartTrack, UploadMedia,
+    AnswerTask, ArchiveFeedback, CancelReservation, CheckCaptain, CheckMediaReferences,
+    CheckPermission, CheckRegistered, CheckStartedTrack, CheckTaskReference, DeleteMedia,
+    FindCharacters, FindMedia, GetAuditLog, GetAvailableSlotStarts, GetAvailableTasks,
+    GetAvailableTracks, GetCharacter, GetCharacterPage, GetCompletedTasks, GetDashboard,
+    GetEventPhase, GetFeedback, GetFeedbackInbox, GetMedia, GetMediaContent, GetMediaUsages,
+    GetPlayer, GetProfile, GetSlots, GetStaff, GetTask, GetTeamProgress, GetTeamReservedSlot,
+    GetTeamWithMembers, GetTeams, GetTrackInProgress, GetUser, GetUserTeam, GiveFeedback,
+    GrantRole, RefreshMediaFileID, RenameMedia, RenderTaskGraph, ReplyToFeedback, ReserveSlot,
+    ResetDialogue, ReviewPhotoAnswer, RevokeRole, SetEventPhase, StartTrack,
+    ToggleFavoriteCharacter, UploadMedia, UploadPhotoAnswer, ValidateTaskGraph,
 };
+use crate::bot::command_list::register_commands;
 use crate::bot::dispatcher::BotDispatcher;
+use crate::bot::fsm::StateSerializer;
+use crate::bot::i18n;
+use crate::bot::staff_chat::StaffChat;
+use crate::config::Config;
 use crate::infra::postgres::PostgresRepository;
 use crate::utils::postgres::pool;
 
 mod app;
 mod bot;
+mod config;
 mod domain;
+mod http;
 mod infra;
 mod utils;
 
@@ -25,51 +36,183 @@ mod utils;
 async fn main() {
     pretty_env_logger::init();
 
-    let uri = env::var("DATABASE_URI").expect("DATABASE_URI must be set");
-    let pool =
-        pool::connect(&uri).expect(format!("unable to connect to database: {}", uri).as_str());
+    let config = Config::load().unwrap_or_else(|err| panic!("{err}"));
+    i18n::init(&config.locales.dir).unwrap_or_else(|err| panic!("{err}"));
+    let uri = &config.database.uri;
+    let pool = pool::connect(uri, config.database.pool_size)
+        .expect(format!("unable to connect to database: {}", uri).as_str());
     log::info!("Connected to PostgreSQL database: {}", uri);
 
     let repos = Arc::new(PostgresRepository::new(pool));
-    let state_storage = PostgresStorage::open(&uri, 1, serializer::Json)
-        .await
-        .expect("unable to create PostgreSQL state storage");
+    let state_storage =
+        PostgresStorage::open(uri, config.database.dialogue_pool_size, StateSerializer)
+            .await
+            .expect("unable to create PostgreSQL state storage");
 
+    let check_permission = CheckPermission::new(repos.clone());
+    let get_event_phase = GetEventPhase::new(repos.clone(), config.phases.clone());
     let app = App {
-        answer_task: AnswerTask::new(repos.clone(), repos.clone(), repos.clone(), repos.clone()),
+        answer_task: AnswerTask::new(
+            repos.clone(),
+            repos.clone(),
+            repos.clone(),
+            repos.clone(),
+            repos.clone(),
+            config.normalization.normalizer(),
+        ),
+        archive_feedback: ArchiveFeedback::new(
+            repos.clone(),
+            repos.clone(),
+            check_permission.clone(),
+        ),
         cancel_reservation: CancelReservation::new(
             repos.clone(),
             repos.clone(),
             repos.clone(),
             repos.clone(),
         ),
-        check_admin: CheckAdmin::new(repos.clone()),
         check_captain: CheckCaptain::new(repos.clone()),
+        check_media_references: CheckMediaReferences::new(repos.clone(), check_permission.clone()),
+        check_permission: check_permission.clone(),
         check_registered: CheckRegistered::new(repos.clone()),
         check_started_track: CheckStartedTrack::new(repos.clone()),
+        check_task_reference: CheckTaskReference::new(repos.clone()),
+        delete_media: DeleteMedia::new(repos.clone(), repos.clone(), check_permission.clone()),
+        find_characters: FindCharacters::new(repos.clone(), repos.clone(), repos.clone()),
+        find_media: FindMedia::new(repos.clone(), check_permission.clone()),
+        get_audit_log: GetAuditLog::new(repos.clone(), check_permission.clone()),
         get_available_slot_starts: GetAvailableSlotStarts::new(repos.clone()),
         get_available_tasks: GetAvailableTasks::new(repos.clone(), repos.clone()),
-        get_character: GetCharacter::new(repos.clone(), repos.clone()),
-        get_character_names: GetCharacterNames::new(repos.clone()),
+        get_character: GetCharacter::new(
+            repos.clone(),
+            repos.clone(),
+            repos.clone(),
+            repos.clone(),
+            repos.clone(),
+        ),
+        get_character_page: GetCharacterPage::new(repos.clone(), repos.clone(), repos.clone()),
         get_completed_tasks: GetCompletedTasks::new(repos.clone(), repos.clone()),
-        get_media: GetMedia::new(repos.clone()),
-        get_player: GetPlayer::new(repos.clone(), repos.clone()),
-        get_profile: GetProfile::new(repos.clone(), repos.clone()),
-        get_task: GetTask::new(repos.clone(), repos.clone()),
+        get_dashboard: GetDashboard::new(
+            repos.clone(),
+            repos.clone(),
+            repos.clone(),
+            check_permission.clone(),
+        ),
+        get_event_phase: get_event_phase.clone(),
+        get_feedback: GetFeedback::new(repos.clone(), repos.clone(), check_permission.clone()),
+        get_feedback_inbox: GetFeedbackInbox::new(
+            repos.clone(),
+            repos.clone(),
+            check_permission.clone(),
+        ),
+        get_media: GetMedia::new(repos.clone(), check_permission.clone()),
+        get_media_content: GetMediaContent::new(repos.clone()),
+        get_media_usages: GetMediaUsages::new(repos.clone(), check_permission.clone()),
+        get_player: GetPlayer::new(repos.clone(), repos.clone(), get_event_phase.clone()),
+        get_profile: GetProfile::new(repos.clone(), repos.clone(), repos.clone()),
+        get_slots: GetSlots::new(repos.clone(), repos.clone(), check_permission.clone()),
+        get_staff: GetStaff::new(repos.clone(), repos.clone(), check_permission.clone()),
+        get_task: GetTask::new(repos.clone(), repos.clone(), repos.clone()),
+        get_team_progress: GetTeamProgress::new(
+            repos.clone(),
+            repos.clone(),
+            check_permission.clone(),
+        ),
         get_team_reserved_slot: GetTeamReservedSlot::new(repos.clone(), repos.clone()),
-        get_available_tracks: GetAvailableTracks::new(repos.clone()),
-        get_team_with_members: GetTeamWithMembers::new(repos.clone(), repos.clone()),
+        get_available_tracks: GetAvailableTracks::new(repos.clone(), repos.clone()),
+        get_team_with_members: GetTeamWithMembers::new(
+            repos.clone(),
+            repos.clone(),
+            config.teams.max_size,
+            check_permission.clone(),
+        ),
+        get_teams: GetTeams::new(repos.clone(), check_permission.clone()),
         get_track_in_progress: GetTrackInProgress::new(repos.clone(), repos.clone(), repos.clone()),
         get_user: GetUser::new(repos.clone()),
-        get_user_team: GetUserTeam::new(repos.clone()),
+        get_user_team: GetUserTeam::new(repos.clone(), config.teams.max_size),
         give_feedback: GiveFeedback::new(repos.clone()),
-        reserve_slot: ReserveSlot::new(repos.clone(), repos.clone(), repos.clone(), repos.clone()),
+        grant_role: GrantRole::new(
+            repos.clone(),
+            repos.clone(),
+            repos.clone(),
+            check_permission.clone(),
+        ),
+        refresh_media_file_id: RefreshMediaFileID::new(repos.clone()),
+        rename_media: RenameMedia::new(repos.clone(), repos.clone(), check_permission.clone()),
+        render_task_graph: RenderTaskGraph::new(repos.clone(), check_permission.clone()),
+        reply_to_feedback: ReplyToFeedback::new(
+            repos.clone(),
+            repos.clone(),
+            check_permission.clone(),
+        ),
+        reserve_slot: ReserveSlot::new(
+            repos.clone(),
+            repos.clone(),
+            repos.clone(),
+            repos.clone(),
+            config.event.clone(),
+        ),
+        reset_dialogue: ResetDialogue::new(repos.clone(), repos.clone(), check_permission.clone()),
+        review_photo_answer: ReviewPhotoAnswer::new(
+            repos.clone(),
+            repos.clone(),
+            repos.clone(),
+            repos.clone(),
+            repos.clone(),
+            check_permission.clone(),
+        ),
+        revoke_role: RevokeRole::new(repos.clone(), repos.clone(), check_permission.clone()),
+        set_event_phase: SetEventPhase::new(
+            repos.clone(),
+            repos.clone(),
+            get_event_phase.clone(),
+            check_permission.clone(),
+        ),
         start_track: StartTrack::new(repos.clone(), repos.clone(), repos.clone(), repos.clone()),
-        upload_media: UploadMedia::new(repos.clone()),
+        toggle_favorite_character: ToggleFavoriteCharacter::new(repos.clone(), repos.clone()),
+        upload_media: UploadMedia::new(repos.clone(), repos.clone(), check_permission.clone()),
+        upload_photo_answer: UploadPhotoAnswer::new(repos.clone()),
+        validate_task_graph: ValidateTaskGraph::new(repos.clone(), check_permission.clone()),
     };
 
-    let bot = Bot::from_env();
-    let mut dispatcher = BotDispatcher::create(bot, app, state_storage).await;
+    if config.features.validate_task_graph_on_start {
+        match app.validate_task_graph.validate().await {
+            Ok(issues) if issues.is_empty() => log::info!("Task dependency graph is valid"),
+            Ok(issues) => {
+                for issue in issues {
+                    log::warn!("Task dependency graph: {}", issue);
+                }
+            }
+            Err(err) => log::error!("Unable to validate task dependency graph: {}", err),
+        }
+    }
+
+    let staff_chat_id = config
+        .staff_chat
+        .chat_id
+        .filter(|_| config.features.staff_notifications)
+        .map(ChatId);
+    if staff_chat_id.is_none() {
+        log::warn!("Staff chat is not configured or disabled, staff notifications are off");
+    }
+
+    if config.http.enabled {
+        let http_config = config.http.clone();
+        let app = app.clone();
+        tokio::spawn(async move {
+            if let Err(err) = http::serve(http_config, app).await {
+                log::error!("HTTP API stopped: {}", err);
+            }
+        });
+    }
+
+    let bot = Bot::new(&config.bot.token);
+    if let Err(err) = register_commands(&bot, &app.get_staff).await {
+        log::error!("Unable to register bot commands: {}", err);
+    }
+    let staff_chat = StaffChat::new(staff_chat_id, app.get_profile.clone());
+    let mut dispatcher =
+        BotDispatcher::create(bot, app, state_storage, staff_chat, Arc::new(config)).await;
 
     dispatcher.dispatch().await;
 }
@@ -13,14 +13,14 @@ pub enum ConnectionError {
     BuildError(#[from] deadpool_postgres::BuildError),
 }
 
-pub fn connect(uri: &str) -> Result<Pool, ConnectionError> {
+pub fn connect(uri: &str, max_size: usize) -> Result<Pool, ConnectionError> {
     let config = Config::from_str(uri)?;
     let manager = ManagerConfig {
         recycling_method: RecyclingMethod::Fast,
     };
 
     let manager = Manager::from_config(config, NoTls, manager);
-    let pool = Pool::builder(manager).max_size(16).build()?;
+    let pool = Pool::builder(manager).max_size(max_size).build()?;
 
     Ok(pool)
 }